@@ -0,0 +1,81 @@
+//! Parsing a 50,000-record listing: straight into the typed envelope, as the
+//! client does, against the former detour through `serde_json::Value`.
+//!
+//! ```sh
+//! cargo bench --bench deserialize
+//! cargo bench --bench deserialize --features simd-json
+//! ```
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use hetzner::{Record, RecordsEnvelope};
+use serde_json::{Value, json};
+use std::hint::black_box;
+
+const RECORDS: usize = 50_000;
+
+fn listing_body(records: usize) -> Vec<u8> {
+    let records: Vec<Value> = (0..records)
+        .map(|i| {
+            json!({
+                "id": format!("rec-{i}"),
+                "name": format!("host-{i}"),
+                "ttl": 300,
+                "type": "A",
+                "value": format!("192.0.2.{}", i % 256),
+                "zone_id": "zone-1",
+                "created": "2024-01-01 00:00:00.000 +0000 UTC",
+                "modified": "2024-01-01 00:00:00.000 +0000 UTC",
+            })
+        })
+        .collect();
+    serde_json::to_vec(&json!({ "records": records })).unwrap()
+}
+
+fn via_value(body: &[u8]) -> Vec<Record> {
+    let mut response: Value = serde_json::from_slice(body).unwrap();
+    response
+        .as_object_mut()
+        .unwrap()
+        .remove("records")
+        .unwrap()
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|record| serde_json::from_value(record.clone()).unwrap())
+        .collect()
+}
+
+fn typed(body: &[u8]) -> Vec<Record> {
+    serde_json::from_slice::<RecordsEnvelope>(body)
+        .unwrap()
+        .records
+}
+
+#[cfg(feature = "simd-json")]
+fn typed_simd(body: &[u8]) -> Vec<Record> {
+    let mut scratch = body.to_vec();
+    simd_json::serde::from_slice::<RecordsEnvelope>(&mut scratch)
+        .unwrap()
+        .records
+}
+
+fn records_listing(c: &mut Criterion) {
+    let body = listing_body(RECORDS);
+    let mut group = c.benchmark_group("records_listing");
+    group.throughput(Throughput::Bytes(body.len() as u64));
+    group.sample_size(20);
+    group.bench_with_input(BenchmarkId::new("via_value", RECORDS), &body, |b, body| {
+        b.iter(|| via_value(black_box(body)))
+    });
+    group.bench_with_input(BenchmarkId::new("typed", RECORDS), &body, |b, body| {
+        b.iter(|| typed(black_box(body)))
+    });
+    #[cfg(feature = "simd-json")]
+    group.bench_with_input(BenchmarkId::new("typed_simd", RECORDS), &body, |b, body| {
+        b.iter(|| typed_simd(black_box(body)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, records_listing);
+criterion_main!(benches);
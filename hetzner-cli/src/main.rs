@@ -0,0 +1,367 @@
+use clap::{Parser, Subcommand};
+use dotenv::dotenv;
+use hetzner::api::dns::backup::ZoneBackup;
+use hetzner::table::{TableOptions, TableRow, TableStyle, render_table};
+use hetzner::{
+    AcmeChallenge, AcmeHookAction, HetznerClient, Record, RecordFilter, RecordType, Sort, SortKey,
+};
+use std::ops::Bound;
+use std::process::ExitCode;
+use tracing::{error, info};
+use tracing_subscriber::EnvFilter;
+
+#[cfg(feature = "tui")]
+mod tui;
+
+#[derive(Debug, Parser)]
+#[command(name = "hetzner-dns", about = "Hetzner DNS command line tools")]
+struct Cli {
+    #[arg(long, env = "HETZNER_DNS_API_TOKEN", hide_env_values = true)]
+    token: String,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Present or clean up an ACME DNS-01 challenge.
+    ///
+    /// Without arguments the certbot manual hook environment
+    /// (CERTBOT_DOMAIN, CERTBOT_VALIDATION) is used; otherwise the lego exec
+    /// provider arguments `present|cleanup <fqdn> <value>` are expected.
+    AcmeHook {
+        /// Force the cleanup action when running as a certbot hook.
+        #[arg(long)]
+        cleanup: bool,
+
+        #[arg(trailing_var_arg = true)]
+        args: Vec<String>,
+    },
+
+    /// Print a dnscontrol `dnsconfig.js` for the given zones, or for every
+    /// zone of the account.
+    Dnscontrol {
+        /// Zone names or ids.
+        zones: Vec<String>,
+    },
+
+    /// List a zone's records, optionally filtered.
+    Records {
+        /// Zone name or id.
+        zone: String,
+
+        /// Only records of this type; repeat for several types.
+        #[arg(long = "type", value_name = "TYPE")]
+        types: Vec<RecordType>,
+
+        /// Glob over record names, e.g. `*.staging`.
+        #[arg(long, conflicts_with = "name_regex")]
+        name: Option<String>,
+
+        /// Regular expression over record names.
+        #[arg(long)]
+        name_regex: Option<String>,
+
+        /// Only records with an explicit TTL of at least this, e.g. `300` or `5m`.
+        #[arg(long)]
+        min_ttl: Option<hetzner::Ttl>,
+
+        /// Only records with an explicit TTL of at most this.
+        #[arg(long)]
+        max_ttl: Option<hetzner::Ttl>,
+
+        /// Only records whose value contains this text.
+        #[arg(long)]
+        value: Option<String>,
+
+        /// Order the output by name, type, ttl or modified instead of creation order.
+        #[arg(long, value_name = "KEY")]
+        sort: Option<SortKey>,
+
+        /// Reverse the `--sort` order.
+        #[arg(long, requires = "sort")]
+        desc: bool,
+
+        /// Print an aligned table with a header instead of tab-separated lines.
+        #[arg(long)]
+        table: bool,
+
+        /// Only these table columns, in this order, e.g. `name,type,value`.
+        #[arg(long, value_delimiter = ',', requires = "table")]
+        columns: Vec<String>,
+
+        /// Wrap table cells so no line is wider than this.
+        #[arg(long, requires = "table")]
+        width: Option<u16>,
+    },
+
+    /// Browse zones and records interactively.
+    #[cfg(feature = "tui")]
+    Tui,
+
+    /// Accept authenticated `POST /update` requests from routers or CI jobs
+    /// and point the allowed address records at the posted address.
+    #[cfg(feature = "webhook-server")]
+    Serve {
+        #[arg(long, default_value = "0.0.0.0:8053")]
+        listen: std::net::SocketAddr,
+
+        /// Bearer token callers must send.
+        #[arg(long, env = "HETZNER_WEBHOOK_TOKEN", hide_env_values = true)]
+        webhook_token: String,
+
+        /// A record callers may update, as `zone/name`; repeat for several.
+        #[arg(long = "allow", value_name = "ZONE/NAME", required = true)]
+        allow: Vec<String>,
+
+        /// TTL for created or updated records, e.g. `300` or `5m`.
+        #[arg(long)]
+        ttl: Option<hetzner::Ttl>,
+    },
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    dotenv().ok();
+
+    let cli = Cli::parse();
+    // Log lines would draw over the alternate screen, so the TUI runs without them.
+    #[cfg(feature = "tui")]
+    if !matches!(cli.command, Command::Tui) {
+        init_tracing();
+    }
+    #[cfg(not(feature = "tui"))]
+    init_tracing();
+
+    let client = HetznerClient::new(cli.token);
+
+    match cli.command {
+        Command::AcmeHook { cleanup, args } => acme_hook(&client, cleanup, &args).await,
+        Command::Dnscontrol { zones } => match dnscontrol(&client, &zones).await {
+            Ok(config) => {
+                print!("{config}");
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                error!("failed to export zones: {err}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Records {
+            zone,
+            types,
+            name,
+            name_regex,
+            min_ttl,
+            max_ttl,
+            value,
+            sort,
+            desc,
+            table,
+            columns,
+            width,
+        } => {
+            let mut filter = RecordFilter::new();
+            for record_type in types {
+                filter = filter.record_type(record_type);
+            }
+            if let Some(name) = name {
+                filter = filter.name_glob(&name);
+            }
+            if let Some(pattern) = name_regex {
+                filter = match filter.name_regex(&pattern) {
+                    Ok(filter) => filter,
+                    Err(err) => {
+                        error!("invalid --name-regex: {err}");
+                        return ExitCode::from(2);
+                    }
+                };
+            }
+            if min_ttl.is_some() || max_ttl.is_some() {
+                filter = filter.ttl_range((
+                    min_ttl.map_or(Bound::Unbounded, Bound::Included),
+                    max_ttl.map_or(Bound::Unbounded, Bound::Included),
+                ));
+            }
+            if let Some(value) = value {
+                filter = filter.value_contains(value);
+            }
+            let sort = sort.map(|key| {
+                let sort = Sort::by(key);
+                if desc { sort.descending() } else { sort }
+            });
+            if let Some(column) = columns.iter().find(|column| {
+                !Record::HEADERS
+                    .iter()
+                    .any(|header| header.eq_ignore_ascii_case(column))
+            }) {
+                error!(
+                    "unknown --columns entry {column:?}; expected {}",
+                    Record::HEADERS.join(", ").to_lowercase()
+                );
+                return ExitCode::from(2);
+            }
+            let table = table.then(|| {
+                let mut options = TableOptions::new().style(TableStyle::Plain);
+                if !columns.is_empty() {
+                    options = options.columns(columns);
+                }
+                if let Some(width) = width {
+                    options = options.max_width(width);
+                }
+                options
+            });
+            list_records(&client, &zone, &filter, sort, table.as_ref()).await
+        }
+        #[cfg(feature = "tui")]
+        Command::Tui => match tui::run(&client).await {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("tui failed: {err}");
+                ExitCode::FAILURE
+            }
+        },
+        #[cfg(feature = "webhook-server")]
+        Command::Serve {
+            listen,
+            webhook_token,
+            allow,
+            ttl,
+        } => serve(client, listen, webhook_token, &allow, ttl).await,
+    }
+}
+
+#[cfg(feature = "webhook-server")]
+async fn serve(
+    client: HetznerClient,
+    listen: std::net::SocketAddr,
+    token: String,
+    allow: &[String],
+    ttl: Option<hetzner::Ttl>,
+) -> ExitCode {
+    let mut server = match hetzner::WebhookServer::new(client, token) {
+        Ok(server) => server.ttl(ttl),
+        Err(err) => {
+            error!("{err}");
+            return ExitCode::from(2);
+        }
+    };
+    for entry in allow {
+        let Some((zone, name)) = entry.split_once('/') else {
+            error!(entry, "expected --allow zone/name");
+            return ExitCode::from(2);
+        };
+        server = server.allow(zone, name);
+    }
+    let listener = match tokio::net::TcpListener::bind(listen).await {
+        Ok(listener) => listener,
+        Err(err) => {
+            error!(%listen, "failed to listen: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    info!(%listen, "serving webhook updates");
+    match server.serve(listener).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            error!("webhook server failed: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn acme_hook(client: &HetznerClient, force_cleanup: bool, args: &[String]) -> ExitCode {
+    let parsed = if args.is_empty() {
+        AcmeChallenge::from_certbot_env()
+    } else {
+        AcmeChallenge::from_lego_args(args)
+    };
+
+    let Some((action, challenge)) = parsed else {
+        error!(
+            "expected CERTBOT_DOMAIN/CERTBOT_VALIDATION or `present|cleanup <fqdn> <value>` arguments"
+        );
+        return ExitCode::from(2);
+    };
+
+    let action = if force_cleanup {
+        AcmeHookAction::Cleanup
+    } else {
+        action
+    };
+
+    let result = match action {
+        AcmeHookAction::Present => client.dns().acme_present(&challenge).await.map(|_| ()),
+        AcmeHookAction::Cleanup => client.dns().acme_cleanup(&challenge).await.map(|_| ()),
+    };
+
+    match result {
+        Ok(()) => {
+            info!(fqdn = %challenge.fqdn, ?action, "acme hook finished");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            error!(fqdn = %challenge.fqdn, ?action, "acme hook failed: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn dnscontrol(client: &HetznerClient, zones: &[String]) -> hetzner::Result<String> {
+    if zones.is_empty() {
+        return client.dns().export_dnscontrol().await;
+    }
+    let mut backups = Vec::new();
+    for zone in zones {
+        let zone = client.dns().zone(zone).get().await?.zone;
+        let records = client.dns().records(&zone.id).list().await?;
+        backups.push(ZoneBackup { zone, records });
+    }
+    Ok(hetzner::api::dns::dnscontrol::render_config(&backups))
+}
+
+async fn list_records(
+    client: &HetznerClient,
+    zone: &str,
+    filter: &RecordFilter,
+    sort: Option<Sort>,
+    table: Option<&TableOptions>,
+) -> ExitCode {
+    match client.dns().records(zone).list_filtered(filter).await {
+        Ok(mut records) => {
+            if let Some(sort) = sort {
+                sort.records(&mut records);
+            }
+            if let Some(options) = table {
+                print!("{}", render_table(&records, options));
+                return ExitCode::SUCCESS;
+            }
+            for record in records {
+                let ttl = record
+                    .ttl
+                    .map_or_else(|| "-".to_string(), |ttl| ttl.seconds().to_string());
+                println!(
+                    "{}\t{}\t{}\t{}\t{}",
+                    record.id, record.name, record.record_type, ttl, record.value
+                );
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            error!(zone, "failed to list records: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn init_tracing() {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let subscriber = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr);
+    #[cfg(feature = "json-logs")]
+    subscriber.json().init();
+    #[cfg(not(feature = "json-logs"))]
+    subscriber.init();
+}
@@ -0,0 +1,286 @@
+use hetzner::api::dns::records::UpdateRecordInput;
+use hetzner::types::DEFAULT_ZONE_TTL;
+use hetzner::{HetznerClient, Record, Ttl, Zone};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Style, Stylize};
+use ratatui::widgets::{Block, List, ListState, Paragraph, Row, Table, TableState};
+use ratatui::{DefaultTerminal, Frame};
+use std::io;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditField {
+    Ttl,
+    Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Mode {
+    Zones,
+    Records,
+    Search,
+    Edit(EditField),
+    Confirm(EditField),
+}
+
+struct App {
+    zones: Vec<Zone>,
+    zone_state: ListState,
+    records: Vec<Record>,
+    zone_ttl: Ttl,
+    record_state: TableState,
+    mode: Mode,
+    query: String,
+    input: String,
+    status: String,
+}
+
+impl App {
+    fn visible_records(&self) -> Vec<&Record> {
+        let query = self.query.to_ascii_lowercase();
+        self.records
+            .iter()
+            .filter(|record| {
+                query.is_empty()
+                    || record.name.to_ascii_lowercase().contains(&query)
+                    || record.value.to_ascii_lowercase().contains(&query)
+                    || record.record_type.to_ascii_lowercase() == query
+            })
+            .collect()
+    }
+
+    fn selected_record(&self) -> Option<Record> {
+        let index = self.record_state.selected()?;
+        self.visible_records()
+            .get(index)
+            .map(|record| (*record).clone())
+    }
+}
+
+pub async fn run(client: &HetznerClient) -> io::Result<()> {
+    let zones = client
+        .dns()
+        .list_zones()
+        .await
+        .map_err(|err| io::Error::other(err.to_string()))?;
+
+    let mut app = App {
+        zone_state: ListState::default().with_selected((!zones.is_empty()).then_some(0)),
+        zones,
+        records: Vec::new(),
+        zone_ttl: DEFAULT_ZONE_TTL,
+        record_state: TableState::default(),
+        mode: Mode::Zones,
+        query: String::new(),
+        input: String::new(),
+        status: "enter: open zone  q: quit".to_string(),
+    };
+
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, client, &mut app).await;
+    ratatui::restore();
+    result
+}
+
+async fn event_loop(
+    terminal: &mut DefaultTerminal,
+    client: &HetznerClient,
+    app: &mut App,
+) -> io::Result<()> {
+    loop {
+        terminal.draw(|frame| draw(frame, app))?;
+
+        // Reading blocks until a key is pressed, so keep it off the runtime's
+        // worker threads.
+        let event = tokio::task::spawn_blocking(event::read)
+            .await
+            .map_err(io::Error::other)??;
+        let Event::Key(key) = event else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match app.mode.clone() {
+            Mode::Zones => match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Down | KeyCode::Char('j') => app.zone_state.select_next(),
+                KeyCode::Up | KeyCode::Char('k') => app.zone_state.select_previous(),
+                KeyCode::Enter => open_zone(client, app).await,
+                _ => {}
+            },
+            Mode::Records => match key.code {
+                KeyCode::Char('q') => return Ok(()),
+                KeyCode::Esc => {
+                    app.mode = Mode::Zones;
+                    app.query.clear();
+                    app.status = "enter: open zone  q: quit".to_string();
+                }
+                KeyCode::Down | KeyCode::Char('j') => app.record_state.select_next(),
+                KeyCode::Up | KeyCode::Char('k') => app.record_state.select_previous(),
+                KeyCode::Char('/') => app.mode = Mode::Search,
+                KeyCode::Char('t') => start_edit(app, EditField::Ttl),
+                KeyCode::Char('v') => start_edit(app, EditField::Value),
+                _ => {}
+            },
+            Mode::Search => match key.code {
+                KeyCode::Enter | KeyCode::Esc => {
+                    app.mode = Mode::Records;
+                    app.record_state.select(Some(0));
+                }
+                KeyCode::Backspace => {
+                    app.query.pop();
+                }
+                KeyCode::Char(c) => app.query.push(c),
+                _ => {}
+            },
+            Mode::Edit(field) => match key.code {
+                KeyCode::Esc => app.mode = Mode::Records,
+                KeyCode::Enter => {
+                    app.mode = Mode::Confirm(field);
+                    app.status = "apply change? y/n".to_string();
+                }
+                KeyCode::Backspace => {
+                    app.input.pop();
+                }
+                KeyCode::Char(c) => app.input.push(c),
+                _ => {}
+            },
+            Mode::Confirm(field) => match key.code {
+                KeyCode::Char('y') => apply_edit(client, app, field).await,
+                KeyCode::Char('n') | KeyCode::Esc => {
+                    app.mode = Mode::Records;
+                    app.status = "change discarded".to_string();
+                }
+                _ => {}
+            },
+        }
+    }
+}
+
+async fn open_zone(client: &HetznerClient, app: &mut App) {
+    let Some(zone) = app.zone_state.selected().and_then(|i| app.zones.get(i)) else {
+        return;
+    };
+
+    match client.dns().records(&zone.id).list().await {
+        Ok(records) => {
+            app.status = format!(
+                "{}: {} records  /: search  t: edit ttl  v: edit value  esc: back",
+                zone.name,
+                records.len()
+            );
+            app.records = records;
+            app.zone_ttl = zone.default_ttl();
+            app.record_state.select(Some(0));
+            app.mode = Mode::Records;
+        }
+        Err(err) => app.status = format!("failed to load records: {err}"),
+    }
+}
+
+fn start_edit(app: &mut App, field: EditField) {
+    let Some(record) = app.selected_record() else {
+        return;
+    };
+    app.input = match field {
+        EditField::Ttl => record.ttl.unwrap_or(app.zone_ttl).seconds().to_string(),
+        EditField::Value => record.value,
+    };
+    app.mode = Mode::Edit(field);
+}
+
+async fn apply_edit(client: &HetznerClient, app: &mut App, field: EditField) {
+    app.mode = Mode::Records;
+    let Some(record) = app.selected_record() else {
+        return;
+    };
+
+    let mut input = UpdateRecordInput::from(&record);
+    match field {
+        EditField::Ttl if app.input.trim().eq_ignore_ascii_case("default") => {
+            input = input.ttl(None);
+        }
+        EditField::Ttl => match app.input.parse::<Ttl>() {
+            Ok(ttl) => input = input.ttl(ttl),
+            Err(err) => {
+                app.status = err.to_string();
+                return;
+            }
+        },
+        EditField::Value => input.value = app.input.clone(),
+    }
+
+    match client.dns().record(&record.id).update(input).await {
+        Ok(updated) => {
+            if let Some(slot) = app.records.iter_mut().find(|r| r.id == record.id) {
+                *slot = updated.record;
+            }
+            app.status = format!("updated {} {}", record.name, record.record_type);
+        }
+        Err(err) => app.status = format!("update failed: {err}"),
+    }
+}
+
+fn draw(frame: &mut Frame, app: &mut App) {
+    let [main, footer] =
+        Layout::vertical([Constraint::Min(1), Constraint::Length(3)]).areas(frame.area());
+
+    match app.mode {
+        Mode::Zones => {
+            let items = app
+                .zones
+                .iter()
+                .map(|zone| format!("{}  ({} records)", zone.name, zone.records_count));
+            let list = List::new(items)
+                .block(Block::bordered().title("Zones"))
+                .highlight_style(Style::new().reversed())
+                .highlight_symbol("> ");
+            frame.render_stateful_widget(list, main, &mut app.zone_state);
+        }
+        _ => {
+            let rows = app
+                .visible_records()
+                .into_iter()
+                .map(|record| {
+                    Row::new(vec![
+                        record.name.clone(),
+                        record.record_type.clone(),
+                        record
+                            .ttl
+                            .map_or_else(|| "default".to_string(), |ttl| ttl.seconds().to_string()),
+                        record.value.clone(),
+                    ])
+                })
+                .collect::<Vec<_>>();
+            let title = if app.query.is_empty() {
+                "Records".to_string()
+            } else {
+                format!("Records matching \"{}\"", app.query)
+            };
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Percentage(25),
+                    Constraint::Length(7),
+                    Constraint::Length(8),
+                    Constraint::Fill(1),
+                ],
+            )
+            .header(Row::new(vec!["Name", "Type", "TTL", "Value"]).bold())
+            .block(Block::bordered().title(title))
+            .row_highlight_style(Style::new().reversed());
+            frame.render_stateful_widget(table, main, &mut app.record_state);
+        }
+    }
+
+    let footer_text = match &app.mode {
+        Mode::Search => format!("search: {}", app.query),
+        Mode::Edit(EditField::Ttl) => format!("new ttl: {}", app.input),
+        Mode::Edit(EditField::Value) => format!("new value: {}", app.input),
+        Mode::Confirm(_) => format!("{} -> {}", app.status, app.input),
+        _ => app.status.clone(),
+    };
+    frame.render_widget(Paragraph::new(footer_text).block(Block::bordered()), footer);
+}
@@ -1,12 +1,20 @@
 #!/usr/bin/env node
 /**
  * Generates integration tests for all Hetzner Cloud API operations.
- * Each operation gets its own #[tokio::test]. Tests hit the real API when
- * HETZNER_CLOUD_API_TOKEN is set.
+ * Each operation gets two tests:
+ * - `test_<op>` seeds a `hetzner::testing::FakeCloudApi` with the resources
+ *   the operation touches, calls it with their ids and checks the response,
+ *   so it is part of a plain `cargo test`;
+ * - `live_test_<op>` calls the real API with placeholder ids and accepts the
+ *   API errors those provoke. It is ignored by default; run it with
+ *   `cargo test -- --ignored` and HETZNER_CLOUD_API_TOKEN set.
+ *
+ * The output is run through rustfmt, so `cargo fmt` leaves it unchanged.
  *
  * Usage: node scripts/generate_cloud_integration_tests.mjs [specPath] [outPath]
  */
 
+import { execFileSync } from 'node:child_process';
 import { readFileSync, writeFileSync } from 'node:fs';
 
 const specPath = process.argv[2] ?? 'hetzner-cloud-openapi.json';
@@ -16,6 +24,8 @@ const spec = JSON.parse(readFileSync(specPath, 'utf8'));
 const paths = spec.paths ?? {};
 const methods = ['get', 'post', 'put', 'patch', 'delete'];
 
+const IGNORE_REASON = 'hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN';
+
 // Operations that return 410 deprecated or require special handling - skip
 const SKIP_OPERATIONS = new Set([
   'get_actions', // 410 deprecated - requires id query, list endpoint removed
@@ -63,20 +73,219 @@ function placeholderForParam(paramName, path) {
 }
 
 const lines = [];
-lines.push('//! Integration tests against the real Hetzner Cloud API.');
-lines.push('//! Set HETZNER_CLOUD_API_TOKEN to run these tests.');
+lines.push('//! Integration tests for every Hetzner Cloud API operation.');
+lines.push('//! `test_<op>` seeds a `FakeCloudApi`, calls the operation with the seeded');
+lines.push('//! ids and checks the response. `live_test_<op>` runs it against the real');
+lines.push('//! API and is ignored by default; set HETZNER_CLOUD_API_TOKEN and run');
+lines.push('//! `cargo test --test cloud_integration_test -- --ignored`.');
 lines.push('//!');
 lines.push('//! Auto-generated by scripts/generate_cloud_integration_tests.mjs');
 lines.push('');
 lines.push('use hetzner::HetznerClient;');
+lines.push('use hetzner::testing::FakeCloudApi;');
+lines.push('use serde_json::{Value, json};');
 lines.push('use std::env;');
 lines.push('');
-lines.push('fn api_token() -> Option<String> {');
+lines.push('fn live_client() -> Option<HetznerClient> {');
 lines.push('    dotenv::dotenv().ok();');
 lines.push('    dotenv::from_filename(".env.local").ok();');
-lines.push('    env::var("HETZNER_CLOUD_API_TOKEN").ok()');
+lines.push('    env::var("HETZNER_CLOUD_API_TOKEN").ok().map(HetznerClient::new)');
+lines.push('}');
+lines.push('');
+lines.push('/// Adds a resource called `name` to `collection` and returns its id.');
+lines.push('fn seed(fake: &FakeCloudApi, collection: &str, name: &str) -> u64 {');
+lines.push('    fake.add(collection, json!({ "name": name }))["id"].as_u64().unwrap()');
 lines.push('}');
 lines.push('');
+lines.push('/// Adds a finished action on the resource `id` and returns its id.');
+lines.push('fn seed_action(fake: &FakeCloudApi, collection: &str, id: u64) -> u64 {');
+lines.push('    fake.add_action(collection, id, "fake_action")["id"].as_u64().unwrap()');
+lines.push('}');
+lines.push('');
+lines.push('fn ids(items: &Value) -> Vec<Value> {');
+lines.push('    items.as_array().unwrap().iter().map(|item| item["id"].clone()).collect()');
+lines.push('}');
+lines.push('');
+
+/** `servers` → `server`, the key the fake wraps a single resource in. */
+function singular(collection) {
+  return collection.endsWith('s') ? collection.slice(0, -1) : collection;
+}
+
+function seedName(collection) {
+  return collection === 'zones' ? 'example.com' : `fake-${singular(collection)}`;
+}
+
+const RRSET_BODY = 'json!({ "name": "www", "type": "A", "records": [{ "value": "192.0.2.1" }] })';
+
+/**
+ * The body of `test_<op>`: seeding, the call with the seeded ids, and the
+ * checks on the response and on the fake's state afterwards.
+ */
+function fakeTest(verb, path, fnName, operationId) {
+  const segments = path.split('/').filter(Boolean);
+  const [collection] = segments;
+  const one = singular(collection);
+  const seedLines = [];
+  const checks = [];
+  let args = [];
+  let body = 'None';
+  const seedResource = (c = collection) =>
+    seedLines.push(`let id = seed(&fake, "${c}", "${seedName(c)}");`);
+  const seedAction = (c = collection) => {
+    seedResource(c);
+    seedLines.push(`let action_id = seed_action(&fake, "${c}", id);`);
+  };
+  const checkAction = () => {
+    checks.push('assert_eq!(body["action"]["id"], action_id);');
+    checks.push('assert_eq!(body["action"]["command"], "fake_action");');
+  };
+  const shape = segments.map((s) => (s.startsWith('{') ? '{}' : s));
+  const isParam = (i) => shape[i] === '{}';
+  const rrsetSeed = () => {
+    seedResource('zones');
+    seedLines.push('fake.add_rrset(id, "www", "A", &["192.0.2.1"]);');
+  };
+
+  if (collection === 'pricing') {
+    checks.push('assert_eq!(body["pricing"]["currency"], "EUR");');
+  } else if (segments.length === 1 && verb === 'get') {
+    seedResource();
+    checks.push(`assert_eq!(ids(&body["${collection}"]), [json!(id)]);`);
+  } else if (segments.length === 1 && verb === 'post') {
+    body = `Some(json!({ "name": "new-${one}" }))`;
+    checks.push(`assert_eq!(body["${one}"]["name"], "new-${one}");`);
+    checks.push(`assert_eq!(fake.resources("${collection}").len(), 1);`);
+  } else if (segments.length === 2 && segments[1] === 'actions') {
+    seedAction();
+    checks.push(`assert_eq!(ids(&body["actions"]), [json!(action_id)]);`);
+  } else if (segments.length === 3 && segments[1] === 'actions') {
+    seedAction();
+    args = ['action_id'];
+    checkAction();
+  } else if (segments.length === 2 && collection === 'actions') {
+    seedAction('servers');
+    args = ['action_id'];
+    checkAction();
+  } else if (segments.length === 2) {
+    seedResource();
+    args = ['id'];
+    if (verb === 'get') {
+      checks.push(`assert_eq!(body["${one}"]["id"], id);`);
+      checks.push(`assert_eq!(body["${one}"]["name"], "${seedName(collection)}");`);
+    } else if (verb === 'put') {
+      body = `Some(json!({ "name": "renamed-${one}" }))`;
+      checks.push(`assert_eq!(body["${one}"]["name"], "renamed-${one}");`);
+      checks.push(`assert_eq!(fake.resource("${collection}", id).unwrap()["name"], "renamed-${one}");`);
+    } else if (verb === 'delete') {
+      checks.push(`assert_eq!(body["action"]["resources"][0]["id"], id);`);
+      checks.push(`assert!(fake.resource("${collection}", id).is_none());`);
+    }
+  } else if (shape[2] === 'actions' && segments.length === 3) {
+    seedAction();
+    args = ['id'];
+    checks.push(`assert_eq!(ids(&body["actions"]), [json!(action_id)]);`);
+  } else if (shape[2] === 'actions' && isParam(3)) {
+    seedAction();
+    args = ['id', 'action_id'];
+    checkAction();
+  } else if (shape[2] === 'actions') {
+    seedResource();
+    args = ['id'];
+    body = 'Some(json!({}))';
+    checks.push(`assert_eq!(body["action"]["command"], "${segments[3]}");`);
+    checks.push(`assert_eq!(body["action"]["resources"][0]["id"], id);`);
+  } else if (shape[2] === 'metrics') {
+    seedResource();
+    args = ['id'];
+    checks.push('assert!(body["metrics"]["time_series"].is_object());');
+  } else if (shape[2] === 'zonefile') {
+    rrsetSeed();
+    args = ['id'];
+    checks.push('let zonefile = body["zonefile"].as_str().unwrap();');
+    checks.push('assert!(zonefile.contains("$ORIGIN example.com."));');
+    checks.push('assert!(zonefile.contains("www 3600 IN A 192.0.2.1"));');
+  } else if (shape[2] === 'rrsets' && segments.length === 3) {
+    args = ['id'];
+    if (verb === 'get') {
+      rrsetSeed();
+      checks.push('assert_eq!(ids(&body["rrsets"]), [json!("www/A")]);');
+    } else {
+      seedResource('zones');
+      body = `Some(${RRSET_BODY})`;
+      checks.push('assert_eq!(body["rrset"]["id"], "www/A");');
+      checks.push('assert_eq!(body["rrset"]["records"][0]["value"], "192.0.2.1");');
+      checks.push('assert_eq!(fake.resources("rrsets").len(), 1);');
+    }
+  } else if (shape[2] === 'rrsets' && segments.length === 5) {
+    rrsetSeed();
+    args = ['id', '"www"', '"A"'];
+    if (verb === 'get') {
+      checks.push('assert_eq!(body["rrset"]["id"], "www/A");');
+      checks.push('assert_eq!(body["rrset"]["zone"], id);');
+    } else if (verb === 'put') {
+      body = 'Some(json!({ "labels": { "env": "test" } }))';
+      checks.push('assert_eq!(body["rrset"]["labels"]["env"], "test");');
+      checks.push('assert_eq!(fake.resources("rrsets")[0]["labels"]["env"], "test");');
+    } else if (verb === 'delete') {
+      checks.push('assert_eq!(body["action"]["resources"][0]["id"], id);');
+      checks.push('assert!(fake.resources("rrsets").is_empty());');
+    }
+  } else if (shape[2] === 'rrsets' && shape[5] === 'actions') {
+    rrsetSeed();
+    args = ['id', '"www"', '"A"'];
+    body = 'Some(json!({}))';
+    checks.push(`assert_eq!(body["action"]["command"], "${segments[6]}");`);
+    checks.push('assert_eq!(body["action"]["resources"][0]["id"], id);');
+  } else {
+    throw new Error(`no fake test for ${verb.toUpperCase()} ${path}`);
+  }
+
+  const out = [];
+  out.push('#[tokio::test]');
+  out.push(`async fn test_${fnName}() {`);
+  out.push('    let fake = FakeCloudApi::start().await.unwrap();');
+  for (const line of seedLines) out.push(`    ${line}`);
+  const callArgs = [...args, 'None', body].join(', ');
+  out.push(`    let result = fake.client().cloud().${fnName}(${callArgs}).await;`);
+  out.push(`    assert!(result.is_ok(), "${operationId}: {:?}", result.err());`);
+  if (checks.length) {
+    out.push('    let body = result.unwrap();');
+    for (const line of checks) out.push(`    ${line}`);
+  }
+  out.push('}');
+  return out;
+}
+
+/**
+ * The body of `live_test_<op>`: the call with placeholder ids, tolerating
+ * the API errors those provoke.
+ */
+function liveTest(verb, path, fnName, operationId, pathParams) {
+  const placeholders = pathParams.map((p) => placeholderForParam(p, path));
+  const callArgs = [...placeholders, 'None', 'None'].join(', ');
+  const isWrite = ['post', 'put', 'patch', 'delete'].includes(verb);
+  // Accept: Ok, 404 (not found), invalid_input (missing params), json_error (write ops need body), service_error (API hiccup)
+  const acceptCond = isWrite
+    ? 'api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error"'
+    : 'api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error"';
+  return [
+    '#[tokio::test]',
+    `#[ignore = "${IGNORE_REASON}"]`,
+    `async fn live_test_${fnName}() {`,
+    '    let Some(client) = live_client() else {',
+    '        return;',
+    '    };',
+    `    let result = client.cloud().${fnName}(${callArgs}).await;`,
+    '    if let Err(hetzner::HetznerError::Api(api_err)) = &result',
+    `        && (${acceptCond})`,
+    '    {',
+    '        return;',
+    '    }',
+    '    assert!(result.is_ok(), "' + operationId + ': {:?}", result.err());',
+    '}',
+  ];
+}
 
 let count = 0;
 for (const path of Object.keys(paths).sort()) {
@@ -91,35 +300,14 @@ for (const path of Object.keys(paths).sort()) {
     const fnName = sanitizeName(operationId);
     const pathParams = extractPathParams(path, operation, pathItem);
 
-    const testName = `test_${fnName}`;
-    const placeholders = pathParams.map((p) => placeholderForParam(p, path));
-    const argsList = [...placeholders, 'None', 'None'];
-    const callArgs = argsList.join(', ');
-
-    lines.push(`#[tokio::test]`);
-    lines.push(`async fn ${testName}() {`);
-    lines.push(`    let token = match api_token() {`);
-    lines.push(`        Some(t) => t,`);
-    lines.push(`        None => return,`);
-    lines.push(`    };`);
-    lines.push(`    let client = HetznerClient::new(token);`);
-    lines.push(`    let result = client.cloud().${fnName}(${callArgs}).await;`);
-    const isWrite = ['post', 'put', 'patch', 'delete'].includes(verb);
-    // Accept: Ok, 404 (not found), invalid_input (missing params), json_error (write ops need body), service_error (API hiccup)
-    const acceptCond = isWrite
-      ? 'api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error"'
-      : 'api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error"';
-    lines.push(`    if let Err(e) = &result {`);
-    lines.push(`        if let hetzner::HetznerError::Api(api_err) = e {`);
-    lines.push(`            if ${acceptCond} { return; }`);
-    lines.push(`        }`);
-    lines.push(`    }`);
-    lines.push('    assert!(result.is_ok(), "' + operationId + ': {:?}", result.err());');
-    lines.push(`}`);
+    lines.push(...fakeTest(verb, path, fnName, operationId));
+    lines.push('');
+    lines.push(...liveTest(verb, path, fnName, operationId, pathParams));
     lines.push('');
     count++;
   }
 }
 
 writeFileSync(outPath, lines.join('\n'));
+execFileSync('rustfmt', ['--edition', '2024', outPath], { stdio: 'inherit' });
 console.log(`Generated ${outPath} with ${count} integration tests`);
@@ -0,0 +1,250 @@
+import { readFileSync, writeFileSync } from 'node:fs';
+
+const specPath = process.argv[2] ?? 'hetzner-cloud-openapi.json';
+const outPath = process.argv[3] ?? 'src/api/cloud/generated_models.rs';
+const testsPath = process.argv[4] ?? 'tests/cloud_generated_models_test.rs';
+
+const spec = JSON.parse(readFileSync(specPath, 'utf8'));
+const paths = spec.paths ?? {};
+
+const methods = ['get', 'post', 'put', 'patch', 'delete'];
+
+const rustKeywords = new Set([
+  'as','break','const','continue','crate','else','enum','extern','false','fn','for','if','impl','in',
+  'let','loop','match','mod','move','mut','pub','ref','return','self','Self','static','struct','super',
+  'trait','true','type','unsafe','use','where','while','async','await','dyn','abstract','become','box',
+  'do','final','macro','override','priv','typeof','unsized','virtual','yield','try'
+]);
+
+function pascal(name) {
+  return (name || '')
+    .split(/[^a-zA-Z0-9]+/)
+    .filter(Boolean)
+    .map((part) => part[0].toUpperCase() + part.slice(1))
+    .join('');
+}
+
+function singular(name) {
+  if (name.endsWith('ies')) return `${name.slice(0, -3)}y`;
+  if (name.endsWith('ses')) return name.slice(0, -2);
+  if (name.endsWith('s') && !/(ss|us)$/.test(name)) return name.slice(0, -1);
+  return name;
+}
+
+function fieldName(name) {
+  let n = name.replace(/[^a-zA-Z0-9_]/g, '_');
+  if (/^[0-9]/.test(n)) n = `_${n}`;
+  return rustKeywords.has(n) ? `r#${n}` : n;
+}
+
+function docLine(text) {
+  const line = (text || '').split(/\r?\n/)[0].trim();
+  return line.replace(/</g, '&lt;').replace(/>/g, '&gt;');
+}
+
+function resolveRef(schema) {
+  if (!schema?.$ref) return schema;
+  const name = schema.$ref.split('/').pop();
+  return spec.components?.schemas?.[name] ?? {};
+}
+
+/** Merges `allOf` parts into one object schema. */
+function flatten(schema) {
+  schema = resolveRef(schema);
+  if (!schema?.allOf) return schema;
+  const merged = { type: 'object', properties: {}, required: [] };
+  for (const part of schema.allOf.map(flatten)) {
+    Object.assign(merged.properties, part.properties ?? {});
+    merged.required.push(...(part.required ?? []));
+    if (part.nullable) merged.nullable = true;
+  }
+  if (schema.nullable) merged.nullable = true;
+  if (schema.description) merged.description = schema.description;
+  return merged;
+}
+
+function isStruct(schema) {
+  return (
+    schema &&
+    (schema.type === 'object' || (!schema.type && schema.properties)) &&
+    Object.keys(schema.properties ?? {}).length > 0
+  );
+}
+
+// name -> { signature, lines, schema }
+const models = new Map();
+const order = [];
+
+/** The struct name for `schema`: `name`, or an identical model already
+ * defined under it; on a mismatch `fallback`, then numbered variants. */
+function defineStruct(name, schema, origin, fallback = name) {
+  const fields = Object.entries(schema.properties).sort(([a], [b]) => a.localeCompare(b));
+  const required = new Set(schema.required ?? []);
+  const resolved = fields.map(([key, property]) => {
+    const child = flatten(property);
+    const optional = !required.has(key) || child.nullable;
+    return { key, child, optional, type: rustType(name, key, child, origin) };
+  });
+  const signature = JSON.stringify(resolved.map(({ key, optional, type }) => [key, optional, type]));
+
+  const candidates = [name, fallback];
+  for (let suffix = 2; suffix < 100; suffix += 1) candidates.push(`${fallback}${suffix}`);
+  let finalName;
+  for (const candidate of candidates) {
+    if (!models.has(candidate)) {
+      finalName = candidate;
+      break;
+    }
+    if (models.get(candidate).signature === signature) return candidate;
+  }
+
+  const lines = [];
+  if (schema.description) lines.push(`/// ${docLine(schema.description)}`);
+  else lines.push(`/// Generated from \`${origin}\`.`);
+  lines.push('#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]');
+  lines.push(`pub struct ${finalName} {`);
+  for (const { key, child, optional, type } of resolved) {
+    if (child.description) lines.push(`    /// ${docLine(child.description)}`);
+    const rustName = fieldName(key);
+    const attributes = [];
+    if (rustName.replace(/^r#/, '') !== key) attributes.push(`rename = "${key}"`);
+    if (optional) attributes.push('default', 'skip_serializing_if = "Option::is_none"');
+    if (attributes.length) lines.push(`    #[serde(${attributes.join(', ')})]`);
+    lines.push(`    pub ${rustName}: ${optional ? `Option<${type}>` : type},`);
+  }
+  lines.push('}');
+  models.set(finalName, { signature, lines, schema });
+  order.push(finalName);
+  return finalName;
+}
+
+/** The Rust type of property `key` of model `parent`. Nested models are
+ * named after the property (`status` -> `Status`), or `ParentStatus` when a
+ * different `Status` exists. */
+function rustType(parent, key, schema, origin) {
+  schema = flatten(schema);
+  if (schema.oneOf || schema.anyOf) return 'serde_json::Value';
+  switch (schema.type) {
+    case 'integer':
+      return 'i64';
+    case 'number':
+      return 'f64';
+    case 'boolean':
+      return 'bool';
+    case 'string':
+      // Enums stay strings, so values added to the API don't break parsing.
+      return 'String';
+    case 'array': {
+      const item = singular(key) === key ? `${key}_item` : singular(key);
+      return `Vec<${rustType(parent, item, schema.items ?? {}, origin)}>`;
+    }
+    default:
+      if (isStruct(schema)) {
+        return defineStruct(pascal(key), schema, origin, `${parent}${pascal(key)}`);
+      }
+      if (schema.additionalProperties?.type === 'string') {
+        return 'std::collections::BTreeMap<String, String>';
+      }
+      return 'serde_json::Value';
+  }
+}
+
+/** Models for an envelope's properties are named after the property
+ * (`server` -> `Server`, `servers` -> `Server`), so they are shared by all
+ * operations returning that resource. */
+function defineEnvelope(name, schema, origin) {
+  return defineStruct(name, schema, origin);
+}
+
+const operations = [];
+for (const path of Object.keys(paths).sort()) {
+  for (const verb of methods) {
+    const operation = paths[path]?.[verb];
+    if (!operation) continue;
+    const origin = `${verb.toUpperCase()} ${path}`;
+    const base = pascal(operation.operationId || `${verb}_${path}`);
+
+    const body = flatten(operation.requestBody?.content?.['application/json']?.schema);
+    if (isStruct(body)) defineStruct(`${base}Request`, body, origin);
+
+    const success = Object.entries(operation.responses ?? {}).find(([status]) => /^2/.test(status));
+    const response = flatten(success?.[1]?.content?.['application/json']?.schema);
+    if (isStruct(response)) {
+      const name = defineEnvelope(`${base}Response`, response, origin);
+      operations.push({ name, schema: response, origin });
+    }
+  }
+}
+
+/** A value matching `schema`, built from its examples. */
+function example(schema) {
+  schema = flatten(schema);
+  if (schema.example !== undefined && schema.type !== 'object' && schema.type !== 'array') {
+    return schema.example;
+  }
+  if (schema.oneOf || schema.anyOf) return example((schema.oneOf ?? schema.anyOf)[0]);
+  switch (schema.type) {
+    case 'integer':
+      return 1;
+    case 'number':
+      return 1.5;
+    case 'boolean':
+      return true;
+    case 'string':
+      return schema.enum?.[0] ?? 'string';
+    case 'array':
+      return [example(schema.items ?? {})];
+    default: {
+      if (!schema.properties) return schema.example ?? {};
+      const value = {};
+      for (const [key, property] of Object.entries(schema.properties)) {
+        value[key] = example(property);
+      }
+      return value;
+    }
+  }
+}
+
+const lines = [];
+lines.push('// This file is auto-generated by scripts/generate_cloud_models.mjs.');
+lines.push('// Do not edit manually.');
+lines.push('');
+lines.push('//! Request and response models of the Hetzner Cloud API, generated from');
+lines.push('//! its OpenAPI document. Enumerations are kept as strings so that values');
+lines.push('//! added to the API do not break parsing.');
+lines.push('');
+lines.push('use serde::{Deserialize, Serialize};');
+for (const name of order) {
+  lines.push('');
+  lines.push(...models.get(name).lines);
+}
+lines.push('');
+writeFileSync(outPath, lines.join('\n'));
+console.log(`Generated ${outPath} (${order.length} models)`);
+
+const tests = [];
+tests.push('// This file is auto-generated by scripts/generate_cloud_models.mjs.');
+tests.push('// Do not edit manually.');
+tests.push('');
+tests.push('use hetzner::api::cloud::generated_models::*;');
+tests.push('use serde_json::json;');
+tests.push('');
+tests.push('/// Every response model parses the example response of its operation and');
+tests.push('/// survives a serialization round trip.');
+tests.push('#[test]');
+tests.push('fn test_generated_models_parse_spec_examples() {');
+const seen = new Set();
+for (const { name, schema, origin } of operations) {
+  if (seen.has(name)) continue;
+  seen.add(name);
+  const value = JSON.stringify(example(schema));
+  tests.push(`    // ${origin}`);
+  tests.push(`    let value = json!(${value});`);
+  tests.push(`    let model: ${name} = serde_json::from_value(value).unwrap();`);
+  tests.push(`    let again: ${name} = serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();`);
+  tests.push('    assert_eq!(again, model);');
+}
+tests.push('}');
+tests.push('');
+writeFileSync(testsPath, tests.join('\n'));
+console.log(`Generated ${testsPath}`);
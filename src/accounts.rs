@@ -0,0 +1,89 @@
+//! Routing between several Hetzner projects, each with its own token.
+
+use crate::HetznerClient;
+use crate::error::Result;
+use futures_util::TryStreamExt;
+use std::collections::BTreeMap;
+
+/// Clients for several projects plus a map of which project owns which zone.
+///
+/// ```no_run
+/// # async fn example() -> hetzner::Result<()> {
+/// use hetzner::HetznerAccounts;
+///
+/// let mut accounts = HetznerAccounts::new()
+///     .with_project_token("customer-a", "token-a")
+///     .with_project_token("customer-b", "token-b");
+/// accounts.discover_zones().await?;
+/// let client = accounts.for_zone("www.example.com");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HetznerAccounts {
+    projects: BTreeMap<String, HetznerClient>,
+    zones: BTreeMap<String, String>,
+}
+
+impl HetznerAccounts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_project(mut self, project: impl Into<String>, client: HetznerClient) -> Self {
+        self.projects.insert(project.into(), client);
+        self
+    }
+
+    pub fn with_project_token(self, project: impl Into<String>, token: impl Into<String>) -> Self {
+        self.with_project(project, HetznerClient::new(token))
+    }
+
+    /// Routes `zone` to `project` without asking the API.
+    pub fn with_zone(mut self, zone: impl Into<String>, project: impl Into<String>) -> Self {
+        self.zones.insert(normalize(&zone.into()), project.into());
+        self
+    }
+
+    pub fn projects(&self) -> impl Iterator<Item = &str> {
+        self.projects.keys().map(String::as_str)
+    }
+
+    pub fn project(&self, project: &str) -> Option<&HetznerClient> {
+        self.projects.get(project)
+    }
+
+    /// The project owning `name`, matched against known zones by longest
+    /// suffix so record names route to their zone's project too.
+    pub fn project_for_zone(&self, name: &str) -> Option<&str> {
+        let name = normalize(name);
+        self.zones
+            .iter()
+            .filter(|(zone, _)| name == **zone || name.ends_with(&format!(".{zone}")))
+            .max_by_key(|(zone, _)| zone.len())
+            .map(|(_, project)| project.as_str())
+    }
+
+    pub fn for_zone(&self, name: &str) -> Option<&HetznerClient> {
+        self.project_for_zone(name)
+            .and_then(|project| self.project(project))
+    }
+
+    /// Lists every project's zones and routes them to that project. Mappings
+    /// added with [`with_zone`](Self::with_zone) are kept.
+    pub async fn discover_zones(&mut self) -> Result<()> {
+        for (project, client) in &self.projects {
+            let zones: Vec<_> = client.dns().zones_stream().try_collect().await?;
+            for zone in zones {
+                self.zones
+                    .entry(normalize(&zone.name))
+                    .or_insert_with(|| project.clone());
+            }
+        }
+        Ok(())
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.trim_end_matches('.').to_ascii_lowercase()
+}
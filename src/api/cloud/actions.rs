@@ -9,6 +9,12 @@ pub struct ListActionsParams {
 }
 
 impl ListActionsParams {
+    /// Adds an action id to fetch.
+    pub fn id(mut self, id: u64) -> Self {
+        self.ids.push(id);
+        self
+    }
+
     fn to_query_pairs(&self) -> Vec<(String, String)> {
         self.ids
             .iter()
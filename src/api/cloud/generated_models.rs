@@ -0,0 +1,4065 @@
+// This file is auto-generated by scripts/generate_cloud_models.mjs.
+// Do not edit manually.
+
+//! Request and response models of the Hetzner Cloud API, generated from
+//! its OpenAPI document. Enumerations are kept as strings so that values
+//! added to the API do not break parsing.
+
+use serde::{Deserialize, Serialize};
+
+/// Error message for the Action if an error occurred, otherwise null.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Error {
+    /// Fixed error code for machines.
+    pub code: String,
+    /// Error message for humans.
+    pub message: String,
+}
+
+/// Generated from `GET /actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Resource {
+    /// ID of the Resource.
+    pub id: i64,
+    /// Type of the Resource.
+    pub r#type: String,
+}
+
+/// Generated from `GET /actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Action {
+    /// Command executed in the Action.
+    pub command: String,
+    /// Error message for the Action if an error occurred, otherwise null.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<Error>,
+    /// Point in time when the Action was finished (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format). Only set if the Action is finished otherwise null.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished: Option<String>,
+    /// ID of the [Action](#description/actions).
+    pub id: i64,
+    /// Progress of the Action in percent.
+    pub progress: i64,
+    /// Resources the Action relates to.
+    pub resources: Vec<Resource>,
+    /// Point in time when the Action was started (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub started: String,
+    /// Status of the Action.
+    pub status: String,
+}
+
+/// Generated from `GET /actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetActionsResponse {
+    pub actions: Vec<Action>,
+}
+
+/// Generated from `GET /actions/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetActionResponse {
+    pub action: Action,
+}
+
+/// If issuance or renewal reports `failed`, this property contains information about what happened.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct StatusError {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Current status of a type `managed` Certificate, always *null* for type `uploaded` Certificates.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Status {
+    /// If issuance or renewal reports `failed`, this property contains information about what happened.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<StatusError>,
+    /// Status of the issuance process of the Certificate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuance: Option<String>,
+    /// Status of the renewal process of the Certificate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub renewal: Option<String>,
+}
+
+/// Generated from `GET /certificates`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UsedByItem {
+    /// ID of resource referenced.
+    pub id: i64,
+    /// Type of resource referenced.
+    pub r#type: String,
+}
+
+/// Generated from `GET /certificates`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Certificate {
+    /// Certificate and chain in PEM format, in order so that each record directly certifies the one preceding.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<String>,
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// Domains and subdomains covered by the Certificate.
+    pub domain_names: Vec<String>,
+    /// SHA256 fingerprint of the Certificate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+    /// ID of the [Certificate](#tag/certificates).
+    pub id: i64,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Name of the Resource. Must be unique per Project.
+    pub name: String,
+    /// Point in time when the Certificate stops being valid (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_valid_after: Option<String>,
+    /// Point in time when the Certificate becomes valid (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_valid_before: Option<String>,
+    /// Current status of a type `managed` Certificate, always *null* for type `uploaded` Certificates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+    /// Type of the Certificate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// Resources currently using the Certificate.
+    pub used_by: Vec<UsedByItem>,
+}
+
+/// See "[Pagination](#description/pagination)" for more information.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Pagination {
+    /// Page number of the last page available. Can be null if the current page is the last one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_page: Option<i64>,
+    /// Page number of the next page. Can be null if the current page is the last one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<i64>,
+    /// Current page number.
+    pub page: i64,
+    /// Maximum number of entries returned per page.
+    pub per_page: i64,
+    /// Page number of the previous page. Can be null if the current page is the first one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_page: Option<i64>,
+    /// Total number of entries that exist for this query. Can be null if unknown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_entries: Option<i64>,
+}
+
+/// Generated from `GET /certificates`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Meta {
+    /// See "[Pagination](#description/pagination)" for more information.
+    pub pagination: Pagination,
+}
+
+/// Generated from `GET /certificates`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListCertificatesResponse {
+    pub certificates: Vec<Certificate>,
+    pub meta: Meta,
+}
+
+/// Generated from `POST /certificates`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateCertificateRequest {
+    /// Certificate and chain in PEM format, in order so that each record directly certifies the one preceding. Required for type `uploaded` Certificates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<String>,
+    /// Domains and subdomains that should be contained in the Certificate issued by *Let's Encrypt*. Required for type `managed` Certificates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain_names: Option<Vec<String>>,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// Name of the Certificate.
+    pub name: String,
+    /// Certificate key in PEM format. Required for type `uploaded` Certificates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<String>,
+    /// Choose between uploading a Certificate in PEM format or requesting a managed *Let's Encrypt* Certificate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+/// Generated from `POST /certificates`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateCertificateResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<Action>,
+    pub certificate: Certificate,
+}
+
+/// Generated from `GET /certificates/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListCertificatesActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Generated from `GET /certificates/actions/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetCertificatesActionResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /certificates/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetCertificateResponse {
+    pub certificate: Certificate,
+}
+
+/// Generated from `PUT /certificates/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateCertificateRequest {
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// New Certificate name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Generated from `PUT /certificates/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateCertificateResponse {
+    pub certificate: Certificate,
+}
+
+/// Generated from `GET /certificates/{id}/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListCertificateActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RetryCertificateResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /certificates/{id}/actions/{action_id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetCertificateActionResponse {
+    pub action: Action,
+}
+
+/// [Location](#tag/locations) the [Data Center](#tag/data-centers) is located at.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Location {
+    /// Name of the closest city to the [Location](#tag/locations).
+    pub city: String,
+    /// Country the [Location](#tag/locations) resides in.
+    pub country: String,
+    /// Human readable description of the [Location](#tag/locations).
+    pub description: String,
+    /// ID of the [Location](#tag/locations).
+    pub id: i64,
+    /// Latitude of the city closest to the [Location](#tag/locations).
+    pub latitude: f64,
+    /// Longitude of the city closest to the [Location](#tag/locations).
+    pub longitude: f64,
+    /// Unique identifier of the [Location](#tag/locations).
+    pub name: String,
+    /// Name of the Network Zone this [Location](#tag/locations) resides in.
+    pub network_zone: String,
+}
+
+/// [Server Types](#tag/server-types) supported and available in this [Data Center](#tag/data-centers).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ServerTypes {
+    /// [Server Types](#tag/server-types) currently available in this [Data Center](#tag/data-centers).
+    pub available: Vec<i64>,
+    /// [Server Types](#tag/server-types) available to migrate to in this [Data Center](#tag/data-centers).
+    pub available_for_migration: Vec<i64>,
+    /// List of [Server Types](#tag/server-types) supported in this [Data Center](#tag/data-centers).
+    pub supported: Vec<i64>,
+}
+
+/// Generated from `GET /datacenters`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Datacenter {
+    /// Descriptive name for the [Data Center](#tag/data-centers).
+    pub description: String,
+    /// ID of the [Data Center](#tag/data-centers).
+    pub id: i64,
+    /// [Location](#tag/locations) the [Data Center](#tag/data-centers) is located at.
+    pub location: Location,
+    /// Unique name for the [Data Center](#tag/data-centers).
+    pub name: String,
+    /// [Server Types](#tag/server-types) supported and available in this [Data Center](#tag/data-centers).
+    pub server_types: ServerTypes,
+}
+
+/// Generated from `GET /datacenters`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListDatacentersResponse {
+    /// List of [Data Centers](#tag/data-centers).
+    pub datacenters: Vec<Datacenter>,
+    pub meta: Meta,
+    /// Recommended [Data Center](#tag/data-centers) for creating new resources.
+    pub recommendation: i64,
+}
+
+/// Generated from `GET /datacenters/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetDatacenterResponse {
+    pub datacenter: Datacenter,
+}
+
+/// [Server](#tag/servers) the [Firewall](#tag/firewalls) is applied to.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Server {
+    /// ID of the [Server](#tag/servers).
+    pub id: i64,
+}
+
+/// Generated from `GET /firewalls`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AppliedToResource {
+    /// [Server](#tag/servers) the [Firewall](#tag/firewalls) is applied to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<Server>,
+    /// Type of resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+/// [Label Selector](#description/label-selector) the [Firewall](#tag/firewalls) is applied to.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct LabelSelector {
+    /// The selector.
+    pub selector: String,
+}
+
+/// Generated from `GET /firewalls`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AppliedToItem {
+    /// Resources applied to via this [Label Selector](#description/label-selector).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub applied_to_resources: Option<Vec<AppliedToResource>>,
+    /// [Label Selector](#description/label-selector) the [Firewall](#tag/firewalls) is applied to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_selector: Option<LabelSelector>,
+    /// [Server](#tag/servers) the [Firewall](#tag/firewalls) is applied to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<Server>,
+    /// The type of resource to apply.
+    pub r#type: String,
+}
+
+/// Generated from `GET /firewalls`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Rule {
+    /// Description of the rule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// List of permitted IPv4/IPv6 addresses for outgoing traffic.
+    pub destination_ips: Vec<String>,
+    /// Traffic direction in which the rule should be applied to.
+    pub direction: String,
+    /// Port or port range to apply the rule for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<String>,
+    /// Network protocol to apply the rule for.
+    pub protocol: String,
+    /// List of permitted IPv4/IPv6 addresses for incoming traffic.
+    pub source_ips: Vec<String>,
+}
+
+/// Generated from `GET /firewalls`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Firewall {
+    pub applied_to: Vec<AppliedToItem>,
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// ID of the [Firewall](#tag/firewalls).
+    pub id: i64,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// Name of the [Firewall](#tag/firewalls).
+    pub name: String,
+    pub rules: Vec<Rule>,
+}
+
+/// Generated from `GET /firewalls`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListFirewallsResponse {
+    pub firewalls: Vec<Firewall>,
+    pub meta: Meta,
+}
+
+/// Generated from `POST /firewalls`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ApplyToItem {
+    /// [Label Selector](#description/label-selector) the [Firewall](#tag/firewalls) is applied to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_selector: Option<LabelSelector>,
+    /// [Server](#tag/servers) the [Firewall](#tag/firewalls) is applied to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<Server>,
+    /// Type of the resource.
+    pub r#type: String,
+}
+
+/// Generated from `POST /firewalls`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateFirewallRequestRule {
+    /// Description of the rule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// List of permitted IPv4/IPv6 addresses for outgoing traffic.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destination_ips: Option<Vec<String>>,
+    /// Traffic direction in which the rule should be applied to.
+    pub direction: String,
+    /// Port or port range to apply the rule for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<String>,
+    /// Network protocol to apply the rule for.
+    pub protocol: String,
+    /// List of permitted IPv4/IPv6 addresses for incoming traffic.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_ips: Option<Vec<String>>,
+}
+
+/// Generated from `POST /firewalls`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateFirewallRequest {
+    /// Resources to apply the [Firewall](#tag/firewalls) to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub apply_to: Option<Vec<ApplyToItem>>,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// Name of the [Firewall](#tag/firewalls).
+    pub name: String,
+    /// Array of rules.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Vec<CreateFirewallRequestRule>>,
+}
+
+/// Generated from `POST /firewalls`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateFirewallResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<Action>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub firewall: Option<Firewall>,
+}
+
+/// Generated from `GET /firewalls/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListFirewallsActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Generated from `GET /firewalls/actions/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetFirewallsActionResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /firewalls/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetFirewallResponse {
+    pub firewall: Firewall,
+}
+
+/// Generated from `PUT /firewalls/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateFirewallRequest {
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// Name of the [Firewall](#tag/firewalls).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Generated from `PUT /firewalls/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateFirewallResponse {
+    pub firewall: Firewall,
+}
+
+/// Generated from `GET /firewalls/{id}/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListFirewallActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Generated from `POST /firewalls/{id}/actions/apply_to_resources`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ApplyFirewallToResourcesRequest {
+    /// Resources to apply the [Firewall](#tag/firewalls) to.
+    pub apply_to: Vec<ApplyToItem>,
+}
+
+/// Generated from `POST /firewalls/{id}/actions/apply_to_resources`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ApplyFirewallToResourcesResponse {
+    pub actions: Vec<Action>,
+}
+
+/// Generated from `POST /firewalls/{id}/actions/remove_from_resources`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RemoveFromItem {
+    /// [Label Selector](#description/label-selector) the [Firewall](#tag/firewalls) is applied to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_selector: Option<LabelSelector>,
+    /// [Server](#tag/servers) the [Firewall](#tag/firewalls) is applied to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<Server>,
+    /// Type of the resource.
+    pub r#type: String,
+}
+
+/// Generated from `POST /firewalls/{id}/actions/remove_from_resources`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RemoveFirewallFromResourcesRequest {
+    /// Resources to remove the [Firewall](#tag/firewalls) from.
+    pub remove_from: Vec<RemoveFromItem>,
+}
+
+/// Generated from `POST /firewalls/{id}/actions/remove_from_resources`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RemoveFirewallFromResourcesResponse {
+    pub actions: Vec<Action>,
+}
+
+/// Generated from `POST /firewalls/{id}/actions/set_rules`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SetFirewallRulesRequestRule {
+    /// Description of the rule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// List of permitted IPv4/IPv6 addresses for outgoing traffic.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destination_ips: Option<Vec<String>>,
+    /// Traffic direction in which the rule should be applied to.
+    pub direction: String,
+    /// Port or port range to apply the rule for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<String>,
+    /// Network protocol to apply the rule for.
+    pub protocol: String,
+    /// List of permitted IPv4/IPv6 addresses for incoming traffic.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_ips: Option<Vec<String>>,
+}
+
+/// Generated from `POST /firewalls/{id}/actions/set_rules`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SetFirewallRulesRequest {
+    /// Array of rules.
+    pub rules: Vec<SetFirewallRulesRequestRule>,
+}
+
+/// Generated from `POST /firewalls/{id}/actions/set_rules`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SetFirewallRulesResponse {
+    pub actions: Vec<Action>,
+}
+
+/// Generated from `GET /firewalls/{id}/actions/{action_id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetFirewallActionResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /floating_ips`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DnsPtrItem {
+    /// Domain Name to point to.
+    pub dns_ptr: String,
+    /// Single IPv4 or IPv6 address to create pointer for.
+    pub ip: String,
+}
+
+/// Generated from `GET /floating_ips`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct HomeLocation {
+    /// Name of the closest city to the [Location](#tag/locations).
+    pub city: String,
+    /// Country the [Location](#tag/locations) resides in.
+    pub country: String,
+    /// Human readable description of the [Location](#tag/locations).
+    pub description: String,
+    /// ID of the [Location](#tag/locations).
+    pub id: i64,
+    /// Latitude of the city closest to the [Location](#tag/locations).
+    pub latitude: f64,
+    /// Longitude of the city closest to the [Location](#tag/locations).
+    pub longitude: f64,
+    /// Unique identifier of the [Location](#tag/locations).
+    pub name: String,
+    /// Name of the Network Zone this [Location](#tag/locations) resides in.
+    pub network_zone: String,
+}
+
+/// Protection configuration for the Resource.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Protection {
+    /// Prevent the Resource from being deleted.
+    pub delete: bool,
+}
+
+/// Generated from `GET /floating_ips`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct FloatingIp {
+    /// Indicates whether the [Floating IP](#tag/floating-ips) is blocked.
+    pub blocked: bool,
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// Description of the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// List of reverse DNS entries for the [Floating IP](#tag/floating-ips).
+    pub dns_ptr: Vec<DnsPtrItem>,
+    pub home_location: HomeLocation,
+    /// ID of the [Floating IP](#tag/floating-ips).
+    pub id: i64,
+    /// IP address.
+    pub ip: String,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Name of the Resource. Must be unique per Project.
+    pub name: String,
+    /// Protection configuration for the Resource.
+    pub protection: Protection,
+    /// [Server](#tag/servers) the [Floating IP](#tag/floating-ips) is assigned to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<i64>,
+    /// The Floating IP type.
+    pub r#type: String,
+}
+
+/// Generated from `GET /floating_ips`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListFloatingIpsResponse {
+    pub floating_ips: Vec<FloatingIp>,
+    pub meta: Meta,
+}
+
+/// Generated from `POST /floating_ips`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateFloatingIpRequest {
+    /// Description of the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Home [Location](#tag/locations) for the [Floating IP](#tag/floating-ips).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub home_location: Option<String>,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// Name of the Resource. Must be unique per Project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// [Server](#tag/servers) the [Floating IP](#tag/floating-ips) is assigned to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<i64>,
+    /// The Floating IP type.
+    pub r#type: String,
+}
+
+/// Generated from `POST /floating_ips`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateFloatingIpResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<Action>,
+    pub floating_ip: FloatingIp,
+}
+
+/// Generated from `GET /floating_ips/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListFloatingIpsActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Generated from `GET /floating_ips/actions/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetFloatingIpsActionResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /floating_ips/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetFloatingIpResponse {
+    pub floating_ip: FloatingIp,
+}
+
+/// Generated from `PUT /floating_ips/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateFloatingIpRequest {
+    /// Description of the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// Name of the Resource. Must be unique per Project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Generated from `PUT /floating_ips/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateFloatingIpResponse {
+    pub floating_ip: FloatingIp,
+}
+
+/// Generated from `GET /floating_ips/{id}/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListFloatingIpActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Generated from `POST /floating_ips/{id}/actions/assign`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AssignFloatingIpRequest {
+    /// [Server](#tag/servers) the [Floating IP](#tag/floating-ips) is assigned to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<i64>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AssignFloatingIpResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /floating_ips/{id}/actions/change_dns_ptr`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeFloatingIpDnsPtrRequest {
+    /// Domain Name to point to.
+    pub dns_ptr: String,
+    /// Single IPv4 or IPv6 address to create pointer for.
+    pub ip: String,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeFloatingIpDnsPtrResponse {
+    pub action: Action,
+}
+
+/// Protection configuration for the Resource.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeFloatingIpProtectionRequest {
+    /// Prevent the Resource from being deleted.
+    pub delete: bool,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeFloatingIpProtectionResponse {
+    pub action: Action,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UnassignFloatingIpResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /floating_ips/{id}/actions/{action_id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetFloatingIpActionResponse {
+    pub action: Action,
+}
+
+/// Information about the Server the Image was created from.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreatedFrom {
+    /// ID of the Server the Image was created from.
+    pub id: i64,
+    /// Server name at the time the Image was created.
+    pub name: String,
+}
+
+/// Generated from `GET /images`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Image {
+    /// CPU architecture compatible with the Image.
+    pub architecture: String,
+    /// ID of Server the Image is bound to. Only set for Images of type `backup`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bound_to: Option<i64>,
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// Information about the Server the Image was created from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_from: Option<CreatedFrom>,
+    /// Point in time where the Image was deleted (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<String>,
+    /// Point in time when the Image is considered to be deprecated (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<String>,
+    /// Description of the Image.
+    pub description: String,
+    /// Size of the disk contained in the Image in GB.
+    pub disk_size: f64,
+    /// ID of the [Image](#tag/images).
+    pub id: i64,
+    /// Size of the Image file in our storage in GB. For snapshot Images this is the value relevant for calculating costs for the Image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_size: Option<f64>,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Unique identifier of the Image. This value is only set for system Images.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Flavor of operating system contained in the Image.
+    pub os_flavor: String,
+    /// Operating system version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub os_version: Option<String>,
+    /// Protection configuration for the Resource.
+    pub protection: Protection,
+    /// Indicates that rapid deploy of the Image is available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rapid_deploy: Option<bool>,
+    /// Status of the Image.
+    pub status: String,
+    /// Type of the Image.
+    pub r#type: String,
+}
+
+/// Generated from `GET /images`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListImagesResponse {
+    pub images: Vec<Image>,
+    pub meta: Meta,
+}
+
+/// Generated from `GET /images/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListImagesActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Generated from `GET /images/actions/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetImagesActionResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /images/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetImageResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<Image>,
+}
+
+/// Generated from `PUT /images/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateImageRequest {
+    /// New description of Image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// Destination Image type to convert to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+/// Generated from `PUT /images/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateImageResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<Image>,
+}
+
+/// Generated from `GET /images/{id}/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListImageActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Generated from `POST /images/{id}/actions/change_protection`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeImageProtectionRequest {
+    /// If true, prevents the snapshot from being deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete: Option<bool>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeImageProtectionResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /images/{id}/actions/{action_id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetImageActionResponse {
+    pub action: Action,
+}
+
+/// Describes if, when and how the resource is deprecated. If this field is
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Deprecation {
+    /// Date of the deprecation announcement.
+    pub announced: String,
+    /// Date of the deprecated resource removal.
+    pub unavailable_after: String,
+}
+
+/// Generated from `GET /isos`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Iso {
+    /// CPU architecture compatible with the ISO.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub architecture: Option<String>,
+    /// Describes if, when and how the resource is deprecated. If this field is
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<Deprecation>,
+    /// Description of the ISO.
+    pub description: String,
+    /// ID of the [ISO](#tag/isos).
+    pub id: i64,
+    /// Unique identifier of the ISO. Only set for public ISOs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Type of the ISO.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+/// Generated from `GET /isos`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListIsosResponse {
+    pub isos: Vec<Iso>,
+    pub meta: Meta,
+}
+
+/// Generated from `GET /isos/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetIsoResponse {
+    pub iso: Iso,
+}
+
+/// Hourly price in this [Location](#tag/locations).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PriceHourly {
+    /// Price with VAT added.
+    pub gross: String,
+    /// Price without VAT.
+    pub net: String,
+}
+
+/// Monthly price in this [Location](#tag/locations).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PriceMonthly {
+    /// Price with VAT added.
+    pub gross: String,
+    /// Price without VAT.
+    pub net: String,
+}
+
+/// Additional traffic price per TB in this [Location](#tag/locations).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PricePerTbTraffic {
+    /// Price with VAT added.
+    pub gross: String,
+    /// Price without VAT.
+    pub net: String,
+}
+
+/// Generated from `GET /load_balancer_types`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Price {
+    /// Free traffic per month in bytes in this [Location](#tag/locations).
+    pub included_traffic: i64,
+    /// Name of the [Location](#tag/locations) the price is for.
+    pub location: String,
+    /// Hourly price in this [Location](#tag/locations).
+    pub price_hourly: PriceHourly,
+    /// Monthly price in this [Location](#tag/locations).
+    pub price_monthly: PriceMonthly,
+    /// Additional traffic price per TB in this [Location](#tag/locations).
+    pub price_per_tb_traffic: PricePerTbTraffic,
+}
+
+/// Generated from `GET /load_balancer_types`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct LoadBalancerType {
+    /// Point in time when the Load Balancer type is deprecated (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<String>,
+    /// Description of the Load Balancer type.
+    pub description: String,
+    /// ID of the Load Balancer type.
+    pub id: i64,
+    /// Number of SSL Certificates that can be assigned to a single Load Balancer.
+    pub max_assigned_certificates: i64,
+    /// Number of maximum simultaneous open connections.
+    pub max_connections: i64,
+    /// Number of services a Load Balancer of this type can have.
+    pub max_services: i64,
+    /// Number of targets a single Load Balancer can have.
+    pub max_targets: i64,
+    /// Unique identifier of the Load Balancer type.
+    pub name: String,
+    /// Price per [Location](#tag/locations).
+    pub prices: Vec<Price>,
+}
+
+/// Generated from `GET /load_balancer_types`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListLoadBalancerTypesResponse {
+    pub load_balancer_types: Vec<LoadBalancerType>,
+    pub meta: Meta,
+}
+
+/// Generated from `GET /load_balancer_types/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetLoadBalancerTypeResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub load_balancer_type: Option<LoadBalancerType>,
+}
+
+/// Algorithm of the Load Balancer.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Algorithm {
+    /// Type of the algorithm.
+    pub r#type: String,
+}
+
+/// Generated from `GET /load_balancers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PrivateNetItem {
+    /// IP address (v4) of this Load Balancer in this Network.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    /// ID of the Network.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<i64>,
+}
+
+/// IP address (v4).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Ipv4 {
+    /// Reverse DNS PTR entry for the IPv4 address of this Load Balancer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dns_ptr: Option<String>,
+    /// IP address (v4) of this Load Balancer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+}
+
+/// IP address (v6).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Ipv6 {
+    /// Reverse DNS PTR entry for the IPv6 address of this Load Balancer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dns_ptr: Option<String>,
+    /// IP address (v6) of this Load Balancer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+}
+
+/// Public network information.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PublicNet {
+    /// Public Interface enabled or not.
+    pub enabled: bool,
+    /// IP address (v4).
+    pub ipv4: Ipv4,
+    /// IP address (v6).
+    pub ipv6: Ipv6,
+}
+
+/// Additional configuration for protocol http.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Http {
+    /// Host header to send in the HTTP request. May not contain spaces, percent or backslash symbols. Can be null, in that case no host header is sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    /// HTTP path to use for health checks. May not contain literal spaces, use percent-encoding instead.
+    pub path: String,
+    /// String that must be contained in HTTP response in order to pass the health check.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response: Option<String>,
+    /// List of returned HTTP status codes in order to pass the health check. Supports the wildcards `?` for exactly one character and `*` for multiple ones.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_codes: Option<Vec<String>>,
+    /// Use HTTPS for health check.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<bool>,
+}
+
+/// Service health check.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct HealthCheck {
+    /// Additional configuration for protocol http.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http: Option<Http>,
+    /// Time interval in seconds health checks are performed.
+    pub interval: i64,
+    /// Port the health check will be performed on.
+    pub port: i64,
+    /// Type of the health check.
+    pub protocol: String,
+    /// Unsuccessful retries needed until a target is considered unhealthy; an unhealthy target needs the same number of successful retries to become healthy again.
+    pub retries: i64,
+    /// Time in seconds after an attempt is considered a timeout.
+    pub timeout: i64,
+}
+
+/// Configuration option for protocols http and https.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ServiceHttp {
+    /// IDs of the Certificates to use for TLS/SSL termination by the Load Balancer; empty for TLS/SSL passthrough or if `protocol` is `http`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificates: Option<Vec<i64>>,
+    /// Lifetime of the cookie used for sticky sessions (in seconds).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cookie_lifetime: Option<i64>,
+    /// Name of the cookie used for sticky sessions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cookie_name: Option<String>,
+    /// Redirect HTTP requests to HTTPS. Only available if `protocol` is `https`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redirect_http: Option<bool>,
+    /// Use sticky sessions. Only available if `protocol` is `http` or `https`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sticky_sessions: Option<bool>,
+}
+
+/// Generated from `GET /load_balancers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Service {
+    /// Port the Load Balancer will balance to.
+    pub destination_port: i64,
+    /// Service health check.
+    pub health_check: HealthCheck,
+    /// Configuration option for protocols http and https.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http: Option<ServiceHttp>,
+    /// Port the Load Balancer listens on.
+    pub listen_port: i64,
+    /// Protocol of the Load Balancer.
+    pub protocol: String,
+    /// Is Proxyprotocol enabled or not.
+    pub proxyprotocol: bool,
+}
+
+/// Generated from `GET /load_balancers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct HealthStatusItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub listen_port: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+/// IP target where the traffic should be routed to. It is only possible to use the (Public or vSwitch) IPs of Hetzner Online Root Servers belonging to the project owner. IPs belonging to other users are blocked. Additionally IPs belonging to services provided by Hetzner Cloud (Servers, Load Balancers, ...) are blocked as well. Only present for target type `ip`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Ip {
+    /// IP of a server that belongs to the same customer (public IPv4/IPv6) or private IP in a subnet type vswitch.
+    pub ip: String,
+}
+
+/// Generated from `GET /load_balancers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Target {
+    /// List of health statuses of the services on this target. Only present for target types "server" and "ip".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_status: Option<Vec<HealthStatusItem>>,
+    /// Server where the traffic should be routed to. Only present for target type "server".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<Server>,
+    /// Type of the resource. Here always "server".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// Use the private network IP instead of the public IP. Only present for target types "server" and "label_selector".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_private_ip: Option<bool>,
+}
+
+/// Generated from `GET /load_balancers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct LoadBalancerTarget {
+    /// List of health statuses of the services on this target. Only present for target types "server" and "ip".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_status: Option<Vec<HealthStatusItem>>,
+    /// IP target where the traffic should be routed to. It is only possible to use the (Public or vSwitch) IPs of Hetzner Online Root Servers belonging to the project owner. IPs belonging to other users are blocked. Additionally IPs belonging to services provided by Hetzner Cloud (Servers, Load Balancers, ...) are blocked as well. Only present for target type `ip`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<Ip>,
+    /// Label selector used to determine targets. Only present for target type "label_selector".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_selector: Option<LabelSelector>,
+    /// Server where the traffic should be routed to. Only present for target type "server".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<Server>,
+    /// List of resolved label selector target Servers. Only present for type "label_selector".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub targets: Option<Vec<Target>>,
+    /// Type of the resource.
+    pub r#type: String,
+    /// Use the private network IP instead of the public IP. Only present for target types "server" and "label_selector".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_private_ip: Option<bool>,
+}
+
+/// Generated from `GET /load_balancers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct LoadBalancer {
+    /// Algorithm of the Load Balancer.
+    pub algorithm: Algorithm,
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// ID of the [Load Balancer](#tag/load-balancers).
+    pub id: i64,
+    /// Free Traffic for the current billing period in bytes.
+    pub included_traffic: i64,
+    /// Inbound Traffic for the current billing period in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ingoing_traffic: Option<i64>,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    pub load_balancer_type: LoadBalancerType,
+    pub location: Location,
+    /// Name of the Resource. Must be unique per Project.
+    pub name: String,
+    /// Outbound Traffic for the current billing period in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outgoing_traffic: Option<i64>,
+    /// Private networks information.
+    pub private_net: Vec<PrivateNetItem>,
+    /// Protection configuration for the Resource.
+    pub protection: Protection,
+    /// Public network information.
+    pub public_net: PublicNet,
+    /// List of services that belong to this Load Balancer.
+    pub services: Vec<Service>,
+    /// List of targets that belong to this Load Balancer.
+    pub targets: Vec<LoadBalancerTarget>,
+}
+
+/// Generated from `GET /load_balancers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListLoadBalancersResponse {
+    pub load_balancers: Vec<LoadBalancer>,
+    pub meta: Meta,
+}
+
+/// Generated from `POST /load_balancers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateLoadBalancerRequestTarget {
+    /// Configuration for an IP target. It is only possible to use the (Public or vSwitch) IPs of Hetzner Online Root Servers belonging to the project owner. IPs belonging to other users are blocked. Additionally IPs belonging to services provided by Hetzner Cloud (Servers, Load Balancers, ...) are blocked as well. Only valid and required if type is `ip`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<Ip>,
+    /// Configuration for label selector targets, only valid and required if type is `label_selector`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_selector: Option<LabelSelector>,
+    /// Configuration for type Server, only valid and required if type is `server`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<Server>,
+    /// Type of the resource.
+    pub r#type: String,
+    /// Use the private network IP instead of the public IP of the Server, requires the Server and Load Balancer to be in the same network. Only valid for target types `server` and `label_selector`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_private_ip: Option<bool>,
+}
+
+/// Generated from `POST /load_balancers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateLoadBalancerRequest {
+    /// Algorithm of the Load Balancer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<Algorithm>,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// ID or name of the Load Balancer type this Load Balancer should be created with.
+    pub load_balancer_type: String,
+    /// ID or name of Location to create Load Balancer in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /// Name of the Load Balancer.
+    pub name: String,
+    /// ID of the network the Load Balancer should be attached to on creation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<i64>,
+    /// Name of network zone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_zone: Option<String>,
+    /// Enable or disable the public interface of the Load Balancer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_interface: Option<bool>,
+    /// Array of services.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub services: Option<Vec<Service>>,
+    /// Array of targets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub targets: Option<Vec<CreateLoadBalancerRequestTarget>>,
+}
+
+/// Generated from `POST /load_balancers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateLoadBalancerResponse {
+    pub action: Action,
+    pub load_balancer: LoadBalancer,
+}
+
+/// Generated from `GET /load_balancers/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListLoadBalancersActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Generated from `GET /load_balancers/actions/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetLoadBalancersActionResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /load_balancers/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetLoadBalancerResponse {
+    pub load_balancer: LoadBalancer,
+}
+
+/// Generated from `PUT /load_balancers/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateLoadBalancerRequest {
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// New Load Balancer name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Generated from `PUT /load_balancers/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateLoadBalancerResponse {
+    pub load_balancer: LoadBalancer,
+}
+
+/// Generated from `GET /load_balancers/{id}/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListLoadBalancerActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Configuration option for protocols http and https.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AddLoadBalancerServiceRequestHttp {
+    /// IDs of the Certificates to use for TLS/SSL termination by the Load Balancer; empty for TLS/SSL passthrough or if `protocol` is `http`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificates: Option<Vec<i64>>,
+    /// Lifetime of the cookie used for sticky sessions (in seconds).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cookie_lifetime: Option<i64>,
+    /// Name of the cookie used for sticky sessions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cookie_name: Option<String>,
+    /// Redirect HTTP requests to HTTPS. Only available if `protocol` is `https`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redirect_http: Option<bool>,
+    /// Use sticky sessions. Only available if `protocol` is `http` or `https`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sticky_sessions: Option<bool>,
+}
+
+/// Generated from `POST /load_balancers/{id}/actions/add_service`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AddLoadBalancerServiceRequest {
+    /// Port the Load Balancer will balance to.
+    pub destination_port: i64,
+    /// Service health check.
+    pub health_check: HealthCheck,
+    /// Configuration option for protocols http and https.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http: Option<AddLoadBalancerServiceRequestHttp>,
+    /// Port the Load Balancer listens on.
+    pub listen_port: i64,
+    /// Protocol of the Load Balancer.
+    pub protocol: String,
+    /// Is Proxyprotocol enabled or not.
+    pub proxyprotocol: bool,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AddLoadBalancerServiceResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /load_balancers/{id}/actions/add_target`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AddLoadBalancerTargetRequest {
+    /// Configuration for an IP target. It is only possible to use the (Public or vSwitch) IPs of Hetzner Online Root Servers belonging to the project owner. IPs belonging to other users are blocked. Additionally IPs belonging to services provided by Hetzner Cloud (Servers, Load Balancers, ...) are blocked as well. Only valid and required if type is `ip`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<Ip>,
+    /// Configuration for label selector targets, only valid and required if type is `label_selector`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_selector: Option<LabelSelector>,
+    /// Configuration for type Server, only valid and required if type is `server`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<Server>,
+    /// Type of the resource.
+    pub r#type: String,
+    /// Use the private network IP instead of the public IP of the Server, requires the Server and Load Balancer to be in the same network. Only valid for target types `server` and `label_selector`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_private_ip: Option<bool>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AddLoadBalancerTargetResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /load_balancers/{id}/actions/attach_to_network`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AttachLoadBalancerToNetworkRequest {
+    /// IP to request to be assigned to this Load Balancer; if you do not provide this then you will be auto assigned an IP address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    /// IP range in CIDR block notation of the subnet to attach to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_range: Option<String>,
+    /// ID of an existing network to attach the Load Balancer to.
+    pub network: i64,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AttachLoadBalancerToNetworkResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /load_balancers/{id}/actions/change_algorithm`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeLoadBalancerAlgorithmRequest {
+    /// Algorithm of the Load Balancer.
+    pub r#type: String,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeLoadBalancerAlgorithmResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /load_balancers/{id}/actions/change_dns_ptr`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeLoadBalancerDnsPtrRequest {
+    /// Hostname to set as a reverse DNS PTR entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dns_ptr: Option<String>,
+    /// Public IP address for which the reverse DNS entry should be set.
+    pub ip: String,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeLoadBalancerDnsPtrResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /load_balancers/{id}/actions/change_protection`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeLoadBalancerProtectionRequest {
+    /// If true, prevents the Load Balancer from being deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete: Option<bool>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeLoadBalancerProtectionResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /load_balancers/{id}/actions/change_type`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeLoadBalancerTypeRequest {
+    /// ID or name of Load Balancer type the Load Balancer should migrate to.
+    pub load_balancer_type: String,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeLoadBalancerTypeResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /load_balancers/{id}/actions/delete_service`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DeleteLoadBalancerServiceRequest {
+    /// The listen port of the service you want to delete.
+    pub listen_port: i64,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DeleteLoadBalancerServiceResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /load_balancers/{id}/actions/detach_from_network`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DetachLoadBalancerFromNetworkRequest {
+    /// ID of an existing network to detach the Load Balancer from.
+    pub network: i64,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DetachLoadBalancerFromNetworkResponse {
+    pub action: Action,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DisableLoadBalancerPublicInterfaceResponse {
+    pub action: Action,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct EnableLoadBalancerPublicInterfaceResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /load_balancers/{id}/actions/remove_target`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RemoveLoadBalancerTargetRequest {
+    /// IP target where the traffic should be routed to. It is only possible to use the (Public or vSwitch) IPs of Hetzner Online Root Servers belonging to the project owner. IPs belonging to other users are blocked. Additionally IPs belonging to services provided by Hetzner Cloud (Servers, Load Balancers, ...) are blocked as well. Only present for target type `ip`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<Ip>,
+    /// Configuration for label selector targets, required if type is `label_selector`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_selector: Option<LabelSelector>,
+    /// Configuration for type Server, required if type is `server`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<Server>,
+    /// Type of the resource.
+    pub r#type: String,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RemoveLoadBalancerTargetResponse {
+    pub action: Action,
+}
+
+/// Additional configuration for protocol http.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct HealthCheckHttp {
+    /// Host header to send in the HTTP request. May not contain spaces, percent or backslash symbols. Can be null, in that case no host header is sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    /// HTTP path to use for health checks. May not contain literal spaces, use percent-encoding instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// String that must be contained in HTTP response in order to pass the health check.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response: Option<String>,
+    /// List of returned HTTP status codes in order to pass the health check. Supports the wildcards `?` for exactly one character and `*` for multiple ones.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_codes: Option<Vec<String>>,
+    /// Use HTTPS for health check.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<bool>,
+}
+
+/// Service health check.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateLoadBalancerServiceRequestHealthCheck {
+    /// Additional configuration for protocol http.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http: Option<HealthCheckHttp>,
+    /// Time interval in seconds health checks are performed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval: Option<i64>,
+    /// Port the health check will be performed on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<i64>,
+    /// Type of the health check.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    /// Unsuccessful retries needed until a target is considered unhealthy; an unhealthy target needs the same number of successful retries to become healthy again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<i64>,
+    /// Time in seconds after an attempt is considered a timeout.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<i64>,
+}
+
+/// Configuration option for protocols http and https.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateLoadBalancerServiceRequestHttp {
+    /// IDs of the Certificates to use for TLS/SSL termination by the Load Balancer; empty for TLS/SSL passthrough or if `protocol` is "http".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificates: Option<Vec<i64>>,
+    /// Lifetime of the cookie used for sticky sessions (in seconds).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cookie_lifetime: Option<i64>,
+    /// Name of the cookie used for sticky sessions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cookie_name: Option<String>,
+    /// Redirect HTTP requests to HTTPS. Only available if protocol is "https".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redirect_http: Option<bool>,
+    /// Use sticky sessions. Only available if protocol is "http" or "https".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sticky_sessions: Option<bool>,
+}
+
+/// Generated from `POST /load_balancers/{id}/actions/update_service`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateLoadBalancerServiceRequest {
+    /// Port the Load Balancer will balance to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destination_port: Option<i64>,
+    /// Service health check.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_check: Option<UpdateLoadBalancerServiceRequestHealthCheck>,
+    /// Configuration option for protocols http and https.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http: Option<UpdateLoadBalancerServiceRequestHttp>,
+    /// Port the Load Balancer listens on.
+    pub listen_port: i64,
+    /// Protocol of the Load Balancer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    /// Is Proxyprotocol enabled or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxyprotocol: Option<bool>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateLoadBalancerServiceResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /load_balancers/{id}/actions/{action_id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetLoadBalancerActionResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /load_balancers/{id}/metrics`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Metrics {
+    /// End of period of metrics reported (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub end: String,
+    /// Start of period of metrics reported (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub start: String,
+    /// Resolution of results in seconds.
+    pub step: f64,
+    /// Hash with timeseries information, containing the name of timeseries as key.
+    pub time_series: serde_json::Value,
+}
+
+/// Generated from `GET /load_balancers/{id}/metrics`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetLoadBalancerMetricsResponse {
+    pub metrics: Metrics,
+}
+
+/// Generated from `GET /locations`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListLocationsResponse {
+    /// List of [Locations](#tag/locations).
+    pub locations: Vec<Location>,
+    pub meta: Meta,
+}
+
+/// Generated from `GET /locations/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetLocationResponse {
+    pub location: Location,
+}
+
+/// Generated from `GET /networks`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Route {
+    /// Destination network or host of the route.
+    pub destination: String,
+    /// Gateway of the route.
+    pub gateway: String,
+}
+
+/// Generated from `GET /networks`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Subnet {
+    /// Gateway for [Servers](#tag/servers) attached to this subnet.
+    pub gateway: String,
+    /// IP range of the subnet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_range: Option<String>,
+    /// Name of the [Network Zone](#tag/network-zones).
+    pub network_zone: String,
+    /// Type of subnet.
+    pub r#type: String,
+    /// ID of the robot vSwitch if the subnet is of type `vswitch`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vswitch_id: Option<i64>,
+}
+
+/// Generated from `GET /networks`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Network {
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// Indicates if the routes from this [Network](#tag/networks) should be exposed to the vSwitch connection.
+    pub expose_routes_to_vswitch: bool,
+    /// ID of the [Network](#tag/networks).
+    pub id: i64,
+    /// IP range of the [Network](#tag/networks).
+    pub ip_range: String,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Array of IDs of [Load Balancers](#tag/load-balancers) attached to this [Network](#tag/networks).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub load_balancers: Option<Vec<i64>>,
+    /// Name of the [Network](#tag/networks).
+    pub name: String,
+    /// Protection configuration for the Resource.
+    pub protection: Protection,
+    /// Array of routes set in this [Network](#tag/networks).
+    pub routes: Vec<Route>,
+    /// Array of IDs of [Servers](#tag/servers) attached to this [Network](#tag/networks).
+    pub servers: Vec<i64>,
+    /// List of subnets allocated in this [Network](#tag/networks).
+    pub subnets: Vec<Subnet>,
+}
+
+/// Generated from `GET /networks`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListNetworksResponse {
+    pub meta: Meta,
+    pub networks: Vec<Network>,
+}
+
+/// Generated from `POST /networks`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateNetworkRequestSubnet {
+    /// IP range of the subnet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_range: Option<String>,
+    /// Name of the [Network Zone](#tag/network-zones).
+    pub network_zone: String,
+    /// Type of subnet.
+    pub r#type: String,
+    /// ID of the robot vSwitch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vswitch_id: Option<i64>,
+}
+
+/// Generated from `POST /networks`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateNetworkRequest {
+    /// Toggle to expose routes to the [Networks](#tag/networks) vSwitch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expose_routes_to_vswitch: Option<bool>,
+    /// IP range of the [Network](#tag/networks).
+    pub ip_range: String,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// Name of the [Network](#tag/networks).
+    pub name: String,
+    /// Array of routes set in this [Network](#tag/networks).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub routes: Option<Vec<Route>>,
+    /// Array of subnets to allocate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subnets: Option<Vec<CreateNetworkRequestSubnet>>,
+}
+
+/// Generated from `POST /networks`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateNetworkResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<Network>,
+}
+
+/// Generated from `GET /networks/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListNetworksActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Generated from `GET /networks/actions/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetNetworksActionResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /networks/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetNetworkResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<Network>,
+}
+
+/// Generated from `PUT /networks/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateNetworkRequest {
+    /// Toggle to expose routes to the [Networks](#tag/networks) vSwitch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expose_routes_to_vswitch: Option<bool>,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// New [Network](#tag/networks) name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Generated from `PUT /networks/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateNetworkResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<Network>,
+}
+
+/// Generated from `GET /networks/{id}/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListNetworkActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Generated from `POST /networks/{id}/actions/add_route`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AddNetworkRouteRequest {
+    /// Destination network or host of the route.
+    pub destination: String,
+    /// Gateway of the route.
+    pub gateway: String,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AddNetworkRouteResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /networks/{id}/actions/add_subnet`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AddNetworkSubnetRequest {
+    /// IP range of the subnet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_range: Option<String>,
+    /// Name of the [Network Zone](#tag/network-zones).
+    pub network_zone: String,
+    /// Type of subnet.
+    pub r#type: String,
+    /// ID of the robot vSwitch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vswitch_id: Option<i64>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AddNetworkSubnetResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /networks/{id}/actions/change_ip_range`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeNetworkIpRangeRequest {
+    /// IP range of the [Network](#tag/networks).
+    pub ip_range: String,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeNetworkIpRangeResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /networks/{id}/actions/change_protection`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeNetworkProtectionRequest {
+    /// Delete protection setting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete: Option<bool>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeNetworkProtectionResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /networks/{id}/actions/delete_route`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DeleteNetworkRouteRequest {
+    /// Destination network or host of the route.
+    pub destination: String,
+    /// Gateway of the route.
+    pub gateway: String,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DeleteNetworkRouteResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /networks/{id}/actions/delete_subnet`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DeleteNetworkSubnetRequest {
+    /// IP range in CIDR block notation of the subnet to delete.
+    pub ip_range: String,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DeleteNetworkSubnetResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /networks/{id}/actions/{action_id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetNetworkActionResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /placement_groups`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PlacementGroup {
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// ID of the [Placement Group](#tag/placement-groups).
+    pub id: i64,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Name of the Resource. Must be unique per Project.
+    pub name: String,
+    /// Array of IDs of Servers that are part of this Placement Group.
+    pub servers: Vec<i64>,
+    /// Type of Placement Group.
+    pub r#type: String,
+}
+
+/// Generated from `GET /placement_groups`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListPlacementGroupsResponse {
+    pub meta: Meta,
+    pub placement_groups: Vec<PlacementGroup>,
+}
+
+/// Generated from `POST /placement_groups`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreatePlacementGroupRequest {
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// Name of the Placement Group.
+    pub name: String,
+    /// Define the Placement Group Type.
+    pub r#type: String,
+}
+
+/// Generated from `POST /placement_groups`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreatePlacementGroupResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<Action>,
+    pub placement_group: PlacementGroup,
+}
+
+/// Generated from `GET /placement_groups/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetPlacementGroupResponse {
+    pub placement_group: PlacementGroup,
+}
+
+/// Generated from `PUT /placement_groups/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdatePlacementGroupRequest {
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// New Placement Group name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Generated from `PUT /placement_groups/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdatePlacementGroupResponse {
+    pub placement_group: PlacementGroup,
+}
+
+/// Price of [Floating IPs](#tag/floating-ips).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PricingFloatingIp {
+    /// Price of one [Floating IP](#tag/floating-ips) per month.
+    pub price_monthly: PriceMonthly,
+}
+
+/// Generated from `GET /pricing`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct FloatingIpPrice {
+    /// Name of the [Location](#tag/locations) the price is for.
+    pub location: String,
+    /// Monthly price in this [Location](#tag/locations).
+    pub price_monthly: PriceMonthly,
+}
+
+/// Generated from `GET /pricing`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PricingFloatingIp2 {
+    /// Price of the [Floating IP](#tag/floating-ips) type per [Location](#tag/locations).
+    pub prices: Vec<FloatingIpPrice>,
+    /// Type of [Floating IP](#tag/floating-ips) the price is for.
+    pub r#type: String,
+}
+
+/// Price of [Images](#tag/images) per GB/month.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PricePerGbMonth {
+    /// Price with VAT added.
+    pub gross: String,
+    /// Price without VAT.
+    pub net: String,
+}
+
+/// Price of [Images](#tag/images).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PricingImage {
+    /// Price of [Images](#tag/images) per GB/month.
+    pub price_per_gb_month: PricePerGbMonth,
+}
+
+/// Generated from `GET /pricing`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PricingLoadBalancerType {
+    /// ID of the [Load Balancer Types](#tag/load-balancer-types) the price is for.
+    pub id: i64,
+    /// Name of the [Load Balancer Types](#tag/load-balancer-types) the price is for.
+    pub name: String,
+    /// Price of the [Load Balancer Types](#tag/load-balancer-types) per [Location](#tag/locations).
+    pub prices: Vec<Price>,
+}
+
+/// Generated from `GET /pricing`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PrimaryIpPrice {
+    /// Name of the [Location](#tag/locations) the price is for.
+    pub location: String,
+    /// Hourly price in this [Location](#tag/locations).
+    pub price_hourly: PriceHourly,
+    /// Monthly price in this [Location](#tag/locations).
+    pub price_monthly: PriceMonthly,
+}
+
+/// Generated from `GET /pricing`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PrimaryIp {
+    /// Price of the [Primary IP](#tag/primary-ips) type per [Location](#tag/locations).
+    pub prices: Vec<PrimaryIpPrice>,
+    /// Type of [Primary IP](#tag/primary-ips) the price is for.
+    pub r#type: String,
+}
+
+/// Price of [Server](#tag/servers) backups.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ServerBackup {
+    /// Price increase of the [Server](#tag/servers) base price in percentage.
+    pub percentage: String,
+}
+
+/// Generated from `GET /pricing`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ServerType {
+    /// ID of the [Server Types](#tag/server-types) the price is for.
+    pub id: i64,
+    /// Name of the [Server Types](#tag/server-types) the price is for.
+    pub name: String,
+    /// Price of the [Server Types](#tag/server-types) per [Location](#tag/locations).
+    pub prices: Vec<Price>,
+}
+
+/// Price of [Volumes](#tag/volumes).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Volume {
+    /// Price of [Volumes](#tag/volumes) per GB/month.
+    pub price_per_gb_month: PricePerGbMonth,
+}
+
+/// Generated from `GET /pricing`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Pricing {
+    /// Currency the returned prices are expressed in, coded according to [ISO 4217](https://wikipedia.org/wiki/ISO_4217).
+    pub currency: String,
+    /// Price of [Floating IPs](#tag/floating-ips).
+    pub floating_ip: PricingFloatingIp,
+    /// Price of [Floating IPs](#tag/floating-ips) per type and per [Location](#tag/locations).
+    pub floating_ips: Vec<PricingFloatingIp2>,
+    /// Price of [Images](#tag/images).
+    pub image: PricingImage,
+    /// Price of Load Balancer per [type](#tag/load-balancer-types) and per [Location](#tag/locations).
+    pub load_balancer_types: Vec<PricingLoadBalancerType>,
+    /// Price of [Primary IPs](#tag/primary-ips) per type and per [Location](#tag/locations).
+    pub primary_ips: Vec<PrimaryIp>,
+    /// Price of [Server](#tag/servers) backups.
+    pub server_backup: ServerBackup,
+    /// Price of Server per [type](#tag/server-types) and per [Location](#tag/locations).
+    pub server_types: Vec<ServerType>,
+    /// VAT rate used for calculating prices with VAT.
+    pub vat_rate: String,
+    /// Price of [Volumes](#tag/volumes).
+    pub volume: Volume,
+}
+
+/// Generated from `GET /pricing`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetPricingResponse {
+    pub pricing: Pricing,
+}
+
+/// Generated from `GET /primary_ips`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListPrimaryIpsResponsePrimaryIp {
+    /// ID of resource the [Primary IP](#tag/primary-ips) is assigned to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<i64>,
+    /// Type of resource the [Primary IP](#tag/primary-ips) can get assigned to.
+    pub assignee_type: String,
+    /// Auto deletion state.
+    pub auto_delete: bool,
+    /// Blocked state of the [Primary IP](#tag/primary-ips).
+    pub blocked: bool,
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// **Deprecated**: This property is deprecated and will be removed after the 1 July 2026.
+    pub datacenter: Datacenter,
+    /// List of reverse DNS records.
+    pub dns_ptr: Vec<DnsPtrItem>,
+    /// ID of the [Primary IP](#tag/primary-ips).
+    pub id: i64,
+    /// IP address.
+    pub ip: String,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// [Location](#tag/locations) of the [Primary IP](#tag/primary-ips).
+    pub location: Location,
+    /// Name of the Resource. Must be unique per Project.
+    pub name: String,
+    /// Protection configuration for the Resource.
+    pub protection: Protection,
+    /// [Primary IP](#tag/primary-ips) type.
+    pub r#type: String,
+}
+
+/// Generated from `GET /primary_ips`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListPrimaryIpsResponse {
+    pub meta: Meta,
+    pub primary_ips: Vec<ListPrimaryIpsResponsePrimaryIp>,
+}
+
+/// Generated from `POST /primary_ips`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreatePrimaryIpRequest {
+    /// ID of resource to assign the [Primary IP](#tag/primary-ips) to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<i64>,
+    /// Type of resource the [Primary IP](#tag/primary-ips) can get assigned to.
+    pub assignee_type: String,
+    /// Auto deletion state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_delete: Option<bool>,
+    /// **Deprecated**: This property is deprecated and will be removed after 1 July 2026.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datacenter: Option<String>,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// [Location](#tag/locations) ID or name the [Primary IP](#tag/primary-ips) will be bound to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /// Name of the Resource. Must be unique per Project.
+    pub name: String,
+    /// [Primary IP](#tag/primary-ips) type.
+    pub r#type: String,
+}
+
+/// Generated from `POST /primary_ips`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreatePrimaryIpResponsePrimaryIp {
+    /// ID of resource the [Primary IP](#tag/primary-ips) is assigned to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<i64>,
+    /// Type of resource the [Primary IP](#tag/primary-ips) can get assigned to.
+    pub assignee_type: String,
+    /// Auto deletion state.
+    pub auto_delete: bool,
+    /// Blocked state of the [Primary IP](#tag/primary-ips).
+    pub blocked: bool,
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// **Deprecated**: This property is deprecated and will be removed after the 1 July 2026.
+    pub datacenter: Datacenter,
+    /// List of reverse DNS records.
+    pub dns_ptr: Vec<DnsPtrItem>,
+    /// ID of the [Primary IP](#tag/primary-ips).
+    pub id: i64,
+    /// IP address.
+    pub ip: String,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// [Location](#tag/locations) of the [Primary IP](#tag/primary-ips).
+    pub location: Location,
+    /// Name of the Resource. Must be unique per Project.
+    pub name: String,
+    /// Protection configuration for the Resource.
+    pub protection: Protection,
+    /// [Primary IP](#tag/primary-ips) type.
+    pub r#type: String,
+}
+
+/// Generated from `POST /primary_ips`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreatePrimaryIpResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<Action>,
+    pub primary_ip: CreatePrimaryIpResponsePrimaryIp,
+}
+
+/// Generated from `GET /primary_ips/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListPrimaryIpsActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Generated from `GET /primary_ips/actions/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetPrimaryIpsActionResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /primary_ips/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetPrimaryIpResponsePrimaryIp {
+    /// ID of resource the [Primary IP](#tag/primary-ips) is assigned to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<i64>,
+    /// Type of resource the [Primary IP](#tag/primary-ips) can get assigned to.
+    pub assignee_type: String,
+    /// Auto deletion state.
+    pub auto_delete: bool,
+    /// Blocked state of the [Primary IP](#tag/primary-ips).
+    pub blocked: bool,
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// **Deprecated**: This property is deprecated and will be removed after the 1 July 2026.
+    pub datacenter: Datacenter,
+    /// List of reverse DNS records.
+    pub dns_ptr: Vec<DnsPtrItem>,
+    /// ID of the [Primary IP](#tag/primary-ips).
+    pub id: i64,
+    /// IP address.
+    pub ip: String,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// [Location](#tag/locations) of the [Primary IP](#tag/primary-ips).
+    pub location: Location,
+    /// Name of the Resource. Must be unique per Project.
+    pub name: String,
+    /// Protection configuration for the Resource.
+    pub protection: Protection,
+    /// [Primary IP](#tag/primary-ips) type.
+    pub r#type: String,
+}
+
+/// Generated from `GET /primary_ips/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetPrimaryIpResponse {
+    pub primary_ip: GetPrimaryIpResponsePrimaryIp,
+}
+
+/// Generated from `PUT /primary_ips/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdatePrimaryIpRequest {
+    /// Auto deletion state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_delete: Option<bool>,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// Name of the Resource. Must be unique per Project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Generated from `PUT /primary_ips/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdatePrimaryIpResponsePrimaryIp {
+    /// ID of resource the [Primary IP](#tag/primary-ips) is assigned to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<i64>,
+    /// Type of resource the [Primary IP](#tag/primary-ips) can get assigned to.
+    pub assignee_type: String,
+    /// Auto deletion state.
+    pub auto_delete: bool,
+    /// Blocked state of the [Primary IP](#tag/primary-ips).
+    pub blocked: bool,
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// **Deprecated**: This property is deprecated and will be removed after the 1 July 2026.
+    pub datacenter: Datacenter,
+    /// List of reverse DNS records.
+    pub dns_ptr: Vec<DnsPtrItem>,
+    /// ID of the [Primary IP](#tag/primary-ips).
+    pub id: i64,
+    /// IP address.
+    pub ip: String,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// [Location](#tag/locations) of the [Primary IP](#tag/primary-ips).
+    pub location: Location,
+    /// Name of the Resource. Must be unique per Project.
+    pub name: String,
+    /// Protection configuration for the Resource.
+    pub protection: Protection,
+    /// [Primary IP](#tag/primary-ips) type.
+    pub r#type: String,
+}
+
+/// Generated from `PUT /primary_ips/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdatePrimaryIpResponse {
+    pub primary_ip: UpdatePrimaryIpResponsePrimaryIp,
+}
+
+/// Generated from `GET /primary_ips/{id}/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListPrimaryIpActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Generated from `POST /primary_ips/{id}/actions/assign`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AssignPrimaryIpRequest {
+    /// ID of a resource of type `assignee_type`.
+    pub assignee_id: i64,
+    /// Type of resource assigning the Primary IP to.
+    pub assignee_type: String,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AssignPrimaryIpResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /primary_ips/{id}/actions/change_dns_ptr`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangePrimaryIpDnsPtrRequest {
+    /// Domain Name to point to.
+    pub dns_ptr: String,
+    /// Single IPv4 or IPv6 address to create pointer for.
+    pub ip: String,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangePrimaryIpDnsPtrResponse {
+    pub action: Action,
+}
+
+/// Protection configuration for the Resource.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangePrimaryIpProtectionRequest {
+    /// Prevent the Resource from being deleted.
+    pub delete: bool,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangePrimaryIpProtectionResponse {
+    pub action: Action,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UnassignPrimaryIpResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /primary_ips/{id}/actions/{action_id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetPrimaryIpActionResponse {
+    pub action: Action,
+}
+
+/// A [Location](#tag/locations) (containing only id and name) and Server Type specific metadata.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ServerTypeLocation {
+    /// Describes if, when and how the resource is deprecated. If this field is
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<Deprecation>,
+    /// ID of the [Location](#tag/locations).
+    pub id: i64,
+    /// Name of the [Location](#tag/locations).
+    pub name: String,
+}
+
+/// Generated from `GET /server_types`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListServerTypesResponseServerType {
+    /// CPU architecture of the Server Type.
+    pub architecture: String,
+    /// Category of Server Type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Number of cpu cores a Server of this type will have.
+    pub cores: f64,
+    /// Type of cpu.
+    pub cpu_type: String,
+    /// This field is deprecated. Use the deprecation object instead.
+    pub deprecated: bool,
+    /// This field is deprecated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<Deprecation>,
+    /// Description of the Server type.
+    pub description: String,
+    /// Disk size a Server of this type will have in GB.
+    pub disk: f64,
+    /// ID of the Server type.
+    pub id: i64,
+    /// Supported [Location](#tag/locations) and per [Location](#tag/locations) details for the [Server Type](#tag/server-types).
+    pub locations: Vec<ServerTypeLocation>,
+    /// Memory a Server of this type will have in GB.
+    pub memory: f64,
+    /// Unique identifier of the Server type.
+    pub name: String,
+    /// Price per [Location](#tag/locations).
+    pub prices: Vec<Price>,
+    /// Type of Server boot drive. Local has higher speed. Network has better availability.
+    pub storage_type: String,
+}
+
+/// Generated from `GET /server_types`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListServerTypesResponse {
+    pub meta: Meta,
+    pub server_types: Vec<ListServerTypesResponseServerType>,
+}
+
+/// Generated from `GET /server_types/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetServerTypeResponseServerType {
+    /// CPU architecture of the Server Type.
+    pub architecture: String,
+    /// Category of Server Type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Number of cpu cores a Server of this type will have.
+    pub cores: f64,
+    /// Type of cpu.
+    pub cpu_type: String,
+    /// This field is deprecated. Use the deprecation object instead.
+    pub deprecated: bool,
+    /// This field is deprecated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<Deprecation>,
+    /// Description of the Server type.
+    pub description: String,
+    /// Disk size a Server of this type will have in GB.
+    pub disk: f64,
+    /// ID of the Server type.
+    pub id: i64,
+    /// Supported [Location](#tag/locations) and per [Location](#tag/locations) details for the [Server Type](#tag/server-types).
+    pub locations: Vec<ServerTypeLocation>,
+    /// Memory a Server of this type will have in GB.
+    pub memory: f64,
+    /// Unique identifier of the Server type.
+    pub name: String,
+    /// Price per [Location](#tag/locations).
+    pub prices: Vec<Price>,
+    /// Type of Server boot drive. Local has higher speed. Network has better availability.
+    pub storage_type: String,
+}
+
+/// Generated from `GET /server_types/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetServerTypeResponse {
+    pub server_type: GetServerTypeResponseServerType,
+}
+
+/// Generated from `GET /servers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ServerPrivateNetItem {
+    /// Additional IP addresses of the server on the network.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias_ips: Option<Vec<String>>,
+    /// The server IP address on the network.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    /// The server MAC address on the network.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mac_address: Option<String>,
+    /// The Network ID the server is attached to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<i64>,
+}
+
+/// Protection configuration for the Server.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ServerProtection {
+    /// If true, prevents the Server from being deleted.
+    pub delete: bool,
+    /// If true, prevents the Server from being rebuilt.
+    pub rebuild: bool,
+}
+
+/// Generated from `GET /servers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PublicNetFirewall {
+    /// ID of the [Firewall](#tag/firewalls).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    /// Status of the Firewall on the Server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+/// IP address (v4) and its reverse DNS entry of this Server.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PublicNetIpv4 {
+    /// If the IP is blocked by our anti abuse dept.
+    pub blocked: bool,
+    /// Reverse DNS PTR entry for the IPv4 addresses of this Server.
+    pub dns_ptr: String,
+    /// ID of the [Primary IP](#tag/primary-ips).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    /// IP address (v4) of this Server.
+    pub ip: String,
+}
+
+/// IPv6 network assigned to this Server and its reverse DNS entry.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PublicNetIpv6 {
+    /// If the IP is blocked by our anti abuse dept.
+    pub blocked: bool,
+    /// Reverse DNS PTR entries for the IPv6 addresses of this Server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dns_ptr: Option<Vec<DnsPtrItem>>,
+    /// ID of the [Primary IP](#tag/primary-ips).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    /// IP address (v6) of this Server.
+    pub ip: String,
+}
+
+/// Public network information. The Server's IPv4 address can be found in `public_net-&gt;ipv4-&gt;ip`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ServerPublicNet {
+    /// Firewalls applied to the public network interface of this Server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub firewalls: Option<Vec<PublicNetFirewall>>,
+    /// IDs of Floating IPs assigned to this Server.
+    pub floating_ips: Vec<i64>,
+    /// IP address (v4) and its reverse DNS entry of this Server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ipv4: Option<PublicNetIpv4>,
+    /// IPv6 network assigned to this Server and its reverse DNS entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ipv6: Option<PublicNetIpv6>,
+}
+
+/// Generated from `GET /servers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ServerServerType {
+    /// CPU architecture of the Server Type.
+    pub architecture: String,
+    /// Category of Server Type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Number of cpu cores a Server of this type will have.
+    pub cores: f64,
+    /// Type of cpu.
+    pub cpu_type: String,
+    /// This field is deprecated. Use the deprecation object instead.
+    pub deprecated: bool,
+    /// This field is deprecated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<Deprecation>,
+    /// Description of the Server type.
+    pub description: String,
+    /// Disk size a Server of this type will have in GB.
+    pub disk: f64,
+    /// ID of the Server type.
+    pub id: i64,
+    /// Supported [Location](#tag/locations) and per [Location](#tag/locations) details for the [Server Type](#tag/server-types).
+    pub locations: Vec<ServerTypeLocation>,
+    /// Memory a Server of this type will have in GB.
+    pub memory: f64,
+    /// Unique identifier of the Server type.
+    pub name: String,
+    /// Price per [Location](#tag/locations).
+    pub prices: Vec<Price>,
+    /// Type of Server boot drive. Local has higher speed. Network has better availability.
+    pub storage_type: String,
+}
+
+/// Generated from `GET /servers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListServersResponseServer {
+    /// Time window (UTC) in which the backup will run, or null if the backups are not enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup_window: Option<String>,
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// **Deprecated**: This property is deprecated and will be removed after the 1 July 2026.
+    pub datacenter: Datacenter,
+    /// ID of the [Server](#tag/servers).
+    pub id: i64,
+    /// Image the server is based on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<Image>,
+    /// Free Traffic for the current billing period in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub included_traffic: Option<i64>,
+    /// Inbound Traffic for the current billing period in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ingoing_traffic: Option<i64>,
+    /// ISO Image that is attached to this Server. Null if no ISO is attached.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iso: Option<Iso>,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Load Balancer IDs assigned to the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub load_balancers: Option<Vec<i64>>,
+    /// Location this Resource is located at.
+    pub location: Location,
+    /// True if Server has been locked and is not available to user.
+    pub locked: bool,
+    /// Name of the Server (must be unique per Project and a valid hostname as per RFC 1123).
+    pub name: String,
+    /// Outbound Traffic for the current billing period in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outgoing_traffic: Option<i64>,
+    /// The placement group the server is assigned to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placement_group: Option<PlacementGroup>,
+    /// Size of the primary Disk.
+    pub primary_disk_size: f64,
+    /// Private networks information.
+    pub private_net: Vec<ServerPrivateNetItem>,
+    /// Protection configuration for the Server.
+    pub protection: ServerProtection,
+    /// Public network information. The Server's IPv4 address can be found in `public_net-&gt;ipv4-&gt;ip`.
+    pub public_net: ServerPublicNet,
+    /// True if rescue mode is enabled. Server will then boot into rescue system on next reboot.
+    pub rescue_enabled: bool,
+    pub server_type: ServerServerType,
+    /// Status of the Server.
+    pub status: String,
+    /// IDs of Volumes assigned to this Server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<Vec<i64>>,
+}
+
+/// Generated from `GET /servers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListServersResponse {
+    pub meta: Meta,
+    pub servers: Vec<ListServersResponseServer>,
+}
+
+/// Generated from `POST /servers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateServerRequestFirewall {
+    /// ID of the Firewall.
+    pub firewall: i64,
+}
+
+/// Public Network options.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateServerRequestPublicNet {
+    /// Attach an IPv4 on the public NIC. If false, no IPv4 address will be attached.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enable_ipv4: Option<bool>,
+    /// Attach an IPv6 on the public NIC. If false, no IPv6 address will be attached.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enable_ipv6: Option<bool>,
+    /// ID of the ipv4 Primary IP to use. If omitted and enable_ipv4 is true, a new ipv4 Primary IP will automatically be created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ipv4: Option<i64>,
+    /// ID of the ipv6 Primary IP to use. If omitted and enable_ipv6 is true, a new ipv6 Primary IP will automatically be created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ipv6: Option<i64>,
+}
+
+/// Generated from `POST /servers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateServerRequest {
+    /// Auto-mount Volumes after attach.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automount: Option<bool>,
+    /// **Deprecated**: This property is deprecated and will be removed after the 1 July 2026.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datacenter: Option<String>,
+    /// Firewalls which should be applied on the Server's public network interface at creation time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub firewalls: Option<Vec<CreateServerRequestFirewall>>,
+    /// ID or name of the Image the Server is created from.
+    pub image: String,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// ID or name of the Location to create the Server in (must not be used together with `datacenter`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /// Name of the Server to create (must be unique per Project and a valid hostname as per RFC 1123).
+    pub name: String,
+    /// Network IDs which should be attached to the Server private network interface at the creation time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub networks: Option<Vec<i64>>,
+    /// ID of the Placement Group the Server should be in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placement_group: Option<i64>,
+    /// Public Network options.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_net: Option<CreateServerRequestPublicNet>,
+    /// ID or name of the Server type this Server should be created with.
+    pub server_type: String,
+    /// SSH key IDs (`integer`) or names (`string`) which should be injected into the Server at creation time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssh_keys: Option<Vec<String>>,
+    /// This automatically triggers a [Power on a Server-Server Action](#tag/server-actions/poweron_server) after the creation is finished and is returned in the `next_actions` response object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_after_create: Option<bool>,
+    /// Cloud-Init user data to use during Server creation. This field is limited to 32KiB.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_data: Option<String>,
+    /// Volume IDs which should be attached to the Server at the creation time. Volumes must be in the same Location.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<Vec<i64>>,
+}
+
+/// Generated from `POST /servers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct NextAction {
+    /// Command executed in the Action.
+    pub command: String,
+    /// Error message for the Action if an error occurred, otherwise null.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<Error>,
+    /// Point in time when the Action was finished (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format). Only set if the Action is finished otherwise null.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished: Option<String>,
+    /// ID of the [Action](#description/actions).
+    pub id: i64,
+    /// Progress of the Action in percent.
+    pub progress: i64,
+    /// Resources the Action relates to.
+    pub resources: Vec<Resource>,
+    /// Point in time when the Action was started (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub started: String,
+    /// Status of the Action.
+    pub status: String,
+}
+
+/// Generated from `POST /servers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateServerResponseServer {
+    /// Time window (UTC) in which the backup will run, or null if the backups are not enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup_window: Option<String>,
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// **Deprecated**: This property is deprecated and will be removed after the 1 July 2026.
+    pub datacenter: Datacenter,
+    /// ID of the [Server](#tag/servers).
+    pub id: i64,
+    /// Image the server is based on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<Image>,
+    /// Free Traffic for the current billing period in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub included_traffic: Option<i64>,
+    /// Inbound Traffic for the current billing period in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ingoing_traffic: Option<i64>,
+    /// ISO Image that is attached to this Server. Null if no ISO is attached.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iso: Option<Iso>,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Load Balancer IDs assigned to the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub load_balancers: Option<Vec<i64>>,
+    /// Location this Resource is located at.
+    pub location: Location,
+    /// True if Server has been locked and is not available to user.
+    pub locked: bool,
+    /// Name of the Server (must be unique per Project and a valid hostname as per RFC 1123).
+    pub name: String,
+    /// Outbound Traffic for the current billing period in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outgoing_traffic: Option<i64>,
+    /// The placement group the server is assigned to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placement_group: Option<PlacementGroup>,
+    /// Size of the primary Disk.
+    pub primary_disk_size: f64,
+    /// Private networks information.
+    pub private_net: Vec<ServerPrivateNetItem>,
+    /// Protection configuration for the Server.
+    pub protection: ServerProtection,
+    /// Public network information. The Server's IPv4 address can be found in `public_net-&gt;ipv4-&gt;ip`.
+    pub public_net: ServerPublicNet,
+    /// True if rescue mode is enabled. Server will then boot into rescue system on next reboot.
+    pub rescue_enabled: bool,
+    pub server_type: ServerServerType,
+    /// Status of the Server.
+    pub status: String,
+    /// IDs of Volumes assigned to this Server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<Vec<i64>>,
+}
+
+/// Generated from `POST /servers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateServerResponse {
+    pub action: Action,
+    pub next_actions: Vec<NextAction>,
+    /// Root password when no SSH keys have been specified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root_password: Option<String>,
+    pub server: CreateServerResponseServer,
+}
+
+/// Generated from `GET /servers/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListServersActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Generated from `GET /servers/actions/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetServersActionResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /servers/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetServerResponseServer {
+    /// Time window (UTC) in which the backup will run, or null if the backups are not enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup_window: Option<String>,
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// **Deprecated**: This property is deprecated and will be removed after the 1 July 2026.
+    pub datacenter: Datacenter,
+    /// ID of the [Server](#tag/servers).
+    pub id: i64,
+    /// Image the server is based on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<Image>,
+    /// Free Traffic for the current billing period in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub included_traffic: Option<i64>,
+    /// Inbound Traffic for the current billing period in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ingoing_traffic: Option<i64>,
+    /// ISO Image that is attached to this Server. Null if no ISO is attached.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iso: Option<Iso>,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Load Balancer IDs assigned to the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub load_balancers: Option<Vec<i64>>,
+    /// Location this Resource is located at.
+    pub location: Location,
+    /// True if Server has been locked and is not available to user.
+    pub locked: bool,
+    /// Name of the Server (must be unique per Project and a valid hostname as per RFC 1123).
+    pub name: String,
+    /// Outbound Traffic for the current billing period in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outgoing_traffic: Option<i64>,
+    /// The placement group the server is assigned to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placement_group: Option<PlacementGroup>,
+    /// Size of the primary Disk.
+    pub primary_disk_size: f64,
+    /// Private networks information.
+    pub private_net: Vec<ServerPrivateNetItem>,
+    /// Protection configuration for the Server.
+    pub protection: ServerProtection,
+    /// Public network information. The Server's IPv4 address can be found in `public_net-&gt;ipv4-&gt;ip`.
+    pub public_net: ServerPublicNet,
+    /// True if rescue mode is enabled. Server will then boot into rescue system on next reboot.
+    pub rescue_enabled: bool,
+    pub server_type: ServerServerType,
+    /// Status of the Server.
+    pub status: String,
+    /// IDs of Volumes assigned to this Server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<Vec<i64>>,
+}
+
+/// Generated from `GET /servers/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetServerResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<GetServerResponseServer>,
+}
+
+/// Generated from `PUT /servers/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateServerRequest {
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// New name to set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Generated from `PUT /servers/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateServerResponseServer {
+    /// Time window (UTC) in which the backup will run, or null if the backups are not enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup_window: Option<String>,
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// **Deprecated**: This property is deprecated and will be removed after the 1 July 2026.
+    pub datacenter: Datacenter,
+    /// ID of the [Server](#tag/servers).
+    pub id: i64,
+    /// Image the server is based on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<Image>,
+    /// Free Traffic for the current billing period in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub included_traffic: Option<i64>,
+    /// Inbound Traffic for the current billing period in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ingoing_traffic: Option<i64>,
+    /// ISO Image that is attached to this Server. Null if no ISO is attached.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iso: Option<Iso>,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Load Balancer IDs assigned to the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub load_balancers: Option<Vec<i64>>,
+    /// Location this Resource is located at.
+    pub location: Location,
+    /// True if Server has been locked and is not available to user.
+    pub locked: bool,
+    /// Name of the Server (must be unique per Project and a valid hostname as per RFC 1123).
+    pub name: String,
+    /// Outbound Traffic for the current billing period in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outgoing_traffic: Option<i64>,
+    /// The placement group the server is assigned to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placement_group: Option<PlacementGroup>,
+    /// Size of the primary Disk.
+    pub primary_disk_size: f64,
+    /// Private networks information.
+    pub private_net: Vec<ServerPrivateNetItem>,
+    /// Protection configuration for the Server.
+    pub protection: ServerProtection,
+    /// Public network information. The Server's IPv4 address can be found in `public_net-&gt;ipv4-&gt;ip`.
+    pub public_net: ServerPublicNet,
+    /// True if rescue mode is enabled. Server will then boot into rescue system on next reboot.
+    pub rescue_enabled: bool,
+    pub server_type: ServerServerType,
+    /// Status of the Server.
+    pub status: String,
+    /// IDs of Volumes assigned to this Server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<Vec<i64>>,
+}
+
+/// Generated from `PUT /servers/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateServerResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<UpdateServerResponseServer>,
+}
+
+/// Generated from `DELETE /servers/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DeleteServerResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<Action>,
+}
+
+/// Generated from `GET /servers/{id}/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListServerActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Generated from `POST /servers/{id}/actions/add_to_placement_group`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AddServerToPlacementGroupRequest {
+    /// ID of Placement Group the Server should be added to.
+    pub placement_group: i64,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AddServerToPlacementGroupResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /servers/{id}/actions/attach_iso`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AttachServerIsoRequest {
+    /// ID or name of ISO to attach to the Server as listed in GET `/isos`.
+    pub iso: String,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AttachServerIsoResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /servers/{id}/actions/attach_to_network`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AttachServerToNetworkRequest {
+    /// Additional IPs to be assigned to this Server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias_ips: Option<Vec<String>>,
+    /// IP to request to be assigned to this Server; if you do not provide this then you will be auto assigned an IP address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    /// IP range in CIDR block notation of the subnet to attach to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_range: Option<String>,
+    /// ID of an existing network to attach the Server to.
+    pub network: i64,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AttachServerToNetworkResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /servers/{id}/actions/change_alias_ips`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeServerAliasIpsRequest {
+    /// New alias IPs to set for this Server.
+    pub alias_ips: Vec<String>,
+    /// ID of an existing Network already attached to the Server.
+    pub network: i64,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeServerAliasIpsResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /servers/{id}/actions/change_dns_ptr`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeServerDnsPtrRequest {
+    /// Hostname to set as a reverse DNS PTR entry, reset to original value if `null`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dns_ptr: Option<String>,
+    /// Primary IP address for which the reverse DNS entry should be set.
+    pub ip: String,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeServerDnsPtrResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /servers/{id}/actions/change_protection`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeServerProtectionRequest {
+    /// If true, prevents the Server from being deleted (currently delete and rebuild attribute needs to have the same value).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete: Option<bool>,
+    /// If true, prevents the Server from being rebuilt (currently delete and rebuild attribute needs to have the same value).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rebuild: Option<bool>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeServerProtectionResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /servers/{id}/actions/change_type`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeServerTypeRequest {
+    /// ID or name of Server type the Server should migrate to.
+    pub server_type: String,
+    /// If false, do not upgrade the disk (this allows downgrading the Server type later).
+    pub upgrade_disk: bool,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeServerTypeResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /servers/{id}/actions/create_image`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateServerImageRequest {
+    /// Description of the Image, will be auto-generated if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// Type of Image to create.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+/// Generated from `POST /servers/{id}/actions/create_image`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateServerImageResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<Action>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<Image>,
+}
+
+/// Generated from `POST /servers/{id}/actions/detach_from_network`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DetachServerFromNetworkRequest {
+    /// ID of an existing network to detach the Server from.
+    pub network: i64,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DetachServerFromNetworkResponse {
+    pub action: Action,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DetachServerIsoResponse {
+    pub action: Action,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DisableServerBackupResponse {
+    pub action: Action,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DisableServerRescueResponse {
+    pub action: Action,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct EnableServerBackupResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /servers/{id}/actions/enable_rescue`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct EnableServerRescueRequest {
+    /// Array of SSH key IDs which should be injected into the rescue system.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssh_keys: Option<Vec<i64>>,
+    /// Type of rescue system to boot.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+/// The `root_password` key in the reply contains the root password that can be used to access the booted rescue system.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct EnableServerRescueResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<Action>,
+    /// Password that will be set for this Server once the Action succeeds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root_password: Option<String>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PoweroffServerResponse {
+    pub action: Action,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PoweronServerResponse {
+    pub action: Action,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RebootServerResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /servers/{id}/actions/rebuild`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RebuildServerRequest {
+    /// ID or name of Image to rebuilt from.
+    pub image: String,
+    /// Cloud-Init user data to use during Server rebuild. This field is limited to 32KiB. If not specified, the Server's previous user_data value will be re-used (if any was set).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_data: Option<String>,
+}
+
+/// Generated from `POST /servers/{id}/actions/rebuild`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RebuildServerResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<Action>,
+    /// New root password when not using SSH keys.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root_password: Option<String>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RemoveServerFromPlacementGroupResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /servers/{id}/actions/request_console`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RequestServerConsoleResponse {
+    pub action: Action,
+    /// VNC password to use for this connection (this password only works in combination with a wss_url with valid token).
+    pub password: String,
+    /// URL of websocket proxy to use; this includes a token which is valid for a limited time only.
+    pub wss_url: String,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ResetServerResponse {
+    pub action: Action,
+}
+
+/// The `root_password` key in the reply contains the new root password that will be active if the Action succeeds.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ResetServerPasswordResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<Action>,
+    /// Password that will be set for this Server once the Action succeeds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root_password: Option<String>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ShutdownServerResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /servers/{id}/actions/{action_id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetServerActionResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /servers/{id}/metrics`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetServerMetricsResponse {
+    pub metrics: Metrics,
+}
+
+/// Generated from `GET /ssh_keys`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SshKey {
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// MD5 fingerprint of the SSH public key.
+    pub fingerprint: String,
+    /// ID of the [SSH Key](#tag/ssh-keys).
+    pub id: i64,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Name of the Resource. Must be unique per Project.
+    pub name: String,
+    /// Public key.
+    pub public_key: String,
+}
+
+/// Generated from `GET /ssh_keys`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListSshKeysResponse {
+    pub meta: Meta,
+    pub ssh_keys: Vec<SshKey>,
+}
+
+/// Generated from `POST /ssh_keys`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateSshKeyRequest {
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// Name of the SSH key.
+    pub name: String,
+    /// Public key.
+    pub public_key: String,
+}
+
+/// Generated from `POST /ssh_keys`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateSshKeyResponse {
+    pub ssh_key: SshKey,
+}
+
+/// Generated from `GET /ssh_keys/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetSshKeyResponse {
+    pub ssh_key: SshKey,
+}
+
+/// Generated from `PUT /ssh_keys/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateSshKeyRequest {
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// New name Name to set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Generated from `PUT /ssh_keys/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateSshKeyResponse {
+    pub ssh_key: SshKey,
+}
+
+/// Generated from `GET /volumes`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListVolumesResponseVolume {
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// Filesystem of the Volume if formatted on creation, null if not formatted on creation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// ID of the [Volume](#tag/volumes).
+    pub id: i64,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Device path on the file system for the Volume.
+    pub linux_device: String,
+    /// Location of the Volume. Volume can only be attached to Servers in the same Location.
+    pub location: Location,
+    /// Name of the Resource. Must be unique per Project.
+    pub name: String,
+    /// Protection configuration for the Resource.
+    pub protection: Protection,
+    /// ID of the Server the Volume is attached to, null if it is not attached at all.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<i64>,
+    /// Size in GB of the Volume.
+    pub size: f64,
+    /// Status of the Volume.
+    pub status: String,
+}
+
+/// Generated from `GET /volumes`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListVolumesResponse {
+    pub meta: Meta,
+    pub volumes: Vec<ListVolumesResponseVolume>,
+}
+
+/// Generated from `POST /volumes`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateVolumeRequest {
+    /// Auto-mount Volume after attach. `server` must be provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automount: Option<bool>,
+    /// Format Volume after creation. One of: `xfs`, `ext4`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// Location to create the Volume in (can be omitted if Server is specified).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /// Name of the volume.
+    pub name: String,
+    /// Server to which to attach the Volume once it's created (Volume will be created in the same Location as the server).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<i64>,
+    /// Size of the Volume in GB.
+    pub size: i64,
+}
+
+/// Generated from `POST /volumes`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateVolumeResponseVolume {
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// Filesystem of the Volume if formatted on creation, null if not formatted on creation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// ID of the [Volume](#tag/volumes).
+    pub id: i64,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Device path on the file system for the Volume.
+    pub linux_device: String,
+    /// Location of the Volume. Volume can only be attached to Servers in the same Location.
+    pub location: Location,
+    /// Name of the Resource. Must be unique per Project.
+    pub name: String,
+    /// Protection configuration for the Resource.
+    pub protection: Protection,
+    /// ID of the Server the Volume is attached to, null if it is not attached at all.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<i64>,
+    /// Size in GB of the Volume.
+    pub size: f64,
+    /// Status of the Volume.
+    pub status: String,
+}
+
+/// Generated from `POST /volumes`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateVolumeResponse {
+    pub action: Action,
+    pub next_actions: Vec<NextAction>,
+    pub volume: CreateVolumeResponseVolume,
+}
+
+/// Generated from `GET /volumes/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListVolumesActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Generated from `GET /volumes/actions/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetVolumesActionResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /volumes/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetVolumeResponseVolume {
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// Filesystem of the Volume if formatted on creation, null if not formatted on creation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// ID of the [Volume](#tag/volumes).
+    pub id: i64,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Device path on the file system for the Volume.
+    pub linux_device: String,
+    /// Location of the Volume. Volume can only be attached to Servers in the same Location.
+    pub location: Location,
+    /// Name of the Resource. Must be unique per Project.
+    pub name: String,
+    /// Protection configuration for the Resource.
+    pub protection: Protection,
+    /// ID of the Server the Volume is attached to, null if it is not attached at all.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<i64>,
+    /// Size in GB of the Volume.
+    pub size: f64,
+    /// Status of the Volume.
+    pub status: String,
+}
+
+/// Generated from `GET /volumes/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetVolumeResponse {
+    pub volume: GetVolumeResponseVolume,
+}
+
+/// Generated from `PUT /volumes/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateVolumeRequest {
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// New Volume name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Generated from `PUT /volumes/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateVolumeResponseVolume {
+    /// Point in time when the Resource was created (in [RFC3339](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6) format).
+    pub created: String,
+    /// Filesystem of the Volume if formatted on creation, null if not formatted on creation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// ID of the [Volume](#tag/volumes).
+    pub id: i64,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Device path on the file system for the Volume.
+    pub linux_device: String,
+    /// Location of the Volume. Volume can only be attached to Servers in the same Location.
+    pub location: Location,
+    /// Name of the Resource. Must be unique per Project.
+    pub name: String,
+    /// Protection configuration for the Resource.
+    pub protection: Protection,
+    /// ID of the Server the Volume is attached to, null if it is not attached at all.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<i64>,
+    /// Size in GB of the Volume.
+    pub size: f64,
+    /// Status of the Volume.
+    pub status: String,
+}
+
+/// Generated from `PUT /volumes/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateVolumeResponse {
+    pub volume: UpdateVolumeResponseVolume,
+}
+
+/// Generated from `GET /volumes/{id}/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListVolumeActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Generated from `POST /volumes/{id}/actions/attach`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AttachVolumeRequest {
+    /// Auto-mount the Volume after attaching it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automount: Option<bool>,
+    /// ID of the Server the Volume will be attached to.
+    pub server: i64,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AttachVolumeResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /volumes/{id}/actions/change_protection`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeVolumeProtectionRequest {
+    /// If true, prevents the Volume from being deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete: Option<bool>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeVolumeProtectionResponse {
+    pub action: Action,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DetachVolumeResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /volumes/{id}/actions/resize`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ResizeVolumeRequest {
+    /// New Volume size in GB (must be greater than current size).
+    pub size: f64,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ResizeVolumeResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /volumes/{id}/actions/{action_id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetVolumeActionResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /zones`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListZonesResponse {
+    pub meta: Meta,
+    pub zones: Vec<serde_json::Value>,
+}
+
+/// Primary nameserver that returns [Zones](#tag/zones) via `AXFR`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PrimaryNameserver {
+    /// Public IPv4 or IPv6 address of the primary nameserver.
+    pub address: String,
+    /// Port of the primary nameserver.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<i64>,
+    /// [Transaction signature (TSIG)](https://en.wikipedia.org/wiki/TSIG) algorithm
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tsig_algorithm: Option<String>,
+    /// [Transaction signature (TSIG)](https://en.wikipedia.org/wiki/TSIG) key to use
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tsig_key: Option<String>,
+}
+
+/// Record of a [RRSet](#tag/zone-rrsets).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Record {
+    /// Comment of the record.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Value of the record.
+    pub value: String,
+}
+
+/// Generated from `POST /zones`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Rrset {
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// Name of the [RRSet](#tag/zone-rrsets).
+    pub name: String,
+    /// Records of the [RRSet](#tag/zone-rrsets).
+    pub records: Vec<Record>,
+    /// Time To Live (TTL) of the [RRSet](#tag/zone-rrsets).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<i64>,
+    /// Type of the [RRSet](#tag/zone-rrsets).
+    pub r#type: String,
+}
+
+/// Generated from `POST /zones`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateZoneRequest {
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// Mode of the [Zone](#tag/zones).
+    pub mode: String,
+    /// Name of the [Zone](#tag/zones).
+    pub name: String,
+    /// Primary nameservers of the [Zone](#tag/zones).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_nameservers: Option<Vec<PrimaryNameserver>>,
+    /// [RRSets](#tag/zone-rrsets) to be added to the [Zone](#tag/zones).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rrsets: Option<Vec<Rrset>>,
+    /// Default Time To Live (TTL) of the [Zone](#tag/zones).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<i64>,
+    /// Zone file to import.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zonefile: Option<String>,
+}
+
+/// Generated from `POST /zones`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateZoneResponse {
+    pub action: Action,
+    pub zone: serde_json::Value,
+}
+
+/// Generated from `GET /zones/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListZonesActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Generated from `GET /zones/actions/{id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetZonesActionResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /zones/{id_or_name}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetZoneResponse {
+    pub zone: serde_json::Value,
+}
+
+/// Generated from `PUT /zones/{id_or_name}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateZoneRequest {
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+}
+
+/// Generated from `PUT /zones/{id_or_name}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateZoneResponse {
+    pub zone: serde_json::Value,
+}
+
+/// Generated from `DELETE /zones/{id_or_name}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DeleteZoneResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /zones/{id_or_name}/actions`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListZoneActionsResponse {
+    pub actions: Vec<Action>,
+    pub meta: Meta,
+}
+
+/// Generated from `POST /zones/{id_or_name}/actions/change_primary_nameservers`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeZonePrimaryNameserversRequest {
+    /// Primary nameservers of the [Zone](#tag/zones).
+    pub primary_nameservers: Vec<PrimaryNameserver>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeZonePrimaryNameserversResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /zones/{id_or_name}/actions/change_protection`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeZoneProtectionRequest {
+    /// Prevents the [Zone](#tag/zones) from being deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete: Option<bool>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeZoneProtectionResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /zones/{id_or_name}/actions/change_ttl`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeZoneTtlRequest {
+    /// Default Time To Live (TTL) of the [Zone](#tag/zones).
+    pub ttl: i64,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeZoneTtlResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /zones/{id_or_name}/actions/import_zonefile`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ImportZoneZonefileRequest {
+    /// Zone file to import.
+    pub zonefile: String,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ImportZoneZonefileResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /zones/{id_or_name}/actions/{action_id}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetZoneActionResponse {
+    pub action: Action,
+}
+
+/// Protection of the [RRSet](#tag/zone-rrsets).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RrsetProtection {
+    /// Prevent the Resource from being changed.
+    pub change: bool,
+}
+
+/// Generated from `GET /zones/{id_or_name}/rrsets`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListZoneRrsetsResponseRrset {
+    /// ID of the RRSet.
+    pub id: String,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Name of the [RRSet](#tag/zone-rrsets).
+    pub name: String,
+    /// Protection of the [RRSet](#tag/zone-rrsets).
+    pub protection: RrsetProtection,
+    /// Records of the [RRSet](#tag/zone-rrsets).
+    pub records: Vec<Record>,
+    /// Time To Live (TTL) of the [RRSet](#tag/zone-rrsets).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<i64>,
+    /// Type of the [RRSet](#tag/zone-rrsets).
+    pub r#type: String,
+    /// ID of the [Zone](#tag/zones).
+    pub zone: i64,
+}
+
+/// Generated from `GET /zones/{id_or_name}/rrsets`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ListZoneRrsetsResponse {
+    pub meta: Meta,
+    pub rrsets: Vec<ListZoneRrsetsResponseRrset>,
+}
+
+/// Generated from `POST /zones/{id_or_name}/rrsets`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateZoneRrsetRequest {
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+    /// Name of the [RRSet](#tag/zone-rrsets).
+    pub name: String,
+    /// Records of the [RRSet](#tag/zone-rrsets).
+    pub records: Vec<Record>,
+    /// Time To Live (TTL) of the [RRSet](#tag/zone-rrsets).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<i64>,
+    /// Type of the [RRSet](#tag/zone-rrsets).
+    pub r#type: String,
+}
+
+/// Generated from `POST /zones/{id_or_name}/rrsets`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateZoneRrsetResponseRrset {
+    /// ID of the RRSet.
+    pub id: String,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Name of the [RRSet](#tag/zone-rrsets).
+    pub name: String,
+    /// Protection of the [RRSet](#tag/zone-rrsets).
+    pub protection: RrsetProtection,
+    /// Records of the [RRSet](#tag/zone-rrsets).
+    pub records: Vec<Record>,
+    /// Time To Live (TTL) of the [RRSet](#tag/zone-rrsets).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<i64>,
+    /// Type of the [RRSet](#tag/zone-rrsets).
+    pub r#type: String,
+    /// ID of the [Zone](#tag/zones).
+    pub zone: i64,
+}
+
+/// Generated from `POST /zones/{id_or_name}/rrsets`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CreateZoneRrsetResponse {
+    pub action: Action,
+    pub rrset: CreateZoneRrsetResponseRrset,
+}
+
+/// Generated from `GET /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetZoneRrsetResponseRrset {
+    /// ID of the RRSet.
+    pub id: String,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Name of the [RRSet](#tag/zone-rrsets).
+    pub name: String,
+    /// Protection of the [RRSet](#tag/zone-rrsets).
+    pub protection: RrsetProtection,
+    /// Records of the [RRSet](#tag/zone-rrsets).
+    pub records: Vec<Record>,
+    /// Time To Live (TTL) of the [RRSet](#tag/zone-rrsets).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<i64>,
+    /// Type of the [RRSet](#tag/zone-rrsets).
+    pub r#type: String,
+    /// ID of the [Zone](#tag/zones).
+    pub zone: i64,
+}
+
+/// Generated from `GET /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetZoneRrsetResponse {
+    pub rrset: GetZoneRrsetResponseRrset,
+}
+
+/// Generated from `PUT /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateZoneRrsetRequest {
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<std::collections::BTreeMap<String, String>>,
+}
+
+/// Generated from `PUT /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateZoneRrsetResponseRrset {
+    /// ID of the RRSet.
+    pub id: String,
+    /// User-defined labels (`key/value` pairs) for the Resource.
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Name of the [RRSet](#tag/zone-rrsets).
+    pub name: String,
+    /// Protection of the [RRSet](#tag/zone-rrsets).
+    pub protection: RrsetProtection,
+    /// Records of the [RRSet](#tag/zone-rrsets).
+    pub records: Vec<Record>,
+    /// Time To Live (TTL) of the [RRSet](#tag/zone-rrsets).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<i64>,
+    /// Type of the [RRSet](#tag/zone-rrsets).
+    pub r#type: String,
+    /// ID of the [Zone](#tag/zones).
+    pub zone: i64,
+}
+
+/// Generated from `PUT /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateZoneRrsetResponse {
+    pub rrset: UpdateZoneRrsetResponseRrset,
+}
+
+/// Generated from `DELETE /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DeleteZoneRrsetResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}/actions/add_records`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AddZoneRrsetRecordsRequest {
+    /// Records to add to the [RRSet](#tag/zone-rrsets).
+    pub records: Vec<Record>,
+    /// Time To Live (TTL) of the [RRSet](#tag/zone-rrsets).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<i64>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AddZoneRrsetRecordsResponse {
+    pub action: Action,
+}
+
+/// Protection of the [RRSet](#tag/zone-rrsets).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeZoneRrsetProtectionRequest {
+    /// Prevent the Resource from being changed.
+    pub change: bool,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeZoneRrsetProtectionResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}/actions/change_ttl`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeZoneRrsetTtlRequest {
+    /// Time To Live (TTL) of the [RRSet](#tag/zone-rrsets).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<i64>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ChangeZoneRrsetTtlResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}/actions/remove_records`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RemoveZoneRrsetRecordsRequest {
+    /// Records to remove from the [RRSet](#tag/zone-rrsets).
+    pub records: Vec<Record>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RemoveZoneRrsetRecordsResponse {
+    pub action: Action,
+}
+
+/// Generated from `POST /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}/actions/set_records`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SetZoneRrsetRecordsRequest {
+    /// Records to set in the [RRSet](#tag/zone-rrsets).
+    pub records: Vec<Record>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SetZoneRrsetRecordsResponse {
+    pub action: Action,
+}
+
+/// Record of a [RRSet](#tag/zone-rrsets).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateZoneRrsetRecordsRequestRecord {
+    /// New comment for the record.
+    pub comment: String,
+    /// Value of the record to update.
+    pub value: String,
+}
+
+/// Generated from `POST /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}/actions/update_records`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateZoneRrsetRecordsRequest {
+    /// Records to update in the [RRSet](#tag/zone-rrsets).
+    pub records: Vec<UpdateZoneRrsetRecordsRequestRecord>,
+}
+
+/// Response for a created [Action](#description/actions).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct UpdateZoneRrsetRecordsResponse {
+    pub action: Action,
+}
+
+/// Generated from `GET /zones/{id_or_name}/zonefile`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GetZoneZonefileResponse {
+    /// Generated zone file.
+    pub zonefile: String,
+}
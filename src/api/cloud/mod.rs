@@ -3,6 +3,7 @@ use crate::HetznerClient;
 pub mod actions;
 pub mod domains;
 pub mod enums;
+pub mod generated_models;
 pub mod generated_ops;
 pub mod load_balancers_api;
 pub mod private_networks;
@@ -3,7 +3,7 @@ use crate::HetznerClient;
 use crate::error::Result;
 use crate::types::{CloudServer, CloudServerEnvelope, CloudServersEnvelope, CreateServerResponse};
 use reqwest::Method;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Default)]
 pub struct ListServersParams {
@@ -16,6 +16,38 @@ pub struct ListServersParams {
 }
 
 impl ListServersParams {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn label_selector(mut self, selector: impl Into<String>) -> Self {
+        self.label_selector = Some(selector.into());
+        self
+    }
+
+    /// Adds a sort key; earlier keys take precedence.
+    pub fn sort(mut self, sort: ServerSort) -> Self {
+        self.sort.push(sort);
+        self
+    }
+
+    /// Adds a status to match; servers in any of them are listed.
+    pub fn status(mut self, status: ServerStatus) -> Self {
+        self.status.push(status);
+        self
+    }
+
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
     fn to_query_pairs(&self) -> Vec<(String, String)> {
         let mut query = Vec::new();
 
@@ -47,7 +79,7 @@ impl ListServersParams {
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CreateServerInput {
     pub name: String,
     pub server_type: String,
@@ -58,6 +90,33 @@ pub struct CreateServerInput {
     pub start_after_create: Option<bool>,
 }
 
+impl CreateServerInput {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn server_type(mut self, server_type: impl Into<String>) -> Self {
+        self.server_type = server_type.into();
+        self
+    }
+
+    pub fn image(mut self, image: impl Into<String>) -> Self {
+        self.image = image.into();
+        self
+    }
+
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    pub fn start_after_create(mut self, start: bool) -> Self {
+        self.start_after_create = Some(start);
+        self
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct ServersApi<'a> {
     pub(crate) client: &'a HetznerClient,
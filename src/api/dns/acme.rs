@@ -0,0 +1,301 @@
+use crate::HetznerClient;
+use crate::api::dns::names::relative_name;
+use crate::api::dns::records::Ttl;
+use crate::api::dns::zones::find_zone;
+#[cfg(feature = "acme")]
+use crate::error::not_found_as_none;
+use crate::error::{HetznerError, Result};
+use crate::logging::info;
+#[cfg(feature = "acme")]
+use crate::types::Record;
+use crate::types::{CreatedRecord, Zone};
+use serde::{Deserialize, Serialize};
+use std::env;
+#[cfg(feature = "acme")]
+use std::sync::Mutex;
+
+const ACME_CHALLENGE_LABEL: &str = "_acme-challenge";
+const ACME_CHALLENGE_TTL: Ttl = Ttl::MINUTE;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AcmeHookAction {
+    Present,
+    Cleanup,
+}
+
+/// A DNS-01 challenge: the TXT record `fqdn` that must carry `value`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AcmeChallenge {
+    pub fqdn: String,
+    pub value: String,
+}
+
+impl AcmeChallenge {
+    /// Builds the challenge for `domain` (wildcard prefixes are stripped).
+    pub fn new(domain: &str, value: impl Into<String>) -> Self {
+        let domain = normalize_name(domain);
+        let domain = domain.strip_prefix("*.").unwrap_or(&domain);
+        Self {
+            fqdn: format!("{ACME_CHALLENGE_LABEL}.{domain}"),
+            value: value.into(),
+        }
+    }
+
+    /// Reads certbot's `--manual-auth-hook` / `--manual-cleanup-hook` environment.
+    ///
+    /// Certbot only sets `CERTBOT_AUTH_OUTPUT` for the cleanup hook, which is
+    /// how the action is told apart.
+    pub fn from_certbot_env() -> Option<(AcmeHookAction, Self)> {
+        let domain = env::var("CERTBOT_DOMAIN").ok()?;
+        let validation = env::var("CERTBOT_VALIDATION").ok()?;
+        let action = if env::var_os("CERTBOT_AUTH_OUTPUT").is_some() {
+            AcmeHookAction::Cleanup
+        } else {
+            AcmeHookAction::Present
+        };
+        Some((action, Self::new(&domain, validation)))
+    }
+
+    /// Parses the arguments of lego's `exec` provider (`present|cleanup <fqdn> <value>`).
+    pub fn from_lego_args<S: AsRef<str>>(args: &[S]) -> Option<(AcmeHookAction, Self)> {
+        let [action, fqdn, value] = args else {
+            return None;
+        };
+        let action = match action.as_ref() {
+            "present" => AcmeHookAction::Present,
+            "cleanup" => AcmeHookAction::Cleanup,
+            _ => return None,
+        };
+        Some((
+            action,
+            Self {
+                fqdn: normalize_name(fqdn.as_ref()),
+                value: value.as_ref().to_string(),
+            },
+        ))
+    }
+}
+
+pub async fn present(client: &HetznerClient, challenge: &AcmeChallenge) -> Result<CreatedRecord> {
+    let client = &client.correlated();
+    let (zone, name) = locate_zone(client, &challenge.fqdn).await?;
+    create_challenge_record(client, &zone, name, challenge).await
+}
+
+async fn create_challenge_record(
+    client: &HetznerClient,
+    zone: &Zone,
+    name: String,
+    challenge: &AcmeChallenge,
+) -> Result<CreatedRecord> {
+    info!(zone = %zone.name, %name, "presenting acme challenge");
+    client
+        .dns()
+        .records(&zone.id)
+        .create(name, "TXT", challenge.value.clone(), ACME_CHALLENGE_TTL)
+        .await
+}
+
+pub async fn cleanup(client: &HetznerClient, challenge: &AcmeChallenge) -> Result<usize> {
+    let client = &client.correlated();
+    let (zone, name) = locate_zone(client, &challenge.fqdn).await?;
+    let records = client.dns().records(&zone.id).list().await?;
+    let mut removed = 0;
+
+    for record in records.iter().filter(|record| {
+        record.record_type == "TXT"
+            && record.name == name
+            && record.value.trim_matches('"') == challenge.value
+    }) {
+        client.dns().record(&record.id).delete().await?;
+        removed += 1;
+    }
+
+    info!(zone = %zone.name, %name, removed, "cleaned up acme challenge");
+    Ok(removed)
+}
+
+/// Solves DNS-01 challenges for an in-process ACME client (`acme` feature).
+/// It implements lers' [`Solver`](lers::solver::Solver), so it can be
+/// registered with `Directory::builder(url).dns01_solver(Box::new(solver))`.
+/// Clients without a solver trait, such as instant-acme, call
+/// [`present`](Self::present) for every authorization, wait until the
+/// zones' nameservers serve the records, tell the CA the challenges are
+/// ready, and [`cleanup`](Self::cleanup) once the order is finalized. See
+/// [`DnsApi::dns01_solver`](crate::api::dns::DnsApi::dns01_solver).
+///
+/// The solver remembers the id of every record it creates, and cleanup
+/// deletes exactly those records, leaving other TXT records with the same
+/// name or value alone.
+#[cfg(feature = "acme")]
+#[derive(Debug)]
+pub struct Dns01Solver {
+    client: HetznerClient,
+    presented: Mutex<Vec<Presented>>,
+}
+
+/// A record [`Dns01Solver`] created, with the lers challenge token it
+/// answers, if it was presented through the [`Solver`](lers::solver::Solver)
+/// trait.
+#[cfg(feature = "acme")]
+#[derive(Debug, Clone)]
+struct Presented {
+    token: Option<String>,
+    challenge: AcmeChallenge,
+    zone: Zone,
+    record: Record,
+}
+
+#[cfg(feature = "acme")]
+impl Dns01Solver {
+    pub(crate) fn new(client: &HetznerClient) -> Self {
+        Self {
+            client: client.clone(),
+            presented: Mutex::default(),
+        }
+    }
+
+    /// Creates the `_acme-challenge` TXT record for `identifier` (the
+    /// authorization's domain, wildcards allowed) carrying `dns_value`, the
+    /// base64url SHA-256 digest of the key authorization.
+    pub async fn present(&self, identifier: &str, dns_value: impl Into<String>) -> Result<Record> {
+        self.present_for(None, identifier, dns_value.into()).await
+    }
+
+    async fn present_for(
+        &self,
+        token: Option<String>,
+        identifier: &str,
+        dns_value: String,
+    ) -> Result<Record> {
+        let challenge = AcmeChallenge::new(identifier, dns_value);
+        let client = &self.client.correlated();
+        let (zone, name) = locate_zone(client, &challenge.fqdn).await?;
+        let record = create_challenge_record(client, &zone, name, &challenge)
+            .await?
+            .record;
+        self.presented.lock().unwrap().push(Presented {
+            token,
+            challenge,
+            zone,
+            record: record.clone(),
+        });
+        Ok(record)
+    }
+
+    /// The challenges presented and not yet cleaned up.
+    pub fn challenges(&self) -> Vec<AcmeChallenge> {
+        let presented = self.presented.lock().unwrap();
+        presented.iter().map(|p| p.challenge.clone()).collect()
+    }
+
+    /// Waits until every nameserver of each zone serves the presented
+    /// records, which is what the CA will query (`propagation` feature).
+    #[cfg(feature = "propagation")]
+    pub async fn await_propagation(&self, timeout: std::time::Duration) -> Result<()> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        let presented = self.presented.lock().unwrap().clone();
+        for Presented { zone, record, .. } in &presented {
+            let nameservers = crate::api::dns::propagation::zone_nameservers(zone).await;
+            let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+            crate::api::dns::propagation::await_propagation(
+                &self.client,
+                record,
+                &nameservers,
+                nameservers.len(),
+                remaining,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Deletes every record presented so far, returning how many were
+    /// deleted; records already gone are skipped. Cleanup continues past
+    /// failures; the first one is returned.
+    pub async fn cleanup(&self) -> Result<usize> {
+        let presented = std::mem::take(&mut *self.presented.lock().unwrap());
+        self.delete(presented).await
+    }
+
+    async fn delete(&self, presented: Vec<Presented>) -> Result<usize> {
+        let client = self.client.correlated();
+        let mut removed = 0;
+        let mut first_error = None;
+        for Presented { zone, record, .. } in presented {
+            match not_found_as_none(client.dns().record(&record.id).delete().await) {
+                Ok(deleted) => {
+                    info!(zone = %zone.name, name = %record.name, "cleaned up acme challenge");
+                    removed += usize::from(deleted.is_some());
+                }
+                Err(err) => {
+                    first_error.get_or_insert(err);
+                }
+            }
+        }
+        match first_error {
+            Some(err) => Err(err),
+            None => Ok(removed),
+        }
+    }
+}
+
+/// lers hands over the DNS value of the key authorization and the
+/// challenge token; cleanup deletes the records presented for that token.
+#[cfg(feature = "acme")]
+#[async_trait::async_trait]
+impl lers::solver::Solver for Dns01Solver {
+    async fn present(
+        &self,
+        domain: String,
+        token: String,
+        key_authorization: String,
+    ) -> std::result::Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.present_for(Some(token), &domain, key_authorization)
+            .await
+            .map(drop)
+            .map_err(lers::solver::boxed_err)
+    }
+
+    async fn cleanup(
+        &self,
+        token: &str,
+    ) -> std::result::Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let presented = {
+            let mut all = self.presented.lock().unwrap();
+            let (presented, rest) = std::mem::take(&mut *all)
+                .into_iter()
+                .partition(|p| p.token.as_deref() == Some(token));
+            *all = rest;
+            presented
+        };
+        self.delete(presented)
+            .await
+            .map(drop)
+            .map_err(lers::solver::boxed_err)
+    }
+}
+
+/// Finds the zone with the longest name that is a suffix of `fqdn` and returns
+/// it together with the zone-relative record name. Each candidate suffix is
+/// looked up by name, longest first, so zones beyond the first page of a
+/// listing are found too.
+async fn locate_zone(client: &HetznerClient, fqdn: &str) -> Result<(Zone, String)> {
+    let fqdn = normalize_name(fqdn);
+    let candidates = fqdn
+        .match_indices('.')
+        .map(|(dot, _)| &fqdn[dot + 1..])
+        .filter(|suffix| suffix.contains('.'));
+    for candidate in std::iter::once(fqdn.as_str()).chain(candidates) {
+        if let Some(zone) = find_zone(client, candidate).await? {
+            let name = relative_name(&fqdn, &zone.name);
+            return Ok((zone, name));
+        }
+    }
+    Err(HetznerError::ZoneNotFound(fqdn))
+}
+
+fn normalize_name(name: &str) -> String {
+    name.trim().trim_end_matches('.').to_ascii_lowercase()
+}
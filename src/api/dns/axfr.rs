@@ -0,0 +1,223 @@
+//! Compares a secondary zone with its primary via AXFR (`axfr` feature).
+
+use crate::HetznerClient;
+use crate::api::dns::names::{absolute_name, relative_name};
+use crate::api::dns::records::Ttl;
+use crate::error::{HetznerError, Result};
+use crate::types::{Record, RecordType as DnsType, Zone};
+use futures_util::StreamExt;
+use hickory_net::client::{Client, ClientHandle};
+use hickory_net::runtime::TokioRuntimeProvider;
+use hickory_net::tcp::TcpClientStream;
+use hickory_proto::rr::{Name, RecordType};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// A record as served by the primary, with its name relative to the zone
+/// (`@` for the apex) to match the Hetzner representation. A TTL outside
+/// Hetzner's limits is brought to the nearest one Hetzner accepts.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TransferredRecord {
+    pub name: String,
+    pub record_type: String,
+    pub value: String,
+    pub ttl: Ttl,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RecordSetDiff {
+    pub name: String,
+    pub record_type: String,
+    pub primary_values: Vec<String>,
+    pub hetzner_values: Vec<String>,
+    pub primary_ttl: Ttl,
+    pub hetzner_ttl: Ttl,
+}
+
+/// Differences between the primary's zone and Hetzner's copy. SOA records are
+/// ignored since Hetzner serves its own.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ZoneComparison {
+    /// On the primary but absent at Hetzner.
+    pub missing: Vec<TransferredRecord>,
+    /// Present on both sides with different values or TTL.
+    pub stale: Vec<RecordSetDiff>,
+    /// At Hetzner but not on the primary.
+    pub extra: Vec<Record>,
+}
+
+impl ZoneComparison {
+    pub fn is_in_sync(&self) -> bool {
+        self.missing.is_empty() && self.stale.is_empty() && self.extra.is_empty()
+    }
+}
+
+/// Runs a full zone transfer of `zone_name` from `primary` over TCP.
+pub async fn transfer_zone(
+    primary: SocketAddr,
+    zone_name: &str,
+    timeout: Duration,
+) -> Result<Vec<TransferredRecord>> {
+    let origin = Name::from_str(&format!("{}.", zone_name.trim_end_matches('.')))
+        .map_err(|err| HetznerError::ZoneTransfer(format!("invalid zone name: {err}")))?;
+    let (stream, sender) =
+        TcpClientStream::new(primary, None, Some(timeout), TokioRuntimeProvider::new());
+    let stream = stream
+        .await
+        .map_err(|err| HetznerError::ZoneTransfer(format!("connect to {primary}: {err}")))?;
+    let (mut client, background) =
+        Client::<TokioRuntimeProvider>::with_timeout(stream, sender, timeout);
+    let background = tokio::spawn(background);
+
+    let mut responses = client.zone_transfer(origin.clone(), None);
+    let mut records = Vec::new();
+    while let Some(response) = responses.next().await {
+        let response = response
+            .map_err(|err| HetznerError::ZoneTransfer(format!("transfer {zone_name}: {err}")))?;
+        for answer in &response.answers {
+            if answer.record_type() == RecordType::SOA {
+                continue;
+            }
+            records.push(TransferredRecord {
+                name: relative_name(&answer.name.to_ascii(), zone_name),
+                record_type: answer.record_type().to_string(),
+                value: answer.data.to_string(),
+                ttl: Ttl::saturating(answer.ttl.into()),
+            });
+        }
+    }
+    background.abort();
+    Ok(records)
+}
+
+/// Values and TTL of all records sharing a name and type.
+#[derive(Debug)]
+struct RecordSet<'a> {
+    values: Vec<String>,
+    ttl: Ttl,
+    records: Vec<&'a Record>,
+}
+
+impl RecordSet<'_> {
+    fn new(ttl: Ttl) -> Self {
+        Self {
+            values: Vec::new(),
+            ttl,
+            records: Vec::new(),
+        }
+    }
+}
+
+type RecordSets<'a> = BTreeMap<(String, String), RecordSet<'a>>;
+
+/// Diffs the primary's records against Hetzner's, grouping by name and type.
+pub fn compare(zone: &Zone, hetzner: &[Record], primary: &[TransferredRecord]) -> ZoneComparison {
+    let mut primary_sets = RecordSets::new();
+    for record in primary {
+        let set = primary_sets
+            .entry(key(&record.name, &record.record_type))
+            .or_insert_with(|| RecordSet::new(record.ttl));
+        set.ttl = record.ttl;
+        set.values.push(normalize_value(
+            &record.record_type,
+            &record.value,
+            &zone.name,
+        ));
+    }
+
+    let mut hetzner_sets = RecordSets::new();
+    for record in hetzner
+        .iter()
+        .filter(|r| !r.record_type.eq_ignore_ascii_case("SOA"))
+    {
+        let set = hetzner_sets
+            .entry(key(&record.relative_name(zone), &record.record_type))
+            .or_insert_with(|| RecordSet::new(record.effective_ttl(zone)));
+        set.ttl = record.effective_ttl(zone);
+        set.values.push(normalize_value(
+            &record.record_type,
+            &record.value,
+            &zone.name,
+        ));
+        set.records.push(record);
+    }
+
+    let mut comparison = ZoneComparison::default();
+    for record in primary {
+        if !hetzner_sets.contains_key(&key(&record.name, &record.record_type)) {
+            comparison.missing.push(record.clone());
+        }
+    }
+    for ((name, record_type), mut primary_set) in primary_sets {
+        let Some(mut hetzner_set) = hetzner_sets.remove(&(name.clone(), record_type.clone()))
+        else {
+            continue;
+        };
+        primary_set.values.sort();
+        hetzner_set.values.sort();
+        if primary_set.values != hetzner_set.values || primary_set.ttl != hetzner_set.ttl {
+            comparison.stale.push(RecordSetDiff {
+                name,
+                record_type,
+                primary_values: primary_set.values,
+                hetzner_values: hetzner_set.values,
+                primary_ttl: primary_set.ttl,
+                hetzner_ttl: hetzner_set.ttl,
+            });
+        }
+    }
+    comparison.extra = hetzner_sets
+        .into_values()
+        .flat_map(|set| set.records.into_iter().cloned())
+        .collect();
+    comparison
+}
+
+pub(crate) async fn compare_with_primary(
+    client: &HetznerClient,
+    zone_id: &str,
+    primary: SocketAddr,
+    timeout: Duration,
+) -> Result<ZoneComparison> {
+    let zone = client.dns().zone(zone_id).get().await?.zone;
+    let records = client.dns().records(zone_id).list().await?;
+    let transferred = transfer_zone(primary, &zone.name, timeout).await?;
+    Ok(compare(&zone, &records, &transferred))
+}
+
+fn key(name: &str, record_type: &str) -> (String, String) {
+    (
+        name.trim_end_matches('.').to_ascii_lowercase(),
+        record_type.to_ascii_uppercase(),
+    )
+}
+
+/// [`normalize_value`](crate::types::normalize_value) with hostnames
+/// relative to the zone qualified first, as the primary sends them absolute.
+fn normalize_value(record_type: &str, value: &str, zone_name: &str) -> String {
+    let kind = DnsType::from(record_type);
+    if !kind.requires_fqdn_value() {
+        let value = crate::types::normalize_value(record_type, value);
+        // Presentation formats differ in case, e.g. for hex data.
+        return if kind == DnsType::Txt {
+            value
+        } else {
+            value.to_ascii_lowercase()
+        };
+    }
+    let qualified = value
+        .split_whitespace()
+        .map(|part| {
+            if part.ends_with('.') || part.chars().all(|c| c.is_ascii_digit()) {
+                part.to_string()
+            } else {
+                absolute_name(part, zone_name)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    crate::types::normalize_value(record_type, &qualified)
+}
@@ -0,0 +1,381 @@
+//! Exports every zone of an account to a directory, one zone file and one
+//! JSON document per zone, and plans restoring such a directory.
+
+use crate::HetznerClient;
+use crate::api::dns::batch::BatchResult;
+use crate::api::dns::presets::PresetPlan;
+use crate::api::dns::provider::DnsRecord;
+use crate::api::dns::records::Ttl;
+use crate::error::{HetznerError, Result};
+use crate::progress::{Progress, ProgressHook, ProgressTracker};
+use crate::types::{Record, Zone};
+use futures_util::{StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+const DEFAULT_CONCURRENCY: usize = 8;
+
+/// A zone and its records as written to `<zone>.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ZoneBackup {
+    pub zone: Zone,
+    pub records: Vec<Record>,
+}
+
+impl ZoneBackup {
+    /// The records as a BIND-style zone file relative to `$ORIGIN`.
+    pub fn zone_file(&self) -> String {
+        let mut output = format!(
+            "$ORIGIN {}.\n$TTL {}\n",
+            self.zone.name.trim_end_matches('.'),
+            self.zone.default_ttl().seconds()
+        );
+        for record in &self.records {
+            let record = Record {
+                name: record.relative_name(&self.zone),
+                ..record.clone()
+            };
+            output.push_str(&format!("{record}\n"));
+        }
+        output
+    }
+
+    /// Reads a `<zone>.json` document written by
+    /// [`backup_account_to_dir`].
+    pub fn read(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|err| {
+            HetznerError::Backup(format!("failed to read {}: {err}", path.display()))
+        })?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+}
+
+/// Reported after each zone, whether it was written or failed.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BackupProgress {
+    pub zone_id: String,
+    pub zone_name: String,
+    /// Zones finished so far, including this one.
+    pub completed: usize,
+    pub total: usize,
+    pub error: Option<String>,
+    /// Time since the backup started.
+    #[serde(default)]
+    pub elapsed: Duration,
+}
+
+impl BackupProgress {
+    /// The remaining time if the remaining zones take as long as the
+    /// finished ones did on average.
+    pub fn eta(&self) -> Option<Duration> {
+        crate::progress::eta(self.completed, self.total, self.elapsed)
+    }
+}
+
+pub type ProgressCallback = Arc<dyn Fn(&BackupProgress) + Send + Sync>;
+
+#[derive(Clone)]
+pub struct BackupOptions {
+    /// Zones exported at the same time, 8 by default.
+    pub concurrency: usize,
+    pub on_progress: Option<ProgressCallback>,
+}
+
+impl Default for BackupOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: DEFAULT_CONCURRENCY,
+            on_progress: None,
+        }
+    }
+}
+
+impl fmt::Debug for BackupOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BackupOptions")
+            .field("concurrency", &self.concurrency)
+            .field("on_progress", &self.on_progress.is_some())
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BackupFailure {
+    pub zone_id: String,
+    pub zone_name: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BackupReport {
+    pub directory: PathBuf,
+    /// Names of the zones written, sorted.
+    pub zones: Vec<String>,
+    pub failed: Vec<BackupFailure>,
+}
+
+impl BackupReport {
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Writes `<zone>.zone` and `<zone>.json` for every zone of the account into
+/// `dir`, creating it if needed. Zones are exported `options.concurrency` at
+/// a time; a zone that fails is reported in [`BackupReport::failed`] without
+/// stopping the others.
+pub async fn backup_account_to_dir(
+    client: &HetznerClient,
+    dir: impl AsRef<Path>,
+    options: BackupOptions,
+) -> Result<BackupReport> {
+    let dir = dir.as_ref();
+    tokio::fs::create_dir_all(dir).await.map_err(|err| {
+        HetznerError::Backup(format!("failed to create {}: {err}", dir.display()))
+    })?;
+    let zones: Vec<Zone> = client.dns().zones_stream().try_collect().await?;
+
+    let total = zones.len();
+    let started = Instant::now();
+    let completed = AtomicUsize::new(0);
+    let outcomes: Vec<(Zone, Result<()>)> = futures_util::stream::iter(zones)
+        .map(|zone| async {
+            let outcome = backup_zone(client, dir, &zone).await;
+            let progress = BackupProgress {
+                zone_id: zone.id.clone(),
+                zone_name: zone.name.clone(),
+                completed: completed.fetch_add(1, Ordering::SeqCst) + 1,
+                total,
+                error: outcome.as_ref().err().map(ToString::to_string),
+                elapsed: started.elapsed(),
+            };
+            if let Some(callback) = &options.on_progress {
+                callback(&progress);
+            }
+            (zone, outcome)
+        })
+        .buffer_unordered(options.concurrency.max(1))
+        .collect()
+        .await;
+
+    let mut report = BackupReport {
+        directory: dir.to_path_buf(),
+        ..Default::default()
+    };
+    for (zone, outcome) in outcomes {
+        match outcome {
+            Ok(()) => report.zones.push(zone.name),
+            Err(err) => report.failed.push(BackupFailure {
+                zone_id: zone.id,
+                zone_name: zone.name,
+                error: err.to_string(),
+            }),
+        }
+    }
+    report.zones.sort();
+    report.failed.sort_by(|a, b| a.zone_name.cmp(&b.zone_name));
+    Ok(report)
+}
+
+/// What restoring one backed-up zone would change.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ZoneRestorePlan {
+    pub zone_name: String,
+    /// `None` when the zone no longer exists and will be created.
+    pub zone_id: Option<String>,
+    pub ttl: Option<Ttl>,
+    pub changes: PresetPlan,
+}
+
+impl ZoneRestorePlan {
+    pub fn creates_zone(&self) -> bool {
+        self.zone_id.is_none()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.creates_zone() && self.changes.is_empty()
+    }
+}
+
+/// Per-zone plans for a backup directory, to be reviewed before
+/// [`apply`](Self::apply). Zones can be dropped from `zones` to leave them
+/// untouched.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RestorePlan {
+    pub zones: Vec<ZoneRestorePlan>,
+}
+
+impl RestorePlan {
+    /// Zones with something to change.
+    pub fn pending(&self) -> impl Iterator<Item = &ZoneRestorePlan> {
+        self.zones.iter().filter(|plan| !plan.is_empty())
+    }
+
+    /// Creates missing zones and brings each zone's records in line with
+    /// the backup, one rolled-back-on-failure batch per zone. A failing zone
+    /// does not stop the others; each outcome is returned with its zone name.
+    pub async fn apply(&self, client: &HetznerClient) -> Vec<(String, Result<BatchResult>)> {
+        self.apply_reporting(client, None).await
+    }
+
+    /// Like [`apply`](Self::apply), calling `callback` after each zone with
+    /// the zones done out of those pending.
+    pub async fn apply_with_progress(
+        &self,
+        client: &HetznerClient,
+        callback: impl Fn(&Progress) + Send + Sync + 'static,
+    ) -> Vec<(String, Result<BatchResult>)> {
+        self.apply_reporting(client, Some(&ProgressHook::new(callback)))
+            .await
+    }
+
+    async fn apply_reporting(
+        &self,
+        client: &HetznerClient,
+        hook: Option<&ProgressHook>,
+    ) -> Vec<(String, Result<BatchResult>)> {
+        let client = client.correlated();
+        let mut progress = ProgressTracker::new(hook, self.pending().count());
+        let mut outcomes = Vec::new();
+        for plan in self.pending() {
+            let outcome = apply_zone(&client, plan).await;
+            progress.advance(1, Some(&plan.zone_name));
+            outcomes.push((plan.zone_name.clone(), outcome));
+        }
+        outcomes
+    }
+}
+
+/// Reads every `<zone>.json` in `dir` and diffs it against the live zone of
+/// the same name. Nothing is changed; see [`RestorePlan::apply`].
+///
+/// SOA and apex NS records are left out, since Hetzner manages them.
+pub async fn restore_account_from_dir(
+    client: &HetznerClient,
+    dir: impl AsRef<Path>,
+) -> Result<RestorePlan> {
+    let backups = read_backups(dir.as_ref())?;
+    let live_zones: Vec<Zone> = client.dns().zones_stream().try_collect().await?;
+
+    let mut plan = RestorePlan::default();
+    for backup in backups {
+        client.check_cancelled()?;
+        let live = live_zones
+            .iter()
+            .find(|zone| zone.name.eq_ignore_ascii_case(&backup.zone.name));
+        let existing = match live {
+            Some(zone) => client.dns().records(&zone.id).list().await?,
+            None => Vec::new(),
+        };
+        plan.zones.push(ZoneRestorePlan {
+            zone_name: backup.zone.name.clone(),
+            zone_id: live.map(|zone| zone.id.clone()),
+            ttl: backup.zone.ttl,
+            changes: diff_records(&backup, &existing),
+        });
+    }
+    Ok(plan)
+}
+
+fn read_backups(dir: &Path) -> Result<Vec<ZoneBackup>> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|err| HetznerError::Backup(format!("failed to read {}: {err}", dir.display())))?;
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|err| {
+                HetznerError::Backup(format!("failed to read {}: {err}", dir.display()))
+            })?
+            .path();
+        if path.extension().is_some_and(|ext| ext == "json") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    paths.iter().map(ZoneBackup::read).collect()
+}
+
+fn diff_records(backup: &ZoneBackup, existing: &[Record]) -> PresetPlan {
+    let same =
+        |wanted: &Record, record: &Record| wanted.key() == record.key() && wanted.ttl == record.ttl;
+    let wanted: Vec<&Record> = backup
+        .records
+        .iter()
+        .filter(|record| !is_managed(record))
+        .collect();
+    let mut plan = PresetPlan::default();
+    for record in existing.iter().filter(|record| !is_managed(record)) {
+        if wanted.iter().any(|wanted| same(wanted, record)) {
+            plan.unchanged.push(record.clone());
+        } else {
+            plan.delete.push(record.clone());
+        }
+    }
+    plan.create = wanted
+        .into_iter()
+        .filter(|wanted| !plan.unchanged.iter().any(|record| same(wanted, record)))
+        .map(DnsRecord::from)
+        .collect();
+    plan
+}
+
+fn is_managed(record: &Record) -> bool {
+    record.record_type.eq_ignore_ascii_case("SOA")
+        || record.record_type.eq_ignore_ascii_case("NS") && matches!(record.name.as_str(), "@" | "")
+}
+
+async fn apply_zone(client: &HetznerClient, plan: &ZoneRestorePlan) -> Result<BatchResult> {
+    let zone_id = match &plan.zone_id {
+        Some(zone_id) => zone_id.clone(),
+        None => {
+            client
+                .dns()
+                .ensure_zone(&plan.zone_name, plan.ttl)
+                .await?
+                .id
+        }
+    };
+    if plan.changes.is_empty() {
+        return Ok(BatchResult::default());
+    }
+    let mut batch = client.dns().batch(&zone_id).rollback_on_failure();
+    for record in &plan.changes.delete {
+        batch = batch.delete(record.id.clone());
+    }
+    for record in &plan.changes.create {
+        batch = batch.create(
+            record.name.clone(),
+            record.record_type.clone(),
+            record.value.clone(),
+            record.ttl,
+        );
+    }
+    batch.commit().await
+}
+
+async fn backup_zone(client: &HetznerClient, dir: &Path, zone: &Zone) -> Result<()> {
+    client.check_cancelled()?;
+    let records = client.dns().records(&zone.id).list().await?;
+    let backup = ZoneBackup {
+        zone: zone.clone(),
+        records,
+    };
+    let base = zone.name.trim_end_matches('.');
+    write(&dir.join(format!("{base}.zone")), backup.zone_file()).await?;
+    write(
+        &dir.join(format!("{base}.json")),
+        serde_json::to_string_pretty(&backup)?,
+    )
+    .await
+}
+
+async fn write(path: &Path, contents: String) -> Result<()> {
+    tokio::fs::write(path, contents)
+        .await
+        .map_err(|err| HetznerError::Backup(format!("failed to write {}: {err}", path.display())))
+}
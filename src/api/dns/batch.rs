@@ -0,0 +1,388 @@
+use crate::HetznerClient;
+use crate::api::dns::records::{BulkUpdateRecordInput, CreateRecordInput, Ttl, UpdateRecordInput};
+use crate::api::dns::zone_ref::ZoneRef;
+use crate::error::Result;
+use crate::mutation::MutationOperation;
+use crate::policy::ProposedChange;
+use crate::progress::{Progress, ProgressHook, ProgressTracker};
+use crate::types::Record;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchOperation {
+    Create(CreateRecordInput),
+    Update {
+        record_id: String,
+        input: UpdateRecordInput,
+    },
+    Delete {
+        record_id: String,
+    },
+}
+
+/// A change a committed batch made, with the record's prior state where the
+/// zone held it before the commit.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppliedChange {
+    Created(Record),
+    Updated {
+        before: Option<Record>,
+        after: Record,
+    },
+    Deleted {
+        record_id: String,
+        before: Option<Record>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BatchFailure {
+    pub operation: BatchOperation,
+    pub error: String,
+}
+
+/// The aggregated outcome of [`Batch::commit`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BatchResult {
+    pub applied: Vec<AppliedChange>,
+    pub failed: Vec<BatchFailure>,
+    /// Operations not attempted because an earlier step failed.
+    pub skipped: Vec<BatchOperation>,
+    /// Set when the commit failed and [`Batch::rollback_on_failure`] was on.
+    pub rollback: Option<RollbackReport>,
+}
+
+/// What [`BatchResult::rollback`] managed to undo.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RollbackReport {
+    pub reverted: Vec<AppliedChange>,
+    /// Changes that are still in place, with the reason.
+    pub not_reverted: Vec<(AppliedChange, String)>,
+}
+
+impl RollbackReport {
+    pub fn is_complete(&self) -> bool {
+        self.not_reverted.is_empty()
+    }
+}
+
+impl BatchResult {
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty() && self.skipped.is_empty()
+    }
+
+    /// Best-effort undo of the applied changes, newest first: created records
+    /// are deleted, updated records get their prior fields back and deleted
+    /// records are re-created (under a new id). Changes without a known prior
+    /// state cannot be reverted.
+    pub async fn rollback(&self, client: &HetznerClient) -> RollbackReport {
+        let client = client.correlated();
+        let mut report = RollbackReport::default();
+        for change in self.applied.iter().rev() {
+            match revert(&client, change).await {
+                Ok(()) => report.reverted.push(change.clone()),
+                Err(reason) => report.not_reverted.push((change.clone(), reason)),
+            }
+        }
+        report
+    }
+}
+
+async fn revert(client: &HetznerClient, change: &AppliedChange) -> std::result::Result<(), String> {
+    let missing_state = || "prior state unknown".to_string();
+    match change {
+        AppliedChange::Created(record) => client.dns().record(&record.id).delete().await,
+        AppliedChange::Updated { before, after } => {
+            let before = before.as_ref().ok_or_else(missing_state)?;
+            client
+                .dns()
+                .record(&after.id)
+                .update(UpdateRecordInput {
+                    zone_id: before.zone_id.clone(),
+                    record_type: before.record_type.clone(),
+                    name: before.name.clone(),
+                    value: before.value.clone(),
+                    ttl: before.ttl,
+                })
+                .await
+                .map(drop)
+        }
+        AppliedChange::Deleted { before, .. } => {
+            let before = before.as_ref().ok_or_else(missing_state)?;
+            client
+                .dns()
+                .records(&before.zone_id)
+                .create(&before.name, &before.record_type, &before.value, before.ttl)
+                .await
+                .map(drop)
+        }
+    }
+    .map_err(|err| err.to_string())
+}
+
+/// Record changes to one zone, queued and then applied together by
+/// [`commit`](Self::commit).
+#[derive(Debug, Clone)]
+pub struct Batch<'a> {
+    pub(crate) client: &'a HetznerClient,
+    pub(crate) zone: ZoneRef<&'a str>,
+    pub(crate) operations: Vec<BatchOperation>,
+    pub(crate) rollback_on_failure: bool,
+    pub(crate) on_progress: Option<ProgressHook>,
+}
+
+impl<'a> Batch<'a> {
+    pub fn create(
+        mut self,
+        name: impl Into<String>,
+        record_type: impl Into<String>,
+        value: impl Into<String>,
+        ttl: impl Into<Option<Ttl>>,
+    ) -> Self {
+        self.operations
+            .push(BatchOperation::Create(CreateRecordInput {
+                value: value.into(),
+                ttl: ttl.into(),
+                record_type: record_type.into(),
+                name: name.into(),
+                zone_id: self.zone.to_string(),
+            }));
+        self
+    }
+
+    pub fn update(
+        mut self,
+        record_id: impl Into<String>,
+        name: impl Into<String>,
+        record_type: impl Into<String>,
+        value: impl Into<String>,
+        ttl: impl Into<Option<Ttl>>,
+    ) -> Self {
+        self.operations.push(BatchOperation::Update {
+            record_id: record_id.into(),
+            input: UpdateRecordInput {
+                zone_id: self.zone.to_string(),
+                record_type: record_type.into(),
+                name: name.into(),
+                value: value.into(),
+                ttl: ttl.into(),
+            },
+        });
+        self
+    }
+
+    pub fn delete(mut self, record_id: impl Into<String>) -> Self {
+        self.operations.push(BatchOperation::Delete {
+            record_id: record_id.into(),
+        });
+        self
+    }
+
+    /// Rolls back the changes already applied if any operation fails, so the
+    /// zone isn't left half-applied; see [`BatchResult::rollback`].
+    pub fn rollback_on_failure(mut self) -> Self {
+        self.rollback_on_failure = true;
+        self
+    }
+
+    /// Calls `callback` as the commit proceeds: after each delete and after
+    /// the bulk update and bulk create steps, counting operations (skipped
+    /// ones included) against all queued ones. Rollbacks are not reported.
+    pub fn on_progress(mut self, callback: impl Fn(&Progress) + Send + Sync + 'static) -> Self {
+        self.on_progress = Some(ProgressHook::new(callback));
+        self
+    }
+
+    /// The queued operations. A zone given by name is only resolved by
+    /// [`commit`](Self::commit), so until then their `zone_id` holds the name.
+    pub fn operations(&self) -> &[BatchOperation] {
+        &self.operations
+    }
+
+    /// Applies the queued operations in dependency order: deletes first (so
+    /// e.g. a CNAME can make way for an A record of the same name), then all
+    /// updates in one bulk request, then all creates in one bulk request.
+    /// A step with failures stops the commit and the remaining operations are
+    /// reported as skipped.
+    pub async fn commit(self) -> Result<BatchResult> {
+        let client = self.client.correlated();
+        let zone_id = client.zone_id(self.zone.as_ref()).await?.into_owned();
+        let zone = self.zone.to_string();
+        let total = self.operations.len();
+        let before: BTreeMap<String, Record> = client
+            .dns()
+            .records(&zone_id)
+            .list()
+            .await?
+            .into_iter()
+            .map(|record| (record.id.clone(), record))
+            .collect();
+
+        let mut creates = Vec::new();
+        let mut updates = Vec::new();
+        let mut deletes = Vec::new();
+        for operation in self.operations {
+            match operation {
+                BatchOperation::Create(mut input) => {
+                    input.zone_id.clone_from(&zone_id);
+                    creates.push(input)
+                }
+                BatchOperation::Update {
+                    record_id,
+                    mut input,
+                } => {
+                    input.zone_id.clone_from(&zone_id);
+                    updates.push(BulkUpdateRecordInput {
+                        id: record_id,
+                        record: input,
+                    })
+                }
+                BatchOperation::Delete { record_id } => deletes.push(record_id),
+            }
+        }
+
+        // Check the whole batch first, so a denied change doesn't leave it
+        // half applied; the requests below (and a rollback) skip the check.
+        let proposed = || {
+            deletes
+                .iter()
+                .map(|record_id| {
+                    ProposedChange::new(
+                        MutationOperation::DeleteRecord,
+                        Some(zone_id.clone()),
+                        None,
+                        before.get(record_id).cloned(),
+                    )
+                })
+                .chain(updates.iter().map(|update| {
+                    ProposedChange::new(
+                        MutationOperation::UpdateRecord,
+                        Some(zone_id.clone()),
+                        Some(update.record.proposed()),
+                        before.get(&update.id).cloned(),
+                    )
+                }))
+                .chain(creates.iter().map(|input| {
+                    ProposedChange::new(
+                        MutationOperation::CreateRecord,
+                        Some(zone_id.clone()),
+                        Some(input.proposed()),
+                        None,
+                    )
+                }))
+                .collect()
+        };
+        client.enforce_policy(proposed).await?;
+        let client = client.without_policy();
+        let records = client.dns().records(&zone_id);
+
+        let mut progress = ProgressTracker::new(self.on_progress.as_ref(), total);
+        let mut result = BatchResult::default();
+        let mut deletes = deletes.into_iter();
+        while let Some(record_id) = deletes.next() {
+            match client.dns().record(&record_id).delete().await {
+                Ok(()) => result.applied.push(AppliedChange::Deleted {
+                    before: before.get(&record_id).cloned(),
+                    record_id,
+                }),
+                Err(err) => {
+                    result.failed.push(BatchFailure {
+                        operation: BatchOperation::Delete { record_id },
+                        error: err.to_string(),
+                    });
+                    let rest = deletes.len();
+                    result.skipped.extend(
+                        deletes
+                            .by_ref()
+                            .map(|record_id| BatchOperation::Delete { record_id }),
+                    );
+                    progress.advance(1 + rest, Some(&zone));
+                    break;
+                }
+            }
+            progress.advance(1, Some(&zone));
+        }
+
+        if !updates.is_empty() {
+            let count = updates.len();
+            if !result.failed.is_empty() {
+                result
+                    .skipped
+                    .extend(updates.into_iter().map(update_operation));
+            } else {
+                match records.bulk_update(&updates[..]).await {
+                    Ok(response) => {
+                        for after in response.records {
+                            result.applied.push(AppliedChange::Updated {
+                                before: before.get(&after.id).cloned(),
+                                after,
+                            });
+                        }
+                        for failed in response.failed_records {
+                            result.failed.push(BatchFailure {
+                                operation: update_operation(failed),
+                                error: "rejected by the bulk update".to_string(),
+                            });
+                        }
+                    }
+                    Err(err) => {
+                        result
+                            .failed
+                            .extend(updates.into_iter().map(|update| BatchFailure {
+                                operation: update_operation(update),
+                                error: err.to_string(),
+                            }))
+                    }
+                }
+            }
+            progress.advance(count, Some(&zone));
+        }
+
+        if !creates.is_empty() {
+            let count = creates.len();
+            if !result.failed.is_empty() {
+                result
+                    .skipped
+                    .extend(creates.into_iter().map(BatchOperation::Create));
+            } else {
+                match records.bulk_create(&creates[..]).await {
+                    Ok(response) => {
+                        result
+                            .applied
+                            .extend(response.records.into_iter().map(AppliedChange::Created));
+                        for invalid in response.invalid_records {
+                            result.failed.push(BatchFailure {
+                                operation: BatchOperation::Create(invalid),
+                                error: "rejected by the bulk create".to_string(),
+                            });
+                        }
+                    }
+                    Err(err) => {
+                        result
+                            .failed
+                            .extend(creates.into_iter().map(|input| BatchFailure {
+                                operation: BatchOperation::Create(input),
+                                error: err.to_string(),
+                            }))
+                    }
+                }
+            }
+            progress.advance(count, Some(&zone));
+        }
+
+        if self.rollback_on_failure && !result.failed.is_empty() {
+            result.rollback = Some(result.rollback(&client).await);
+        }
+        Ok(result)
+    }
+}
+
+fn update_operation(update: BulkUpdateRecordInput) -> BatchOperation {
+    BatchOperation::Update {
+        record_id: update.id,
+        input: update.record,
+    }
+}
@@ -0,0 +1,132 @@
+//! Renders zones as a [dnscontrol](https://dnscontrol.org) `dnsconfig.js`,
+//! so a dnscontrol setup can start from the live state at Hetzner.
+//!
+//! Every zone becomes one `D(...)` stanza using the registrar `REG_NONE` and
+//! the provider `DSP_HETZNER`. SOA and apex NS records are left out, as
+//! Hetzner manages them; records dnscontrol cannot express (such as RP and
+//! HINFO) or whose value does not parse are listed in a comment above the
+//! stanza instead.
+//!
+//! ```no_run
+//! # async fn run(client: hetzner::HetznerClient) -> Result<(), Box<dyn std::error::Error>> {
+//! std::fs::write("dnsconfig.js", client.dns().export_dnscontrol().await?)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::api::dns::backup::ZoneBackup;
+use crate::api::dns::record_value::character_strings;
+use crate::types::{Record, RecordType, Zone};
+
+const HEADER: &str = "var REG_NONE = NewRegistrar(\"none\");\n\
+                      var DSP_HETZNER = NewDnsProvider(\"hetzner\");\n";
+
+/// A complete `dnsconfig.js` declaring the registrar and provider and one
+/// stanza per zone.
+pub fn render_config(zones: &[ZoneBackup]) -> String {
+    let mut output = HEADER.to_string();
+    for backup in zones {
+        output.push('\n');
+        output.push_str(&render_zone(&backup.zone, &backup.records));
+    }
+    output
+}
+
+/// The `D(...)` stanza for `zone`, preceded by comments for the records it
+/// leaves out.
+pub fn render_zone(zone: &Zone, records: &[Record]) -> String {
+    let mut output = String::new();
+    let mut items = vec![format!("DefaultTTL({})", zone.default_ttl().seconds())];
+    for record in records {
+        let kind = record.kind();
+        let name = record.relative_name(zone);
+        if kind == RecordType::Soa || (kind == RecordType::Ns && name == "@") {
+            continue;
+        }
+        match item(record, &name) {
+            Some(mut item) => {
+                if let Some(ttl) = record.ttl.filter(|&ttl| ttl != zone.default_ttl()) {
+                    item.insert_str(item.len() - 1, &format!(", TTL({})", ttl.seconds()));
+                }
+                items.push(item);
+            }
+            None => output.push_str(&format!(
+                "// not exported: {} {} {}\n",
+                name, record.record_type, record.value
+            )),
+        }
+    }
+    output.push_str(&format!(
+        "D({}, REG_NONE, DnsProvider(DSP_HETZNER),\n",
+        string(zone.name.trim_end_matches('.'))
+    ));
+    output.push_str(&format!("\t{}\n);\n", items.join(",\n\t")));
+    output
+}
+
+/// The dnscontrol function call for one record, or `None` when it cannot be
+/// expressed.
+fn item(record: &Record, name: &str) -> Option<String> {
+    let name = string(name);
+    let fields: Vec<&str> = record.value.split_whitespace().collect();
+    let item = match (record.kind(), fields.as_slice()) {
+        (
+            RecordType::A | RecordType::Aaaa | RecordType::Cname | RecordType::Ns | RecordType::Ptr,
+            [target],
+        ) => {
+            format!("{}({name}, {})", record.kind(), string(target))
+        }
+        (RecordType::Mx, [priority, target]) => {
+            format!("MX({name}, {}, {})", number(priority)?, string(target))
+        }
+        (RecordType::Srv, [priority, weight, port, target]) => format!(
+            "SRV({name}, {}, {}, {}, {})",
+            number(priority)?,
+            number(weight)?,
+            number(port)?,
+            string(target)
+        ),
+        (RecordType::Caa, [flags, tag, ..]) => {
+            let value = record.value.splitn(3, char::is_whitespace).nth(2)?;
+            let critical = match number(flags)? {
+                0 => "",
+                128 => ", CAA_CRITICAL",
+                _ => return None,
+            };
+            let value = character_strings(value).concat();
+            format!("CAA({name}, {}, {}{critical})", string(tag), string(&value))
+        }
+        (RecordType::Tlsa, [usage, selector, matching, data]) => format!(
+            "TLSA({name}, {}, {}, {}, {})",
+            number(usage)?,
+            number(selector)?,
+            number(matching)?,
+            string(data)
+        ),
+        (RecordType::Ds, [key_tag, algorithm, digest_type, digest]) => format!(
+            "DS({name}, {}, {}, {}, {})",
+            number(key_tag)?,
+            number(algorithm)?,
+            number(digest_type)?,
+            string(digest)
+        ),
+        (RecordType::Txt, _) => match character_strings(&record.value).as_slice() {
+            [single] => format!("TXT({name}, {})", string(single)),
+            strings => {
+                let strings: Vec<String> = strings.iter().map(|s| string(s)).collect();
+                format!("TXT({name}, [{}])", strings.join(", "))
+            }
+        },
+        _ => return None,
+    };
+    Some(item)
+}
+
+/// A JavaScript string literal; JSON's escaping is valid JavaScript.
+fn string(value: &str) -> String {
+    serde_json::Value::from(value).to_string()
+}
+
+fn number(field: &str) -> Option<u32> {
+    field.parse().ok()
+}
@@ -0,0 +1,173 @@
+//! Keeping an address record pointed at the host's current public address
+//! (`dyndns` feature).
+
+use crate::HetznerClient;
+use crate::api::dns::ip_source::{IpSource, IpVersion};
+use crate::api::dns::record_ref::has_name_and_type;
+use crate::api::dns::records::{Ttl, UpdateRecordInput};
+use crate::api::dns::zone_ref::ZoneRef;
+use crate::error::Result;
+use crate::logging::info;
+use crate::types::Record;
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv6Addr};
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DynDnsUpdate {
+    /// A record of the name already served the detected address.
+    Unchanged(Record),
+    Updated {
+        before: Record,
+        after: Record,
+    },
+    Created(Record),
+}
+
+impl DynDnsUpdate {
+    pub fn record(&self) -> &Record {
+        match self {
+            Self::Unchanged(record) | Self::Created(record) => record,
+            Self::Updated { after, .. } => after,
+        }
+    }
+
+    pub fn changed(&self) -> bool {
+        !matches!(self, Self::Unchanged(_))
+    }
+}
+
+/// Points the A (or AAAA) record `name` of a zone at the address an
+/// [`IpSource`] detects; see
+/// [`DnsApi::dyndns`](crate::api::dns::DnsApi::dyndns).
+#[derive(Debug, Clone)]
+pub struct DynDns<'a> {
+    pub(crate) client: &'a HetznerClient,
+    pub(crate) zone: ZoneRef<&'a str>,
+    pub(crate) name: String,
+    pub(crate) version: IpVersion,
+    pub(crate) ttl: Option<Ttl>,
+    pub(crate) prefix_len: Option<u8>,
+    pub(crate) suffix: Option<Ipv6Addr>,
+}
+
+impl<'a> DynDns<'a> {
+    pub(crate) fn new(client: &'a HetznerClient, zone: ZoneRef<&'a str>, name: String) -> Self {
+        Self {
+            client,
+            zone,
+            name,
+            version: IpVersion::V4,
+            ttl: None,
+            prefix_len: None,
+            suffix: None,
+        }
+    }
+
+    /// IPv4 (an A record) by default.
+    pub fn version(mut self, version: IpVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// TTL for created or updated records; the zone default unless set.
+    pub fn ttl(mut self, ttl: impl Into<Option<Ttl>>) -> Self {
+        self.ttl = ttl.into();
+        self
+    }
+
+    /// Treats detected IPv6 addresses as carrying a delegated prefix of
+    /// `prefix_len` bits: only the prefix is taken from them, and the host
+    /// bits come from [`suffix`](Self::suffix) or, if unset, from the
+    /// record's current address. Useful for pointing a record at another
+    /// machine in the delegated network, or when the ISP renumbers.
+    pub fn prefix_len(mut self, prefix_len: u8) -> Self {
+        self.prefix_len = Some(prefix_len.min(128));
+        self
+    }
+
+    /// Host bits to keep under a changing prefix (the first
+    /// [`prefix_len`](Self::prefix_len) bits of `suffix` are ignored).
+    /// Without a prefix length, the whole detected address is used.
+    pub fn suffix(mut self, suffix: Ipv6Addr) -> Self {
+        self.suffix = Some(suffix);
+        self
+    }
+
+    /// Detects the address with `source` and applies it.
+    pub async fn update(self, source: &dyn IpSource) -> Result<DynDnsUpdate> {
+        let address = source.detect(self.version).await?;
+        self.set(address).await
+    }
+
+    /// Points the record at `address` (or, with a
+    /// [`prefix_len`](Self::prefix_len), at its prefix plus the kept host
+    /// bits). If several records of the name exist and none serves the
+    /// target, the first is updated.
+    pub async fn set(self, address: IpAddr) -> Result<DynDnsUpdate> {
+        let client = self.client.correlated();
+        let zone_id = client.zone_id(self.zone).await?;
+        let record_type = IpVersion::of(&address).record_type();
+        let existing: Vec<Record> = client
+            .dns()
+            .records(&*zone_id)
+            .list()
+            .await?
+            .into_iter()
+            .filter(|record| has_name_and_type(record, &self.name, record_type))
+            .collect();
+
+        let address = match (address, self.prefix_len) {
+            (IpAddr::V6(detected), Some(prefix_len)) => {
+                let host = self
+                    .suffix
+                    .or_else(|| existing.iter().find_map(|record| record.value.parse().ok()))
+                    .unwrap_or(detected);
+                IpAddr::V6(replace_prefix(detected, prefix_len, host))
+            }
+            _ => address,
+        };
+        let value = address.to_string();
+        if let Some(current) = existing
+            .iter()
+            .find(|record| record.value.parse::<IpAddr>() == Ok(address))
+        {
+            return Ok(DynDnsUpdate::Unchanged(current.clone()));
+        }
+        match existing.into_iter().next() {
+            Some(before) => {
+                let after = client
+                    .dns()
+                    .record(&before.id)
+                    .update(UpdateRecordInput {
+                        zone_id: zone_id.to_string(),
+                        record_type: record_type.to_string(),
+                        name: before.name.clone(),
+                        value: value.clone(),
+                        ttl: self.ttl.or(before.ttl),
+                    })
+                    .await?
+                    .record;
+                info!(name = %self.name, from = %before.value, to = %value, "updated dyndns record");
+                Ok(DynDnsUpdate::Updated { before, after })
+            }
+            None => {
+                let created = client
+                    .dns()
+                    .records(&*zone_id)
+                    .create(&self.name, record_type, &value, self.ttl)
+                    .await?
+                    .record;
+                info!(name = %self.name, to = %value, "created dyndns record");
+                Ok(DynDnsUpdate::Created(created))
+            }
+        }
+    }
+}
+
+/// The first `prefix_len` bits of `prefix` followed by the remaining bits of
+/// `host`.
+pub fn replace_prefix(prefix: Ipv6Addr, prefix_len: u8, host: Ipv6Addr) -> Ipv6Addr {
+    let mask = u128::MAX.checked_shr(u32::from(prefix_len)).unwrap_or(0);
+    Ipv6Addr::from((u128::from(prefix) & !mask) | (u128::from(host) & mask))
+}
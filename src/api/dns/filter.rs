@@ -0,0 +1,106 @@
+use crate::api::dns::records::Ttl;
+use crate::error::{HetznerError, Result};
+use crate::types::{Record, RecordType};
+use regex::Regex;
+use std::ops::{Bound, RangeBounds};
+
+/// Client-side predicate over fetched records. All configured conditions must
+/// match; an empty filter matches every record.
+#[derive(Debug, Clone, Default)]
+pub struct RecordFilter {
+    types: Vec<RecordType>,
+    name: Option<Regex>,
+    min_ttl: Option<Ttl>,
+    max_ttl: Option<Ttl>,
+    value_contains: Option<String>,
+}
+
+impl RecordFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a type to the accepted set; repeat to accept several types.
+    pub fn record_type(mut self, record_type: RecordType) -> Self {
+        self.types.push(record_type);
+        self
+    }
+
+    /// Matches the record name against a shell-style glob (`*` and `?`).
+    pub fn name_glob(mut self, pattern: &str) -> Self {
+        let mut regex = String::from("^");
+        for ch in pattern.chars() {
+            match ch {
+                '*' => regex.push_str(".*"),
+                '?' => regex.push('.'),
+                other => regex.push_str(&regex::escape(&other.to_string())),
+            }
+        }
+        regex.push('$');
+        self.name = Some(Regex::new(&regex).expect("escaped glob is a valid regex"));
+        self
+    }
+
+    pub fn name_regex(mut self, pattern: &str) -> Result<Self> {
+        let regex = Regex::new(pattern)
+            .map_err(|err| HetznerError::InvalidConfig(format!("name regex {pattern:?}: {err}")))?;
+        self.name = Some(regex);
+        Ok(self)
+    }
+
+    /// Restricts the explicit record TTL; records without a TTL never match.
+    pub fn ttl_range(mut self, range: impl RangeBounds<Ttl>) -> Self {
+        self.min_ttl = match range.start_bound() {
+            Bound::Included(&min) => Some(min),
+            Bound::Excluded(&min) => Some(Ttl::saturating(u64::from(min) + 1)),
+            Bound::Unbounded => None,
+        };
+        self.max_ttl = match range.end_bound() {
+            Bound::Included(&max) => Some(max),
+            Bound::Excluded(&max) => Some(Ttl::saturating(u64::from(max) - 1)),
+            Bound::Unbounded => None,
+        };
+        self
+    }
+
+    pub fn value_contains(mut self, needle: impl Into<String>) -> Self {
+        self.value_contains = Some(needle.into());
+        self
+    }
+
+    pub fn matches(&self, record: &Record) -> bool {
+        if !self.types.is_empty()
+            && !self
+                .types
+                .iter()
+                .any(|t| t.as_str().eq_ignore_ascii_case(&record.record_type))
+        {
+            return false;
+        }
+        if let Some(name) = &self.name
+            && !name.is_match(&record.name)
+        {
+            return false;
+        }
+        if self.min_ttl.is_some() || self.max_ttl.is_some() {
+            let Some(ttl) = record.ttl else {
+                return false;
+            };
+            if self.min_ttl.is_some_and(|min| ttl < min)
+                || self.max_ttl.is_some_and(|max| ttl > max)
+            {
+                return false;
+            }
+        }
+        if let Some(needle) = &self.value_contains
+            && !record.value.contains(needle.as_str())
+        {
+            return false;
+        }
+        true
+    }
+
+    pub fn apply(&self, records: Vec<Record>) -> Vec<Record> {
+        records.into_iter().filter(|r| self.matches(r)).collect()
+    }
+}
@@ -0,0 +1,273 @@
+//! Zone health reports combining lint findings with live DNS checks
+//! (`health` feature).
+
+use crate::HetznerClient;
+use crate::api::dns::lint::{LintFinding, Severity, lint_zone};
+use crate::api::dns::lookup::{connect, fqdn, resolve_nameservers};
+use crate::api::dns::zone_ref::ZoneRef;
+use crate::error::Result;
+use crate::types::{Record, Zone};
+use futures_util::StreamExt;
+use hickory_net::client::{Client, ClientHandle};
+use hickory_net::runtime::TokioRuntimeProvider;
+use hickory_proto::op::ResponseCode;
+use hickory_proto::rr::{DNSClass, RData, RecordType};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::time::Duration;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+const CONCURRENT_QUERIES: usize = 8;
+
+/// How one of the zone's nameservers answered the SOA query.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct NameserverStatus {
+    pub name: String,
+    /// `None` when the name could not be resolved.
+    pub address: Option<SocketAddr>,
+    pub soa_serial: Option<u32>,
+    pub error: Option<String>,
+}
+
+impl NameserverStatus {
+    pub fn is_reachable(&self) -> bool {
+        self.soa_serial.is_some()
+    }
+}
+
+/// A name and type in the zone that the nameserver did not answer for.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ResolutionFailure {
+    pub name: String,
+    pub record_type: String,
+    pub record_ids: Vec<String>,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct HealthReport {
+    pub zone_id: String,
+    pub zone_name: String,
+    pub lint: Vec<LintFinding>,
+    pub nameservers: Vec<NameserverStatus>,
+    /// Empty when no nameserver was reachable to query.
+    pub unresolvable: Vec<ResolutionFailure>,
+}
+
+impl HealthReport {
+    pub fn nameservers_reachable(&self) -> bool {
+        !self.nameservers.is_empty() && self.nameservers.iter().all(|ns| ns.is_reachable())
+    }
+
+    /// Whether every reachable nameserver serves the same SOA serial.
+    pub fn soa_serials_consistent(&self) -> bool {
+        let mut serials = self.nameservers.iter().filter_map(|ns| ns.soa_serial);
+        let first = serials.next();
+        serials.all(|serial| Some(serial) == first)
+    }
+
+    /// Lint errors, unreachable nameservers, diverging serials and
+    /// unresolvable records are errors; other lint findings keep their own
+    /// severity. `None` for a clean zone.
+    pub fn worst_severity(&self) -> Option<Severity> {
+        if !self.nameservers_reachable()
+            || !self.soa_serials_consistent()
+            || !self.unresolvable.is_empty()
+        {
+            return Some(Severity::Error);
+        }
+        self.lint.iter().map(|finding| finding.severity).max()
+    }
+
+    /// No errors; warnings and informational findings are tolerated.
+    pub fn is_healthy(&self) -> bool {
+        self.worst_severity() < Some(Severity::Error)
+    }
+}
+
+/// Builds a [`HealthReport`]; see
+/// [`ZoneApi::health_check`](crate::api::dns::zones::ZoneApi::health_check).
+#[derive(Debug, Clone)]
+pub struct HealthCheck<'a> {
+    pub(crate) client: &'a HetznerClient,
+    pub(crate) zone: ZoneRef<&'a str>,
+    pub(crate) nameservers: Option<Vec<SocketAddr>>,
+    pub(crate) timeout: Duration,
+}
+
+impl<'a> HealthCheck<'a> {
+    pub(crate) fn new(client: &'a HetznerClient, zone: ZoneRef<&'a str>) -> Self {
+        Self {
+            client,
+            zone,
+            nameservers: None,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Queries these servers instead of resolving the zone's `ns` names.
+    pub fn nameservers(mut self, nameservers: impl IntoIterator<Item = SocketAddr>) -> Self {
+        self.nameservers = Some(nameservers.into_iter().collect());
+        self
+    }
+
+    /// Per-query timeout, 5 seconds by default.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub async fn run(self) -> Result<HealthReport> {
+        self.client.check_cancelled()?;
+        let zone = self.client.dns().zone(self.zone).get().await?.zone;
+        let records = self.client.dns().records(&zone.id).list().await?;
+        let lint = lint_zone(&zone, &records);
+
+        let targets = match self.nameservers {
+            Some(addresses) => addresses
+                .into_iter()
+                .map(|address| (address.to_string(), Ok(address)))
+                .collect(),
+            None => resolve_nameservers(&zone).await,
+        };
+        let mut nameservers = Vec::new();
+        let mut authoritative = None;
+        for (name, address) in targets {
+            self.client.check_cancelled()?;
+            let status = match address {
+                Ok(address) => match soa_serial(address, &zone.name, self.timeout).await {
+                    Ok(serial) => {
+                        authoritative.get_or_insert(address);
+                        NameserverStatus {
+                            name,
+                            address: Some(address),
+                            soa_serial: Some(serial),
+                            error: None,
+                        }
+                    }
+                    Err(error) => NameserverStatus {
+                        name,
+                        address: Some(address),
+                        soa_serial: None,
+                        error: Some(error),
+                    },
+                },
+                Err(error) => NameserverStatus {
+                    name,
+                    address: None,
+                    soa_serial: None,
+                    error: Some(error),
+                },
+            };
+            nameservers.push(status);
+        }
+
+        let unresolvable = match authoritative {
+            Some(address) => unresolvable_records(address, &zone, &records, self.timeout).await,
+            None => Vec::new(),
+        };
+        Ok(HealthReport {
+            zone_id: zone.id,
+            zone_name: zone.name,
+            lint,
+            nameservers,
+            unresolvable,
+        })
+    }
+}
+
+async fn soa_serial(
+    address: SocketAddr,
+    zone_name: &str,
+    timeout: Duration,
+) -> std::result::Result<u32, String> {
+    let mut client = connect(address, timeout);
+    let response = client
+        .query(fqdn("@", zone_name)?, DNSClass::IN, RecordType::SOA)
+        .await
+        .map_err(|err| format!("SOA query to {address}: {err}"))?;
+    response
+        .answers
+        .iter()
+        .find_map(|answer| match &answer.data {
+            RData::SOA(soa) => Some(soa.serial),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            format!(
+                "{address} returned no SOA ({})",
+                response.metadata.response_code
+            )
+        })
+}
+
+/// Queries `address` once per name and type in the zone, apart from the SOA
+/// which [`soa_serial`] already covers.
+async fn unresolvable_records(
+    address: SocketAddr,
+    zone: &Zone,
+    records: &[Record],
+    timeout: Duration,
+) -> Vec<ResolutionFailure> {
+    let mut sets: BTreeMap<(String, String), Vec<String>> = BTreeMap::new();
+    for record in records
+        .iter()
+        .filter(|r| !r.record_type.eq_ignore_ascii_case("SOA"))
+    {
+        sets.entry((record.name.clone(), record.record_type.to_ascii_uppercase()))
+            .or_default()
+            .push(record.id.clone());
+    }
+
+    let client = connect(address, timeout);
+    let mut failures: Vec<ResolutionFailure> = futures_util::stream::iter(sets)
+        .map(|((name, record_type), record_ids)| {
+            let mut client = client.clone();
+            async move {
+                let reason = match resolve(&mut client, &name, &record_type, &zone.name).await {
+                    Ok(()) => return None,
+                    Err(reason) => reason,
+                };
+                Some(ResolutionFailure {
+                    name,
+                    record_type,
+                    record_ids,
+                    reason,
+                })
+            }
+        })
+        .buffer_unordered(CONCURRENT_QUERIES)
+        .filter_map(|failure| async move { failure })
+        .collect()
+        .await;
+    failures.sort_by(|a, b| (&a.name, &a.record_type).cmp(&(&b.name, &b.record_type)));
+    failures
+}
+
+async fn resolve(
+    client: &mut Client<TokioRuntimeProvider>,
+    name: &str,
+    record_type: &str,
+    zone_name: &str,
+) -> std::result::Result<(), String> {
+    let query_type = RecordType::from_str(record_type)
+        .map_err(|err| format!("unsupported type {record_type}: {err}"))?;
+    let response = client
+        .query(fqdn(name, zone_name)?, DNSClass::IN, query_type)
+        .await
+        .map_err(|err| format!("query failed: {err}"))?;
+    let code = response.metadata.response_code;
+    if code != ResponseCode::NoError {
+        return Err(format!("answered {code}"));
+    }
+    if !response
+        .answers
+        .iter()
+        .any(|answer| answer.record_type() == query_type)
+    {
+        return Err("no answer of this type".to_string());
+    }
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use crate::HetznerClient;
+use crate::api::dns::records::{Ttl, UpdateRecordInput};
+use crate::error::Result;
+use crate::types::{CreatedRecord, Record, RecordEnvelope, Zone};
+use futures_util::TryStreamExt;
+use serde_json::Value;
+use std::future::Future;
+
+/// The DNS operations of [`HetznerClient`] as a trait, so application code can
+/// take `impl HetznerDnsApi` and be unit-tested against
+/// `hetzner::testing::MockHetznerClient` (`testing` feature).
+pub trait HetznerDnsApi {
+    /// Every zone of the account, across all pages.
+    fn list_zones(&self) -> impl Future<Output = Result<Vec<Zone>>> + Send;
+
+    fn list_records(&self, zone_id: &str) -> impl Future<Output = Result<Vec<Record>>> + Send;
+
+    /// Creates a record; a `ttl` of `None` leaves it on the zone default.
+    fn create_record(
+        &self,
+        zone_id: &str,
+        name: &str,
+        record_type: &str,
+        value: &str,
+        ttl: impl Into<Option<Ttl>> + Send,
+    ) -> impl Future<Output = Result<CreatedRecord>> + Send;
+
+    fn get_record(&self, record_id: &str) -> impl Future<Output = Result<Record>> + Send;
+
+    /// The record as returned by the API, for fields [`Record`] does not model.
+    fn get_record_raw(&self, record_id: &str) -> impl Future<Output = Result<Value>> + Send;
+
+    fn update_record(
+        &self,
+        record_id: &str,
+        input: UpdateRecordInput,
+    ) -> impl Future<Output = Result<RecordEnvelope>> + Send;
+
+    fn delete_record(&self, record_id: &str) -> impl Future<Output = Result<()>> + Send;
+}
+
+impl HetznerDnsApi for HetznerClient {
+    async fn list_zones(&self) -> Result<Vec<Zone>> {
+        self.dns().zones_stream().try_collect().await
+    }
+
+    async fn list_records(&self, zone_id: &str) -> Result<Vec<Record>> {
+        self.dns().records(zone_id).list().await
+    }
+
+    async fn create_record(
+        &self,
+        zone_id: &str,
+        name: &str,
+        record_type: &str,
+        value: &str,
+        ttl: impl Into<Option<Ttl>> + Send,
+    ) -> Result<CreatedRecord> {
+        self.dns()
+            .records(zone_id)
+            .create(name, record_type, value, ttl)
+            .await
+    }
+
+    async fn get_record(&self, record_id: &str) -> Result<Record> {
+        self.dns().record(record_id).get().await
+    }
+
+    async fn get_record_raw(&self, record_id: &str) -> Result<Value> {
+        self.dns().record(record_id).get_raw().await
+    }
+
+    async fn update_record(
+        &self,
+        record_id: &str,
+        input: UpdateRecordInput,
+    ) -> Result<RecordEnvelope> {
+        self.dns().record(record_id).update(input).await
+    }
+
+    async fn delete_record(&self, record_id: &str) -> Result<()> {
+        self.dns().record(record_id).delete().await
+    }
+}
@@ -0,0 +1,567 @@
+//! Public IP address detection for DynDNS updates (`dyndns` feature).
+
+use crate::error::{HetznerError, Result};
+use futures_util::future::BoxFuture;
+use reqwest::Url;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::Duration;
+use tokio::net::UdpSocket;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+const SSDP_ADDRESS: &str = "239.255.255.250:1900";
+const IGD_SEARCH_TARGET: &str = "urn:schemas-upnp-org:device:InternetGatewayDevice:1";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IpVersion {
+    V4,
+    V6,
+}
+
+impl IpVersion {
+    pub fn of(address: &IpAddr) -> Self {
+        match address {
+            IpAddr::V4(_) => Self::V4,
+            IpAddr::V6(_) => Self::V6,
+        }
+    }
+
+    /// The record type holding addresses of this version.
+    pub fn record_type(self) -> &'static str {
+        match self {
+            Self::V4 => "A",
+            Self::V6 => "AAAA",
+        }
+    }
+
+    fn unspecified(self) -> IpAddr {
+        match self {
+            Self::V4 => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            Self::V6 => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+        }
+    }
+}
+
+impl fmt::Display for IpVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::V4 => "IPv4",
+            Self::V6 => "IPv6",
+        })
+    }
+}
+
+/// Somewhere the host's public address can be learned from. Failures are
+/// reported as [`HetznerError::IpDetection`].
+pub trait IpSource: Send + Sync {
+    /// A short label for logs and consensus errors.
+    fn name(&self) -> String;
+
+    fn detect(&self, version: IpVersion) -> BoxFuture<'_, Result<IpAddr>>;
+}
+
+fn detection_error(source: &str, message: impl fmt::Display) -> HetznerError {
+    HetznerError::IpDetection(format!("{source}: {message}"))
+}
+
+/// A web service answering with the caller's address as plain text. The
+/// request leaves from an unspecified address of the wanted version, so a
+/// dual-stack service reports the matching one.
+#[derive(Debug, Clone)]
+pub struct HttpsSource {
+    url: String,
+    timeout: Duration,
+}
+
+impl HttpsSource {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    pub fn ifconfig_co() -> Self {
+        Self::new("https://ifconfig.co/ip")
+    }
+
+    pub fn ipify() -> Self {
+        Self::new("https://api64.ipify.org")
+    }
+
+    pub fn icanhazip() -> Self {
+        Self::new("https://icanhazip.com")
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    async fn fetch(&self, version: IpVersion) -> Result<IpAddr> {
+        let error = |message: String| detection_error(&self.url, message);
+        let http = reqwest::Client::builder()
+            .local_address(version.unspecified())
+            .timeout(self.timeout)
+            .build()
+            .map_err(|err| error(err.to_string()))?;
+        let body = http
+            .get(&self.url)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|err| error(err.to_string()))?
+            .text()
+            .await
+            .map_err(|err| error(err.to_string()))?;
+        let address: IpAddr = body
+            .trim()
+            .parse()
+            .map_err(|_| error(format!("not an IP address: {:?}", body.trim())))?;
+        if IpVersion::of(&address) != version {
+            return Err(error(format!(
+                "returned {address}, not an {version} address"
+            )));
+        }
+        Ok(address)
+    }
+}
+
+impl IpSource for HttpsSource {
+    fn name(&self) -> String {
+        self.url.clone()
+    }
+
+    fn detect(&self, version: IpVersion) -> BoxFuture<'_, Result<IpAddr>> {
+        Box::pin(self.fetch(version))
+    }
+}
+
+/// The address the host would send from to reach a public target, read
+/// from the routing table without sending anything. Only useful on hosts
+/// with a public address on an interface (no NAT in between).
+///
+/// For IPv6 on Linux the interface addresses are read as well, so that
+/// temporary (privacy) addresses, which change daily, are skipped in favour
+/// of the stable global address, and [`interface`](Self::interface) can pick
+/// the interface.
+#[derive(Debug, Clone)]
+pub struct InterfaceSource {
+    v4_target: SocketAddr,
+    v6_target: SocketAddr,
+    interface: Option<String>,
+    allow_temporary: bool,
+}
+
+impl Default for InterfaceSource {
+    fn default() -> Self {
+        Self {
+            v4_target: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1)), 53),
+            v6_target: SocketAddr::new(
+                IpAddr::V6(Ipv6Addr::new(0x2606, 0x4700, 0x4700, 0, 0, 0, 0, 0x1111)),
+                53,
+            ),
+            interface: None,
+            allow_temporary: false,
+        }
+    }
+}
+
+impl InterfaceSource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Routes towards `target` instead of a public resolver.
+    pub fn target(mut self, target: SocketAddr) -> Self {
+        match IpVersion::of(&target.ip()) {
+            IpVersion::V4 => self.v4_target = target,
+            IpVersion::V6 => self.v6_target = target,
+        }
+        self
+    }
+
+    /// Only considers IPv6 addresses of this interface, e.g. `eth0`.
+    pub fn interface(mut self, name: impl Into<String>) -> Self {
+        self.interface = Some(name.into());
+        self
+    }
+
+    /// Also accepts temporary IPv6 addresses (RFC 8981).
+    pub fn allow_temporary(mut self) -> Self {
+        self.allow_temporary = true;
+        self
+    }
+
+    async fn local_address(&self, version: IpVersion) -> Result<IpAddr> {
+        let routed = self.routed_address(version).await;
+        if version == IpVersion::V4 {
+            return routed;
+        }
+        let Ok(addresses) = InterfaceAddress::read_all() else {
+            return routed;
+        };
+        let candidates: Vec<Ipv6Addr> = addresses
+            .iter()
+            .filter(|entry| {
+                entry.is_global()
+                    && !entry.is_deprecated()
+                    && !entry.is_tentative()
+                    && (self.allow_temporary || !entry.is_temporary())
+                    && self
+                        .interface
+                        .as_ref()
+                        .is_none_or(|name| &entry.interface == name)
+            })
+            .map(|entry| entry.address)
+            .collect();
+        match routed {
+            Ok(IpAddr::V6(address)) if candidates.contains(&address) => Ok(IpAddr::V6(address)),
+            _ => candidates
+                .first()
+                .map(|&address| IpAddr::V6(address))
+                .ok_or_else(|| {
+                    detection_error(
+                        "interface",
+                        format!(
+                            "no stable global IPv6 address{}",
+                            self.interface
+                                .as_ref()
+                                .map(|name| format!(" on {name}"))
+                                .unwrap_or_default()
+                        ),
+                    )
+                }),
+        }
+    }
+
+    async fn routed_address(&self, version: IpVersion) -> Result<IpAddr> {
+        let error = |err: std::io::Error| detection_error("interface", err);
+        let target = match version {
+            IpVersion::V4 => self.v4_target,
+            IpVersion::V6 => self.v6_target,
+        };
+        let socket = UdpSocket::bind(SocketAddr::new(version.unspecified(), 0))
+            .await
+            .map_err(error)?;
+        socket.connect(target).await.map_err(error)?;
+        Ok(socket.local_addr().map_err(error)?.ip())
+    }
+}
+
+impl IpSource for InterfaceSource {
+    fn name(&self) -> String {
+        match &self.interface {
+            Some(name) => format!("interface {name}"),
+            None => "interface".to_string(),
+        }
+    }
+
+    fn detect(&self, version: IpVersion) -> BoxFuture<'_, Result<IpAddr>> {
+        Box::pin(self.local_address(version))
+    }
+}
+
+const IFA_F_TEMPORARY: u8 = 0x01;
+const IFA_F_DEPRECATED: u8 = 0x20;
+const IFA_F_TENTATIVE: u8 = 0x40;
+
+/// An IPv6 address assigned to a local interface, as listed in Linux's
+/// `/proc/net/if_inet6`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterfaceAddress {
+    pub interface: String,
+    pub address: Ipv6Addr,
+    pub prefix_len: u8,
+    pub scope: u8,
+    pub flags: u8,
+}
+
+impl InterfaceAddress {
+    pub fn read_all() -> Result<Vec<Self>> {
+        std::fs::read_to_string("/proc/net/if_inet6")
+            .map(|contents| Self::parse_if_inet6(&contents))
+            .map_err(|err| HetznerError::IpDetection(format!("/proc/net/if_inet6: {err}")))
+    }
+
+    /// Parses the `/proc/net/if_inet6` format, skipping malformed lines.
+    pub fn parse_if_inet6(contents: &str) -> Vec<Self> {
+        contents
+            .lines()
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                let [address, _, prefix_len, scope, flags, interface] = fields[..] else {
+                    return None;
+                };
+                Some(Self {
+                    interface: interface.to_string(),
+                    address: Ipv6Addr::from(u128::from_str_radix(address, 16).ok()?),
+                    prefix_len: u8::from_str_radix(prefix_len, 16).ok()?,
+                    scope: u8::from_str_radix(scope, 16).ok()?,
+                    flags: u8::from_str_radix(flags, 16).ok()?,
+                })
+            })
+            .collect()
+    }
+
+    /// Globally routable: global scope and outside the unique local range.
+    pub fn is_global(&self) -> bool {
+        self.scope == 0 && (self.address.segments()[0] & 0xfe00) != 0xfc00
+    }
+
+    pub fn is_temporary(&self) -> bool {
+        self.flags & IFA_F_TEMPORARY != 0
+    }
+
+    pub fn is_deprecated(&self) -> bool {
+        self.flags & IFA_F_DEPRECATED != 0
+    }
+
+    /// Still undergoing duplicate address detection.
+    pub fn is_tentative(&self) -> bool {
+        self.flags & IFA_F_TENTATIVE != 0
+    }
+}
+
+/// The external address of the local router, asked over UPnP IGD
+/// (`GetExternalIPAddress`). IPv4 only.
+#[derive(Debug, Clone)]
+pub struct UpnpSource {
+    gateway: Option<String>,
+    timeout: Duration,
+}
+
+impl Default for UpnpSource {
+    fn default() -> Self {
+        Self {
+            gateway: None,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+}
+
+impl UpnpSource {
+    /// Discovers the gateway with an SSDP search on the local network.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Uses the device description at `location` instead of discovering it.
+    pub fn gateway(mut self, location: impl Into<String>) -> Self {
+        self.gateway = Some(location.into());
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    async fn external_address(&self, version: IpVersion) -> Result<IpAddr> {
+        let error = |message: String| detection_error("upnp", message);
+        if version == IpVersion::V6 {
+            return Err(error("IGD reports IPv4 addresses only".to_string()));
+        }
+        let location = match &self.gateway {
+            Some(location) => location.clone(),
+            None => tokio::time::timeout(self.timeout, discover_gateway())
+                .await
+                .map_err(|_| error("no gateway answered the SSDP search".to_string()))??,
+        };
+        let location = Url::parse(&location).map_err(|err| error(format!("{location}: {err}")))?;
+        let http = reqwest::Client::builder()
+            .timeout(self.timeout)
+            .build()
+            .map_err(|err| error(err.to_string()))?;
+
+        let description = http
+            .get(location.clone())
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|err| error(err.to_string()))?
+            .text()
+            .await
+            .map_err(|err| error(err.to_string()))?;
+        let (service_type, control_url) = wan_connection_service(&description)
+            .ok_or_else(|| error("gateway has no WAN connection service".to_string()))?;
+        let control_url = location
+            .join(&control_url)
+            .map_err(|err| error(format!("{control_url}: {err}")))?;
+
+        let body = format!(
+            "<?xml version=\"1.0\"?>\
+             <s:Envelope xmlns:s=\"http://schemas.xmlsoap.org/soap/envelope/\" \
+             s:encodingStyle=\"http://schemas.xmlsoap.org/soap/encoding/\">\
+             <s:Body><u:GetExternalIPAddress xmlns:u=\"{service_type}\"/></s:Body>\
+             </s:Envelope>"
+        );
+        let response = http
+            .post(control_url)
+            .header("content-type", "text/xml; charset=\"utf-8\"")
+            .header(
+                "soapaction",
+                format!("\"{service_type}#GetExternalIPAddress\""),
+            )
+            .body(body)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|err| error(err.to_string()))?
+            .text()
+            .await
+            .map_err(|err| error(err.to_string()))?;
+        let address = element(&response, "NewExternalIPAddress")
+            .ok_or_else(|| error("no NewExternalIPAddress in the response".to_string()))?;
+        address
+            .trim()
+            .parse()
+            .map_err(|_| error(format!("not an IP address: {address:?}")))
+    }
+}
+
+impl IpSource for UpnpSource {
+    fn name(&self) -> String {
+        "upnp".to_string()
+    }
+
+    fn detect(&self, version: IpVersion) -> BoxFuture<'_, Result<IpAddr>> {
+        Box::pin(self.external_address(version))
+    }
+}
+
+/// The `LOCATION` of the first gateway answering an SSDP search.
+async fn discover_gateway() -> Result<String> {
+    let error = |err: std::io::Error| detection_error("upnp", err);
+    let socket = UdpSocket::bind("0.0.0.0:0").await.map_err(error)?;
+    let search = format!(
+        "M-SEARCH * HTTP/1.1\r\nHOST: {SSDP_ADDRESS}\r\nMAN: \"ssdp:discover\"\r\nMX: 2\r\nST: {IGD_SEARCH_TARGET}\r\n\r\n"
+    );
+    socket
+        .send_to(search.as_bytes(), SSDP_ADDRESS)
+        .await
+        .map_err(error)?;
+    let mut buf = vec![0; 2048];
+    loop {
+        let (len, _) = socket.recv_from(&mut buf).await.map_err(error)?;
+        let response = String::from_utf8_lossy(&buf[..len]);
+        let location = response.lines().find_map(|line| {
+            let (header, value) = line.split_once(':')?;
+            header
+                .trim()
+                .eq_ignore_ascii_case("location")
+                .then(|| value.trim().to_string())
+        });
+        if let Some(location) = location {
+            return Ok(location);
+        }
+    }
+}
+
+/// Service type and control URL of the first `WANIPConnection` or
+/// `WANPPPConnection` service in an IGD device description.
+fn wan_connection_service(description: &str) -> Option<(String, String)> {
+    description.split("<service>").skip(1).find_map(|service| {
+        let service_type = element(service, "serviceType")?;
+        if !service_type.contains(":WANIPConnection:")
+            && !service_type.contains(":WANPPPConnection:")
+        {
+            return None;
+        }
+        Some((
+            service_type.trim().to_string(),
+            element(service, "controlURL")?.trim().to_string(),
+        ))
+    })
+}
+
+/// Text of the first `<tag>` element, ignoring namespace prefixes.
+fn element<'a>(xml: &'a str, tag: &str) -> Option<&'a str> {
+    let open = xml
+        .match_indices('<')
+        .map(|(start, _)| &xml[start + 1..])
+        .find(|rest| {
+            let name = rest.split(['>', ' ', '/']).next().unwrap_or_default();
+            name.rsplit(':').next() == Some(tag)
+        })?;
+    let content = &open[open.find('>')? + 1..];
+    Some(&content[..content.find('<')?])
+}
+
+/// Asks several sources at once and settles on the address reported by at
+/// least `quorum` of them, so one compromised or broken service can't
+/// redirect a record. When several addresses tie for the most reports,
+/// nothing is settled on.
+pub struct Consensus {
+    sources: Vec<Box<dyn IpSource>>,
+    quorum: usize,
+}
+
+impl Consensus {
+    pub fn new(quorum: usize) -> Self {
+        Self {
+            sources: Vec::new(),
+            quorum,
+        }
+    }
+
+    pub fn source(mut self, source: impl IpSource + 'static) -> Self {
+        self.sources.push(Box::new(source));
+        self
+    }
+
+    async fn agree(&self, version: IpVersion) -> Result<IpAddr> {
+        let outcomes = futures_util::future::join_all(
+            self.sources.iter().map(|source| source.detect(version)),
+        )
+        .await;
+        let mut votes: BTreeMap<IpAddr, usize> = BTreeMap::new();
+        let mut reports = Vec::new();
+        for (source, outcome) in self.sources.iter().zip(outcomes) {
+            match outcome {
+                Ok(address) => {
+                    *votes.entry(address).or_default() += 1;
+                    reports.push(format!("{} said {address}", source.name()));
+                }
+                Err(err) => reports.push(err.to_string()),
+            }
+        }
+        let top = votes.values().copied().max().unwrap_or(0);
+        if top < self.quorum.max(1) {
+            return Err(HetznerError::IpDetection(format!(
+                "fewer than {} sources agree on an {version} address ({})",
+                self.quorum.max(1),
+                reports.join("; ")
+            )));
+        }
+        let mut leaders = votes.into_iter().filter(|(_, count)| *count == top);
+        match (leaders.next(), leaders.next()) {
+            (Some((address, _)), None) => Ok(address),
+            _ => Err(HetznerError::IpDetection(format!(
+                "sources disagree on the {version} address ({})",
+                reports.join("; ")
+            ))),
+        }
+    }
+}
+
+impl fmt::Debug for Consensus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<String> = self.sources.iter().map(|source| source.name()).collect();
+        f.debug_struct("Consensus")
+            .field("sources", &names)
+            .field("quorum", &self.quorum)
+            .finish()
+    }
+}
+
+impl IpSource for Consensus {
+    fn name(&self) -> String {
+        "consensus".to_string()
+    }
+
+    fn detect(&self, version: IpVersion) -> BoxFuture<'_, Result<IpAddr>> {
+        Box::pin(self.agree(version))
+    }
+}
@@ -0,0 +1,164 @@
+//! Records that expire: each lease is tracked by a TXT record in the zone
+//! itself, so any machine with the token (e.g. a nightly CI job) can sweep
+//! expired ones without shared local state.
+
+use crate::HetznerClient;
+use crate::api::dns::records::Ttl;
+use crate::api::dns::zone_ref::ZoneRef;
+use crate::error::Result;
+use crate::logging::info;
+use crate::types::Record;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Name of the TXT records tracking the leases of a zone.
+pub const LEASE_REGISTRY_NAME: &str = "_hetzner-lease";
+
+/// A record that may be deleted by [`RecordsApi::cleanup_expired`] once
+/// `expires_at` has passed.
+///
+/// [`RecordsApi::cleanup_expired`]: crate::api::dns::records::RecordsApi::cleanup_expired
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Lease {
+    pub record: Record,
+    /// Seconds since the Unix epoch.
+    pub expires_at: u64,
+    /// The id of the TXT record tracking the lease.
+    pub registry_record_id: String,
+}
+
+impl Lease {
+    pub fn is_expired(&self, now: SystemTime) -> bool {
+        self.expires_at <= unix_seconds(now)
+    }
+}
+
+/// A registry entry: the value of a [`LEASE_REGISTRY_NAME`] TXT record.
+struct Entry {
+    record_id: String,
+    expires_at: u64,
+}
+
+impl Entry {
+    fn value(&self) -> String {
+        format!("\"record={} expires={}\"", self.record_id, self.expires_at)
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        let mut record_id = None;
+        let mut expires_at = None;
+        for field in value.trim_matches('"').split_whitespace() {
+            match field.split_once('=')? {
+                ("record", id) => record_id = Some(id.to_string()),
+                ("expires", at) => expires_at = at.parse().ok(),
+                _ => {}
+            }
+        }
+        Some(Self {
+            record_id: record_id?,
+            expires_at: expires_at?,
+        })
+    }
+}
+
+pub(crate) async fn create(
+    client: &HetznerClient,
+    zone: ZoneRef<&str>,
+    name: String,
+    record_type: String,
+    value: String,
+    ttl: Option<Ttl>,
+    lease: Duration,
+) -> Result<Lease> {
+    let client = client.correlated();
+    let zone_id = client.zone_id(zone).await?.into_owned();
+    let records = client.dns().records(&zone_id);
+    let record = records.create(name, record_type, value, ttl).await?.record;
+    let entry = Entry {
+        record_id: record.id.clone(),
+        expires_at: unix_seconds(SystemTime::now() + lease),
+    };
+    let registered = records
+        .create(LEASE_REGISTRY_NAME, "TXT", entry.value(), Ttl::MINUTE)
+        .await;
+    match registered {
+        Ok(registry) => Ok(Lease {
+            record,
+            expires_at: entry.expires_at,
+            registry_record_id: registry.record.id,
+        }),
+        Err(err) => {
+            // An untracked record would never expire; don't leave one behind.
+            let _ = client.dns().record(&record.id).delete().await;
+            Err(err)
+        }
+    }
+}
+
+/// The zone's leases, and the ids of registry records whose leased record
+/// no longer exists.
+pub(crate) async fn list(
+    client: &HetznerClient,
+    zone: ZoneRef<&str>,
+) -> Result<(Vec<Lease>, Vec<String>)> {
+    let records = client.dns().records(zone).list().await?;
+    let mut registry = Vec::new();
+    let mut by_id = BTreeMap::new();
+    for record in records {
+        if record.record_type == "TXT" && record.name == LEASE_REGISTRY_NAME {
+            registry.push(record);
+        } else {
+            by_id.insert(record.id.clone(), record);
+        }
+    }
+
+    let mut leases = Vec::new();
+    let mut orphans = Vec::new();
+    for entry_record in registry {
+        let Some(entry) = Entry::parse(&entry_record.value) else {
+            continue;
+        };
+        match by_id.remove(&entry.record_id) {
+            Some(record) => leases.push(Lease {
+                record,
+                expires_at: entry.expires_at,
+                registry_record_id: entry_record.id,
+            }),
+            None => orphans.push(entry_record.id),
+        }
+    }
+    Ok((leases, orphans))
+}
+
+pub(crate) async fn cleanup_expired(
+    client: &HetznerClient,
+    zone: ZoneRef<&str>,
+) -> Result<Vec<Lease>> {
+    let client = client.correlated();
+    let (leases, orphans) = list(&client, zone).await?;
+    let now = SystemTime::now();
+    let mut removed = Vec::new();
+    for lease in leases.into_iter().filter(|lease| lease.is_expired(now)) {
+        match client.dns().record(&lease.record.id).delete().await {
+            Err(err) if !err.is_not_found() => return Err(err),
+            _ => {}
+        }
+        client
+            .dns()
+            .record(&lease.registry_record_id)
+            .delete()
+            .await?;
+        info!(record = %lease.record.id, name = %lease.record.name, "deleted expired record");
+        removed.push(lease);
+    }
+    for orphan in orphans {
+        client.dns().record(&orphan).delete().await?;
+    }
+    Ok(removed)
+}
+
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
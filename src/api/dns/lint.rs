@@ -0,0 +1,275 @@
+//! Offline hygiene checks over a zone's records.
+
+use crate::api::dns::names::relative_name;
+use crate::api::dns::records::Ttl;
+use crate::types::{Record, Zone, txt_data};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// TTLs below this are reported as suspiciously low.
+pub const MIN_REASONABLE_TTL: Ttl = Ttl::FIVE_MINUTES;
+
+/// RFC 7208 caps the DNS lookups an SPF evaluation may trigger at ten.
+pub const SPF_LOOKUP_LIMIT: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintRule {
+    /// A CNAME pointing at a name inside the zone that has no records.
+    DanglingCname,
+    /// A CNAME on the zone apex, which conflicts with its SOA and NS records.
+    CnameAtApex,
+    /// A CNAME sharing its name with other records.
+    CnameWithOtherData,
+    /// An SPF policy that authorises senders on a name without MX records.
+    SpfWithoutMx,
+    /// An SPF policy needing more than [`SPF_LOOKUP_LIMIT`] DNS lookups.
+    SpfLookupLimit,
+    /// Several SPF policies on one name, which receivers reject outright.
+    MultipleSpf,
+    DuplicateRecord,
+    LowTtl,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LintFinding {
+    pub severity: Severity,
+    pub rule: LintRule,
+    /// Record name relative to the zone, `@` for the apex.
+    pub name: String,
+    pub record_ids: Vec<String>,
+    pub message: String,
+}
+
+impl LintFinding {
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+/// Checks `records` of `zone` for common mistakes, most severe findings
+/// first. Targets outside the zone are not resolved.
+pub fn lint_zone(zone: &Zone, records: &[Record]) -> Vec<LintFinding> {
+    let mut by_name: BTreeMap<String, Vec<&Record>> = BTreeMap::new();
+    for record in records {
+        by_name
+            .entry(record.relative_name(zone))
+            .or_default()
+            .push(record);
+    }
+
+    let mut findings = Vec::new();
+    for (name, records) in &by_name {
+        check_cname(&mut findings, zone, name, records, &by_name);
+        check_spf(&mut findings, name, records);
+        check_duplicates(&mut findings, name, records);
+        for record in records {
+            let ttl = record.effective_ttl(zone);
+            if ttl < MIN_REASONABLE_TTL {
+                findings.push(finding(
+                    Severity::Info,
+                    LintRule::LowTtl,
+                    name,
+                    [*record],
+                    format!(
+                        "{} record has a TTL of {}s, below {}s",
+                        record.record_type,
+                        ttl.seconds(),
+                        MIN_REASONABLE_TTL.seconds()
+                    ),
+                ));
+            }
+        }
+    }
+    findings.sort_by(|a, b| {
+        b.severity
+            .cmp(&a.severity)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    findings
+}
+
+fn check_cname(
+    findings: &mut Vec<LintFinding>,
+    zone: &Zone,
+    name: &str,
+    records: &[&Record],
+    by_name: &BTreeMap<String, Vec<&Record>>,
+) {
+    let cnames: Vec<&Record> = records
+        .iter()
+        .copied()
+        .filter(|r| is_type(r, "CNAME"))
+        .collect();
+    if cnames.is_empty() {
+        return;
+    }
+    if name == "@" {
+        findings.push(finding(
+            Severity::Error,
+            LintRule::CnameAtApex,
+            name,
+            cnames.iter().copied(),
+            "CNAME on the zone apex conflicts with its SOA and NS records".to_string(),
+        ));
+    } else if records.len() > cnames.len() || cnames.len() > 1 {
+        findings.push(finding(
+            Severity::Error,
+            LintRule::CnameWithOtherData,
+            name,
+            records.iter().copied(),
+            "a CNAME must be the only record of its name".to_string(),
+        ));
+    }
+    for cname in cnames {
+        let Some(target) = in_zone_target(&cname.value, &zone.name) else {
+            continue;
+        };
+        if !by_name.contains_key(&target) {
+            findings.push(finding(
+                Severity::Error,
+                LintRule::DanglingCname,
+                name,
+                [cname],
+                format!("CNAME target {} has no records in the zone", cname.value),
+            ));
+        }
+    }
+}
+
+fn check_spf(findings: &mut Vec<LintFinding>, name: &str, records: &[&Record]) {
+    let policies: Vec<(&Record, String)> = records
+        .iter()
+        .filter(|r| is_type(r, "TXT"))
+        .map(|r| (*r, txt_data(&r.value)))
+        .filter(|(_, data)| {
+            let data = data.to_ascii_lowercase();
+            data == "v=spf1" || data.starts_with("v=spf1 ")
+        })
+        .collect();
+    if policies.len() > 1 {
+        findings.push(finding(
+            Severity::Error,
+            LintRule::MultipleSpf,
+            name,
+            policies.iter().map(|(record, _)| *record),
+            "more than one SPF policy; receivers treat this as a permanent error".to_string(),
+        ));
+    }
+    let has_mx = records.iter().any(|r| is_type(r, "MX"));
+    for (record, policy) in &policies {
+        let lookups = spf_lookups(policy);
+        if lookups > SPF_LOOKUP_LIMIT {
+            findings.push(finding(
+                Severity::Error,
+                LintRule::SpfLookupLimit,
+                name,
+                [*record],
+                format!(
+                    "SPF policy needs at least {lookups} DNS lookups, more than {SPF_LOOKUP_LIMIT}"
+                ),
+            ));
+        }
+        if !has_mx && authorises_senders(policy) {
+            findings.push(finding(
+                Severity::Warning,
+                LintRule::SpfWithoutMx,
+                name,
+                [*record],
+                "SPF policy authorises senders but the name has no MX record".to_string(),
+            ));
+        }
+    }
+}
+
+fn check_duplicates(findings: &mut Vec<LintFinding>, name: &str, records: &[&Record]) {
+    let mut seen: BTreeMap<(String, String), Vec<&Record>> = BTreeMap::new();
+    for record in records {
+        let key = record.key();
+        seen.entry((key.record_type, key.value))
+            .or_default()
+            .push(record);
+    }
+    for ((record_type, _), duplicates) in seen {
+        if duplicates.len() > 1 {
+            findings.push(finding(
+                Severity::Warning,
+                LintRule::DuplicateRecord,
+                name,
+                duplicates.iter().copied(),
+                format!("{} identical {record_type} records", duplicates.len()),
+            ));
+        }
+    }
+}
+
+fn finding<'a>(
+    severity: Severity,
+    rule: LintRule,
+    name: &str,
+    records: impl IntoIterator<Item = &'a Record>,
+    message: String,
+) -> LintFinding {
+    LintFinding {
+        severity,
+        rule,
+        name: name.to_string(),
+        record_ids: records.into_iter().map(|r| r.id.clone()).collect(),
+        message,
+    }
+}
+
+fn is_type(record: &Record, record_type: &str) -> bool {
+    record.record_type.eq_ignore_ascii_case(record_type)
+}
+
+/// The relative name a CNAME value points at, if it lies inside the zone.
+/// Values without a trailing dot are relative to the zone.
+fn in_zone_target(value: &str, zone_name: &str) -> Option<String> {
+    let value = value.trim().to_ascii_lowercase();
+    let Some(absolute) = value.strip_suffix('.') else {
+        return Some(relative_name(&value, zone_name));
+    };
+    let zone = zone_name.trim_end_matches('.').to_ascii_lowercase();
+    if absolute == zone || absolute.ends_with(&format!(".{zone}")) {
+        Some(relative_name(absolute, zone_name))
+    } else {
+        None
+    }
+}
+
+/// Mechanisms and modifiers that cost a DNS lookup; nested includes are not
+/// followed, so the real count can only be higher.
+fn spf_lookups(policy: &str) -> usize {
+    policy
+        .split_whitespace()
+        .skip(1)
+        .map(|term| {
+            term.trim_start_matches(['+', '-', '~', '?'])
+                .to_ascii_lowercase()
+        })
+        .filter(|term| {
+            let mechanism = term.split([':', '/', '=']).next().unwrap_or_default();
+            matches!(
+                mechanism,
+                "include" | "a" | "mx" | "ptr" | "exists" | "redirect"
+            )
+        })
+        .count()
+}
+
+/// Whether the policy allows any sender, i.e. is more than `v=spf1 -all`.
+fn authorises_senders(policy: &str) -> bool {
+    policy
+        .split_whitespace()
+        .skip(1)
+        .any(|term| !matches!(term.to_ascii_lowercase().as_str(), "-all" | "~all" | "?all"))
+}
@@ -0,0 +1,46 @@
+//! DNS queries shared by the live checks (`health` and `propagation`
+//! features).
+
+use crate::api::dns::names::absolute_name;
+use crate::types::Zone;
+use hickory_net::client::Client;
+use hickory_net::runtime::TokioRuntimeProvider;
+use hickory_net::udp::UdpClientStream;
+use hickory_proto::rr::Name;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// The first address of each of the zone's `ns` names, or why it has none.
+pub(crate) async fn resolve_nameservers(
+    zone: &Zone,
+) -> Vec<(String, std::result::Result<SocketAddr, String>)> {
+    let mut resolved = Vec::new();
+    for ns in &zone.ns {
+        let host = ns.trim_end_matches('.');
+        let address = match tokio::net::lookup_host((host, 53)).await {
+            Ok(mut addresses) => addresses
+                .next()
+                .ok_or_else(|| format!("{host} has no addresses")),
+            Err(err) => Err(format!("resolve {host}: {err}")),
+        };
+        resolved.push((host.to_string(), address));
+    }
+    resolved
+}
+
+/// A UDP DNS client for `address`, its background task spawned on tokio.
+pub(crate) fn connect(address: SocketAddr, timeout: Duration) -> Client<TokioRuntimeProvider> {
+    let stream = UdpClientStream::builder(address, TokioRuntimeProvider::new())
+        .with_timeout(Some(timeout))
+        .build();
+    let (client, background) = Client::<TokioRuntimeProvider>::from_sender(stream);
+    tokio::spawn(background);
+    client
+}
+
+/// `name` (relative, `@` for the apex, or already absolute) under `zone_name`.
+pub(crate) fn fqdn(name: &str, zone_name: &str) -> std::result::Result<Name, String> {
+    let fqdn = absolute_name(name, zone_name);
+    Name::from_str(&fqdn).map_err(|err| format!("invalid name {fqdn}: {err}"))
+}
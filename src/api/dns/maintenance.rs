@@ -0,0 +1,122 @@
+//! Pointing names at a maintenance page and back.
+
+use crate::HetznerClient;
+use crate::api::dns::record_ref::has_name_and_type;
+use crate::api::dns::records::{Ttl, replace_record_set};
+use crate::api::dns::zone_ref::ZoneRef;
+use crate::error::Result;
+use crate::types::{Record, RecordType};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+
+/// TTL of the maintenance records, so resolvers return to the real
+/// addresses soon after maintenance ends.
+pub const MAINTENANCE_TTL: Ttl = Ttl::MINUTE;
+
+/// The address records some names held before
+/// [`DnsApi::enter_maintenance`](crate::api::dns::DnsApi::enter_maintenance)
+/// replaced them. Keep it (it serializes) to pass to
+/// [`DnsApi::exit_maintenance`](crate::api::dns::DnsApi::exit_maintenance).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MaintenanceSnapshot {
+    pub zone_id: String,
+    /// Relative to the zone, `@` for the apex.
+    pub names: Vec<String>,
+    pub maintenance_ip: IpAddr,
+    /// The A and AAAA records of `names` before maintenance.
+    pub records: Vec<Record>,
+}
+
+pub(crate) async fn enter(
+    client: &HetznerClient,
+    zone: ZoneRef<&str>,
+    names: &[&str],
+    maintenance_ip: IpAddr,
+) -> Result<MaintenanceSnapshot> {
+    let client = client.correlated();
+    let zone_id = client.zone_id(zone).await?.into_owned();
+    let records = client
+        .dns()
+        .records(&zone_id)
+        .list()
+        .await?
+        .into_iter()
+        .filter(|record| {
+            let record_type = record.kind();
+            record_type.is_address()
+                && names
+                    .iter()
+                    .any(|name| has_name_and_type(record, name, record_type.as_str()))
+        })
+        .collect();
+    let snapshot = MaintenanceSnapshot {
+        zone_id,
+        names: names.iter().map(|name| name.to_string()).collect(),
+        maintenance_ip,
+        records,
+    };
+
+    let (wanted, other) = match maintenance_ip {
+        IpAddr::V4(_) => (RecordType::A, RecordType::Aaaa),
+        IpAddr::V6(_) => (RecordType::Aaaa, RecordType::A),
+    };
+    let address = [maintenance_ip.to_string()];
+    for (done, name) in names.iter().enumerate() {
+        let applied = async {
+            let zone = ZoneRef::Id(snapshot.zone_id.as_str());
+            replace_record_set(
+                &client,
+                zone,
+                name,
+                wanted.as_str(),
+                &address,
+                Some(MAINTENANCE_TTL),
+            )
+            .await?;
+            replace_record_set(
+                &client,
+                zone,
+                name,
+                other.as_str(),
+                &[],
+                Some(MAINTENANCE_TTL),
+            )
+            .await
+        }
+        .await;
+        if let Err(err) = applied {
+            // Leave no name half-way: put back what was changed so far,
+            // including the name that failed.
+            let _ = restore(&client, &snapshot, &snapshot.names[..=done]).await;
+            return Err(err);
+        }
+    }
+    Ok(snapshot)
+}
+
+pub(crate) async fn exit(client: &HetznerClient, snapshot: &MaintenanceSnapshot) -> Result<()> {
+    restore(&client.correlated(), snapshot, &snapshot.names).await
+}
+
+/// Gives `names` their A and AAAA records from `snapshot` back. Records
+/// are recreated with the TTL the name's first record of the type had.
+async fn restore(
+    client: &HetznerClient,
+    snapshot: &MaintenanceSnapshot,
+    names: &[String],
+) -> Result<()> {
+    let zone = ZoneRef::Id(snapshot.zone_id.as_str());
+    for name in names {
+        for record_type in [RecordType::A, RecordType::Aaaa] {
+            let previous: Vec<&Record> = snapshot
+                .records
+                .iter()
+                .filter(|record| has_name_and_type(record, name, record_type.as_str()))
+                .collect();
+            let values: Vec<String> = previous.iter().map(|r| r.value.clone()).collect();
+            let ttl = previous.first().and_then(|r| r.ttl);
+            replace_record_set(client, zone, name, record_type.as_str(), &values, ttl).await?;
+        }
+    }
+    Ok(())
+}
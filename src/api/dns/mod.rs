@@ -1,18 +1,101 @@
 use crate::HetznerClient;
 
+pub mod acme;
+#[cfg(feature = "axfr")]
+pub mod axfr;
+pub mod backup;
+pub mod batch;
+pub mod dnscontrol;
+#[cfg(feature = "dyndns")]
+pub mod dyndns;
+pub mod filter;
+#[cfg(feature = "health")]
+pub mod health;
+pub mod hetzner_dns_api;
+#[cfg(feature = "dyndns")]
+pub mod ip_source;
+pub mod lease;
+pub mod lint;
+#[cfg(any(feature = "health", feature = "propagation"))]
+pub(crate) mod lookup;
+pub mod maintenance;
+pub mod names;
+pub mod ndjson;
+pub mod page;
+pub mod presets;
+pub mod primary_servers;
+#[cfg(feature = "propagation")]
+pub mod propagation;
+pub mod provider;
+#[cfg(any(feature = "mqtt", feature = "nats"))]
+pub mod publisher;
+pub mod record_ref;
+pub mod record_value;
 pub mod records;
+#[cfg(feature = "scheduler")]
+pub mod scheduler;
+pub mod search;
+pub mod secondary;
+pub mod sort;
+pub mod stats;
+pub mod template;
+pub mod watch;
+#[cfg(feature = "webhook-emitter")]
+pub mod webhook_emitter;
+#[cfg(feature = "webhook-server")]
+pub mod webhook_server;
+pub mod zone_ref;
 pub mod zones;
 
+/// Matches returned by [`DnsApi::search_zones`], enough for a type-ahead list.
+const SEARCH_PAGE_SIZE: u32 = 20;
+
 #[derive(Debug, Clone, Copy)]
 pub struct DnsApi<'a> {
     pub(crate) client: &'a HetznerClient,
 }
 
 impl<'a> DnsApi<'a> {
-    pub fn records(self, zone_id: &'a str) -> records::RecordsApi<'a> {
+    /// The records of a zone, given by id or name; see
+    /// [`ZoneRef`](zone_ref::ZoneRef).
+    pub fn records(self, zone: impl Into<zone_ref::ZoneRef<&'a str>>) -> records::RecordsApi<'a> {
         records::RecordsApi {
             client: self.client,
-            zone_id,
+            zone: zone.into(),
+        }
+    }
+
+    /// Starts a [`DynDns`](dyndns::DynDns) update of the address record
+    /// `name` in `zone`, given by id or by name; names are resolved through
+    /// the cached zone lookup.
+    #[cfg(feature = "dyndns")]
+    pub fn dyndns(
+        self,
+        zone: impl Into<zone_ref::ZoneRef<&'a str>>,
+        name: impl Into<String>,
+    ) -> dyndns::DynDns<'a> {
+        dyndns::DynDns::new(self.client, zone.into(), name.into())
+    }
+
+    /// Starts a [`Batch`](batch::Batch) of record changes to `zone`, given by
+    /// id or by name; a name is resolved when the batch is committed.
+    pub fn batch(self, zone: impl Into<zone_ref::ZoneRef<&'a str>>) -> batch::Batch<'a> {
+        batch::Batch {
+            client: self.client,
+            zone: zone.into(),
+            operations: Vec::new(),
+            rollback_on_failure: false,
+            on_progress: None,
+        }
+    }
+
+    /// Applies service [`presets`] (mail providers, GitHub Pages) to `zone`,
+    /// given by id or by name; names are resolved through the cached zone
+    /// lookup.
+    pub fn presets(self, zone: impl Into<zone_ref::ZoneRef<&'a str>>) -> presets::PresetsApi<'a> {
+        presets::PresetsApi {
+            client: self.client,
+            zone: zone.into(),
         }
     }
 
@@ -23,7 +106,385 @@ impl<'a> DnsApi<'a> {
         }
     }
 
+    /// The record a [`RecordRef`](record_ref::RecordRef) names, for updates
+    /// and deletes without knowing its id.
+    pub fn record_ref(self, record: &'a record_ref::RecordRef) -> record_ref::RecordRefApi<'a> {
+        record_ref::RecordRefApi {
+            client: self.client,
+            record,
+        }
+    }
+
+    pub fn primary_servers(
+        self,
+        zone: impl Into<zone_ref::ZoneRef<&'a str>>,
+    ) -> primary_servers::PrimaryServersApi<'a> {
+        primary_servers::PrimaryServersApi {
+            client: self.client,
+            zone: zone.into(),
+        }
+    }
+
+    pub fn primary_server(
+        self,
+        primary_server_id: &'a str,
+    ) -> primary_servers::PrimaryServerApi<'a> {
+        primary_servers::PrimaryServerApi {
+            client: self.client,
+            primary_server_id,
+        }
+    }
+
+    pub fn zone(self, zone: impl Into<zone_ref::ZoneRef<&'a str>>) -> zones::ZoneApi<'a> {
+        zones::ZoneApi {
+            client: self.client,
+            zone: zone.into(),
+        }
+    }
+
+    /// The id of `zone`. A name is looked up with a `?name=` zone query the
+    /// first time and then served from the client's zone id cache, which its
+    /// clones share; see [`clear_zone_cache`](Self::clear_zone_cache).
+    pub async fn resolve_zone(
+        self,
+        zone: impl Into<zone_ref::ZoneRef<&'a str>>,
+    ) -> crate::error::Result<String> {
+        Ok(self.client.zone_id(zone.into()).await?.into_owned())
+    }
+
+    /// Forgets the cached zone ids, e.g. after zones were deleted and
+    /// re-created elsewhere.
+    pub fn clear_zone_cache(self) {
+        self.client.zone_ids.clear();
+    }
+
     pub async fn list_zones(self) -> crate::error::Result<Vec<crate::types::Zone>> {
         zones::list_zones(self.client).await
     }
+
+    /// Like [`list_zones`](Self::list_zones), but served from the client's
+    /// [read cache](crate::HetznerClient::with_read_cache) when it has one.
+    pub async fn list_zones_cached(self) -> crate::error::Result<Vec<crate::types::Zone>> {
+        self.client
+            .cached_read(
+                |cache| &cache.zones,
+                "zones".to_string(),
+                |client| async move { client.dns().list_zones().await },
+            )
+            .await
+    }
+
+    pub async fn list_zones_sorted(
+        self,
+        sort: sort::Sort,
+    ) -> crate::error::Result<Vec<crate::types::Zone>> {
+        let mut zones = self.list_zones().await?;
+        sort.zones(&mut zones);
+        Ok(zones)
+    }
+
+    pub async fn create_zone(
+        self,
+        name: impl Into<String>,
+        ttl: impl Into<Option<records::Ttl>>,
+    ) -> crate::error::Result<crate::types::Zone> {
+        zones::create_zone(
+            self.client,
+            zones::CreateZoneInput {
+                name: name.into(),
+                ttl: ttl.into(),
+            },
+        )
+        .await
+    }
+
+    /// See [`zones::ensure_zone`].
+    pub async fn ensure_zone(
+        self,
+        name: &str,
+        ttl: impl Into<Option<records::Ttl>>,
+    ) -> crate::error::Result<crate::types::Zone> {
+        zones::ensure_zone(self.client, name, ttl).await
+    }
+
+    /// See [`backup::backup_account_to_dir`].
+    pub async fn backup_account_to_dir(
+        self,
+        dir: impl AsRef<std::path::Path>,
+        options: backup::BackupOptions,
+    ) -> crate::error::Result<backup::BackupReport> {
+        backup::backup_account_to_dir(self.client, dir, options).await
+    }
+
+    /// Every zone of the account as a dnscontrol `dnsconfig.js`; see
+    /// [`dnscontrol`].
+    pub async fn export_dnscontrol(self) -> crate::error::Result<String> {
+        use futures_util::TryStreamExt;
+
+        let mut zones = Vec::new();
+        for zone in self.zones_stream().try_collect::<Vec<_>>().await? {
+            let records = self.records(&zone.id).list().await?;
+            zones.push(backup::ZoneBackup { zone, records });
+        }
+        Ok(dnscontrol::render_config(&zones))
+    }
+
+    /// See [`ndjson::export_records_ndjson`].
+    pub async fn export_records_ndjson<W: tokio::io::AsyncWrite + Unpin>(
+        self,
+        writer: W,
+    ) -> crate::error::Result<u64> {
+        ndjson::export_records_ndjson(self.client, writer).await
+    }
+
+    /// See [`backup::restore_account_from_dir`].
+    pub async fn restore_account_from_dir(
+        self,
+        dir: impl AsRef<std::path::Path>,
+    ) -> crate::error::Result<backup::RestorePlan> {
+        backup::restore_account_from_dir(self.client, dir).await
+    }
+
+    /// See [`propagation::await_propagation`].
+    #[cfg(feature = "propagation")]
+    pub async fn await_propagation(
+        self,
+        record: &crate::types::Record,
+        resolvers: &[std::net::SocketAddr],
+        quorum: usize,
+        timeout: std::time::Duration,
+    ) -> crate::error::Result<propagation::PropagationStatus> {
+        propagation::await_propagation(self.client, record, resolvers, quorum, timeout).await
+    }
+
+    /// See [`secondary::setup_secondary_zone`].
+    pub async fn setup_secondary_zone(
+        self,
+        name: &str,
+        primaries: &[(&str, u16)],
+        timeout: std::time::Duration,
+    ) -> crate::error::Result<secondary::SecondaryZone> {
+        secondary::setup_secondary_zone(self.client, name, primaries, timeout).await
+    }
+
+    /// See [`watch::watch_zone`].
+    pub fn watch_zone(
+        self,
+        zone: impl Into<zone_ref::ZoneRef<&'a str>>,
+        interval: std::time::Duration,
+    ) -> impl futures_util::Stream<Item = crate::error::Result<watch::ChangeEvent>> + 'a {
+        watch::watch_zone(self.client, zone, interval)
+    }
+
+    pub async fn zones_page(
+        self,
+        page: u32,
+        per_page: u32,
+    ) -> crate::error::Result<page::Page<crate::types::Zone>> {
+        page::Page::fetch(self.client, "zones", "zones", Vec::new(), page, per_page).await
+    }
+
+    /// Every zone of the account, fetched page by page as the stream is
+    /// polled, for use with [`TryStreamExt`](futures_util::TryStreamExt)
+    /// combinators:
+    ///
+    /// ```no_run
+    /// # async fn example(client: hetzner::HetznerClient) -> hetzner::Result<()> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// client
+    ///     .dns()
+    ///     .zones_stream()
+    ///     .try_for_each_concurrent(4, |zone| {
+    ///         let client = client.clone();
+    ///         async move {
+    ///             let records = client.dns().records(&zone.id).list().await?;
+    ///             println!("{}: {} records", zone.name, records.len());
+    ///             Ok(())
+    ///         }
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn zones_stream(
+        self,
+    ) -> impl futures_util::Stream<Item = crate::error::Result<crate::types::Zone>> + 'a {
+        use futures_util::{TryStreamExt, stream};
+
+        let client = self.client;
+        stream::once(self.zones_page(1, records::STREAM_PAGE_SIZE))
+            .map_ok(move |page| page.into_stream(client))
+            .try_flatten()
+    }
+
+    /// First page of zones whose name contains `query` (the API's
+    /// `search_name`), with the total match count in
+    /// [`Page::total_entries`](page::Page::total_entries).
+    pub async fn search_zones(
+        self,
+        query: &str,
+    ) -> crate::error::Result<page::Page<crate::types::Zone>> {
+        self.search_zones_page(query, 1, SEARCH_PAGE_SIZE).await
+    }
+
+    /// One page of zones matching `params`; the first page of 100 unless
+    /// `params` set others.
+    pub async fn list_zones_with(
+        self,
+        params: &zones::ListZonesParams,
+    ) -> crate::error::Result<page::Page<crate::types::Zone>> {
+        page::Page::fetch(
+            self.client,
+            "zones",
+            "zones",
+            params.to_query_pairs(),
+            params.page.unwrap_or(1),
+            params.per_page.unwrap_or(100),
+        )
+        .await
+    }
+
+    pub async fn search_zones_page(
+        self,
+        query: &str,
+        page: u32,
+        per_page: u32,
+    ) -> crate::error::Result<page::Page<crate::types::Zone>> {
+        let query = vec![("search_name".to_string(), query.to_string())];
+        page::Page::fetch(self.client, "zones", "zones", query, page, per_page).await
+    }
+
+    /// See [`RecordsApi::list_typed`](records::RecordsApi::list_typed).
+    pub async fn get_all_records_typed(
+        self,
+        zone: impl Into<zone_ref::ZoneRef<&'a str>>,
+    ) -> crate::error::Result<record_value::TypedRecords> {
+        self.records(zone).list_typed().await
+    }
+
+    /// See [`RecordsApi::get_by_name`](records::RecordsApi::get_by_name).
+    pub async fn get_record_by_name(
+        self,
+        zone: impl Into<zone_ref::ZoneRef<&'a str>>,
+        name: &str,
+        record_type: impl AsRef<str>,
+    ) -> crate::error::Result<Option<crate::types::Record>> {
+        self.records(zone).get_by_name(name, record_type).await
+    }
+
+    /// See [`RecordsApi::create_idempotent`](records::RecordsApi::create_idempotent).
+    pub async fn create_record_idempotent(
+        self,
+        zone: impl Into<zone_ref::ZoneRef<&'a str>>,
+        name: &str,
+        record_type: impl AsRef<str>,
+        value: &str,
+        ttl: impl Into<Option<records::Ttl>>,
+    ) -> crate::error::Result<crate::types::CreatedRecord> {
+        self.records(zone)
+            .create_idempotent(name, record_type.as_ref(), value, ttl)
+            .await
+    }
+
+    /// See [`search::find_records_by_value`].
+    pub async fn find_records_by_value(
+        self,
+        value: &str,
+        scope: search::SearchScope<'_>,
+    ) -> crate::error::Result<Vec<search::RecordMatch>> {
+        search::find_records_by_value(self.client, value, scope).await
+    }
+
+    /// See [`search::find_references`].
+    pub async fn find_references(
+        self,
+        hostname: &str,
+    ) -> crate::error::Result<Vec<search::RecordMatch>> {
+        search::find_references(self.client, hostname).await
+    }
+
+    /// Deletes expired [leased](records::RecordsApi::create_leased) records
+    /// in every zone of the account and returns their leases.
+    pub async fn cleanup_expired(self) -> crate::error::Result<Vec<lease::Lease>> {
+        use futures_util::TryStreamExt;
+
+        let client = self.client.correlated();
+        let zones: Vec<_> = client.dns().zones_stream().try_collect().await?;
+        let mut removed = Vec::new();
+        for zone in zones {
+            removed.extend(client.dns().records(&zone).cleanup_expired().await?);
+        }
+        Ok(removed)
+    }
+
+    /// Exchanges the `record_type` records of two names in one bulk update;
+    /// see [`RecordsApi::swap_record_sets`](records::RecordsApi::swap_record_sets).
+    pub async fn swap_record_sets(
+        self,
+        zone: impl Into<zone_ref::ZoneRef<&'a str>>,
+        name_a: &str,
+        name_b: &str,
+        record_type: impl AsRef<str>,
+    ) -> crate::error::Result<records::BulkUpdateRecordsResponse> {
+        self.records(zone)
+            .swap_record_sets(name_a, name_b, record_type)
+            .await
+    }
+
+    /// One page of a zone's records with the API's counts, see
+    /// [`Page::total_entries`](page::Page::total_entries) and
+    /// [`Page::last_page`](page::Page::last_page).
+    pub async fn get_records_page(
+        self,
+        zone: impl Into<zone_ref::ZoneRef<&'a str>>,
+        page: u32,
+        per_page: u32,
+    ) -> crate::error::Result<page::Page<crate::types::Record>> {
+        self.client.dns().records(zone).page(page, per_page).await
+    }
+
+    pub async fn acme_present(
+        self,
+        challenge: &acme::AcmeChallenge,
+    ) -> crate::error::Result<crate::types::CreatedRecord> {
+        acme::present(self.client, challenge).await
+    }
+
+    pub async fn acme_cleanup(
+        self,
+        challenge: &acme::AcmeChallenge,
+    ) -> crate::error::Result<usize> {
+        acme::cleanup(self.client, challenge).await
+    }
+
+    /// Points the A/AAAA records of `names` at `maintenance_ip` with a
+    /// [short TTL](maintenance::MAINTENANCE_TTL), replacing the addresses of
+    /// both families, and returns a snapshot of the records they replaced.
+    /// If a name cannot be changed, the names changed so far are restored
+    /// before the error is returned.
+    pub async fn enter_maintenance(
+        self,
+        zone: impl Into<zone_ref::ZoneRef<&'a str>>,
+        names: &[&str],
+        maintenance_ip: std::net::IpAddr,
+    ) -> crate::error::Result<maintenance::MaintenanceSnapshot> {
+        maintenance::enter(self.client, zone.into(), names, maintenance_ip).await
+    }
+
+    /// Restores the address records [`enter_maintenance`](Self::enter_maintenance)
+    /// replaced. Restoring twice changes nothing.
+    pub async fn exit_maintenance(
+        self,
+        snapshot: &maintenance::MaintenanceSnapshot,
+    ) -> crate::error::Result<()> {
+        maintenance::exit(self.client, snapshot).await
+    }
+
+    /// Starts a [`Dns01Solver`](acme::Dns01Solver) for the authorizations
+    /// of an ACME order.
+    #[cfg(feature = "acme")]
+    pub fn dns01_solver(self) -> acme::Dns01Solver {
+        acme::Dns01Solver::new(self.client)
+    }
 }
@@ -0,0 +1,231 @@
+//! Validated zone and record names.
+
+use crate::api::dns::zone_ref::ZoneRef;
+use crate::error::{HetznerError, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Longest name DNS allows, without the trailing dot.
+const MAX_NAME_LEN: usize = 253;
+const MAX_LABEL_LEN: usize = 63;
+
+/// A zone name such as `example.com`: at least two labels of letters,
+/// digits and hyphens. Stored lower-cased without a trailing dot.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ZoneName(String);
+
+impl ZoneName {
+    pub fn new(name: impl AsRef<str>) -> Result<Self> {
+        let name = normalize(name.as_ref());
+        let labels = check_labels(&name, false)?;
+        if labels < 2 {
+            return Err(invalid(&name, "a zone needs at least two labels"));
+        }
+        Ok(Self(name))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The name with a trailing dot, `example.com.`.
+    pub fn fqdn(&self) -> String {
+        format!("{}.", self.0)
+    }
+
+    /// Whether `name` (absolute, with or without trailing dot) is the zone
+    /// apex or lies below it.
+    pub fn contains(&self, name: &str) -> bool {
+        let name = normalize(name);
+        name == self.0 || name.ends_with(&format!(".{}", self.0))
+    }
+}
+
+/// A record name relative to its zone, `@` for the apex. Labels may use
+/// underscores (`_dmarc`), and the first may be the wildcard `*`. Stored
+/// lower-cased without a trailing dot.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct RecordName(String);
+
+impl RecordName {
+    pub const APEX: &'static str = "@";
+
+    /// A relative name; `""` and `@` are the apex.
+    pub fn new(name: impl AsRef<str>) -> Result<Self> {
+        let name = normalize(name.as_ref());
+        if name.is_empty() || name == Self::APEX {
+            return Ok(Self::apex());
+        }
+        check_labels(&name, true)?;
+        Ok(Self(name))
+    }
+
+    pub fn apex() -> Self {
+        Self(Self::APEX.to_string())
+    }
+
+    /// The name of `fqdn` relative to `zone`, e.g. `www` for
+    /// `www.example.com.`; fails if `fqdn` lies outside the zone.
+    pub fn relative_to(fqdn: &str, zone: &ZoneName) -> Result<Self> {
+        let name = normalize(fqdn);
+        if name == zone.0 {
+            return Ok(Self::apex());
+        }
+        match name.strip_suffix(&format!(".{}", zone.0)) {
+            Some(relative) => Self::new(relative),
+            None => Err(invalid(&name, &format!("not inside zone {zone}"))),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_apex(&self) -> bool {
+        self.0 == Self::APEX
+    }
+
+    pub fn is_wildcard(&self) -> bool {
+        self.0 == "*" || self.0.starts_with("*.")
+    }
+
+    /// The absolute name in `zone`, with a trailing dot: `www.example.com.`,
+    /// or `example.com.` for the apex.
+    pub fn fqdn(&self, zone: &ZoneName) -> String {
+        if self.is_apex() {
+            zone.fqdn()
+        } else {
+            format!("{}.{}.", self.0, zone.0)
+        }
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.trim().trim_end_matches('.').to_ascii_lowercase()
+}
+
+/// `name` relative to the zone `zone_name`, lower-cased, `@` for the apex.
+/// Names equal to the zone or ending in `.{zone}` count as absolute with or
+/// without a trailing dot; other names are already relative, or absolute
+/// names outside the zone when they end in a dot, and keep their form.
+pub(crate) fn relative_name(name: &str, zone_name: &str) -> String {
+    let name = name.trim().to_ascii_lowercase();
+    let zone = normalize(zone_name);
+    let absolute = name.trim_end_matches('.');
+    if absolute.is_empty() || absolute == RecordName::APEX || absolute == zone {
+        return RecordName::APEX.to_string();
+    }
+    match absolute.strip_suffix(&format!(".{zone}")) {
+        Some(prefix) => prefix.to_string(),
+        None => name,
+    }
+}
+
+/// `name` (relative, `@` or absolute) as an absolute name in the zone
+/// `zone_name`, lower-cased and with a trailing dot.
+pub(crate) fn absolute_name(name: &str, zone_name: &str) -> String {
+    match relative_name(name, zone_name) {
+        name if name == RecordName::APEX => format!("{}.", normalize(zone_name)),
+        name if name.ends_with('.') => name,
+        name => format!("{name}.{}.", normalize(zone_name)),
+    }
+}
+
+fn invalid(name: &str, reason: &str) -> HetznerError {
+    HetznerError::InvalidName(format!("{name:?}: {reason}"))
+}
+
+/// Checks the syntax of every label and returns how many there are.
+fn check_labels(name: &str, record: bool) -> Result<usize> {
+    if name.is_empty() {
+        return Err(invalid(name, "empty name"));
+    }
+    if name.len() > MAX_NAME_LEN {
+        return Err(invalid(name, "longer than 253 characters"));
+    }
+    let mut count = 0;
+    for (index, label) in name.split('.').enumerate() {
+        count += 1;
+        if record && index == 0 && label == "*" {
+            continue;
+        }
+        if label.is_empty() {
+            return Err(invalid(name, "empty label"));
+        }
+        if label.len() > MAX_LABEL_LEN {
+            return Err(invalid(name, "label longer than 63 characters"));
+        }
+        if label.starts_with('-') || label.ends_with('-') {
+            return Err(invalid(name, "label starts or ends with a hyphen"));
+        }
+        let allowed = |c: char| c.is_ascii_alphanumeric() || c == '-' || record && c == '_';
+        if let Some(c) = label.chars().find(|&c| !allowed(c)) {
+            return Err(invalid(name, &format!("invalid character {c:?}")));
+        }
+    }
+    Ok(count)
+}
+
+macro_rules! name_conversions {
+    ($name:ident) => {
+        impl FromStr for $name {
+            type Err = HetznerError;
+
+            fn from_str(value: &str) -> Result<Self> {
+                Self::new(value)
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = HetznerError;
+
+            fn try_from(value: String) -> Result<Self> {
+                Self::new(value)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = HetznerError;
+
+            fn try_from(value: &str) -> Result<Self> {
+                Self::new(value)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+    };
+}
+
+name_conversions!(ZoneName);
+name_conversions!(RecordName);
+
+impl<'a> From<&'a ZoneName> for ZoneRef<&'a str> {
+    fn from(value: &'a ZoneName) -> Self {
+        Self::Name(&value.0)
+    }
+}
+
+impl From<ZoneName> for ZoneRef {
+    fn from(value: ZoneName) -> Self {
+        Self::Name(value.0)
+    }
+}
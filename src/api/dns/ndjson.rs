@@ -0,0 +1,68 @@
+//! Streams every record of the account as newline-delimited JSON, one
+//! record per line, for data lakes or `jq` pipelines:
+//!
+//! ```text
+//! {"zone_name":"example.com","id":"...","name":"www","ttl":300,"type":"A","value":"192.0.2.1","zone_id":"...",...}
+//! ```
+//!
+//! Zones and records are fetched page by page while writing, so memory use
+//! stays at one page however large the account is.
+//!
+//! ```no_run
+//! # async fn run(client: hetzner::HetznerClient) -> hetzner::Result<()> {
+//! let file = tokio::fs::File::create("records.ndjson").await.unwrap();
+//! let written = client.dns().export_records_ndjson(tokio::io::BufWriter::new(file)).await?;
+//! println!("{written} records");
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::HetznerClient;
+use crate::error::{HetznerError, Result};
+use crate::types::Record;
+use futures_util::TryStreamExt;
+use serde::Serialize;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// One exported line: the record's own fields plus its zone's name.
+#[derive(Serialize)]
+struct Line<'a> {
+    zone_name: &'a str,
+    #[serde(flatten)]
+    record: &'a Record,
+}
+
+/// Writes every record of every zone to `writer`, one JSON object per line,
+/// zone by zone, and flushes it. Returns the number of records written.
+/// Stops at the first failed request or write; lines written until then
+/// stay in `writer`.
+pub async fn export_records_ndjson<W: AsyncWrite + Unpin>(
+    client: &HetznerClient,
+    mut writer: W,
+) -> Result<u64> {
+    let mut written = 0;
+    let mut line = Vec::new();
+    let mut zones = std::pin::pin!(client.dns().zones_stream());
+    while let Some(zone) = zones.try_next().await? {
+        let mut records = std::pin::pin!(client.dns().records(zone.id.as_str()).stream());
+        while let Some(record) = records.try_next().await? {
+            line.clear();
+            serde_json::to_writer(
+                &mut line,
+                &Line {
+                    zone_name: &zone.name,
+                    record: &record,
+                },
+            )?;
+            line.push(b'\n');
+            writer.write_all(&line).await.map_err(export_error)?;
+            written += 1;
+        }
+    }
+    writer.flush().await.map_err(export_error)?;
+    Ok(written)
+}
+
+fn export_error(err: std::io::Error) -> HetznerError {
+    HetznerError::Export(format!("failed to write records: {err}"))
+}
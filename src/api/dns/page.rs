@@ -0,0 +1,264 @@
+use crate::HetznerClient;
+use crate::error::Result;
+use crate::types::Pagination;
+use futures_util::{Stream, stream};
+use serde::Deserialize;
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, Visitor,
+};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// One page of a paginated DNS listing. [`next`](Self::next) and
+/// [`prev`](Self::prev) re-issue the original request for the adjacent page.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub pagination: Option<Pagination>,
+    request: PageRequest,
+    _marker: PhantomData<fn() -> T>,
+}
+
+#[derive(Debug, Clone)]
+struct PageRequest {
+    path: &'static str,
+    key: &'static str,
+    query: Vec<(String, String)>,
+    page: u32,
+    per_page: u32,
+}
+
+impl<T: DeserializeOwned> Page<T> {
+    pub(crate) async fn fetch(
+        client: &HetznerClient,
+        path: &'static str,
+        key: &'static str,
+        query: Vec<(String, String)>,
+        page: u32,
+        per_page: u32,
+    ) -> Result<Self> {
+        let request = PageRequest {
+            path,
+            key,
+            query,
+            page: page.max(1),
+            per_page,
+        };
+        Self::load(client, request).await
+    }
+
+    async fn load(client: &HetznerClient, request: PageRequest) -> Result<Self> {
+        let (page, per_page) = (request.page.to_string(), request.per_page.to_string());
+        let query: Vec<(&str, &str)> = request
+            .query
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .chain([("page", page.as_str()), ("per_page", per_page.as_str())])
+            .collect();
+
+        let key = request.key;
+        let Listing { items, pagination } = client
+            .request_dns_query_with(request.path, &query, |body| {
+                ListingSeed::<T>::new(key)
+                    .deserialize(&mut serde_json::Deserializer::from_slice(body))
+            })
+            .await?;
+
+        Ok(Self {
+            items,
+            pagination,
+            request,
+            _marker: PhantomData,
+        })
+    }
+
+    pub fn page(&self) -> u32 {
+        self.request.page
+    }
+
+    pub fn per_page(&self) -> u32 {
+        self.request.per_page
+    }
+
+    /// Number of items across all pages, as reported by the API.
+    pub fn total_entries(&self) -> Option<u32> {
+        self.pagination.as_ref()?.total_entries
+    }
+
+    pub fn last_page(&self) -> Option<u32> {
+        self.pagination.as_ref()?.last_page
+    }
+
+    pub fn has_next(&self) -> bool {
+        self.next_page().is_some()
+    }
+
+    pub fn has_prev(&self) -> bool {
+        self.prev_page().is_some()
+    }
+
+    /// Fetches the following page, or `None` when this is the last one.
+    pub async fn next(&self, client: &HetznerClient) -> Result<Option<Self>> {
+        match self.next_page() {
+            Some(page) => self.goto(client, page).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetches the preceding page, or `None` when this is the first one.
+    pub async fn prev(&self, client: &HetznerClient) -> Result<Option<Self>> {
+        match self.prev_page() {
+            Some(page) => self.goto(client, page).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Yields this page's items, then those of each following page as the
+    /// stream reaches them. A failed fetch is yielded and ends the stream.
+    ///
+    /// ```no_run
+    /// # async fn example(client: hetzner::HetznerClient) -> hetzner::Result<()> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let page = client.dns().zones_page(1, 100).await?;
+    /// let names: Vec<String> = page.into_stream(&client).map_ok(|zone| zone.name).try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_stream<'c>(
+        mut self,
+        client: &'c HetznerClient,
+    ) -> impl Stream<Item = Result<T>> + 'c
+    where
+        T: 'c,
+    {
+        let items = std::mem::take(&mut self.items).into_iter();
+        stream::try_unfold(
+            (items, Some(self)),
+            move |(mut items, mut page)| async move {
+                loop {
+                    if let Some(item) = items.next() {
+                        return Ok(Some((item, (items, page))));
+                    }
+                    let Some(current) = page.take() else {
+                        return Ok(None);
+                    };
+                    let Some(mut next) = current.next(client).await? else {
+                        return Ok(None);
+                    };
+                    if next.items.is_empty() {
+                        return Ok(None);
+                    }
+                    items = std::mem::take(&mut next.items).into_iter();
+                    page = Some(next);
+                }
+            },
+        )
+    }
+
+    async fn goto(&self, client: &HetznerClient, page: u32) -> Result<Self> {
+        let request = PageRequest {
+            page,
+            ..self.request.clone()
+        };
+        Self::load(client, request).await
+    }
+
+    /// The API repeats the current page as `next_page` on the last one.
+    fn next_page(&self) -> Option<u32> {
+        let page = self.request.page;
+        self.pagination
+            .as_ref()?
+            .next_page
+            .filter(|&next| next > page)
+    }
+
+    fn prev_page(&self) -> Option<u32> {
+        let pagination = self.pagination.as_ref()?;
+        pagination
+            .previous_page
+            .or_else(|| (pagination.page > 1).then(|| pagination.page - 1))
+            .filter(|&prev| prev < self.request.page)
+    }
+}
+
+/// The items and pagination of a listing response.
+struct Listing<T> {
+    items: Vec<T>,
+    pagination: Option<Pagination>,
+}
+
+#[derive(Deserialize)]
+struct ListingMeta {
+    #[serde(default)]
+    pagination: Option<Pagination>,
+}
+
+/// Reads the array under `key` straight into `T`s and skips every other
+/// field but `meta` (or rejects it with the `strict` feature), so large
+/// pages are never held as a `serde_json::Value`.
+struct ListingSeed<T> {
+    key: &'static str,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> ListingSeed<T> {
+    fn new(key: &'static str) -> Self {
+        Self {
+            key,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> DeserializeSeed<'de> for ListingSeed<T> {
+    type Value = Listing<T>;
+
+    fn deserialize<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> std::result::Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for ListingSeed<T> {
+    type Value = Listing<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a listing with a `{}` field", self.key)
+    }
+
+    /// An empty body: no items and no pagination.
+    fn visit_unit<E>(self) -> std::result::Result<Self::Value, E> {
+        Ok(Listing {
+            items: Vec::new(),
+            pagination: None,
+        })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(
+        self,
+        mut map: A,
+    ) -> std::result::Result<Self::Value, A::Error> {
+        let mut items = Vec::new();
+        let mut pagination = None;
+        while let Some(field) = map.next_key::<String>()? {
+            if field == self.key {
+                items = map.next_value::<Option<Vec<T>>>()?.unwrap_or_default();
+            } else if field == "meta" {
+                pagination = map
+                    .next_value::<Option<ListingMeta>>()?
+                    .and_then(|meta| meta.pagination);
+            } else if cfg!(feature = "strict") {
+                return Err(de::Error::custom(format_args!(
+                    "unknown field `{field}`, expected `{}` or `meta`",
+                    self.key
+                )));
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(Listing { items, pagination })
+    }
+}
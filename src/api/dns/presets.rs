@@ -0,0 +1,227 @@
+//! Record sets documented by common hosted services, applied idempotently.
+
+use crate::HetznerClient;
+use crate::api::dns::batch::BatchResult;
+use crate::api::dns::provider::DnsRecord;
+use crate::api::dns::zone_ref::ZoneRef;
+use crate::error::Result;
+use crate::types::Record;
+use serde::{Deserialize, Serialize};
+
+/// The records a service expects in a zone. Applying a preset replaces the
+/// zone's records of the same name and type (for TXT only SPF policies, and
+/// anything at a name the preset puts a CNAME on); other records are kept.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Preset {
+    pub name: String,
+    pub records: Vec<DnsRecord>,
+}
+
+impl Preset {
+    pub fn new(name: impl Into<String>, records: Vec<DnsRecord>) -> Self {
+        Self {
+            name: name.into(),
+            records,
+        }
+    }
+
+    /// Whether applying this preset takes over `record`.
+    fn owns(&self, record: &Record) -> bool {
+        self.records.iter().any(|wanted| {
+            if !wanted.name.eq_ignore_ascii_case(&record.name) {
+                return false;
+            }
+            if wanted.record_type.eq_ignore_ascii_case("CNAME")
+                || record.record_type.eq_ignore_ascii_case("CNAME")
+            {
+                return true;
+            }
+            wanted.record_type.eq_ignore_ascii_case(&record.record_type)
+                && (!wanted.record_type.eq_ignore_ascii_case("TXT")
+                    || is_spf(&wanted.value) && is_spf(&record.value))
+        })
+    }
+
+    /// The changes applying the preset to a zone holding `existing` makes.
+    pub fn plan(&self, existing: &[Record]) -> PresetPlan {
+        let mut plan = PresetPlan::default();
+        for record in existing.iter().filter(|record| self.owns(record)) {
+            if self.records.iter().any(|wanted| matches(wanted, record)) {
+                plan.unchanged.push(record.clone());
+            } else {
+                plan.delete.push(record.clone());
+            }
+        }
+        plan.create = self
+            .records
+            .iter()
+            .filter(|wanted| !plan.unchanged.iter().any(|record| matches(wanted, record)))
+            .cloned()
+            .collect();
+        plan
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PresetPlan {
+    pub create: Vec<DnsRecord>,
+    /// Records of the preset's names and types that it does not want.
+    pub delete: Vec<Record>,
+    pub unchanged: Vec<Record>,
+}
+
+impl PresetPlan {
+    pub fn is_empty(&self) -> bool {
+        self.create.is_empty() && self.delete.is_empty()
+    }
+}
+
+/// One line per change, `-` for deletions and `+` for creations, like a diff.
+impl std::fmt::Display for PresetPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "no changes ({} records unchanged)", self.unchanged.len());
+        }
+        for record in &self.delete {
+            writeln!(f, "- {record}")?;
+        }
+        for record in &self.create {
+            writeln!(f, "+ {record}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Google Workspace mail delivery.
+pub fn google_workspace_mx() -> Preset {
+    Preset::new(
+        "Google Workspace MX",
+        vec![DnsRecord::new("@", "MX", "1 smtp.google.com.", None)],
+    )
+}
+
+/// Microsoft 365 mail, SPF and Outlook autodiscovery; `tenant` is the
+/// prefix of the tenant's `*.mail.protection.outlook.com` host.
+pub fn office365(tenant: &str) -> Preset {
+    Preset::new(
+        "Microsoft 365",
+        vec![
+            DnsRecord::new(
+                "@",
+                "MX",
+                format!("0 {tenant}.mail.protection.outlook.com."),
+                None,
+            ),
+            DnsRecord::new(
+                "@",
+                "TXT",
+                "\"v=spf1 include:spf.protection.outlook.com -all\"",
+                None,
+            ),
+            DnsRecord::new("autodiscover", "CNAME", "autodiscover.outlook.com.", None),
+        ],
+    )
+}
+
+/// GitHub Pages on the apex and `www` for the user or organisation `user`.
+pub fn github_pages(user: &str) -> Preset {
+    let mut records: Vec<DnsRecord> = (108..=111)
+        .map(|octet| DnsRecord::new("@", "A", format!("185.199.{octet}.153"), None))
+        .collect();
+    records.extend(
+        (0..=3).map(|n| DnsRecord::new("@", "AAAA", format!("2606:50c0:800{n}::153"), None)),
+    );
+    records.push(DnsRecord::new(
+        "www",
+        "CNAME",
+        format!("{user}.github.io."),
+        None,
+    ));
+    Preset::new("GitHub Pages", records)
+}
+
+/// Fastmail mail delivery, SPF and DKIM for the zone `domain`.
+pub fn fastmail(domain: &str) -> Preset {
+    let domain = domain.trim_end_matches('.');
+    let mut records = vec![
+        DnsRecord::new("@", "MX", "10 in1-smtp.messagingengine.com.", None),
+        DnsRecord::new("@", "MX", "20 in2-smtp.messagingengine.com.", None),
+        DnsRecord::new(
+            "@",
+            "TXT",
+            "\"v=spf1 include:spf.messagingengine.com ?all\"",
+            None,
+        ),
+    ];
+    records.extend((1..=3).map(|n| {
+        DnsRecord::new(
+            format!("fm{n}._domainkey"),
+            "CNAME",
+            format!("fm{n}.{domain}.dkim.fmhosted.com."),
+            None,
+        )
+    }));
+    Preset::new("Fastmail", records)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PresetsApi<'a> {
+    pub(crate) client: &'a HetznerClient,
+    pub(crate) zone: ZoneRef<&'a str>,
+}
+
+impl<'a> PresetsApi<'a> {
+    /// What [`apply`](Self::apply) would change, without changing anything.
+    pub async fn plan(self, preset: &Preset) -> Result<PresetPlan> {
+        let existing = self.client.dns().records(self.zone).list().await?;
+        Ok(preset.plan(&existing))
+    }
+
+    /// Brings the zone in line with `preset` in one batch, rolled back if
+    /// any step fails. Applying the same preset again changes nothing.
+    pub async fn apply(self, preset: &Preset) -> Result<BatchResult> {
+        let client = self.client.correlated();
+        let presets = client.dns().presets(self.zone);
+        let plan = presets.plan(preset).await?;
+        if plan.is_empty() {
+            return Ok(BatchResult::default());
+        }
+        let mut batch = client.dns().batch(self.zone).rollback_on_failure();
+        for record in plan.delete {
+            batch = batch.delete(record.id);
+        }
+        for record in plan.create {
+            batch = batch.create(record.name, record.record_type, record.value, record.ttl);
+        }
+        batch.commit().await
+    }
+
+    pub async fn apply_google_workspace_mx(self) -> Result<BatchResult> {
+        self.apply(&google_workspace_mx()).await
+    }
+
+    pub async fn apply_office365(self, tenant: &str) -> Result<BatchResult> {
+        self.apply(&office365(tenant)).await
+    }
+
+    pub async fn apply_github_pages(self, user: &str) -> Result<BatchResult> {
+        self.apply(&github_pages(user)).await
+    }
+
+    pub async fn apply_fastmail(self) -> Result<BatchResult> {
+        let zone = self.client.dns().zone(self.zone).get().await?.zone;
+        self.apply(&fastmail(&zone.name)).await
+    }
+}
+
+fn matches(wanted: &DnsRecord, record: &Record) -> bool {
+    wanted.key() == record.key()
+}
+
+fn is_spf(value: &str) -> bool {
+    value
+        .trim()
+        .trim_start_matches('"')
+        .to_ascii_lowercase()
+        .starts_with("v=spf1")
+}
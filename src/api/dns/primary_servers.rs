@@ -0,0 +1,199 @@
+use crate::HetznerClient;
+use crate::api::dns::page::Page;
+use crate::api::dns::zone_ref::ZoneRef;
+use crate::error::{Result, not_found_as_none};
+use crate::mutation::{Mutation, MutationOperation};
+use crate::policy::ProposedChange;
+use crate::types::{PrimaryServer, PrimaryServerEnvelope, PrimaryServersEnvelope};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::time::Instant;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrimaryServerInput {
+    pub address: String,
+    pub port: u16,
+    pub zone_id: String,
+}
+
+impl PrimaryServerInput {
+    pub fn address(mut self, address: impl Into<String>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    pub fn zone_id(mut self, zone_id: impl Into<String>) -> Self {
+        self.zone_id = zone_id.into();
+        self
+    }
+}
+
+/// Primary servers a secondary zone transfers from.
+#[derive(Debug, Clone, Copy)]
+pub struct PrimaryServersApi<'a> {
+    pub(crate) client: &'a HetznerClient,
+    pub(crate) zone: ZoneRef<&'a str>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PrimaryServerApi<'a> {
+    pub(crate) client: &'a HetznerClient,
+    pub(crate) primary_server_id: &'a str,
+}
+
+impl<'a> PrimaryServersApi<'a> {
+    pub async fn list(self) -> Result<Vec<PrimaryServer>> {
+        let zone_id = self.client.zone_id(self.zone).await?;
+        let path = format!("primary_servers?zone_id={zone_id}");
+        let response: PrimaryServersEnvelope =
+            self.client.request_dns(Method::GET, &path, None).await?;
+        Ok(response.primary_servers)
+    }
+
+    pub async fn page(self, page: u32, per_page: u32) -> Result<Page<PrimaryServer>> {
+        let zone_id = self.client.zone_id(self.zone).await?;
+        let query = vec![("zone_id".to_string(), zone_id.into_owned())];
+        Page::fetch(
+            self.client,
+            "primary_servers",
+            "primary_servers",
+            query,
+            page,
+            per_page,
+        )
+        .await
+    }
+
+    pub async fn create(self, address: impl Into<String>, port: u16) -> Result<PrimaryServer> {
+        let client = self.client.correlated();
+        let zone_id = client.zone_id(self.zone).await?;
+        let payload = PrimaryServerInput {
+            address: address.into(),
+            port,
+            zone_id: zone_id.to_string(),
+        };
+        client
+            .enforce_policy(|| {
+                vec![ProposedChange::new(
+                    MutationOperation::CreatePrimaryServer,
+                    Some(payload.zone_id.clone()),
+                    None,
+                    None,
+                )]
+            })
+            .await?;
+        let started = Instant::now();
+        let result = client
+            .request_dns::<PrimaryServerEnvelope>(
+                Method::POST,
+                "primary_servers",
+                Some(json!(payload)),
+            )
+            .await
+            .map(|response| response.primary_server);
+        client.emit_mutation(
+            Mutation {
+                started,
+                operation: MutationOperation::CreatePrimaryServer,
+                zone_id: Some(zone_id.into_owned()),
+                resource_id: result.as_ref().ok().map(|server| server.id.clone()),
+                before: None,
+            },
+            &result,
+            |_| None,
+        );
+        result
+    }
+}
+
+impl<'a> PrimaryServerApi<'a> {
+    pub async fn get(self) -> Result<PrimaryServer> {
+        let path = format!("primary_servers/{}", self.primary_server_id);
+        let response: PrimaryServerEnvelope =
+            self.client.request_dns(Method::GET, &path, None).await?;
+        Ok(response.primary_server)
+    }
+
+    /// Like [`get`](Self::get), but a missing primary server is `Ok(None)`.
+    pub async fn get_opt(self) -> Result<Option<PrimaryServer>> {
+        not_found_as_none(self.get().await)
+    }
+
+    pub async fn update(self, input: PrimaryServerInput) -> Result<PrimaryServer> {
+        let client = self.client.correlated();
+        let path = format!("primary_servers/{}", self.primary_server_id);
+        let zone_id = input.zone_id.clone();
+        client
+            .enforce_policy(|| {
+                vec![ProposedChange::new(
+                    MutationOperation::UpdatePrimaryServer,
+                    Some(zone_id.clone()),
+                    None,
+                    None,
+                )]
+            })
+            .await?;
+        let started = Instant::now();
+        let result = client
+            .request_dns::<PrimaryServerEnvelope>(Method::PUT, &path, Some(json!(input)))
+            .await
+            .map(|response| response.primary_server);
+        client.emit_mutation(
+            Mutation {
+                started,
+                operation: MutationOperation::UpdatePrimaryServer,
+                zone_id: Some(zone_id),
+                resource_id: Some(self.primary_server_id.to_string()),
+                before: None,
+            },
+            &result,
+            |_| None,
+        );
+        result
+    }
+
+    pub async fn delete(self) -> Result<()> {
+        let client = self.client.correlated();
+        let path = format!("primary_servers/{}", self.primary_server_id);
+        // Only a policy needs to know the zone the server belongs to.
+        let zone_id = match &client.policy {
+            Some(_) => client
+                .dns()
+                .primary_server(self.primary_server_id)
+                .get_opt()
+                .await?
+                .map(|server| server.zone_id),
+            None => None,
+        };
+        client
+            .enforce_policy(|| {
+                vec![ProposedChange::new(
+                    MutationOperation::DeletePrimaryServer,
+                    zone_id.clone(),
+                    None,
+                    None,
+                )]
+            })
+            .await?;
+        let started = Instant::now();
+        let result = client.request_dns_unit(Method::DELETE, &path, None).await;
+        client.emit_mutation(
+            Mutation {
+                started,
+                operation: MutationOperation::DeletePrimaryServer,
+                zone_id,
+                resource_id: Some(self.primary_server_id.to_string()),
+                before: None,
+            },
+            &result,
+            |_| None,
+        );
+        result
+    }
+}
@@ -0,0 +1,150 @@
+//! Waiting until public resolvers serve a record (`propagation` feature).
+
+use crate::HetznerClient;
+use crate::api::dns::lookup::{connect, fqdn, resolve_nameservers};
+use crate::api::dns::zones::poll_interval;
+use crate::error::{HetznerError, Result};
+use crate::types::{Record, RecordKey, Zone};
+use hickory_net::client::ClientHandle;
+use hickory_proto::rr::{DNSClass, RecordType};
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::time::Instant;
+
+const QUERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub const GOOGLE_DNS: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(8, 8, 8, 8)), 53);
+pub const CLOUDFLARE_DNS: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1)), 53);
+pub const QUAD9_DNS: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(9, 9, 9, 9)), 53);
+
+/// What one resolver returned for the record's name and type in the last
+/// round of queries.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ResolverAnswer {
+    pub resolver: SocketAddr,
+    pub values: Vec<String>,
+    /// Whether one of `values` is the record's value.
+    pub serves_record: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PropagationStatus {
+    pub answers: Vec<ResolverAnswer>,
+    pub quorum: usize,
+}
+
+impl PropagationStatus {
+    /// Resolvers serving the record.
+    pub fn agreeing(&self) -> usize {
+        self.answers.iter().filter(|a| a.serves_record).count()
+    }
+
+    pub fn is_propagated(&self) -> bool {
+        self.agreeing() >= self.quorum
+    }
+}
+
+/// The addresses of `zone`'s nameservers, for checking Hetzner's own view
+/// alongside public resolvers. Names that don't resolve are left out.
+pub async fn zone_nameservers(zone: &Zone) -> Vec<SocketAddr> {
+    resolve_nameservers(zone)
+        .await
+        .into_iter()
+        .filter_map(|(_, address)| address.ok())
+        .collect()
+}
+
+/// Queries `resolvers` (in parallel) for `record` until at least `quorum` of them serve
+/// its value, re-querying at a tenth of `timeout` (between 50ms and 5s).
+/// A `quorum` above the number of resolvers requires all of them.
+///
+/// Values are compared as in [`RecordKey`], so case, trailing dots and TXT
+/// quoting don't matter. Fails with [`HetznerError::Timeout`] once the
+/// deadline passes.
+pub async fn await_propagation(
+    client: &HetznerClient,
+    record: &Record,
+    resolvers: &[SocketAddr],
+    quorum: usize,
+    timeout: Duration,
+) -> Result<PropagationStatus> {
+    let zone = client.dns().zone(&record.zone_id).get().await?.zone;
+    let quorum = quorum.min(resolvers.len());
+    let deadline = Instant::now() + timeout;
+    let interval = poll_interval(timeout);
+    loop {
+        client.check_cancelled()?;
+        let query_timeout = deadline
+            .saturating_duration_since(Instant::now())
+            .clamp(interval, QUERY_TIMEOUT);
+        let answers = futures_util::future::join_all(
+            resolvers
+                .iter()
+                .map(|&resolver| query(resolver, record, &zone.name, query_timeout)),
+        )
+        .await;
+        let status = PropagationStatus { answers, quorum };
+        if status.is_propagated() {
+            return Ok(status);
+        }
+        if Instant::now() + interval > deadline {
+            return Err(HetznerError::Timeout(format!(
+                "{} {} to propagate ({} of {quorum} resolvers agree)",
+                record.record_type,
+                fqdn(&record.name, &zone.name)
+                    .map_or_else(|_| record.name.clone(), |n| n.to_string()),
+                status.agreeing()
+            )));
+        }
+        client.pause(interval).await?;
+    }
+}
+
+async fn query(
+    resolver: SocketAddr,
+    record: &Record,
+    zone_name: &str,
+    timeout: Duration,
+) -> ResolverAnswer {
+    let mut answer = ResolverAnswer {
+        resolver,
+        values: Vec::new(),
+        serves_record: false,
+        error: None,
+    };
+    let name = match fqdn(&record.name, zone_name) {
+        Ok(name) => name,
+        Err(error) => {
+            answer.error = Some(error);
+            return answer;
+        }
+    };
+    let query_type = match RecordType::from_str(&record.record_type) {
+        Ok(query_type) => query_type,
+        Err(err) => {
+            answer.error = Some(format!("unsupported type {}: {err}", record.record_type));
+            return answer;
+        }
+    };
+    let mut client = connect(resolver, timeout);
+    match client.query(name, DNSClass::IN, query_type).await {
+        Ok(response) => {
+            answer.values = response
+                .answers
+                .iter()
+                .filter(|rr| rr.record_type() == query_type)
+                .map(|rr| rr.data.to_string())
+                .collect();
+        }
+        Err(err) => answer.error = Some(format!("query failed: {err}")),
+    }
+    let wanted = record.key().value;
+    answer.serves_record = answer
+        .values
+        .iter()
+        .any(|value| RecordKey::new(&record.name, &record.record_type, value).value == wanted);
+    answer
+}
@@ -0,0 +1,179 @@
+use crate::api::dns::hetzner_dns_api::HetznerDnsApi;
+use crate::api::dns::names::relative_name;
+use crate::api::dns::record_ref::has_name_and_type;
+use crate::api::dns::records::{Ttl, UpdateRecordInput};
+use crate::error::{HetznerError, Result};
+use crate::types::{Record, RecordKey, Zone, normalize_value};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+
+/// A record as exchanged with [`DnsProvider`]: `name` is relative to the zone
+/// (`@` for the apex) and a `ttl` of `None` means the zone default.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize, Serialize)]
+pub struct DnsRecord {
+    pub name: String,
+    pub record_type: String,
+    pub value: String,
+    pub ttl: Option<Ttl>,
+}
+
+impl DnsRecord {
+    pub fn new(
+        name: impl Into<String>,
+        record_type: impl Into<String>,
+        value: impl Into<String>,
+        ttl: impl Into<Option<Ttl>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            record_type: record_type.into(),
+            value: value.into(),
+            ttl: ttl.into(),
+        }
+    }
+
+    pub fn key(&self) -> RecordKey {
+        RecordKey::new(&self.name, &self.record_type, &self.value)
+    }
+}
+
+impl std::fmt::Display for DnsRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::types::fmt_record_line(f, &self.name, self.ttl, &self.record_type, &self.value)
+    }
+}
+
+impl From<&Record> for DnsRecord {
+    fn from(record: &Record) -> Self {
+        Self {
+            name: record.name.clone(),
+            record_type: record.record_type.clone(),
+            value: record.value.clone(),
+            ttl: record.ttl,
+        }
+    }
+}
+
+/// Provider-agnostic DNS operations, addressing zones by name, so tools
+/// managing several providers can treat Hetzner as one backend among others.
+///
+/// Implemented for every [`HetznerDnsApi`], i.e. [`HetznerClient`](crate::HetznerClient)
+/// and `hetzner::testing::MockHetznerClient` (`testing` feature).
+pub trait DnsProvider {
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Names of all zones the credentials can manage.
+    fn list_zones(
+        &self,
+    ) -> impl Future<Output = std::result::Result<Vec<String>, Self::Error>> + Send;
+
+    fn list_records(
+        &self,
+        zone: &str,
+    ) -> impl Future<Output = std::result::Result<Vec<DnsRecord>, Self::Error>> + Send;
+
+    /// Creates `record`, or sets the value and TTL of the existing record with
+    /// the same name and type.
+    fn upsert(
+        &self,
+        zone: &str,
+        record: &DnsRecord,
+    ) -> impl Future<Output = std::result::Result<(), Self::Error>> + Send;
+
+    /// Deletes the records matching `record`'s name, type and value and
+    /// returns how many were removed.
+    fn delete(
+        &self,
+        zone: &str,
+        record: &DnsRecord,
+    ) -> impl Future<Output = std::result::Result<usize, Self::Error>> + Send;
+}
+
+impl<T: HetznerDnsApi + Sync> DnsProvider for T {
+    type Error = HetznerError;
+
+    async fn list_zones(&self) -> Result<Vec<String>> {
+        let zones = HetznerDnsApi::list_zones(self).await?;
+        Ok(zones.into_iter().map(|zone| zone.name).collect())
+    }
+
+    async fn list_records(&self, zone: &str) -> Result<Vec<DnsRecord>> {
+        let zone = find_zone(self, zone).await?;
+        let records = HetznerDnsApi::list_records(self, &zone.id).await?;
+        Ok(records.iter().map(DnsRecord::from).collect())
+    }
+
+    async fn upsert(&self, zone: &str, record: &DnsRecord) -> Result<()> {
+        let zone = find_zone(self, zone).await?;
+        let records = HetznerDnsApi::list_records(self, &zone.id).await?;
+        let existing = records
+            .iter()
+            .find(|existing| same_rrset(existing, &zone, record) && same_value(existing, record))
+            .or_else(|| {
+                records
+                    .iter()
+                    .find(|existing| same_rrset(existing, &zone, record))
+            });
+
+        match existing {
+            Some(existing) if same_value(existing, record) && existing.ttl == record.ttl => {}
+            Some(existing) => {
+                let input = UpdateRecordInput {
+                    zone_id: zone.id.clone(),
+                    record_type: existing.record_type.clone(),
+                    name: existing.name.clone(),
+                    value: record.value.clone(),
+                    ttl: record.ttl,
+                };
+                self.update_record(&existing.id, input).await?;
+            }
+            None => {
+                self.create_record(
+                    &zone.id,
+                    &relative_name(&record.name, &zone.name),
+                    &record.record_type,
+                    &record.value,
+                    record.ttl,
+                )
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, zone: &str, record: &DnsRecord) -> Result<usize> {
+        let zone = find_zone(self, zone).await?;
+        let records = HetznerDnsApi::list_records(self, &zone.id).await?;
+        let mut removed = 0;
+        for existing in records
+            .iter()
+            .filter(|existing| same_rrset(existing, &zone, record) && same_value(existing, record))
+        {
+            self.delete_record(&existing.id).await?;
+            removed += 1;
+        }
+        Ok(removed)
+    }
+}
+
+async fn find_zone(api: &(impl HetznerDnsApi + Sync), name: &str) -> Result<Zone> {
+    let name = name.trim_end_matches('.');
+    HetznerDnsApi::list_zones(api)
+        .await?
+        .into_iter()
+        .find(|zone| zone.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| HetznerError::ZoneNotFound(name.to_string()))
+}
+
+/// Whether the values only differ in spelling; see [`normalize_value`].
+fn same_value(existing: &Record, record: &DnsRecord) -> bool {
+    normalize_value(&existing.record_type, &existing.value)
+        == normalize_value(&record.record_type, &record.value)
+}
+
+/// Whether `existing` has `record`'s name and type; `record`'s name may be
+/// given relative, as `@` or fully qualified.
+fn same_rrset(existing: &Record, zone: &Zone, record: &DnsRecord) -> bool {
+    let name = relative_name(&record.name, &zone.name);
+    has_name_and_type(existing, &name, &record.record_type)
+}
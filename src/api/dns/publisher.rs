@@ -0,0 +1,203 @@
+//! Publishes DNS change events to a message bus, NATS (`nats` feature) or
+//! MQTT (`mqtt` feature), so consumers such as cache purgers react to
+//! changes without polling Hetzner themselves.
+//!
+//! Each event is published as its JSON to a topic naming the zone and what
+//! happened, below a configurable prefix:
+//!
+//! - `{prefix}.{zone_id}.change.{created|updated|deleted}` for
+//!   [`ChangeEvent`]s from [`watch_zone`](crate::api::dns::DnsApi::watch_zone)
+//! - `{prefix}.{zone_id}.mutation.{operation}` for this client's own
+//!   [`MutationEvent`]s, with `_` for writes without a zone id
+//!
+//! NATS subjects use `.` and the prefix `hetzner.dns`; MQTT topics use `/`
+//! and the prefix `hetzner/dns`, so consumers can subscribe to
+//! `hetzner.dns.*.change.>` or `hetzner/dns/+/change/#`. Watches resolve a
+//! zone given by name to its id, so a zone's changes and writes share one
+//! topic tree; separators and wildcards (`.`, `/`, `+`, `#`, `*`, `>`) in a
+//! zone id of a hand-made event are replaced by `_` to keep it one token.
+//!
+//! ```no_run
+//! # #[cfg(feature = "nats")]
+//! # async fn example(client: hetzner::HetznerClient) -> Result<(), Box<dyn std::error::Error>> {
+//! use hetzner::EventPublisher;
+//! use std::time::Duration;
+//!
+//! let publisher = EventPublisher::nats(async_nats::connect("nats://localhost:4222").await?);
+//! let client = client.with_on_mutation(publisher.mutation_hook());
+//! let changes = client.dns().watch_zone("example.com", Duration::from_secs(60));
+//! publisher.forward(changes).await;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::api::dns::watch::ChangeEvent;
+use crate::error::{HetznerError, Result};
+use crate::logging::{debug, warn};
+use crate::mutation::MutationEvent;
+use futures_util::{Stream, StreamExt};
+use std::fmt;
+
+#[derive(Clone)]
+enum Transport {
+    #[cfg(feature = "nats")]
+    Nats(async_nats::Client),
+    #[cfg(feature = "mqtt")]
+    Mqtt {
+        client: rumqttc::AsyncClient,
+        qos: rumqttc::QoS,
+    },
+}
+
+/// Publishes change events to NATS or MQTT; see the [module docs](self).
+#[derive(Clone)]
+pub struct EventPublisher {
+    transport: Transport,
+    prefix: String,
+}
+
+impl EventPublisher {
+    /// Publishes over a connected NATS client, below `hetzner.dns`.
+    #[cfg(feature = "nats")]
+    pub fn nats(client: async_nats::Client) -> Self {
+        Self {
+            transport: Transport::Nats(client),
+            prefix: "hetzner.dns".to_string(),
+        }
+    }
+
+    /// Publishes over an MQTT client with `qos`, below `hetzner/dns`. The
+    /// client's event loop must be polled elsewhere for messages to go out.
+    #[cfg(feature = "mqtt")]
+    pub fn mqtt(client: rumqttc::AsyncClient, qos: rumqttc::QoS) -> Self {
+        Self {
+            transport: Transport::Mqtt { client, qos },
+            prefix: "hetzner/dns".to_string(),
+        }
+    }
+
+    /// The topic prefix, without a trailing separator.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    fn separator(&self) -> &'static str {
+        match self.transport {
+            #[cfg(feature = "nats")]
+            Transport::Nats(_) => ".",
+            #[cfg(feature = "mqtt")]
+            Transport::Mqtt { .. } => "/",
+        }
+    }
+
+    fn topic(&self, parts: [&str; 3]) -> String {
+        let separator = self.separator();
+        let mut topic = self.prefix.clone();
+        for part in parts {
+            topic.push_str(separator);
+            topic.push_str(&topic_token(part));
+        }
+        topic
+    }
+
+    /// The topic `event` is published to.
+    pub fn change_topic(&self, event: &ChangeEvent) -> String {
+        self.topic([&event.zone_id, "change", event.kind.as_str()])
+    }
+
+    /// The topic `event` is published to.
+    pub fn mutation_topic(&self, event: &MutationEvent) -> String {
+        let zone_id = event.zone_id.as_deref().unwrap_or("_");
+        self.topic([zone_id, "mutation", event.operation.as_str()])
+    }
+
+    async fn publish(&self, topic: String, payload: Vec<u8>) -> Result<()> {
+        debug!(topic, "publishing event");
+        match &self.transport {
+            #[cfg(feature = "nats")]
+            Transport::Nats(client) => client
+                .publish(topic, payload.into())
+                .await
+                .map_err(|err| HetznerError::Publish(err.to_string())),
+            #[cfg(feature = "mqtt")]
+            Transport::Mqtt { client, qos } => client
+                .publish(topic, *qos, false, payload)
+                .await
+                .map_err(|err| HetznerError::Publish(err.to_string())),
+        }
+    }
+
+    pub async fn publish_change(&self, event: &ChangeEvent) -> Result<()> {
+        let payload = serde_json::to_vec(event)?;
+        self.publish(self.change_topic(event), payload).await
+    }
+
+    pub async fn publish_mutation(&self, event: &MutationEvent) -> Result<()> {
+        let payload = serde_json::to_vec(event)?;
+        self.publish(self.mutation_topic(event), payload).await
+    }
+
+    /// A callback for
+    /// [`HetznerClient::with_on_mutation`](crate::HetznerClient::with_on_mutation)
+    /// that publishes each write from a background task. Failures are logged.
+    pub fn mutation_hook(&self) -> impl Fn(&MutationEvent) + Send + Sync + 'static {
+        let publisher = self.clone();
+        move |event| {
+            let publisher = publisher.clone();
+            let event = event.clone();
+            tokio::spawn(async move {
+                #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+                if let Err(err) = publisher.publish_mutation(&event).await {
+                    warn!(error = %err, "dropping mutation event");
+                }
+            });
+        }
+    }
+
+    /// Publishes every event of a [`watch_zone`](crate::api::dns::DnsApi::watch_zone)
+    /// stream in order until it ends. Poll errors and failed publishes are
+    /// logged and skipped.
+    pub async fn forward(&self, changes: impl Stream<Item = Result<ChangeEvent>>) {
+        let mut changes = std::pin::pin!(changes);
+        while let Some(change) = changes.next().await {
+            let result = match change {
+                Ok(change) => self.publish_change(&change).await,
+                Err(err) => Err(err),
+            };
+            #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+            if let Err(err) = result {
+                warn!(error = %err, "dropping change event");
+            }
+        }
+    }
+}
+
+/// `part` as a single topic token for both NATS and MQTT.
+fn topic_token(part: &str) -> String {
+    if part.is_empty() {
+        return "_".to_string();
+    }
+    part.chars()
+        .map(|c| match c {
+            '.' | '/' | '+' | '#' | '*' | '>' => '_',
+            c if c.is_whitespace() => '_',
+            c => c,
+        })
+        .collect()
+}
+
+impl fmt::Debug for EventPublisher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let transport = match self.transport {
+            #[cfg(feature = "nats")]
+            Transport::Nats(_) => "nats",
+            #[cfg(feature = "mqtt")]
+            Transport::Mqtt { .. } => "mqtt",
+        };
+        f.debug_struct("EventPublisher")
+            .field("transport", &transport)
+            .field("prefix", &self.prefix)
+            .finish()
+    }
+}
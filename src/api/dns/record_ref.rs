@@ -0,0 +1,156 @@
+//! Addressing records by what they are rather than by their Hetzner id.
+
+use crate::HetznerClient;
+use crate::api::dns::records::{Ttl, UpdateRecordInput};
+use crate::api::dns::zone_ref::ZoneRef;
+use crate::error::{HetznerError, Result};
+use crate::types::{Record, RecordEnvelope, RecordKey};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A record identified by zone, name and type, and optionally its value when
+/// the name holds several records of the type (e.g. round-robin A records).
+/// Names and values are compared like [`RecordKey`]s, so `WWW.` matches `www`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct RecordRef {
+    pub zone: ZoneRef,
+    /// Relative to the zone, `@` for the apex.
+    pub name: String,
+    #[serde(rename = "type")]
+    pub record_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+impl RecordRef {
+    pub fn new(
+        zone: impl Into<ZoneRef>,
+        name: impl Into<String>,
+        record_type: impl Into<String>,
+    ) -> Self {
+        Self {
+            zone: zone.into(),
+            name: name.into(),
+            record_type: record_type.into(),
+            value: None,
+        }
+    }
+
+    /// Only matches the record with this value.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Whether `record` is (one of) the records this refers to. The zone is
+    /// not compared.
+    pub fn matches(&self, record: &Record) -> bool {
+        match &self.value {
+            Some(value) => record.key() == RecordKey::new(&self.name, &self.record_type, value),
+            None => has_name_and_type(record, &self.name, &self.record_type),
+        }
+    }
+}
+
+/// Whether `record` is named `name` and of `record_type`, ignoring case and
+/// a trailing dot; `""` and `@` both mean the apex.
+pub(crate) fn has_name_and_type(record: &Record, name: &str, record_type: &str) -> bool {
+    let wanted = RecordKey::new(name, record_type, "");
+    let key = RecordKey::new(&record.name, &record.record_type, "");
+    key == wanted
+}
+
+impl fmt::Display for RecordRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} in {}", self.name, self.record_type, self.zone)?;
+        if let Some(value) = &self.value {
+            write!(f, " with value {value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads and writes the record a [`RecordRef`] names; see
+/// [`DnsApi::record_ref`](crate::api::dns::DnsApi::record_ref). Every call
+/// lists the zone's records to find it.
+#[derive(Debug, Clone, Copy)]
+pub struct RecordRefApi<'a> {
+    pub(crate) client: &'a HetznerClient,
+    pub(crate) record: &'a RecordRef,
+}
+
+impl<'a> RecordRefApi<'a> {
+    /// The record, or [`HetznerError::RecordNotFound`] if there is none and
+    /// [`HetznerError::AmbiguousRecord`] if several match.
+    pub async fn get(self) -> Result<Record> {
+        self.get_opt()
+            .await?
+            .ok_or_else(|| HetznerError::RecordNotFound(self.record.to_string()))
+    }
+
+    /// Like [`get`](Self::get), but a missing record is `Ok(None)`.
+    pub async fn get_opt(self) -> Result<Option<Record>> {
+        let mut matches = self
+            .client
+            .dns()
+            .records(&self.record.zone)
+            .list()
+            .await?
+            .into_iter()
+            .filter(|record| self.record.matches(record));
+        let first = matches.next();
+        let more = matches.count();
+        if more > 0 {
+            return Err(HetznerError::AmbiguousRecord {
+                record: self.record.to_string(),
+                matches: more + 1,
+            });
+        }
+        Ok(first)
+    }
+
+    /// Replaces the record's value and TTL, keeping its name and type.
+    pub async fn update(
+        self,
+        value: impl Into<String>,
+        ttl: impl Into<Option<Ttl>>,
+    ) -> Result<RecordEnvelope> {
+        let value = value.into();
+        let ttl = ttl.into();
+        self.modify(|input| {
+            input.value = value;
+            input.ttl = ttl;
+        })
+        .await
+    }
+
+    pub async fn update_value(self, value: impl Into<String>) -> Result<RecordEnvelope> {
+        let value = value.into();
+        self.modify(|input| input.value = value).await
+    }
+
+    pub async fn update_ttl(self, ttl: impl Into<Option<Ttl>>) -> Result<RecordEnvelope> {
+        let ttl = ttl.into();
+        self.modify(|input| input.ttl = ttl).await
+    }
+
+    pub async fn delete(self) -> Result<()> {
+        let client = self.client.correlated();
+        let current = client.dns().record_ref(self.record).get().await?;
+        client.dns().record(&current.id).delete().await
+    }
+
+    async fn modify(self, change: impl FnOnce(&mut UpdateRecordInput)) -> Result<RecordEnvelope> {
+        let client = self.client.correlated();
+        let current = client.dns().record_ref(self.record).get().await?;
+        let mut input = UpdateRecordInput {
+            zone_id: current.zone_id,
+            record_type: current.record_type,
+            name: current.name,
+            value: current.value,
+            ttl: current.ttl,
+        };
+        change(&mut input);
+        client.dns().record(&current.id).update(input).await
+    }
+}
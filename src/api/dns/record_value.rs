@@ -0,0 +1,308 @@
+//! Record values parsed into their fields, such as an MX record's priority
+//! or an SRV record's port, for tools that analyse zones.
+//!
+//! ```no_run
+//! # async fn example(client: hetzner::HetznerClient) -> hetzner::Result<()> {
+//! use hetzner::RecordValue;
+//!
+//! let typed = client.dns().get_all_records_typed("example.com").await?;
+//! for record in &typed.records {
+//!     if let RecordValue::Mx { priority, exchange } = &record.value {
+//!         println!("{priority} {exchange}");
+//!     }
+//! }
+//! for issue in &typed.issues {
+//!     eprintln!("{}: {}", issue.record.name, issue.message);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::{HetznerError, Result};
+use crate::types::{Record, RecordType};
+use serde::{Deserialize, Serialize};
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+/// The value of a record, by type. Host names are kept as written, with or
+/// without a trailing dot.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "UPPERCASE")]
+pub enum RecordValue {
+    A {
+        address: Ipv4Addr,
+    },
+    Aaaa {
+        address: Ipv6Addr,
+    },
+    Cname {
+        target: String,
+    },
+    Ns {
+        target: String,
+    },
+    Ptr {
+        target: String,
+    },
+    Mx {
+        priority: u16,
+        exchange: String,
+    },
+    Srv {
+        priority: u16,
+        weight: u16,
+        port: u16,
+        target: String,
+    },
+    /// The character strings, unquoted and unescaped.
+    Txt {
+        strings: Vec<String>,
+    },
+    Caa {
+        flags: u8,
+        tag: String,
+        value: String,
+    },
+    Tlsa {
+        usage: u8,
+        selector: u8,
+        matching_type: u8,
+        data: String,
+    },
+    Ds {
+        key_tag: u16,
+        algorithm: u8,
+        digest_type: u8,
+        digest: String,
+    },
+    Soa {
+        mname: String,
+        rname: String,
+        serial: u32,
+        refresh: u32,
+        retry: u32,
+        expire: u32,
+        minimum: u32,
+    },
+    Hinfo {
+        cpu: String,
+        os: String,
+    },
+    Rp {
+        mailbox: String,
+        txt_domain: String,
+    },
+    /// A type this crate does not know, with the value as sent.
+    Other {
+        record_type: String,
+        value: String,
+    },
+}
+
+impl RecordValue {
+    /// Parses `value` as data of `record_type`, failing with
+    /// [`HetznerError::InvalidRecordValue`] when it doesn't conform.
+    pub fn parse(record_type: &RecordType, value: &str) -> Result<Self> {
+        parse(record_type, value).map_err(|reason| {
+            HetznerError::InvalidRecordValue(format!("{record_type} {value:?}: {reason}"))
+        })
+    }
+}
+
+/// A record with its parsed value.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TypedRecord {
+    pub record: Record,
+    pub value: RecordValue,
+}
+
+/// A record whose value does not conform to its type.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ParseIssue {
+    pub record: Record,
+    /// Why the value was rejected, e.g. `expected 2 fields, got 1`.
+    pub message: String,
+}
+
+/// Records split into those whose values parsed and those that did not.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TypedRecords {
+    pub records: Vec<TypedRecord>,
+    pub issues: Vec<ParseIssue>,
+}
+
+impl TypedRecords {
+    /// Parses every record's value, keeping the input order in both lists.
+    pub fn parse(records: Vec<Record>) -> Self {
+        let mut typed = Self::default();
+        for record in records {
+            match parse(&record.kind(), &record.value) {
+                Ok(value) => typed.records.push(TypedRecord { record, value }),
+                Err(message) => typed.issues.push(ParseIssue { record, message }),
+            }
+        }
+        typed
+    }
+}
+
+fn parse(record_type: &RecordType, value: &str) -> std::result::Result<RecordValue, String> {
+    let fields: Vec<&str> = value.split_whitespace().collect();
+    let expect = |count: usize| {
+        if fields.len() == count {
+            Ok(())
+        } else {
+            Err(format!("expected {count} fields, got {}", fields.len()))
+        }
+    };
+    let parsed = match record_type {
+        RecordType::A => {
+            expect(1)?;
+            RecordValue::A {
+                address: number("address", fields[0])?,
+            }
+        }
+        RecordType::Aaaa => {
+            expect(1)?;
+            RecordValue::Aaaa {
+                address: number("address", fields[0])?,
+            }
+        }
+        RecordType::Cname | RecordType::Ns | RecordType::Ptr => {
+            expect(1)?;
+            let target = fields[0].to_string();
+            match record_type {
+                RecordType::Cname => RecordValue::Cname { target },
+                RecordType::Ns => RecordValue::Ns { target },
+                _ => RecordValue::Ptr { target },
+            }
+        }
+        RecordType::Mx => {
+            expect(2)?;
+            RecordValue::Mx {
+                priority: number("priority", fields[0])?,
+                exchange: fields[1].to_string(),
+            }
+        }
+        RecordType::Srv => {
+            expect(4)?;
+            RecordValue::Srv {
+                priority: number("priority", fields[0])?,
+                weight: number("weight", fields[1])?,
+                port: number("port", fields[2])?,
+                target: fields[3].to_string(),
+            }
+        }
+        RecordType::Txt => RecordValue::Txt {
+            strings: character_strings(value),
+        },
+        RecordType::Caa => {
+            let mut parts = value.trim().splitn(3, char::is_whitespace);
+            let (Some(flags), Some(tag), Some(rest)) = (parts.next(), parts.next(), parts.next())
+            else {
+                return Err("expected flags, tag and value".to_string());
+            };
+            RecordValue::Caa {
+                flags: number("flags", flags)?,
+                tag: tag.to_string(),
+                value: character_strings(rest).concat(),
+            }
+        }
+        RecordType::Tlsa => {
+            expect(4)?;
+            RecordValue::Tlsa {
+                usage: number("usage", fields[0])?,
+                selector: number("selector", fields[1])?,
+                matching_type: number("matching type", fields[2])?,
+                data: hex("data", fields[3])?,
+            }
+        }
+        RecordType::Ds => {
+            expect(4)?;
+            RecordValue::Ds {
+                key_tag: number("key tag", fields[0])?,
+                algorithm: number("algorithm", fields[1])?,
+                digest_type: number("digest type", fields[2])?,
+                digest: hex("digest", fields[3])?,
+            }
+        }
+        RecordType::Soa => {
+            expect(7)?;
+            RecordValue::Soa {
+                mname: fields[0].to_string(),
+                rname: fields[1].to_string(),
+                serial: number("serial", fields[2])?,
+                refresh: number("refresh", fields[3])?,
+                retry: number("retry", fields[4])?,
+                expire: number("expire", fields[5])?,
+                minimum: number("minimum", fields[6])?,
+            }
+        }
+        RecordType::Hinfo => match character_strings_or_fields(value).as_slice() {
+            [cpu, os] => RecordValue::Hinfo {
+                cpu: cpu.clone(),
+                os: os.clone(),
+            },
+            strings => return Err(format!("expected 2 strings, got {}", strings.len())),
+        },
+        RecordType::Rp => {
+            expect(2)?;
+            RecordValue::Rp {
+                mailbox: fields[0].to_string(),
+                txt_domain: fields[1].to_string(),
+            }
+        }
+        RecordType::Other(record_type) => RecordValue::Other {
+            record_type: record_type.clone(),
+            value: value.to_string(),
+        },
+    };
+    Ok(parsed)
+}
+
+fn number<T: FromStr>(field: &str, value: &str) -> std::result::Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{field} {value:?} is out of range or not a number"))
+}
+
+fn hex(field: &str, value: &str) -> std::result::Result<String, String> {
+    if value.len().is_multiple_of(2) && value.chars().all(|c| c.is_ascii_hexdigit()) {
+        Ok(value.to_string())
+    } else {
+        Err(format!("{field} {value:?} is not hexadecimal"))
+    }
+}
+
+fn character_strings_or_fields(value: &str) -> Vec<String> {
+    if value.trim_start().starts_with('"') {
+        character_strings(value)
+    } else {
+        value.split_whitespace().map(str::to_string).collect()
+    }
+}
+
+/// The character strings of record data: each quoted string with its
+/// escapes resolved, or the whole value when it is not quoted.
+pub(crate) fn character_strings(value: &str) -> Vec<String> {
+    let value = value.trim();
+    if !value.starts_with('"') {
+        return vec![value.to_string()];
+    }
+    let mut strings = Vec::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '"' {
+            continue;
+        }
+        let mut current = String::new();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => current.extend(chars.next()),
+                '"' => break,
+                c => current.push(c),
+            }
+        }
+        strings.push(current);
+    }
+    strings
+}
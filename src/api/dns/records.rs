@@ -1,34 +1,324 @@
 use crate::HetznerClient;
-use crate::error::Result;
-use crate::types::{CreatedRecord, RecordEnvelope, RecordsEnvelope};
+use crate::api::dns::filter::RecordFilter;
+use crate::api::dns::lease::{self, Lease};
+use crate::api::dns::names::RecordName;
+use crate::api::dns::page::Page;
+use crate::api::dns::provider::DnsRecord;
+use crate::api::dns::record_ref::has_name_and_type;
+use crate::api::dns::record_value::TypedRecords;
+use crate::api::dns::sort::Sort;
+use crate::api::dns::zone_ref::ZoneRef;
+use crate::error::{ApiErrorCode, HetznerError, Result, not_found_as_none};
+use crate::mutation::{Mutation, MutationOperation};
+use crate::policy::ProposedChange;
+use crate::types::{CreatedRecord, Record, RecordEnvelope, RecordKey, RecordsEnvelope};
+use futures_util::{Stream, TryStreamExt, stream};
 use reqwest::Method;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::time::Instant;
 
-#[derive(Debug, Clone, Serialize)]
+/// Records per request when [streaming](RecordsApi::stream).
+pub(crate) const STREAM_PAGE_SIZE: u32 = 100;
+
+/// A record or zone TTL in seconds, within Hetzner's limits.
+///
+/// A `Ttl` can only be built through [`new`](Self::new), the constants or
+/// the fallible conversions, all of which reject TTLs outside
+/// [`MIN_SECONDS`](Self::MIN_SECONDS) to [`MAX_SECONDS`](Self::MAX_SECONDS),
+/// so every `Ttl` is one Hetzner accepts. Where a TTL is optional, `None`
+/// inherits the zone's default. Serialized as the number of seconds.
+/// Configuration and command-line input is parsed with [`str::parse`], which
+/// accepts seconds (`300`) or durations (`5m`, `1h 30m`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(try_from = "u64", into = "u32")]
+pub struct Ttl(u32);
+
+impl Ttl {
+    pub const MINUTE: Self = Self(60);
+    pub const FIVE_MINUTES: Self = Self(300);
+    pub const HOUR: Self = Self(3600);
+    pub const DAY: Self = Self(86400);
+
+    /// The lowest TTL Hetzner accepts.
+    pub const MIN_SECONDS: u32 = 60;
+    /// The highest TTL allowed by RFC 2181, 2^31 - 1 seconds.
+    pub const MAX_SECONDS: u32 = 2_147_483_647;
+
+    /// A TTL of `seconds`, or [`HetznerError::InvalidTtl`] if Hetzner would
+    /// reject it.
+    pub fn new(seconds: u32) -> Result<Self> {
+        Self::validate(u64::from(seconds))
+    }
+
+    pub const fn seconds(self) -> u32 {
+        self.0
+    }
+
+    /// `seconds` brought into Hetzner's limits: the nearest TTL Hetzner
+    /// accepts.
+    pub(crate) fn saturating(seconds: u64) -> Self {
+        let seconds = seconds.clamp(Self::MIN_SECONDS.into(), Self::MAX_SECONDS.into());
+        Self(seconds as u32)
+    }
+
+    /// `seconds` as a TTL, or [`HetznerError::InvalidTtl`] if it is outside
+    /// Hetzner's limits.
+    pub fn validate(seconds: u64) -> Result<Self> {
+        match u32::try_from(seconds) {
+            Ok(seconds) if (Self::MIN_SECONDS..=Self::MAX_SECONDS).contains(&seconds) => {
+                Ok(Self(seconds))
+            }
+            _ => Err(HetznerError::InvalidTtl(format!(
+                "{seconds}s is outside {}s to {}s",
+                Self::MIN_SECONDS,
+                Self::MAX_SECONDS
+            ))),
+        }
+    }
+}
+
+impl std::str::FromStr for Ttl {
+    type Err = HetznerError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let value = value.trim();
+        let seconds = match value.parse::<u64>() {
+            Ok(seconds) => seconds,
+            Err(_) => humantime::parse_duration(value)
+                .map_err(|err| HetznerError::InvalidTtl(format!("{value:?}: {err}")))?
+                .as_secs(),
+        };
+        Self::validate(seconds)
+    }
+}
+
+impl TryFrom<u32> for Ttl {
+    type Error = HetznerError;
+
+    fn try_from(value: u32) -> Result<Self> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<u64> for Ttl {
+    type Error = HetznerError;
+
+    fn try_from(value: u64) -> Result<Self> {
+        Self::validate(value)
+    }
+}
+
+impl TryFrom<std::time::Duration> for Ttl {
+    type Error = HetznerError;
+
+    fn try_from(value: std::time::Duration) -> Result<Self> {
+        Self::validate(value.as_secs())
+    }
+}
+
+/// Shown as a duration such as `5m`, `1h 30m` or `1day`.
+impl std::fmt::Display for Ttl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            humantime::format_duration(std::time::Duration::from_secs(self.0.into()))
+        )
+    }
+}
+
+impl From<Ttl> for u32 {
+    fn from(value: Ttl) -> Self {
+        value.0
+    }
+}
+
+impl From<Ttl> for u64 {
+    fn from(value: Ttl) -> Self {
+        value.0.into()
+    }
+}
+
+/// A record to create. Build it with struct update syntax
+/// (`..Default::default()`) or the setters, so fields added later don't
+/// break callers:
+///
+/// ```
+/// use hetzner::api::dns::records::CreateRecordInput;
+///
+/// let input = CreateRecordInput::default()
+///     .zone_id("zone-1")
+///     .name("www")
+///     .record_type("A")
+///     .value("192.0.2.1")
+///     .ttl(hetzner::Ttl::FIVE_MINUTES);
+/// assert_eq!(input.ttl, Some(hetzner::Ttl::FIVE_MINUTES));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
 pub struct CreateRecordInput {
     pub value: String,
-    pub ttl: u64,
+    /// `None` leaves the TTL unset so the record inherits the zone default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<Ttl>,
     #[serde(rename = "type")]
     pub record_type: String,
     pub name: String,
     pub zone_id: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
+/// The new state of a record; the API replaces every field. To change only
+/// some, start from the current record:
+///
+/// ```
+/// # fn example(record: &hetzner::Record) {
+/// use hetzner::api::dns::records::UpdateRecordInput;
+///
+/// let input = UpdateRecordInput::from(record).ttl(hetzner::Ttl::MINUTE);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
 pub struct UpdateRecordInput {
     pub zone_id: String,
     #[serde(rename = "type")]
     pub record_type: String,
     pub name: String,
     pub value: String,
-    pub ttl: u64,
+    /// `None` leaves the TTL unset so the record inherits the zone default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<Ttl>,
+}
+
+impl CreateRecordInput {
+    pub fn zone_id(mut self, zone_id: impl Into<String>) -> Self {
+        self.zone_id = zone_id.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn record_type(mut self, record_type: impl AsRef<str>) -> Self {
+        self.record_type = record_type.as_ref().to_string();
+        self
+    }
+
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// A [`Ttl`], or `None` to inherit the zone's TTL.
+    pub fn ttl(mut self, ttl: impl Into<Option<Ttl>>) -> Self {
+        self.ttl = ttl.into();
+        self
+    }
+
+    pub(crate) fn proposed(&self) -> DnsRecord {
+        DnsRecord {
+            name: self.name.clone(),
+            record_type: self.record_type.clone(),
+            value: self.value.clone(),
+            ttl: self.ttl,
+        }
+    }
+}
+
+impl UpdateRecordInput {
+    pub fn zone_id(mut self, zone_id: impl Into<String>) -> Self {
+        self.zone_id = zone_id.into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn record_type(mut self, record_type: impl AsRef<str>) -> Self {
+        self.record_type = record_type.as_ref().to_string();
+        self
+    }
+
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// A [`Ttl`], or `None` to inherit the zone's TTL.
+    pub fn ttl(mut self, ttl: impl Into<Option<Ttl>>) -> Self {
+        self.ttl = ttl.into();
+        self
+    }
+
+    pub(crate) fn proposed(&self) -> DnsRecord {
+        DnsRecord {
+            name: self.name.clone(),
+            record_type: self.record_type.clone(),
+            value: self.value.clone(),
+            ttl: self.ttl,
+        }
+    }
+}
+
+/// Leaves `record` as it is.
+impl From<&Record> for UpdateRecordInput {
+    fn from(record: &Record) -> Self {
+        Self {
+            zone_id: record.zone_id.clone(),
+            record_type: record.record_type.clone(),
+            name: record.name.clone(),
+            value: record.value.clone(),
+            ttl: record.ttl,
+        }
+    }
+}
+
+/// The body of a single create, borrowing from the caller's arguments.
+#[derive(Debug, Clone, Copy, Serialize)]
+struct CreateRecordBody<'a> {
+    value: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ttl: Option<Ttl>,
+    #[serde(rename = "type")]
+    record_type: &'a str,
+    name: &'a str,
+    zone_id: &'a str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BulkUpdateRecordInput {
+    pub id: String,
+    #[serde(flatten)]
+    pub record: UpdateRecordInput,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BulkCreateRecordsResponse {
+    #[serde(default)]
+    pub records: Vec<Record>,
+    /// Inputs the API rejected; none of them were created.
+    #[serde(default)]
+    pub invalid_records: Vec<CreateRecordInput>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BulkUpdateRecordsResponse {
+    #[serde(default)]
+    pub records: Vec<Record>,
+    /// Inputs the API could not apply.
+    #[serde(default)]
+    pub failed_records: Vec<BulkUpdateRecordInput>,
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct RecordsApi<'a> {
     pub(crate) client: &'a HetznerClient,
-    pub(crate) zone_id: &'a str,
+    pub(crate) zone: ZoneRef<&'a str>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -39,49 +329,675 @@ pub struct RecordApi<'a> {
 
 impl<'a> RecordsApi<'a> {
     pub async fn list(self) -> Result<Vec<crate::types::Record>> {
-        let path = format!("records?zone_id={}", self.zone_id);
+        let zone_id = self.client.zone_id(self.zone).await?;
+        let path = format!("records?zone_id={zone_id}");
         let response: RecordsEnvelope = self.client.request_dns(Method::GET, &path, None).await?;
         Ok(response.records)
     }
 
+    /// Like [`list`](Self::list), but served from the client's
+    /// [read cache](crate::HetznerClient::with_read_cache) when it has one.
+    pub async fn list_cached(self) -> Result<Vec<crate::types::Record>> {
+        let zone_id = self.client.zone_id(self.zone).await?.into_owned();
+        self.client
+            .cached_read(
+                |cache| &cache.records,
+                zone_id.clone(),
+                |client| async move { client.dns().records(zone_id.as_str()).list().await },
+            )
+            .await
+    }
+
+    /// Lists the zone's records with their values parsed; records whose
+    /// values don't conform to their type are returned as
+    /// [`ParseIssue`](crate::ParseIssue)s instead.
+    pub async fn list_typed(self) -> Result<TypedRecords> {
+        Ok(TypedRecords::parse(self.list().await?))
+    }
+
+    /// Lists the zone's records and keeps those matching `filter`.
+    pub async fn list_filtered(self, filter: &RecordFilter) -> Result<Vec<crate::types::Record>> {
+        Ok(filter.apply(self.list().await?))
+    }
+
+    /// The record named `name` (relative, `@` for the apex) of
+    /// `record_type`, compared without regard to case or a trailing dot. If
+    /// the name holds several records of the type, the first is returned;
+    /// see [`RecordRef`](crate::api::dns::record_ref::RecordRef) to pick one
+    /// by value.
+    pub async fn get_by_name(
+        self,
+        name: &str,
+        record_type: impl AsRef<str>,
+    ) -> Result<Option<crate::types::Record>> {
+        let record_type = record_type.as_ref();
+        Ok(self
+            .list()
+            .await?
+            .into_iter()
+            .find(|record| has_name_and_type(record, name, record_type)))
+    }
+
+    pub async fn list_sorted(self, sort: Sort) -> Result<Vec<crate::types::Record>> {
+        let mut records = self.list().await?;
+        sort.records(&mut records);
+        Ok(records)
+    }
+
+    pub async fn page(self, page: u32, per_page: u32) -> Result<Page<crate::types::Record>> {
+        let zone_id = self.client.zone_id(self.zone).await?;
+        let query = vec![("zone_id".to_string(), zone_id.into_owned())];
+        Page::fetch(self.client, "records", "records", query, page, per_page).await
+    }
+
+    /// The zone's records, fetched page by page as the stream is polled, for
+    /// use with [`TryStreamExt`](futures_util::TryStreamExt) combinators.
+    pub fn stream(self) -> impl Stream<Item = Result<crate::types::Record>> + 'a {
+        stream::once(self.page(1, STREAM_PAGE_SIZE))
+            .map_ok(|page| page.into_stream(self.client))
+            .try_flatten()
+    }
+
+    /// Accepts borrowed or owned strings; borrowed ones are sent without
+    /// being copied first.
     pub async fn create(
         self,
-        name: impl Into<String>,
-        record_type: impl Into<String>,
-        value: impl Into<String>,
-        ttl: u64,
+        name: impl Into<Cow<'_, str>>,
+        record_type: impl Into<Cow<'_, str>>,
+        value: impl Into<Cow<'_, str>>,
+        ttl: impl Into<Option<Ttl>>,
     ) -> Result<CreatedRecord> {
-        let payload = CreateRecordInput {
-            value: value.into(),
+        let ttl = ttl.into();
+        let client = self.client.correlated();
+        let zone_id = client.zone_id(self.zone).await?;
+        let (name, record_type, value) = (name.into(), record_type.into(), value.into());
+        let payload = CreateRecordBody {
+            value: &value,
             ttl,
-            record_type: record_type.into(),
-            name: name.into(),
-            zone_id: self.zone_id.to_string(),
+            record_type: &record_type,
+            name: &name,
+            zone_id: &zone_id,
         };
+        client
+            .enforce_policy(|| {
+                vec![ProposedChange::new(
+                    MutationOperation::CreateRecord,
+                    Some(zone_id.to_string()),
+                    Some(DnsRecord {
+                        name: name.to_string(),
+                        record_type: record_type.to_string(),
+                        value: value.to_string(),
+                        ttl: payload.ttl,
+                    }),
+                    None,
+                )]
+            })
+            .await?;
 
-        self.client
-            .request_dns(Method::POST, "records", Some(json!(payload)))
-            .await
+        let started = Instant::now();
+
+        let result = client
+            .request_dns(
+                Method::POST,
+                "records",
+                Some(serde_json::to_value(payload)?),
+            )
+            .await;
+        client.emit_mutation(
+            Mutation {
+                started,
+                operation: MutationOperation::CreateRecord,
+                zone_id: Some(zone_id.into_owned()),
+                resource_id: None,
+                before: None,
+            },
+            &result,
+            |created: &CreatedRecord| Some(created.record.clone()),
+        );
+        result
+    }
+
+    /// Like [`create`](Self::create), but first looks for a record with the
+    /// same name, type and value (compared as in [`Record::key`]) and
+    /// returns it instead of creating a duplicate, whatever its TTL. Retried
+    /// jobs and at-least-once queues can call this safely. Should a
+    /// concurrent writer create the record in between and the API reject
+    /// the create as taken, the zone is read again and that record returned.
+    pub async fn create_idempotent(
+        self,
+        name: impl Into<Cow<'_, str>>,
+        record_type: impl Into<Cow<'_, str>>,
+        value: impl Into<Cow<'_, str>>,
+        ttl: impl Into<Option<Ttl>>,
+    ) -> Result<CreatedRecord> {
+        let ttl = ttl.into();
+        let client = self.client.correlated();
+        let records = client.dns().records(self.zone);
+        let (name, record_type, value) = (name.into(), record_type.into(), value.into());
+        let key = RecordKey::new(&name, &record_type, &value);
+        let find = |existing: Vec<Record>| {
+            existing
+                .into_iter()
+                .find(|record| record.key() == key)
+                .map(|record| CreatedRecord { record })
+        };
+
+        if let Some(existing) = find(records.list().await?) {
+            return Ok(existing);
+        }
+        match records.create(&*name, &*record_type, &*value, ttl).await {
+            Err(err) if is_taken(&err) => match find(records.list().await?) {
+                Some(existing) => Ok(existing),
+                None => Err(err),
+            },
+            result => result,
+        }
+    }
+
+    /// Creates all `records` in one request. Records the API rejects are
+    /// returned in `invalid_records`; the others are still created. Takes a
+    /// `Vec` or a borrowed slice.
+    pub async fn bulk_create(
+        self,
+        records: impl Into<Cow<'_, [CreateRecordInput]>>,
+    ) -> Result<BulkCreateRecordsResponse> {
+        let records = records.into();
+        let client = self.client.correlated();
+        let zone_id = client.zone_id(self.zone).await?;
+        client
+            .enforce_policy(|| {
+                records
+                    .iter()
+                    .map(|input| {
+                        ProposedChange::new(
+                            MutationOperation::CreateRecord,
+                            Some(zone_id.to_string()),
+                            Some(input.proposed()),
+                            None,
+                        )
+                    })
+                    .collect()
+            })
+            .await?;
+        let started = Instant::now();
+        let result: Result<BulkCreateRecordsResponse> = client
+            .request_dns(
+                Method::POST,
+                "records/bulk",
+                Some(json!({ "records": records })),
+            )
+            .await;
+        match &result {
+            Ok(response) => {
+                for record in &response.records {
+                    client.emit_mutation(
+                        bulk_mutation(
+                            &zone_id,
+                            started,
+                            MutationOperation::CreateRecord,
+                            None,
+                            None,
+                        ),
+                        &Ok(record.clone()),
+                        |record: &Record| Some(record.clone()),
+                    );
+                }
+                for _ in &response.invalid_records {
+                    client.emit_mutation(
+                        bulk_mutation(
+                            &zone_id,
+                            started,
+                            MutationOperation::CreateRecord,
+                            None,
+                            None,
+                        ),
+                        &bulk_rejection(),
+                        |_| None,
+                    );
+                }
+            }
+            Err(_) => {
+                for _ in records.iter() {
+                    client.emit_mutation(
+                        bulk_mutation(
+                            &zone_id,
+                            started,
+                            MutationOperation::CreateRecord,
+                            None,
+                            None,
+                        ),
+                        &result,
+                        |_| None,
+                    );
+                }
+            }
+        }
+        result
+    }
+
+    /// Updates all `records` in one request. Records that could not be
+    /// updated are returned in `failed_records`. Takes a `Vec` or a borrowed
+    /// slice.
+    pub async fn bulk_update(
+        self,
+        records: impl Into<Cow<'_, [BulkUpdateRecordInput]>>,
+    ) -> Result<BulkUpdateRecordsResponse> {
+        let records = records.into();
+        let client = self.client.correlated();
+        let zone_id = client.zone_id(self.zone).await?;
+        let mut before: BTreeMap<String, Record> = if client.needs_prior_state() {
+            // Hooks make do without the prior state; a policy needs it.
+            match self.list().await {
+                Ok(records) => records
+                    .into_iter()
+                    .map(|record| (record.id.clone(), record))
+                    .collect(),
+                Err(err) if client.policy.is_some() => return Err(err),
+                Err(_) => BTreeMap::new(),
+            }
+        } else {
+            BTreeMap::new()
+        };
+        client
+            .enforce_policy(|| {
+                records
+                    .iter()
+                    .map(|input| {
+                        ProposedChange::new(
+                            MutationOperation::UpdateRecord,
+                            Some(zone_id.to_string()),
+                            Some(input.record.proposed()),
+                            before.get(&input.id).cloned(),
+                        )
+                    })
+                    .collect()
+            })
+            .await?;
+        let started = Instant::now();
+        let result: Result<BulkUpdateRecordsResponse> = client
+            .request_dns(
+                Method::PUT,
+                "records/bulk",
+                Some(json!({ "records": records })),
+            )
+            .await;
+        match &result {
+            Ok(response) => {
+                for record in &response.records {
+                    client.emit_mutation(
+                        bulk_mutation(
+                            &zone_id,
+                            started,
+                            MutationOperation::UpdateRecord,
+                            Some(record.id.clone()),
+                            before.remove(&record.id),
+                        ),
+                        &Ok(record.clone()),
+                        |record: &Record| Some(record.clone()),
+                    );
+                }
+                for failed in &response.failed_records {
+                    client.emit_mutation(
+                        bulk_mutation(
+                            &zone_id,
+                            started,
+                            MutationOperation::UpdateRecord,
+                            Some(failed.id.clone()),
+                            before.remove(&failed.id),
+                        ),
+                        &bulk_rejection(),
+                        |_| None,
+                    );
+                }
+            }
+            Err(_) => {
+                for input in records.iter() {
+                    client.emit_mutation(
+                        bulk_mutation(
+                            &zone_id,
+                            started,
+                            MutationOperation::UpdateRecord,
+                            Some(input.id.clone()),
+                            before.remove(&input.id),
+                        ),
+                        &result,
+                        |_| None,
+                    );
+                }
+            }
+        }
+        result
+    }
+
+    /// Creates a record that [`cleanup_expired`](Self::cleanup_expired)
+    /// deletes once `lease` has passed. The lease is tracked by a TXT record
+    /// named [`LEASE_REGISTRY_NAME`](crate::api::dns::lease::LEASE_REGISTRY_NAME)
+    /// in the same zone; if that cannot be created, the record is deleted
+    /// again.
+    pub async fn create_leased(
+        self,
+        name: impl Into<String>,
+        record_type: impl Into<String>,
+        value: impl Into<String>,
+        ttl: impl Into<Option<Ttl>>,
+        lease: std::time::Duration,
+    ) -> Result<Lease> {
+        lease::create(
+            self.client,
+            self.zone,
+            name.into(),
+            record_type.into(),
+            value.into(),
+            ttl.into(),
+            lease,
+        )
+        .await
+    }
+
+    /// The zone's leased records, expired or not.
+    pub async fn leases(self) -> Result<Vec<Lease>> {
+        Ok(lease::list(self.client, self.zone).await?.0)
+    }
+
+    /// Deletes the records whose lease has expired, along with their
+    /// registry entries and entries of records deleted by other means, and
+    /// returns the expired leases.
+    pub async fn cleanup_expired(self) -> Result<Vec<Lease>> {
+        lease::cleanup_expired(self.client, self.zone).await
+    }
+
+    /// Exchanges the records of `record_type` at `name_a` and `name_b` in a
+    /// single bulk update that renames each record to the other name, so
+    /// both sets switch at once. For blue/green deployments keep the idle set
+    /// at its own name (e.g. `green.www`): `swap_record_sets("www",
+    /// "green.www", "A")` sends traffic to green, and the same call again
+    /// switches back.
+    ///
+    /// Names are relative, `@` for the apex. Fails with
+    /// [`HetznerError::RecordNotFound`] if neither name holds such records;
+    /// records the API could not rename are in `failed_records`.
+    pub async fn swap_record_sets(
+        self,
+        name_a: &str,
+        name_b: &str,
+        record_type: impl AsRef<str>,
+    ) -> Result<BulkUpdateRecordsResponse> {
+        let record_type = record_type.as_ref();
+        let renamed_a = RecordName::new(name_b)?;
+        let renamed_b = RecordName::new(name_a)?;
+        let client = self.client.correlated();
+        let zone_id = client.zone_id(self.zone).await?.into_owned();
+        let records = client.dns().records(&zone_id).list().await?;
+        let inputs: Vec<BulkUpdateRecordInput> = records
+            .into_iter()
+            .filter_map(|record| {
+                let name = if has_name_and_type(&record, name_a, record_type) {
+                    &renamed_a
+                } else if has_name_and_type(&record, name_b, record_type) {
+                    &renamed_b
+                } else {
+                    return None;
+                };
+                Some(BulkUpdateRecordInput {
+                    id: record.id,
+                    record: UpdateRecordInput {
+                        zone_id: zone_id.clone(),
+                        record_type: record.record_type,
+                        name: name.to_string(),
+                        value: record.value,
+                        ttl: record.ttl,
+                    },
+                })
+            })
+            .collect();
+        if inputs.is_empty() {
+            return Err(HetznerError::RecordNotFound(format!(
+                "{name_a} or {name_b} {record_type} in {}",
+                self.zone
+            )));
+        }
+        client.dns().records(&zone_id).bulk_update(inputs).await
     }
 }
 
 impl<'a> RecordApi<'a> {
-    pub async fn get(self) -> Result<RecordEnvelope> {
+    pub async fn get(self) -> Result<Record> {
         let path = format!("records/{}", self.record_id);
-        self.client.request_dns(Method::GET, &path, None).await
+        let envelope: RecordEnvelope = self.client.request_dns(Method::GET, &path, None).await?;
+        Ok(envelope.record)
+    }
+
+    /// The `record` object exactly as returned by the API, including fields
+    /// [`Record`] does not model. A response without one is
+    /// [`HetznerError::UnexpectedResponse`].
+    pub async fn get_raw(self) -> Result<serde_json::Value> {
+        let path = format!("records/{}", self.record_id);
+        let envelope: serde_json::Value = self.client.request_dns(Method::GET, &path, None).await?;
+        record_of(envelope)
+    }
+
+    /// Like [`get`](Self::get), but a missing record is `Ok(None)`.
+    pub async fn get_opt(self) -> Result<Option<Record>> {
+        not_found_as_none(self.get().await)
     }
 
     pub async fn update(self, input: UpdateRecordInput) -> Result<RecordEnvelope> {
+        let client = self.client.correlated();
         let path = format!("records/{}", self.record_id);
-        self.client
+        let before = client.state_before_mutation(self.record_id).await?;
+        let zone_id = input.zone_id.clone();
+        client
+            .enforce_policy(|| {
+                vec![ProposedChange::new(
+                    MutationOperation::UpdateRecord,
+                    Some(zone_id.clone()),
+                    Some(input.proposed()),
+                    before.clone(),
+                )]
+            })
+            .await?;
+        let started = Instant::now();
+        let result = client
             .request_dns(Method::PUT, &path, Some(json!(input)))
-            .await
+            .await;
+        client.emit_mutation(
+            Mutation {
+                started,
+                operation: MutationOperation::UpdateRecord,
+                zone_id: Some(zone_id),
+                resource_id: Some(self.record_id.to_string()),
+                before,
+            },
+            &result,
+            |updated: &RecordEnvelope| Some(updated.record.clone()),
+        );
+        result
+    }
+
+    /// Like [`update`](Self::update), but reads the record first and sends
+    /// nothing when `input` would not change it: names, types and values are
+    /// compared as in [`Record::key`], so differences such as a trailing dot
+    /// or the case of a hostname don't cause a write. `Ok(None)` when the
+    /// record was left alone.
+    pub async fn update_if_changed(
+        self,
+        input: UpdateRecordInput,
+    ) -> Result<Option<RecordEnvelope>> {
+        let client = self.client.correlated();
+        let record = client.dns().record(self.record_id);
+        let current = record.get().await?;
+        let unchanged = current.zone_id == input.zone_id
+            && current.ttl == input.ttl
+            && current.key() == RecordKey::new(&input.name, &input.record_type, &input.value);
+        if unchanged {
+            return Ok(None);
+        }
+        record.update(input).await.map(Some)
+    }
+
+    /// Changes only the TTL, keeping the record's other fields as they are
+    /// now. The API replaces records wholesale, so this reads the record first.
+    pub async fn update_ttl(self, ttl: impl Into<Option<Ttl>>) -> Result<RecordEnvelope> {
+        let ttl = ttl.into();
+        self.modify(|input| input.ttl = ttl).await
+    }
+
+    /// Changes only the value; see [`update_ttl`](Self::update_ttl).
+    pub async fn update_value(self, value: impl Into<String>) -> Result<RecordEnvelope> {
+        let value = value.into();
+        self.modify(|input| input.value = value).await
+    }
+
+    async fn modify(self, change: impl FnOnce(&mut UpdateRecordInput)) -> Result<RecordEnvelope> {
+        let client = self.client.correlated();
+        let record = client.dns().record(self.record_id);
+        let current = record.get().await?;
+        let mut input = UpdateRecordInput::from(&current);
+        change(&mut input);
+        record.update(input).await
     }
 
     pub async fn delete(self) -> Result<()> {
+        let client = self.client.correlated();
         let path = format!("records/{}", self.record_id);
-        self.client
-            .request_dns_unit(Method::DELETE, &path, None)
-            .await
+        let before = client.state_before_mutation(self.record_id).await?;
+        client
+            .enforce_policy(|| {
+                vec![ProposedChange::new(
+                    MutationOperation::DeleteRecord,
+                    None,
+                    None,
+                    before.clone(),
+                )]
+            })
+            .await?;
+        let started = Instant::now();
+        let result = client.request_dns_unit(Method::DELETE, &path, None).await;
+        client.emit_mutation(
+            Mutation {
+                started,
+                operation: MutationOperation::DeleteRecord,
+                zone_id: None,
+                resource_id: Some(self.record_id.to_string()),
+                before,
+            },
+            &result,
+            |_| None,
+        );
+        result
+    }
+}
+
+fn bulk_mutation(
+    zone_id: &str,
+    started: Instant,
+    operation: MutationOperation,
+    resource_id: Option<String>,
+    before: Option<Record>,
+) -> Mutation {
+    Mutation {
+        started,
+        operation,
+        zone_id: Some(zone_id.to_string()),
+        resource_id,
+        before,
+    }
+}
+
+/// The `record` object of a single-record envelope.
+pub(crate) fn record_of(mut envelope: serde_json::Value) -> Result<serde_json::Value> {
+    match envelope.get_mut("record").map(serde_json::Value::take) {
+        Some(record) if record.is_object() => Ok(record),
+        _ => Err(HetznerError::UnexpectedResponse(
+            "response has no record object",
+        )),
     }
 }
+
+/// The outcome reported for a record a bulk request did not apply.
+fn bulk_rejection() -> Result<()> {
+    Err(HetznerError::UnexpectedResponse(
+        "record rejected by bulk request",
+    ))
+}
+
+/// Makes `name` in the zone hold exactly the records of `record_type` with
+/// `values` and `ttl`, updating existing records in place where it can,
+/// creating the missing ones and deleting the rest. Returns the records the
+/// name held before.
+pub(crate) async fn replace_record_set(
+    client: &HetznerClient,
+    zone: ZoneRef<&str>,
+    name: &str,
+    record_type: &str,
+    values: &[String],
+    ttl: Option<Ttl>,
+) -> Result<Vec<Record>> {
+    let client = client.correlated();
+    let zone_id = client.zone_id(zone).await?.into_owned();
+    let before: Vec<Record> = client
+        .dns()
+        .records(&zone_id)
+        .list()
+        .await?
+        .into_iter()
+        .filter(|record| has_name_and_type(record, name, record_type))
+        .collect();
+
+    let mut spare: Vec<&Record> = Vec::new();
+    let mut missing: Vec<&String> = values.iter().collect();
+    for record in &before {
+        let key = record.key();
+        match missing
+            .iter()
+            .position(|value| RecordKey::new(name, record_type, value) == key)
+        {
+            Some(index) => {
+                missing.remove(index);
+                if record.ttl != ttl {
+                    client.dns().record(&record.id).update_ttl(ttl).await?;
+                }
+            }
+            None => spare.push(record),
+        }
+    }
+    let mut spare = spare.into_iter();
+    for value in missing {
+        match spare.next() {
+            Some(record) => {
+                client
+                    .dns()
+                    .record(&record.id)
+                    .update(UpdateRecordInput {
+                        zone_id: zone_id.clone(),
+                        record_type: record.record_type.clone(),
+                        name: record.name.clone(),
+                        value: value.clone(),
+                        ttl,
+                    })
+                    .await?;
+            }
+            None => {
+                client
+                    .dns()
+                    .records(&zone_id)
+                    .create(name, record_type, value.as_str(), ttl)
+                    .await?;
+            }
+        }
+    }
+    for record in spare {
+        client.dns().record(&record.id).delete().await?;
+    }
+    Ok(before)
+}
+
+/// Whether the API rejected a create because the record already exists.
+fn is_taken(err: &HetznerError) -> bool {
+    err.api_error().is_some_and(|err| {
+        matches!(
+            err.error_code(),
+            ApiErrorCode::TakenName | ApiErrorCode::UniquenessError
+        )
+    })
+}
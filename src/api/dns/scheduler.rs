@@ -0,0 +1,430 @@
+//! Record changes applied at a planned time (`scheduler` feature).
+//!
+//! A [`Scheduler`] keeps its changes in a JSON file, so a restarted daemon
+//! picks up where it left off. Other processes may open the same file to
+//! schedule or cancel changes; the file is read again before every update,
+//! but only one scheduler should [`run`](Scheduler::run) it:
+//!
+//! ```no_run
+//! # async fn example(client: hetzner::HetznerClient) -> hetzner::Result<()> {
+//! use hetzner::{HealthProbe, ScheduledChange, Scheduler, Ttl};
+//! use std::time::{Duration, SystemTime};
+//!
+//! let scheduler = Scheduler::open(client, "schedule.json")?;
+//! let at = SystemTime::now() + Duration::from_secs(3600);
+//! scheduler.schedule(
+//!     ScheduledChange::new("move-www", at, "example.com", "www", "A", ["203.0.113.7"])
+//!         .ttl(Ttl::MINUTE)
+//!         .health_check(HealthProbe::new("https://203.0.113.7/healthz")),
+//! )?;
+//! scheduler.run().await
+//! # }
+//! ```
+//!
+//! When a change is due, the name's records of the type are replaced by the
+//! new values. If the change has a [`HealthProbe`] and the probe fails, the
+//! previous records are put back.
+
+use crate::HetznerClient;
+use crate::api::dns::records::{Ttl, replace_record_set};
+use crate::api::dns::zone_ref::ZoneRef;
+use crate::error::{HetznerError, Result};
+use crate::logging::{Instrument, info, info_span, warn};
+use crate::types::Record;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How often [`Scheduler::run`] looks for due changes at most.
+const MAX_IDLE: Duration = Duration::from_secs(60);
+
+/// Makes `name` hold exactly the records of `record_type` with `values` at
+/// time `at`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScheduledChange {
+    pub id: String,
+    /// Seconds since the Unix epoch.
+    pub at: u64,
+    pub zone: ZoneRef,
+    /// Relative to the zone, `@` for the apex.
+    pub name: String,
+    #[serde(rename = "type")]
+    pub record_type: String,
+    pub values: Vec<String>,
+    /// `None` inherits the zone's TTL.
+    #[serde(default)]
+    pub ttl: Option<Ttl>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_check: Option<HealthProbe>,
+    #[serde(default)]
+    pub status: ScheduleStatus,
+    /// Seconds since the Unix epoch at which the change was executed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub executed_at: Option<u64>,
+    /// The records the name held before the change was applied.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub previous: Vec<Record>,
+}
+
+impl ScheduledChange {
+    pub fn new<V: Into<String>>(
+        id: impl Into<String>,
+        at: SystemTime,
+        zone: impl Into<ZoneRef>,
+        name: impl Into<String>,
+        record_type: impl Into<String>,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            at: unix_seconds(at),
+            zone: zone.into(),
+            name: name.into(),
+            record_type: record_type.into(),
+            values: values.into_iter().map(Into::into).collect(),
+            ttl: None,
+            health_check: None,
+            status: ScheduleStatus::Pending,
+            executed_at: None,
+            previous: Vec::new(),
+        }
+    }
+
+    pub fn ttl(mut self, ttl: impl Into<Option<Ttl>>) -> Self {
+        self.ttl = ttl.into();
+        self
+    }
+
+    /// Reverts the change if `probe` fails after it was applied.
+    pub fn health_check(mut self, probe: HealthProbe) -> Self {
+        self.health_check = Some(probe);
+        self
+    }
+
+    pub fn is_due(&self, now: SystemTime) -> bool {
+        self.status == ScheduleStatus::Pending && self.at <= unix_seconds(now)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduleStatus {
+    #[default]
+    Pending,
+    Applied,
+    /// Applied, then reverted because the health check failed.
+    Reverted {
+        reason: String,
+    },
+    Failed {
+        error: String,
+    },
+}
+
+/// An HTTP check run after a change is applied: it passes when `url`
+/// answers with a success status within `timeout`, tried up to `attempts`
+/// times `delay` apart (the first try also waits `delay`, to let resolvers
+/// and caches pick up the change).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct HealthProbe {
+    pub url: String,
+    #[serde(default = "HealthProbe::default_attempts")]
+    pub attempts: u32,
+    #[serde(default, with = "seconds")]
+    pub delay: Duration,
+    #[serde(default = "HealthProbe::default_timeout", with = "seconds")]
+    pub timeout: Duration,
+}
+
+impl HealthProbe {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            attempts: Self::default_attempts(),
+            delay: Duration::ZERO,
+            timeout: Self::default_timeout(),
+        }
+    }
+
+    pub fn attempts(mut self, attempts: u32) -> Self {
+        self.attempts = attempts.max(1);
+        self
+    }
+
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn default_attempts() -> u32 {
+        3
+    }
+
+    fn default_timeout() -> Duration {
+        Duration::from_secs(10)
+    }
+
+    /// `Ok(())` if any attempt succeeded, otherwise the last failure.
+    async fn check(&self, client: &HetznerClient) -> std::result::Result<(), String> {
+        let mut failure = String::new();
+        for _ in 0..self.attempts.max(1) {
+            client
+                .pause(self.delay)
+                .await
+                .map_err(|err| err.to_string())?;
+            match client
+                .http
+                .get(&self.url)
+                .timeout(self.timeout)
+                .send()
+                .await
+            {
+                Ok(response) if response.status().is_success() => return Ok(()),
+                Ok(response) => failure = format!("{} answered {}", self.url, response.status()),
+                Err(err) => failure = format!("{} failed: {err}", self.url),
+            }
+        }
+        Err(failure)
+    }
+}
+
+/// Applies [`ScheduledChange`]s when they are due; see the
+/// [module docs](self).
+#[derive(Debug)]
+pub struct Scheduler {
+    client: HetznerClient,
+    path: PathBuf,
+    changes: Mutex<Vec<ScheduledChange>>,
+}
+
+impl Scheduler {
+    /// Opens the schedule at `path`, continuing an existing file if present.
+    pub fn open(client: HetznerClient, path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let changes = read_changes(&path)?;
+        Ok(Self {
+            client,
+            path,
+            changes: Mutex::new(changes),
+        })
+    }
+
+    /// Adds `change`, replacing a pending change with the same id.
+    pub fn schedule(&self, change: ScheduledChange) -> Result<()> {
+        let mut changes = self.changes.lock().unwrap();
+        self.reload(&mut changes)?;
+        if changes
+            .iter()
+            .any(|c| c.id == change.id && c.status != ScheduleStatus::Pending)
+        {
+            return Err(HetznerError::Schedule(format!(
+                "change {} was already executed",
+                change.id
+            )));
+        }
+        changes.retain(|c| c.id != change.id);
+        changes.push(change);
+        self.persist(&changes)
+    }
+
+    /// Removes a pending change, returning whether there was one.
+    pub fn cancel(&self, id: &str) -> Result<bool> {
+        let mut changes = self.changes.lock().unwrap();
+        self.reload(&mut changes)?;
+        let before = changes.len();
+        changes.retain(|c| c.id != id || c.status != ScheduleStatus::Pending);
+        let removed = changes.len() < before;
+        self.persist(&changes)?;
+        Ok(removed)
+    }
+
+    pub fn changes(&self) -> Vec<ScheduledChange> {
+        self.changes.lock().unwrap().clone()
+    }
+
+    /// Executes every pending change that is due, oldest first, and returns
+    /// them with their new status. A failing change does not stop the others.
+    ///
+    /// The file is read again first, so changes other processes scheduled or
+    /// cancelled are taken into account.
+    pub async fn run_due(&self) -> Result<Vec<ScheduledChange>> {
+        let now = SystemTime::now();
+        let mut due: Vec<ScheduledChange> = {
+            let mut changes = self.changes.lock().unwrap();
+            self.reload(&mut changes)?;
+            changes
+                .iter()
+                .filter(|change| change.is_due(now))
+                .cloned()
+                .collect()
+        };
+        due.sort_by_key(|change| change.at);
+
+        let mut executed = Vec::with_capacity(due.len());
+        for mut change in due {
+            let span = info_span!(
+                "scheduled_change",
+                id = %change.id,
+                zone = %change.zone,
+                name = %change.name,
+                record_type = %change.record_type
+            );
+            self.execute(&mut change).instrument(span).await;
+            let mut changes = self.changes.lock().unwrap();
+            self.reload(&mut changes)?;
+            match changes.iter_mut().find(|c| c.id == change.id) {
+                Some(stored) => *stored = change.clone(),
+                None => changes.push(change.clone()),
+            }
+            self.persist(&changes)?;
+            executed.push(change);
+        }
+        Ok(executed)
+    }
+
+    /// Runs due changes until the client's
+    /// [cancellation token](HetznerClient::with_cancellation) is cancelled,
+    /// sleeping until the next change is due (checking at least every
+    /// minute, so changes scheduled by other processes are noticed).
+    pub async fn run(&self) -> Result<()> {
+        loop {
+            self.run_due().await?;
+            let now = unix_seconds(SystemTime::now());
+            let next = self
+                .changes()
+                .iter()
+                .filter(|change| change.status == ScheduleStatus::Pending)
+                .map(|change| Duration::from_secs(change.at.saturating_sub(now)))
+                .min()
+                .unwrap_or(MAX_IDLE)
+                .min(MAX_IDLE);
+            match self.client.pause(next.max(Duration::from_secs(1))).await {
+                Err(HetznerError::Cancelled) => return Ok(()),
+                other => other?,
+            }
+        }
+    }
+
+    async fn execute(&self, change: &mut ScheduledChange) {
+        let client = self.client.operation();
+        change.executed_at = Some(unix_seconds(SystemTime::now()));
+        let applied = replace_record_set(
+            &client,
+            change.zone.as_ref(),
+            &change.name,
+            &change.record_type,
+            &change.values,
+            change.ttl,
+        )
+        .await;
+        change.previous = match applied {
+            Ok(previous) => previous,
+            Err(err) => {
+                warn!(error = %err, "scheduled change failed");
+                change.status = ScheduleStatus::Failed {
+                    error: err.to_string(),
+                };
+                return;
+            }
+        };
+        info!(values = ?change.values, "applied scheduled change");
+
+        let Some(probe) = &change.health_check else {
+            change.status = ScheduleStatus::Applied;
+            return;
+        };
+        let Err(reason) = probe.check(&client).await else {
+            info!("health check passed");
+            change.status = ScheduleStatus::Applied;
+            return;
+        };
+        warn!(%reason, "health check failed; reverting scheduled change");
+        let previous_values: Vec<String> =
+            change.previous.iter().map(|r| r.value.clone()).collect();
+        let previous_ttl = change.previous.first().and_then(|r| r.ttl);
+        change.status = match replace_record_set(
+            &client,
+            change.zone.as_ref(),
+            &change.name,
+            &change.record_type,
+            &previous_values,
+            previous_ttl,
+        )
+        .await
+        {
+            Ok(_) => ScheduleStatus::Reverted { reason },
+            Err(err) => ScheduleStatus::Failed {
+                error: format!("health check failed ({reason}) and reverting failed: {err}"),
+            },
+        };
+    }
+
+    /// Replaces `changes` with the file's, keeping the outcome of changes
+    /// this scheduler executed where the file still lists them as pending.
+    fn reload(&self, changes: &mut Vec<ScheduledChange>) -> Result<()> {
+        let mut stored = read_changes(&self.path)?;
+        for change in stored
+            .iter_mut()
+            .filter(|change| change.status == ScheduleStatus::Pending)
+        {
+            if let Some(executed) = changes
+                .iter()
+                .find(|c| c.id == change.id && c.status != ScheduleStatus::Pending)
+            {
+                *change = executed.clone();
+            }
+        }
+        *changes = stored;
+        Ok(())
+    }
+
+    fn persist(&self, changes: &[ScheduledChange]) -> Result<()> {
+        let serialized = serde_json::to_string_pretty(changes)?;
+        fs::write(&self.path, serialized).map_err(|err| {
+            HetznerError::Schedule(format!("failed to write {}: {err}", self.path.display()))
+        })
+    }
+}
+
+fn read_changes(path: &Path) -> Result<Vec<ScheduledChange>> {
+    let failed = |err: &dyn std::fmt::Display| {
+        HetznerError::Schedule(format!("failed to read {}: {err}", path.display()))
+    };
+    match fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str(&content).map_err(|err| failed(&err)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(failed(&err)),
+    }
+}
+
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Durations as whole seconds.
+mod seconds {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub(super) fn serialize<S: Serializer>(
+        value: &Duration,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(value.as_secs())
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_secs)
+    }
+}
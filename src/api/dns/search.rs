@@ -0,0 +1,140 @@
+//! Reverse lookups: which records point at an address or host name, e.g.
+//! everything still aimed at a server about to be decommissioned, or what
+//! would break when a host is renamed.
+
+use crate::HetznerClient;
+use crate::api::dns::names::absolute_name;
+use crate::api::dns::zone_ref::ZoneRef;
+use crate::error::Result;
+use crate::types::{Record, RecordType, Zone, normalize_value};
+use futures_util::{StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+
+/// Zones whose records are listed at the same time by account-wide searches.
+const CONCURRENT_ZONES: usize = 8;
+
+/// Where [`find_records_by_value`] looks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchScope<'a> {
+    Zone(ZoneRef<&'a str>),
+    /// Every zone of the account.
+    Account,
+}
+
+impl<'a> SearchScope<'a> {
+    pub fn zone(zone: impl Into<ZoneRef<&'a str>>) -> Self {
+        Self::Zone(zone.into())
+    }
+}
+
+/// A record found by a search, with the name of the zone it belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RecordMatch {
+    pub zone_name: String,
+    pub record: Record,
+}
+
+/// Every A, AAAA and CNAME record in `scope` whose value is `value`, sorted
+/// by zone name and record. Addresses compare in canonical form, so
+/// `2001:DB8::0:1` finds `2001:db8::1`; CNAME targets compare as absolute
+/// names without case, and a target written without a trailing dot matches
+/// both read relative to its zone and as written.
+pub async fn find_records_by_value(
+    client: &HetznerClient,
+    value: &str,
+    scope: SearchScope<'_>,
+) -> Result<Vec<RecordMatch>> {
+    let zones = match scope {
+        SearchScope::Zone(zone) => vec![client.dns().zone(zone).get().await?.zone],
+        SearchScope::Account => client.dns().zones_stream().try_collect().await?,
+    };
+    let mut matches =
+        search_zones(client, zones, |record, zone| points_at(record, zone, value)).await?;
+    sort(&mut matches);
+    Ok(matches)
+}
+
+/// Every CNAME, MX, NS and SRV record of the account whose target is the
+/// host `hostname`, sorted by zone name and record; check these before
+/// renaming or retiring the host. Targets compare as absolute names without
+/// case, and one written without a trailing dot matches both read relative
+/// to its zone and as written.
+pub async fn find_references(client: &HetznerClient, hostname: &str) -> Result<Vec<RecordMatch>> {
+    let zones = client.dns().zones_stream().try_collect().await?;
+    let mut matches = search_zones(client, zones, |record, zone| {
+        references(record, zone, hostname)
+    })
+    .await?;
+    sort(&mut matches);
+    Ok(matches)
+}
+
+fn sort(matches: &mut [RecordMatch]) {
+    matches.sort_by(|a, b| {
+        a.zone_name
+            .cmp(&b.zone_name)
+            .then_with(|| a.record.cmp(&b.record))
+    });
+}
+
+/// The records of `zones` accepted by `filter`, listing `CONCURRENT_ZONES`
+/// zones at a time; the first failed listing fails the search.
+async fn search_zones(
+    client: &HetznerClient,
+    zones: Vec<Zone>,
+    filter: impl Fn(&Record, &Zone) -> bool,
+) -> Result<Vec<RecordMatch>> {
+    let filter = &filter;
+    futures_util::stream::iter(zones)
+        .map(|zone| async move {
+            client.check_cancelled()?;
+            let records = client.dns().records(&zone.id).list().await?;
+            Ok::<_, crate::error::HetznerError>(
+                records
+                    .into_iter()
+                    .filter(|record| filter(record, &zone))
+                    .map(|record| RecordMatch {
+                        zone_name: zone.name.clone(),
+                        record,
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .buffer_unordered(CONCURRENT_ZONES)
+        .try_concat()
+        .await
+}
+
+fn points_at(record: &Record, zone: &Zone, value: &str) -> bool {
+    match record.kind() {
+        kind @ (RecordType::A | RecordType::Aaaa) => {
+            let kind = kind.to_string();
+            normalize_value(&kind, &record.value) == normalize_value(&kind, value)
+        }
+        RecordType::Cname => targets(&record.value, zone, value),
+        _ => false,
+    }
+}
+
+fn references(record: &Record, zone: &Zone, hostname: &str) -> bool {
+    match record.kind() {
+        RecordType::Cname | RecordType::Ns | RecordType::Mx | RecordType::Srv => record
+            .value
+            .split_whitespace()
+            .last()
+            .is_some_and(|target| targets(target, zone, hostname)),
+        _ => false,
+    }
+}
+
+/// Whether the host name `target`, as written in a record of `zone`, is
+/// `host`.
+fn targets(target: &str, zone: &Zone, host: &str) -> bool {
+    let host = host_name(host);
+    host_name(&absolute_name(target, &zone.name)) == host
+        || (!target.trim().ends_with('.') && host_name(target) == host)
+}
+
+fn host_name(name: &str) -> String {
+    name.trim().trim_end_matches('.').to_ascii_lowercase()
+}
@@ -0,0 +1,62 @@
+use crate::HetznerClient;
+use crate::api::dns::primary_servers::PrimaryServersApi;
+use crate::api::dns::zone_ref::ZoneRef;
+use crate::api::dns::zones::{self, CreateZoneInput};
+use crate::error::Result;
+use crate::types::{PrimaryServer, Zone};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// A secondary zone together with the primary servers it transfers from.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SecondaryZone {
+    pub zone: Zone,
+    pub primary_servers: Vec<PrimaryServer>,
+}
+
+/// Creates `name`, registers every primary, and waits until the first zone
+/// transfer has populated the zone with records.
+///
+/// The zone is left in place if a later step fails, so a retry should look it
+/// up instead of creating it again.
+pub async fn setup_secondary_zone(
+    client: &HetznerClient,
+    name: &str,
+    primaries: &[(&str, u16)],
+    timeout: Duration,
+) -> Result<SecondaryZone> {
+    let client = &client.correlated();
+    client.check_cancelled()?;
+    let zone = zones::create_zone(
+        client,
+        CreateZoneInput {
+            name: name.to_string(),
+            ttl: None,
+        },
+    )
+    .await?;
+
+    let servers = PrimaryServersApi {
+        client,
+        zone: ZoneRef::Id(&zone.id),
+    };
+    let mut primary_servers = Vec::with_capacity(primaries.len());
+    for (address, port) in primaries {
+        client.check_cancelled()?;
+        primary_servers.push(servers.create(*address, *port).await?);
+    }
+
+    let zone = zones::poll_zone(
+        client,
+        &zone.id,
+        timeout,
+        "to receive its first transfer",
+        |zone| zone.records_count > 0,
+    )
+    .await?;
+
+    Ok(SecondaryZone {
+        zone,
+        primary_servers,
+    })
+}
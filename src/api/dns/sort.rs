@@ -0,0 +1,110 @@
+use crate::types::{Record, Zone};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Field a listing is ordered by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortKey {
+    Name,
+    /// Record type; for zones, primary zones before secondary ones.
+    Type,
+    /// Explicit TTL; entries inheriting the default sort first.
+    Ttl,
+    /// Last modification timestamp as reported by the API.
+    Modified,
+}
+
+impl std::str::FromStr for SortKey {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "name" => Ok(Self::Name),
+            "type" => Ok(Self::Type),
+            "ttl" => Ok(Self::Ttl),
+            "modified" => Ok(Self::Modified),
+            _ => Err(format!(
+                "unknown sort key {value} (expected name, type, ttl or modified)"
+            )),
+        }
+    }
+}
+
+/// Client-side ordering for record and zone listings, which the API returns
+/// in creation order. Ties are broken by name, then type and value, so the
+/// output is stable across runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Sort {
+    key: SortKey,
+    descending: bool,
+}
+
+impl Sort {
+    pub fn by(key: SortKey) -> Self {
+        Self {
+            key,
+            descending: false,
+        }
+    }
+
+    pub fn descending(mut self) -> Self {
+        self.descending = true;
+        self
+    }
+
+    pub fn key(&self) -> SortKey {
+        self.key
+    }
+
+    pub fn records(&self, records: &mut [Record]) {
+        records.sort_by(|a, b| self.direction(self.compare_records(a, b)));
+    }
+
+    pub fn zones(&self, zones: &mut [Zone]) {
+        zones.sort_by(|a, b| self.direction(self.compare_zones(a, b)));
+    }
+
+    fn direction(&self, ordering: Ordering) -> Ordering {
+        if self.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+
+    fn compare_records(&self, a: &Record, b: &Record) -> Ordering {
+        let by_name = || {
+            compare_names(&a.name, &b.name)
+                .then_with(|| compare_types(&a.record_type, &b.record_type))
+                .then_with(|| a.value.cmp(&b.value))
+        };
+        match self.key {
+            SortKey::Name => by_name(),
+            SortKey::Type => compare_types(&a.record_type, &b.record_type).then_with(by_name),
+            SortKey::Ttl => a.ttl.cmp(&b.ttl).then_with(by_name),
+            SortKey::Modified => a.modified.cmp(&b.modified).then_with(by_name),
+        }
+    }
+
+    fn compare_zones(&self, a: &Zone, b: &Zone) -> Ordering {
+        let by_name = || compare_names(&a.name, &b.name);
+        match self.key {
+            SortKey::Name => by_name(),
+            SortKey::Type => a
+                .is_secondary_dns
+                .cmp(&b.is_secondary_dns)
+                .then_with(by_name),
+            SortKey::Ttl => a.ttl.cmp(&b.ttl).then_with(by_name),
+            SortKey::Modified => a.modified.cmp(&b.modified).then_with(by_name),
+        }
+    }
+}
+
+fn compare_names(a: &str, b: &str) -> Ordering {
+    a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase())
+}
+
+fn compare_types(a: &str, b: &str) -> Ordering {
+    a.to_ascii_uppercase().cmp(&b.to_ascii_uppercase())
+}
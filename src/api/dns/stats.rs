@@ -0,0 +1,85 @@
+use crate::api::dns::records::Ttl;
+use crate::types::{Record, Zone};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// How many of the largest TXT records [`ZoneStats`] keeps.
+pub const LARGEST_TXT_RECORDS: usize = 10;
+
+/// Summary figures for capacity and hygiene reviews of a zone.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ZoneStats {
+    pub zone_id: String,
+    pub zone_name: String,
+    pub total_records: usize,
+    /// Record count per upper-cased type.
+    pub records_by_type: BTreeMap<String, usize>,
+    /// Record count per effective TTL.
+    pub ttl_distribution: BTreeMap<Ttl, usize>,
+    /// Records using the zone's default TTL rather than their own.
+    pub default_ttl_records: usize,
+    /// Names of wildcard records (`*` or `*.label`), sorted and deduplicated.
+    pub wildcard_names: Vec<String>,
+    /// The largest TXT records by value length, largest first.
+    pub largest_txt: Vec<TxtPayload>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TxtPayload {
+    pub record_id: String,
+    pub name: String,
+    /// Length of the value in bytes, as stored (including quoting).
+    pub bytes: usize,
+}
+
+impl ZoneStats {
+    pub fn compute(zone: &Zone, records: &[Record]) -> Self {
+        let mut stats = Self {
+            zone_id: zone.id.clone(),
+            zone_name: zone.name.clone(),
+            total_records: records.len(),
+            ..Self::default()
+        };
+        for record in records {
+            *stats
+                .records_by_type
+                .entry(record.record_type.to_ascii_uppercase())
+                .or_default() += 1;
+            *stats
+                .ttl_distribution
+                .entry(record.effective_ttl(zone))
+                .or_default() += 1;
+            if record.ttl.is_none() {
+                stats.default_ttl_records += 1;
+            }
+            if record.name == "*" || record.name.starts_with("*.") {
+                stats.wildcard_names.push(record.name.clone());
+            }
+            if record.record_type.eq_ignore_ascii_case("TXT") {
+                stats.largest_txt.push(TxtPayload {
+                    record_id: record.id.clone(),
+                    name: record.name.clone(),
+                    bytes: record.value.len(),
+                });
+            }
+        }
+        stats.wildcard_names.sort();
+        stats.wildcard_names.dedup();
+        stats
+            .largest_txt
+            .sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.name.cmp(&b.name)));
+        stats.largest_txt.truncate(LARGEST_TXT_RECORDS);
+        stats
+    }
+
+    pub fn count_of(&self, record_type: &str) -> usize {
+        self.records_by_type
+            .get(&record_type.to_ascii_uppercase())
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn uses_wildcards(&self) -> bool {
+        !self.wildcard_names.is_empty()
+    }
+}
@@ -0,0 +1,166 @@
+//! Record sets with `{placeholder}`s, rendered per zone into a [`Preset`].
+
+use crate::HetznerClient;
+use crate::api::dns::batch::BatchResult;
+use crate::api::dns::presets::Preset;
+use crate::api::dns::provider::DnsRecord;
+use crate::api::dns::records::Ttl;
+use crate::error::{HetznerError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Records whose names and values may contain `{placeholder}`s, e.g. a
+/// `www` CNAME to `{host}` and an apex A record for `{ip}`. Placeholder names
+/// are letters, digits and `_`; write `{{` and `}}` for literal braces.
+///
+/// When applied with [`apply`](Self::apply), `{zone}` defaults to the zone
+/// name.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RecordTemplate {
+    pub name: String,
+    pub records: Vec<DnsRecord>,
+}
+
+impl RecordTemplate {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            records: Vec::new(),
+        }
+    }
+
+    pub fn record(
+        mut self,
+        name: impl Into<String>,
+        record_type: impl Into<String>,
+        value: impl Into<String>,
+        ttl: impl Into<Option<Ttl>>,
+    ) -> Self {
+        self.records
+            .push(DnsRecord::new(name, record_type, value, ttl));
+        self
+    }
+
+    /// The placeholder names the template uses, sorted.
+    pub fn placeholders(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        for record in &self.records {
+            for text in [&record.name, &record.value] {
+                let _ = substitute(text, &mut |name| {
+                    names.push(name.to_string());
+                    Some(String::new())
+                });
+            }
+        }
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Fills in every placeholder from `params`, failing with
+    /// [`HetznerError::Template`] if one is missing or a brace is unmatched.
+    pub fn render(&self, params: &BTreeMap<String, String>) -> Result<Preset> {
+        let mut lookup = |name: &str| params.get(name).cloned();
+        let records = self
+            .records
+            .iter()
+            .map(|record| {
+                Ok(DnsRecord {
+                    name: substitute(&record.name, &mut lookup)?,
+                    record_type: record.record_type.clone(),
+                    value: substitute(&record.value, &mut lookup)?,
+                    ttl: record.ttl,
+                })
+            })
+            .collect::<Result<Vec<_>>>()
+            .map_err(|err| match err {
+                HetznerError::Template(message) => {
+                    HetznerError::Template(format!("{}: {message}", self.name))
+                }
+                other => other,
+            })?;
+        Ok(Preset::new(self.name.clone(), records))
+    }
+
+    /// Renders and applies the template to one zone; see
+    /// [`PresetsApi::apply`](crate::api::dns::presets::PresetsApi::apply).
+    pub async fn apply(
+        &self,
+        client: &HetznerClient,
+        zone_id: &str,
+        params: &BTreeMap<String, String>,
+    ) -> Result<BatchResult> {
+        let client = client.correlated();
+        let mut params = params.clone();
+        if !params.contains_key("zone") {
+            let zone = client.dns().zone(zone_id).get().await?.zone;
+            params.insert("zone".to_string(), zone.name);
+        }
+        let preset = self.render(&params)?;
+        client.dns().presets(zone_id).apply(&preset).await
+    }
+
+    /// Applies the template to each `(zone_id, params)` target in turn. A
+    /// failing zone does not stop the others; each outcome is returned with
+    /// its zone id.
+    pub async fn apply_all<I, Z>(
+        &self,
+        client: &HetznerClient,
+        targets: I,
+    ) -> Vec<(String, Result<BatchResult>)>
+    where
+        I: IntoIterator<Item = (Z, BTreeMap<String, String>)>,
+        Z: Into<String>,
+    {
+        let mut outcomes = Vec::new();
+        for (zone_id, params) in targets {
+            let zone_id = zone_id.into();
+            let outcome = self.apply(client, &zone_id, &params).await;
+            outcomes.push((zone_id, outcome));
+        }
+        outcomes
+    }
+}
+
+/// Replaces `{name}` in `text` with `lookup(name)`.
+fn substitute(text: &str, lookup: &mut impl FnMut(&str) -> Option<String>) -> Result<String> {
+    let mut output = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                output.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                output.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') if !name.is_empty() => break,
+                        Some(c) if c.is_ascii_alphanumeric() || c == '_' => name.push(c),
+                        _ => {
+                            return Err(HetznerError::Template(format!(
+                                "malformed placeholder in {text:?}"
+                            )));
+                        }
+                    }
+                }
+                let value = lookup(&name).ok_or_else(|| {
+                    HetznerError::Template(format!("no value for placeholder {{{name}}}"))
+                })?;
+                output.push_str(&value);
+            }
+            '}' => {
+                return Err(HetznerError::Template(format!(
+                    "unmatched '}}' in {text:?}"
+                )));
+            }
+            other => output.push(other),
+        }
+    }
+    Ok(output)
+}
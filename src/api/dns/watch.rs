@@ -0,0 +1,151 @@
+use crate::HetznerClient;
+use crate::api::dns::zone_ref::ZoneRef;
+use crate::error::Result;
+use crate::types::Record;
+use futures_util::Stream;
+use futures_util::stream;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::time::{Duration, SystemTime};
+use tokio::time::{Interval, MissedTickBehavior};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+impl ChangeKind {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Updated => "updated",
+            Self::Deleted => "deleted",
+        }
+    }
+}
+
+/// A change to a zone's records observed between two polls, ready to be
+/// persisted or forwarded as JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ChangeEvent {
+    pub zone_id: String,
+    pub kind: ChangeKind,
+    /// The record as of the previous poll; `None` when it was created.
+    pub before: Option<Record>,
+    /// The record as of this poll; `None` when it was deleted.
+    pub after: Option<Record>,
+    /// When the poll that saw the change finished.
+    pub observed_at: SystemTime,
+}
+
+impl ChangeEvent {
+    /// The record as it is now, or as it was before being deleted.
+    ///
+    /// Events from [`watch_zone`] always hold one; `None` only for events
+    /// built or deserialized without either side.
+    pub fn record(&self) -> Option<&Record> {
+        self.after.as_ref().or(self.before.as_ref())
+    }
+}
+
+struct WatchState<'a> {
+    client: &'a HetznerClient,
+    zone: ZoneRef<&'a str>,
+    /// Resolved from `zone` by the first poll.
+    zone_id: Option<String>,
+    ticks: Interval,
+    known: Option<BTreeMap<String, Record>>,
+    pending: VecDeque<ChangeEvent>,
+}
+
+/// Polls the zone's records every `interval` and yields the differences. The
+/// first poll resolves `zone` to its id, which every event carries, and only
+/// takes a snapshot; failed polls yield the error and the stream carries on
+/// with the next tick. The stream ends once the client's cancellation token
+/// is cancelled.
+pub fn watch_zone<'a>(
+    client: &'a HetznerClient,
+    zone: impl Into<ZoneRef<&'a str>>,
+    interval: Duration,
+) -> impl Stream<Item = Result<ChangeEvent>> + 'a {
+    let mut ticks = tokio::time::interval(interval);
+    ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let state = WatchState {
+        client,
+        zone: zone.into(),
+        zone_id: None,
+        ticks,
+        known: None,
+        pending: VecDeque::new(),
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(event) = state.pending.pop_front() {
+                return Some((Ok(event), state));
+            }
+            match &state.client.cancellation {
+                Some(token) => tokio::select! {
+                    _ = token.cancelled() => return None,
+                    _ = state.ticks.tick() => {}
+                },
+                None => {
+                    state.ticks.tick().await;
+                }
+            }
+            let zone_id = match &state.zone_id {
+                Some(zone_id) => zone_id.clone(),
+                None => match state.client.zone_id(state.zone.as_ref()).await {
+                    Ok(zone_id) => state.zone_id.insert(zone_id.into_owned()).clone(),
+                    Err(err) => return Some((Err(err), state)),
+                },
+            };
+            let records = match state.client.dns().records(&zone_id).list().await {
+                Ok(records) => records,
+                Err(err) => return Some((Err(err), state)),
+            };
+            let current: BTreeMap<String, Record> = records
+                .into_iter()
+                .map(|record| (record.id.clone(), record))
+                .collect();
+            if let Some(known) = &state.known {
+                state.pending.extend(diff(&zone_id, known, &current));
+            }
+            state.known = Some(current);
+        }
+    })
+}
+
+fn diff(
+    zone_id: &str,
+    known: &BTreeMap<String, Record>,
+    current: &BTreeMap<String, Record>,
+) -> Vec<ChangeEvent> {
+    let observed_at = SystemTime::now();
+    let event = |kind, before: Option<&Record>, after: Option<&Record>| ChangeEvent {
+        zone_id: zone_id.to_string(),
+        kind,
+        before: before.cloned(),
+        after: after.cloned(),
+        observed_at,
+    };
+    let mut events = Vec::new();
+    for (id, record) in current {
+        match known.get(id) {
+            None => events.push(event(ChangeKind::Created, None, Some(record))),
+            Some(previous) if previous != record => {
+                events.push(event(ChangeKind::Updated, Some(previous), Some(record)))
+            }
+            Some(_) => {}
+        }
+    }
+    for (id, record) in known {
+        if !current.contains_key(id) {
+            events.push(event(ChangeKind::Deleted, Some(record), None));
+        }
+    }
+    events
+}
@@ -0,0 +1,266 @@
+//! Delivers DNS change events to an HTTPS endpoint, e.g. a chat alert or a
+//! CMDB (`webhook-emitter` feature).
+//!
+//! Each event is POSTed as JSON, `{"type": "change", "event": {...}}` for
+//! [`ChangeEvent`]s from [`watch_zone`](crate::api::dns::DnsApi::watch_zone)
+//! and `{"type": "mutation", "event": {...}}` for this client's own
+//! [`MutationEvent`]s, with these headers:
+//!
+//! - `X-Hetzner-Event`: `change` or `mutation`
+//! - `X-Hetzner-Delivery`: an id shared by all attempts to deliver the event
+//! - `X-Hetzner-Timestamp`: the Unix time the attempt was signed at
+//! - `X-Hetzner-Signature`: `sha256=` and the hex HMAC-SHA256 of
+//!   `{timestamp}.{body}` under the shared secret
+//!
+//! Receivers check the signature with [`verify_signature`] and should reject
+//! old timestamps. Failed deliveries (connection errors, 429 and 5xx) are
+//! retried as the emitter's [`BackoffPolicy`] allows.
+//!
+//! ```no_run
+//! # async fn example(client: hetzner::HetznerClient) -> hetzner::Result<()> {
+//! use hetzner::WebhookEmitter;
+//! use std::time::Duration;
+//!
+//! let emitter = WebhookEmitter::new("https://hooks.example.com/dns", "secret")?;
+//! // This client's own writes...
+//! let client = client.with_on_mutation(emitter.mutation_hook());
+//! // ...and changes made elsewhere.
+//! let changes = client.dns().watch_zone("example.com", Duration::from_secs(60));
+//! emitter.forward(changes).await;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::api::dns::watch::ChangeEvent;
+use crate::error::{HetznerError, Result};
+use crate::logging::{info, warn};
+use crate::mutation::MutationEvent;
+use crate::retry::{BackoffPolicy, ExponentialBackoff};
+use futures_util::{Stream, StreamExt};
+use hmac::{Hmac, Mac};
+use reqwest::Url;
+use reqwest::header::CONTENT_TYPE;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::fmt;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const EVENT_HEADER: &str = "X-Hetzner-Event";
+pub const DELIVERY_HEADER: &str = "X-Hetzner-Delivery";
+pub const TIMESTAMP_HEADER: &str = "X-Hetzner-Timestamp";
+pub const SIGNATURE_HEADER: &str = "X-Hetzner-Signature";
+
+/// The body of a webhook request.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "type", content = "event", rename_all = "snake_case")]
+pub enum WebhookEvent {
+    Change(ChangeEvent),
+    Mutation(MutationEvent),
+}
+
+impl WebhookEvent {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Change(_) => "change",
+            Self::Mutation(_) => "mutation",
+        }
+    }
+}
+
+/// POSTs signed change events to one endpoint; see the [module docs](self).
+/// Clones share the HTTP connection pool.
+#[derive(Clone)]
+pub struct WebhookEmitter {
+    http: reqwest::Client,
+    url: Url,
+    secret: Arc<[u8]>,
+    backoff: Arc<dyn BackoffPolicy>,
+    timeout: Duration,
+}
+
+impl WebhookEmitter {
+    /// An emitter signing with `secret`. `url` must be HTTPS; plain HTTP is
+    /// only accepted for loopback addresses, for local testing.
+    pub fn new(url: &str, secret: impl AsRef<[u8]>) -> Result<Self> {
+        let url = Url::parse(url)
+            .map_err(|err| HetznerError::InvalidConfig(format!("webhook URL {url:?}: {err}")))?;
+        let loopback = url.host_str().is_some_and(|host| {
+            host == "localhost"
+                || host
+                    .trim_matches(['[', ']'])
+                    .parse::<IpAddr>()
+                    .is_ok_and(|ip| ip.is_loopback())
+        });
+        if url.scheme() != "https" && !(url.scheme() == "http" && loopback) {
+            return Err(HetznerError::InvalidConfig(format!(
+                "webhook URL {url} must use https"
+            )));
+        }
+        Ok(Self {
+            http: reqwest::Client::new(),
+            url,
+            secret: secret.as_ref().into(),
+            backoff: Arc::new(ExponentialBackoff::default()),
+            timeout: Duration::from_secs(10),
+        })
+    }
+
+    /// How failed deliveries are retried; [`ExponentialBackoff::default`]
+    /// unless set.
+    pub fn backoff(mut self, policy: impl BackoffPolicy + 'static) -> Self {
+        self.backoff = Arc::new(policy);
+        self
+    }
+
+    /// How long one delivery attempt may take; 10 seconds unless set.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Delivers `event`, retrying as the backoff policy allows, and fails
+    /// with [`HetznerError::Webhook`] if the endpoint never accepted it.
+    pub async fn send(&self, event: &WebhookEvent) -> Result<()> {
+        let body = serde_json::to_vec(event)?;
+        let delivery = uuid::Uuid::new_v4().to_string();
+        let mut attempt = 0;
+        loop {
+            let (err, retryable) = match self.attempt(event.kind(), &delivery, &body).await {
+                Ok(()) => return Ok(()),
+                Err(failure) => failure,
+            };
+            attempt += 1;
+            let delay = retryable
+                .then(|| self.backoff.next_delay(attempt, &err))
+                .flatten();
+            let Some(delay) = delay else {
+                return Err(err);
+            };
+            warn!(url = %self.url, delivery, attempt, delay_ms = delay.as_millis(), error = %err, "retrying webhook delivery");
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// One delivery attempt; on failure the error and whether to retry.
+    async fn attempt(
+        &self,
+        kind: &str,
+        delivery: &str,
+        body: &[u8],
+    ) -> std::result::Result<(), (HetznerError, bool)> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let response = self
+            .http
+            .post(self.url.clone())
+            .timeout(self.timeout)
+            .header(CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, kind)
+            .header(DELIVERY_HEADER, delivery)
+            .header(TIMESTAMP_HEADER, timestamp)
+            .header(SIGNATURE_HEADER, signature(&self.secret, timestamp, body))
+            .body(body.to_vec())
+            .send()
+            .await;
+        match response {
+            Ok(response) if response.status().is_success() => {
+                info!(url = %self.url, delivery, kind, "webhook delivered");
+                Ok(())
+            }
+            Ok(response) => {
+                let status = response.status();
+                let retryable = status.is_server_error() || status.as_u16() == 429;
+                let err = HetznerError::Webhook(format!("{} answered {status}", self.url));
+                Err((err, retryable))
+            }
+            Err(err) => Err((HetznerError::Webhook(format!("{}: {err}", self.url)), true)),
+        }
+    }
+
+    pub async fn send_change(&self, event: &ChangeEvent) -> Result<()> {
+        self.send(&WebhookEvent::Change(event.clone())).await
+    }
+
+    pub async fn send_mutation(&self, event: &MutationEvent) -> Result<()> {
+        self.send(&WebhookEvent::Mutation(event.clone())).await
+    }
+
+    /// A callback for
+    /// [`HetznerClient::with_on_mutation`](crate::HetznerClient::with_on_mutation)
+    /// that delivers each write in a background task, so writes don't wait
+    /// for the endpoint. Failed deliveries are logged.
+    pub fn mutation_hook(&self) -> impl Fn(&MutationEvent) + Send + Sync + 'static {
+        let emitter = self.clone();
+        move |event| {
+            let emitter = emitter.clone();
+            let event = WebhookEvent::Mutation(event.clone());
+            tokio::spawn(async move {
+                #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+                if let Err(err) = emitter.send(&event).await {
+                    warn!(error = %err, "dropping mutation event");
+                }
+            });
+        }
+    }
+
+    /// Delivers every event of a [`watch_zone`](crate::api::dns::DnsApi::watch_zone)
+    /// stream in order until it ends. Poll errors and failed deliveries are
+    /// logged and skipped, so one outage doesn't stop the watch.
+    pub async fn forward(&self, changes: impl Stream<Item = Result<ChangeEvent>>) {
+        let mut changes = std::pin::pin!(changes);
+        while let Some(change) = changes.next().await {
+            let result = match change {
+                Ok(change) => self.send_change(&change).await,
+                Err(err) => Err(err),
+            };
+            #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+            if let Err(err) = result {
+                warn!(error = %err, "dropping change event");
+            }
+        }
+    }
+}
+
+impl fmt::Debug for WebhookEmitter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookEmitter")
+            .field("url", &self.url.as_str())
+            .field("timeout", &self.timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+fn mac(secret: &[u8], timestamp: u64, body: &[u8]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC takes keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    mac
+}
+
+/// The `X-Hetzner-Signature` value for a request body sent at `timestamp`.
+pub fn signature(secret: &[u8], timestamp: u64, body: &[u8]) -> String {
+    let digest = mac(secret, timestamp, body).finalize().into_bytes();
+    let hex: String = digest.iter().map(|byte| format!("{byte:02x}")).collect();
+    format!("sha256={hex}")
+}
+
+/// Whether `header` (an `X-Hetzner-Signature` value) signs `body` sent at
+/// `timestamp` under `secret`, compared in constant time.
+pub fn verify_signature(secret: &[u8], timestamp: u64, body: &[u8], header: &str) -> bool {
+    let Some(hex) = header.strip_prefix("sha256=") else {
+        return false;
+    };
+    if hex.len() % 2 != 0 {
+        return false;
+    }
+    let bytes: Option<Vec<u8>> = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect();
+    bytes.is_some_and(|bytes| mac(secret, timestamp, body).verify_slice(&bytes).is_ok())
+}
@@ -0,0 +1,201 @@
+//! A small HTTP server that applies authenticated address updates, a
+//! self-hosted alternative to dyndns providers (`webhook-server` feature).
+//!
+//! Routers and CI jobs `POST /update` with `Authorization: Bearer <token>`
+//! and a JSON body naming the record and its new address:
+//!
+//! ```text
+//! {"zone": "example.com", "name": "home", "value": "203.0.113.7"}
+//! ```
+//!
+//! Only records on the allow-list can be changed. The record type follows
+//! from the address (A or AAAA), and the response is the
+//! [`DynDnsUpdate`] that was applied.
+
+use crate::HetznerClient;
+use crate::api::dns::dyndns::DynDnsUpdate;
+use crate::api::dns::records::Ttl;
+use crate::api::dns::zone_ref::ZoneRef;
+use crate::error::{HetznerError, Result};
+use crate::logging::{info, warn};
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::net::IpAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+
+/// The body of `POST /update`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct UpdateRequest {
+    /// Zone name, e.g. `example.com`.
+    pub zone: String,
+    /// Record name relative to the zone, `@` for the apex.
+    pub name: String,
+    /// The new IPv4 or IPv6 address.
+    pub value: String,
+}
+
+/// Serves `POST /update`; see the [module docs](self).
+#[derive(Debug, Clone)]
+pub struct WebhookServer {
+    client: HetznerClient,
+    token: String,
+    allowed: Vec<(String, String)>,
+    ttl: Option<Ttl>,
+}
+
+impl WebhookServer {
+    /// A server accepting requests that carry `token` as bearer token.
+    /// Nothing can be updated until records are [`allow`](Self::allow)ed.
+    ///
+    /// Fails with [`HetznerError::InvalidConfig`] when `token` is blank,
+    /// since an empty bearer token would let anyone through.
+    pub fn new(client: HetznerClient, token: impl Into<String>) -> Result<Self> {
+        let token = token.into();
+        if token.trim().is_empty() {
+            return Err(HetznerError::InvalidConfig(
+                "webhook token must not be empty".to_string(),
+            ));
+        }
+        Ok(Self {
+            client,
+            token,
+            allowed: Vec::new(),
+            ttl: None,
+        })
+    }
+
+    /// Allows updates of the record `name` (relative, `@` for the apex) in
+    /// the zone named `zone`.
+    pub fn allow(mut self, zone: impl Into<String>, name: impl Into<String>) -> Self {
+        self.allowed
+            .push((normalize(&zone.into()), normalize(&name.into())));
+        self
+    }
+
+    /// TTL for created or updated records; the zone default unless set.
+    pub fn ttl(mut self, ttl: impl Into<Option<Ttl>>) -> Self {
+        self.ttl = ttl.into();
+        self
+    }
+
+    /// The routes, for mounting into an existing axum application.
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/update", post(update))
+            .with_state(Arc::new(self))
+    }
+
+    /// Serves on `listener` until the client's
+    /// [cancellation token](HetznerClient::with_cancellation), if any, is
+    /// cancelled.
+    pub async fn serve(self, listener: TcpListener) -> std::io::Result<()> {
+        let cancellation = self.client.cancellation.clone();
+        let server = axum::serve(listener, self.router());
+        match cancellation {
+            Some(token) => {
+                server
+                    .with_graceful_shutdown(async move { token.cancelled().await })
+                    .await
+            }
+            None => server.await,
+        }
+    }
+
+    fn is_authorized(&self, headers: &HeaderMap) -> bool {
+        headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .filter(|token| !token.is_empty())
+            .is_some_and(|token| constant_time_eq(token.as_bytes(), self.token.as_bytes()))
+    }
+
+    fn is_allowed(&self, zone: &str, name: &str) -> bool {
+        self.allowed
+            .iter()
+            .any(|(allowed_zone, allowed_name)| allowed_zone == zone && allowed_name == name)
+    }
+}
+
+async fn update(
+    State(server): State<Arc<WebhookServer>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    // The body is only parsed once the caller is authenticated, so schema
+    // errors tell nothing about the request format to anyone else.
+    if !server.is_authorized(&headers) {
+        return rejection(StatusCode::UNAUTHORIZED, "missing or invalid bearer token");
+    }
+    let Ok(request) = serde_json::from_slice::<UpdateRequest>(&body) else {
+        return rejection(
+            StatusCode::BAD_REQUEST,
+            "body is not a valid update request",
+        );
+    };
+    let zone = normalize(&request.zone);
+    let name = normalize(&request.name);
+    if !server.is_allowed(&zone, &name) {
+        warn!(%zone, %name, "rejected webhook update outside the allow-list");
+        return rejection(StatusCode::FORBIDDEN, "record is not on the allow-list");
+    }
+    let Ok(address) = request.value.trim().parse::<IpAddr>() else {
+        return rejection(StatusCode::BAD_REQUEST, "value is not an IP address");
+    };
+
+    match apply(&server, &zone, &name, address).await {
+        Ok(update) => {
+            info!(%zone, %name, %address, changed = update.changed(), "applied webhook update");
+            Json(update).into_response()
+        }
+        Err(err) => {
+            warn!(%zone, %name, %address, "webhook update failed: {err}");
+            // The error names upstream messages and zone ids; it is logged
+            // above and only summarized to the caller.
+            if err.is_not_found() {
+                rejection(StatusCode::NOT_FOUND, "zone or record not found")
+            } else {
+                rejection(StatusCode::BAD_GATEWAY, "update failed")
+            }
+        }
+    }
+}
+
+async fn apply(
+    server: &WebhookServer,
+    zone: &str,
+    name: &str,
+    address: IpAddr,
+) -> crate::error::Result<DynDnsUpdate> {
+    let client = server.client.operation();
+    client
+        .dns()
+        .dyndns(ZoneRef::Name(zone), name)
+        .ttl(server.ttl)
+        .set(address)
+        .await
+}
+
+fn rejection(status: StatusCode, message: &str) -> Response {
+    (status, Json(json!({ "error": message }))).into_response()
+}
+
+/// Lower-cased without a trailing dot; an empty name is the apex `@`.
+fn normalize(name: &str) -> String {
+    match name.trim().trim_end_matches('.').to_ascii_lowercase() {
+        name if name.is_empty() => "@".to_string(),
+        name => name,
+    }
+}
+
+/// Compares tokens without returning early on the first differing byte.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
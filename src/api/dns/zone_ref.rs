@@ -0,0 +1,175 @@
+//! Naming zones by id or by name.
+
+use crate::HetznerClient;
+use crate::error::{HetznerError, Result};
+use crate::types::Zone;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+
+/// A zone, given by its Hetzner id or its name. Every zone and record method
+/// accepts one, so configurations can say `example.com` instead of an
+/// opaque id; names are resolved once per client and cached.
+///
+/// `ZoneRef` owns its strings; `ZoneRef<&str>` is the borrowed form the API
+/// facades take. Plain strings convert by shape: anything containing a dot
+/// is a name, anything else an id (Hetzner zone ids never contain dots).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ZoneRef<S = String> {
+    Id(S),
+    Name(S),
+}
+
+impl ZoneRef {
+    pub fn id(id: impl Into<String>) -> Self {
+        Self::Id(id.into())
+    }
+
+    pub fn name(name: impl Into<String>) -> Self {
+        Self::Name(name.into())
+    }
+}
+
+impl<S: AsRef<str>> ZoneRef<S> {
+    pub fn as_ref(&self) -> ZoneRef<&str> {
+        match self {
+            Self::Id(id) => ZoneRef::Id(id.as_ref()),
+            Self::Name(name) => ZoneRef::Name(name.as_ref()),
+        }
+    }
+
+    pub fn to_owned(&self) -> ZoneRef {
+        match self.as_ref() {
+            ZoneRef::Id(id) => ZoneRef::Id(id.to_string()),
+            ZoneRef::Name(name) => ZoneRef::Name(name.to_string()),
+        }
+    }
+
+    /// Whether `zone` is the zone this refers to.
+    pub fn matches(&self, zone: &Zone) -> bool {
+        match self.as_ref() {
+            ZoneRef::Id(id) => zone.id == id,
+            ZoneRef::Name(name) => zone.name.eq_ignore_ascii_case(name.trim_end_matches('.')),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ZoneRef<&'a str> {
+    fn from(value: &'a str) -> Self {
+        if value.contains('.') {
+            Self::Name(value)
+        } else {
+            Self::Id(value)
+        }
+    }
+}
+
+impl<'a> From<&'a String> for ZoneRef<&'a str> {
+    fn from(value: &'a String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl<'a> From<&'a ZoneRef> for ZoneRef<&'a str> {
+    fn from(value: &'a ZoneRef) -> Self {
+        value.as_ref()
+    }
+}
+
+impl<'a> From<&'a Zone> for ZoneRef<&'a str> {
+    fn from(value: &'a Zone) -> Self {
+        Self::Id(&value.id)
+    }
+}
+
+impl From<ZoneRef<&str>> for ZoneRef {
+    fn from(value: ZoneRef<&str>) -> Self {
+        value.to_owned()
+    }
+}
+
+impl From<&String> for ZoneRef {
+    fn from(value: &String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl From<&str> for ZoneRef {
+    fn from(value: &str) -> Self {
+        ZoneRef::<&str>::from(value).to_owned()
+    }
+}
+
+impl From<String> for ZoneRef {
+    fn from(value: String) -> Self {
+        if value.contains('.') {
+            Self::Name(value)
+        } else {
+            Self::Id(value)
+        }
+    }
+}
+
+impl<S: AsRef<str>> fmt::Display for ZoneRef<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.as_ref() {
+            ZoneRef::Id(id) => f.write_str(id),
+            ZoneRef::Name(name) => f.write_str(name.trim_end_matches('.')),
+        }
+    }
+}
+
+/// Zone ids by lower-cased name, shared by a client and its clones.
+#[derive(Debug, Default)]
+pub(crate) struct ZoneIdCache(Mutex<HashMap<String, String>>);
+
+impl ZoneIdCache {
+    pub(crate) fn get(&self, name: &str) -> Option<String> {
+        self.0.lock().unwrap().get(&cache_key(name)).cloned()
+    }
+
+    pub(crate) fn insert(&self, zone: &Zone) {
+        self.0
+            .lock()
+            .unwrap()
+            .insert(cache_key(&zone.name), zone.id.clone());
+    }
+
+    pub(crate) fn name_of(&self, id: &str) -> Option<String> {
+        self.0
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(_, cached)| cached.as_str() == id)
+            .map(|(name, _)| name.clone())
+    }
+
+    pub(crate) fn clear(&self) {
+        self.0.lock().unwrap().clear();
+    }
+}
+
+fn cache_key(name: &str) -> String {
+    name.trim_end_matches('.').to_ascii_lowercase()
+}
+
+impl HetznerClient {
+    /// The id of `zone`, asking the API for the name on the first lookup of
+    /// a name that isn't cached yet.
+    pub(crate) async fn zone_id<'z>(&self, zone: ZoneRef<&'z str>) -> Result<Cow<'z, str>> {
+        let name = match zone {
+            ZoneRef::Id(id) => return Ok(Cow::Borrowed(id)),
+            ZoneRef::Name(name) => name,
+        };
+        if let Some(id) = self.zone_ids.get(name) {
+            return Ok(Cow::Owned(id));
+        }
+        crate::api::dns::zones::find_zone(self, name)
+            .await?
+            .map(|zone| Cow::Owned(zone.id))
+            .ok_or_else(|| HetznerError::ZoneNotFound(name.trim_end_matches('.').to_string()))
+    }
+}
@@ -1,9 +1,322 @@
 use crate::HetznerClient;
-use crate::error::Result;
-use crate::types::{Zone, ZonesEnvelope};
+use crate::api::dns::lint::{LintFinding, lint_zone};
+use crate::api::dns::records::Ttl;
+use crate::api::dns::stats::ZoneStats;
+use crate::api::dns::zone_ref::ZoneRef;
+use crate::error::{ApiErrorCode, HetznerError, Result, not_found_as_none};
+use crate::mutation::{Mutation, MutationOperation};
+use crate::policy::ProposedChange;
+use crate::types::{Zone, ZoneEnvelope, ZonesEnvelope};
 use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::time::Duration;
+use tokio::time::Instant;
+
+const VERIFIED: &str = "verified";
+const MAX_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const MIN_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreateZoneInput {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<Ttl>,
+}
+
+impl CreateZoneInput {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// A [`Ttl`], or `None` for Hetzner's default.
+    pub fn ttl(mut self, ttl: impl Into<Option<Ttl>>) -> Self {
+        self.ttl = ttl.into();
+        self
+    }
+}
+
+/// Query parameters of the zone listing; unset fields are left out. See
+/// [`DnsApi::list_zones_with`](crate::api::dns::DnsApi::list_zones_with).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListZonesParams {
+    /// Only the zone with exactly this name.
+    pub name: Option<String>,
+    /// Only zones whose name contains this text.
+    pub search_name: Option<String>,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
+
+impl ListZonesParams {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn search_name(mut self, query: impl Into<String>) -> Self {
+        self.search_name = Some(query.into());
+        self
+    }
+
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub(crate) fn to_query_pairs(&self) -> Vec<(String, String)> {
+        let mut query = Vec::new();
+        if let Some(name) = &self.name {
+            query.push(("name".to_string(), name.clone()));
+        }
+        if let Some(search_name) = &self.search_name {
+            query.push(("search_name".to_string(), search_name.clone()));
+        }
+        query
+    }
+}
 
 pub async fn list_zones(client: &HetznerClient) -> Result<Vec<Zone>> {
     let response: ZonesEnvelope = client.request_dns(Method::GET, "zones", None).await?;
+    for zone in &response.zones {
+        client.zone_ids.insert(zone);
+    }
     Ok(response.zones)
 }
+
+/// The zone named `name`, asked for by name so zones beyond the first page
+/// of a listing are found too.
+pub(crate) async fn find_zone(client: &HetznerClient, name: &str) -> Result<Option<Zone>> {
+    let name = name.trim_end_matches('.');
+    let params = ListZonesParams::default().name(name.to_ascii_lowercase());
+    let zone = client
+        .dns()
+        .list_zones_with(&params)
+        .await?
+        .items
+        .into_iter()
+        .find(|zone| zone.name.trim_end_matches('.').eq_ignore_ascii_case(name));
+    if let Some(zone) = &zone {
+        client.zone_ids.insert(zone);
+    }
+    Ok(zone)
+}
+
+pub async fn create_zone(client: &HetznerClient, input: CreateZoneInput) -> Result<Zone> {
+    let client = &client.correlated();
+    client
+        .enforce_policy(|| {
+            vec![
+                ProposedChange::new(MutationOperation::CreateZone, None, None, None)
+                    .with_zone_name(input.name.trim_end_matches('.')),
+            ]
+        })
+        .await?;
+    let started = std::time::Instant::now();
+    let result = client
+        .request_dns::<ZoneEnvelope>(Method::POST, "zones", Some(json!(input)))
+        .await
+        .map(|response| response.zone);
+    if let Ok(zone) = &result {
+        client.zone_ids.insert(zone);
+    }
+    client.emit_mutation(
+        Mutation {
+            started,
+            operation: MutationOperation::CreateZone,
+            zone_id: result.as_ref().ok().map(|zone| zone.id.clone()),
+            resource_id: result.as_ref().ok().map(|zone| zone.id.clone()),
+            before: None,
+        },
+        &result,
+        |_| None,
+    );
+    result
+}
+
+/// Returns the zone named `name`, creating it with `ttl` if there is none.
+/// An existing zone is returned as is, even if its TTL differs.
+pub async fn ensure_zone(
+    client: &HetznerClient,
+    name: &str,
+    ttl: impl Into<Option<Ttl>>,
+) -> Result<Zone> {
+    let ttl = ttl.into();
+    let client = &client.correlated();
+    let name = name.trim_end_matches('.');
+    if let Some(zone) = find_zone(client, name).await? {
+        return Ok(zone);
+    }
+    let input = CreateZoneInput {
+        name: name.to_string(),
+        ttl,
+    };
+    match create_zone(client, input).await {
+        Ok(zone) => Ok(zone),
+        // Someone else created it between the lookup and our request.
+        Err(err) if is_taken(&err) => find_zone(client, name).await?.ok_or(err),
+        Err(err) => Err(err),
+    }
+}
+
+fn is_taken(err: &HetznerError) -> bool {
+    err.api_error().is_some_and(|api| {
+        matches!(
+            api.error_code(),
+            ApiErrorCode::TakenName | ApiErrorCode::UniquenessError
+        )
+    })
+}
+
+/// A tenth of `timeout`, between 50ms and 5s.
+pub(crate) fn poll_interval(timeout: Duration) -> Duration {
+    (timeout / 10).clamp(MIN_POLL_INTERVAL, MAX_POLL_INTERVAL)
+}
+
+/// Re-fetches the zone until `done` holds, polling at a tenth of `timeout`
+/// (between 50ms and 5s), and fails with [`HetznerError::Timeout`] naming
+/// `what` once the deadline passes.
+pub(crate) async fn poll_zone(
+    client: &HetznerClient,
+    zone_id: &str,
+    timeout: Duration,
+    what: &str,
+    done: impl Fn(&Zone) -> bool,
+) -> Result<Zone> {
+    let deadline = Instant::now() + timeout;
+    let interval = poll_interval(timeout);
+    let zone_api = ZoneApi {
+        client,
+        zone: ZoneRef::Id(zone_id),
+    };
+    loop {
+        client.check_cancelled()?;
+        let zone = zone_api.get().await?.zone;
+        if done(&zone) {
+            return Ok(zone);
+        }
+        if Instant::now() + interval > deadline {
+            return Err(HetznerError::Timeout(format!(
+                "zone {} {what} (status {})",
+                zone.name,
+                zone.status.as_deref().unwrap_or("unknown")
+            )));
+        }
+        client.pause(interval).await?;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ZoneApi<'a> {
+    pub(crate) client: &'a HetznerClient,
+    pub(crate) zone: ZoneRef<&'a str>,
+}
+
+impl<'a> ZoneApi<'a> {
+    pub async fn get(self) -> Result<ZoneEnvelope> {
+        let zone_id = self.client.zone_id(self.zone).await?;
+        let path = format!("zones/{zone_id}");
+        self.client.request_dns(Method::GET, &path, None).await
+    }
+
+    /// The zone's id, looked up if it was given by name.
+    pub async fn id(self) -> Result<String> {
+        Ok(self.client.zone_id(self.zone).await?.into_owned())
+    }
+
+    /// Like [`get`](Self::get), but a missing zone is `Ok(None)`.
+    pub async fn get_opt(self) -> Result<Option<ZoneEnvelope>> {
+        not_found_as_none(self.get().await)
+    }
+
+    /// Reads what is needed to verify the zone: the TXT record Hetzner
+    /// expects and the nameservers to delegate to. Hetzner re-checks
+    /// delegation on its own; there is no endpoint to force a check.
+    pub async fn verify(self) -> Result<ZoneVerification> {
+        let zone = self.get().await?.zone;
+        let txt = zone.txt_verification.clone().unwrap_or_default();
+        Ok(ZoneVerification {
+            verified: zone.status.as_deref() == Some(VERIFIED),
+            status: zone.status,
+            txt_name: txt.name,
+            txt_token: txt.token,
+            nameservers: zone.ns,
+            zone_id: zone.id,
+        })
+    }
+
+    /// Fetches the zone and its records and summarises them; see [`ZoneStats`].
+    pub async fn stats(self) -> Result<ZoneStats> {
+        let zone = self.get().await?.zone;
+        let records = self.client.dns().records(&zone.id).list().await?;
+        Ok(ZoneStats::compute(&zone, &records))
+    }
+
+    /// Fetches the zone and its records and runs [`lint_zone`] over them.
+    pub async fn lint(self) -> Result<Vec<LintFinding>> {
+        let zone = self.get().await?.zone;
+        let records = self.client.dns().records(&zone.id).list().await?;
+        Ok(lint_zone(&zone, &records))
+    }
+
+    /// Fetches the zone and its records and renders them as a dnscontrol
+    /// `D(...)` stanza; see [`dnscontrol`](crate::api::dns::dnscontrol).
+    pub async fn dnscontrol(self) -> Result<String> {
+        let zone = self.get().await?.zone;
+        let records = self.client.dns().records(&zone.id).list().await?;
+        Ok(crate::api::dns::dnscontrol::render_zone(&zone, &records))
+    }
+
+    /// Lints the zone and checks that its nameservers answer consistently and
+    /// serve every record; see [`HealthCheck`](crate::api::dns::health::HealthCheck)
+    /// for options.
+    #[cfg(feature = "health")]
+    pub async fn health_report(self) -> Result<crate::api::dns::health::HealthReport> {
+        self.health_check().run().await
+    }
+
+    #[cfg(feature = "health")]
+    pub fn health_check(self) -> crate::api::dns::health::HealthCheck<'a> {
+        crate::api::dns::health::HealthCheck::new(self.client, self.zone)
+    }
+
+    /// Transfers the zone from `primary` via AXFR and reports records that
+    /// are missing or stale at Hetzner.
+    #[cfg(feature = "axfr")]
+    pub async fn compare_with_primary(
+        self,
+        primary: std::net::SocketAddr,
+        timeout: Duration,
+    ) -> Result<crate::api::dns::axfr::ZoneComparison> {
+        let zone_id = self.client.zone_id(self.zone).await?;
+        crate::api::dns::axfr::compare_with_primary(self.client, &zone_id, primary, timeout).await
+    }
+
+    /// Polls the zone until its status is `verified`, returning the zone, or
+    /// fails with [`HetznerError::Timeout`] once `timeout` has elapsed.
+    pub async fn wait_until_verified(self, timeout: Duration) -> Result<Zone> {
+        let zone_id = self.client.zone_id(self.zone).await?;
+        poll_zone(self.client, &zone_id, timeout, "to be verified", |zone| {
+            zone.status.as_deref() == Some(VERIFIED)
+        })
+        .await
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ZoneVerification {
+    pub zone_id: String,
+    pub status: Option<String>,
+    pub verified: bool,
+    /// Name and value of the TXT record that proves ownership.
+    pub txt_name: String,
+    pub txt_token: String,
+    /// Nameservers the domain must be delegated to.
+    pub nameservers: Vec<String>,
+}
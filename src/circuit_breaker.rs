@@ -0,0 +1,93 @@
+//! A circuit breaker that stops sending requests after consecutive failures;
+//! installed with
+//! [`HetznerClient::with_circuit_breaker`](crate::HetznerClient::with_circuit_breaker).
+
+use crate::error::HetznerError;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Opens after `failure_threshold` consecutive failed requests and then
+/// fails every request with [`HetznerError::CircuitOpen`] for `cool_down`,
+/// without contacting the API. After the cool-down one request is let
+/// through: if it succeeds the circuit closes, otherwise it stays open for
+/// another cool-down.
+///
+/// Only failures that say the API is unreachable or unusable count:
+/// transport errors, server errors (5xx) and authentication errors. Client
+/// errors such as a missing record or invalid input reset the count like a
+/// success does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreaker {
+    pub failure_threshold: u32,
+    pub cool_down: Duration,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            cool_down: Duration::from_secs(30),
+        }
+    }
+}
+
+/// The breaker's state, shared by a client and its clones.
+#[derive(Debug)]
+pub(crate) struct CircuitState {
+    config: CircuitBreaker,
+    state: Mutex<BreakerState>,
+}
+
+#[derive(Debug, Default)]
+struct BreakerState {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+}
+
+impl CircuitState {
+    pub(crate) fn new(config: CircuitBreaker) -> Self {
+        Self {
+            config,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Fails with [`HetznerError::CircuitOpen`] while the circuit is open.
+    /// Once the cool-down has passed, lets this request through as the trial
+    /// and keeps others out until its outcome is recorded.
+    pub(crate) fn check(&self) -> Result<(), HetznerError> {
+        let mut state = self.state.lock().unwrap();
+        let Some(open_until) = state.open_until else {
+            return Ok(());
+        };
+        let now = Instant::now();
+        if now < open_until {
+            return Err(HetznerError::CircuitOpen {
+                retry_in: open_until - now,
+            });
+        }
+        state.open_until = Some(now + self.config.cool_down);
+        Ok(())
+    }
+
+    pub(crate) fn record(&self, outcome: Result<(), &HetznerError>) {
+        let mut state = self.state.lock().unwrap();
+        match outcome {
+            Err(err) if trips(err) => {
+                state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+                if state.consecutive_failures >= self.config.failure_threshold {
+                    state.open_until = Some(Instant::now() + self.config.cool_down);
+                }
+            }
+            _ => *state = BreakerState::default(),
+        }
+    }
+}
+
+fn trips(err: &HetznerError) -> bool {
+    match err {
+        HetznerError::Http(_) => true,
+        HetznerError::Api(api) => api.status.is_server_error() || err.is_auth_error(),
+        _ => false,
+    }
+}
@@ -0,0 +1,79 @@
+//! Rehearsing scripts without changing anything; enabled with
+//! [`HetznerClientBuilder::dry_run`](crate::HetznerClientBuilder::dry_run).
+
+use reqwest::Method;
+use serde_json::{Map, Value, json};
+use std::sync::Mutex;
+
+/// A write a dry-run client did not send.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DryRunRequest {
+    pub method: Method,
+    /// The full URL, including any query string.
+    pub url: String,
+    pub body: Option<Value>,
+}
+
+/// The requests a dry-run client collected, shared by the client and its
+/// clones.
+#[derive(Debug, Default)]
+pub(crate) struct DryRunLog {
+    requests: Mutex<Vec<DryRunRequest>>,
+}
+
+impl DryRunLog {
+    /// Collects `request` and returns the body of a made-up successful
+    /// response to it.
+    pub(crate) fn record(&self, path: &str, request: DryRunRequest) -> Vec<u8> {
+        let mut requests = self.requests.lock().unwrap();
+        let id = format!("dry-run-{}", requests.len() + 1);
+        let response = response(&request.method, path, request.body.as_ref(), &id);
+        requests.push(request);
+        response
+    }
+
+    pub(crate) fn requests(&self) -> Vec<DryRunRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// The response the API would most likely give: the request body echoed in
+/// the resource's envelope (`{"record": {...}}` for `records/...`), with an
+/// id added where the request has none. Deletes get an empty body.
+fn response(method: &Method, path: &str, body: Option<&Value>, id: &str) -> Vec<u8> {
+    if method == Method::DELETE {
+        return Vec::new();
+    }
+    let path = path.split('?').next().unwrap_or(path);
+    let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+    let collection = segments.next().unwrap_or_default();
+    let resource_id = segments.next();
+    let with_id = |object: Option<&Value>, id: &str| {
+        let mut object = object
+            .and_then(Value::as_object)
+            .cloned()
+            .unwrap_or_else(Map::new);
+        object
+            .entry("id")
+            .or_insert_with(|| Value::String(id.to_string()));
+        Value::Object(object)
+    };
+
+    let envelope = if resource_id == Some("bulk") {
+        let items = body
+            .and_then(|body| body.get(collection))
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let items: Vec<Value> = items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| with_id(Some(item), &format!("{id}-{}", index + 1)))
+            .collect();
+        json!({ collection: items })
+    } else {
+        let singular = collection.strip_suffix('s').unwrap_or(collection);
+        json!({ singular: with_id(body, resource_id.unwrap_or(id)) })
+    };
+    envelope.to_string().into_bytes()
+}
@@ -1,20 +1,66 @@
-use reqwest::StatusCode;
-use serde::{Deserialize, Serialize};
+use reqwest::{Method, StatusCode};
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
 
+/// Errors returned by the SDK. New variants can be added in minor releases,
+/// so matches need a wildcard arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum HetznerError {
+    /// The request did not produce an HTTP response (or its body could not
+    /// be read); see [`transport_kind`](Self::transport_kind). Responses with
+    /// an error status are [`Api`](Self::Api) errors instead.
     Http(reqwest::Error),
     Serialization(serde_json::Error),
-    Api(ApiError),
+    Api(Box<ApiError>),
     UnexpectedResponse(&'static str),
+    ZoneNotFound(String),
+    RecordNotFound(String),
+    AmbiguousRecord {
+        record: String,
+        matches: usize,
+    },
+    Cassette(String),
+    Timeout(String),
+    ZoneTransfer(String),
+    Journal(String),
+    InvalidConfig(String),
+    InvalidTtl(String),
+    InvalidName(String),
+    Cancelled,
+    /// The client's circuit breaker is open; no request was sent.
+    CircuitOpen {
+        retry_in: Duration,
+    },
+    State(String),
+    Template(String),
+    Backup(String),
+    /// An export could not be written to its destination.
+    Export(String),
+    IpDetection(String),
+    Schedule(String),
+    /// A [`Policy`](crate::policy::Policy) denied the change; nothing was sent.
+    PolicyDenied(String),
+    /// A change event could not be delivered to a webhook endpoint.
+    Webhook(String),
+    /// A change event could not be handed to the NATS or MQTT client.
+    Publish(String),
+    /// A record value does not conform to its type; see
+    /// [`RecordValue::parse`](crate::RecordValue::parse).
+    InvalidRecordValue(String),
 }
 
 impl fmt::Display for HetznerError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Http(err) => write!(f, "http request failed: {err}"),
+            Self::Http(err) => write!(
+                f,
+                "http request failed ({}): {err}",
+                TransportErrorKind::of(err)
+            ),
             Self::Serialization(err) => write!(f, "failed to decode response body: {err}"),
             Self::Api(err) => write!(
                 f,
@@ -24,11 +70,220 @@ impl fmt::Display for HetznerError {
                 err.message
             ),
             Self::UnexpectedResponse(message) => write!(f, "unexpected response: {message}"),
+            Self::ZoneNotFound(name) => write!(f, "no zone found for {name}"),
+            Self::RecordNotFound(record) => write!(f, "no record found for {record}"),
+            Self::AmbiguousRecord { record, matches } => {
+                write!(
+                    f,
+                    "{matches} records match {record}; give the value to pick one"
+                )
+            }
+            Self::Cassette(message) => write!(f, "cassette error: {message}"),
+            Self::Timeout(what) => write!(f, "timed out waiting for {what}"),
+            Self::ZoneTransfer(message) => write!(f, "zone transfer failed: {message}"),
+            Self::Journal(message) => write!(f, "undo journal error: {message}"),
+            Self::InvalidConfig(message) => write!(f, "invalid client configuration: {message}"),
+            Self::InvalidTtl(message) => write!(f, "invalid ttl: {message}"),
+            Self::InvalidName(message) => write!(f, "invalid dns name {message}"),
+            Self::Cancelled => write!(f, "operation cancelled"),
+            Self::CircuitOpen { retry_in } => write!(
+                f,
+                "circuit breaker open after repeated failures; retry in {}s",
+                retry_in.as_secs_f64().ceil()
+            ),
+            Self::State(message) => write!(f, "state file error: {message}"),
+            Self::Template(message) => write!(f, "template error: {message}"),
+            Self::Backup(message) => write!(f, "backup error: {message}"),
+            Self::Export(message) => write!(f, "export error: {message}"),
+            Self::IpDetection(message) => write!(f, "ip address detection failed: {message}"),
+            Self::Schedule(message) => write!(f, "schedule error: {message}"),
+            Self::PolicyDenied(message) => write!(f, "denied by policy: {message}"),
+            Self::Webhook(message) => write!(f, "webhook delivery failed: {message}"),
+            Self::Publish(message) => write!(f, "publishing event failed: {message}"),
+            Self::InvalidRecordValue(message) => write!(f, "invalid record value: {message}"),
         }
     }
 }
 
-impl std::error::Error for HetznerError {}
+impl HetznerError {
+    /// The API error payload, if the request reached the API and was rejected.
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            Self::Api(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// The underlying `reqwest` error, if the request failed below the API.
+    pub fn transport_error(&self) -> Option<&reqwest::Error> {
+        match self {
+            Self::Http(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// What went wrong below the API, if the request failed there.
+    pub fn transport_kind(&self) -> Option<TransportErrorKind> {
+        self.transport_error().map(TransportErrorKind::of)
+    }
+
+    /// Whether the request failed on the way to or from the API (network,
+    /// TLS, timeout) rather than being rejected by it. Misconfigured clients
+    /// ([`TransportErrorKind::Builder`]) are not transport failures.
+    pub fn is_transport_error(&self) -> bool {
+        self.transport_kind()
+            .is_some_and(|kind| kind != TransportErrorKind::Builder)
+    }
+
+    /// Whether the API answered with an error status.
+    pub fn is_api_error(&self) -> bool {
+        self.api_error().is_some()
+    }
+
+    /// Whether repeating the same request later may succeed: transport
+    /// timeouts and connection failures (but not TLS failures), rate
+    /// limiting, locked resources, and server-side errors. Conflicts (409)
+    /// are not: the request was understood but clashes with the resource's
+    /// state, so repeating it unchanged fails the same way.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Http(err) => matches!(
+                TransportErrorKind::of(err),
+                TransportErrorKind::Timeout
+                    | TransportErrorKind::Connect
+                    | TransportErrorKind::Request
+            ),
+            Self::Api(err) => {
+                err.status.is_server_error()
+                    || matches!(
+                        err.status,
+                        StatusCode::TOO_MANY_REQUESTS | StatusCode::LOCKED
+                    )
+                    || matches!(
+                        err.code.as_str(),
+                        "rate_limit_exceeded" | "locked" | "timeout" | "unavailable"
+                    )
+            }
+            _ => false,
+        }
+    }
+
+    pub fn is_rate_limited(&self) -> bool {
+        self.api_error().is_some_and(|err| {
+            err.status == StatusCode::TOO_MANY_REQUESTS || err.code == "rate_limit_exceeded"
+        })
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Self::ZoneNotFound(_) | Self::RecordNotFound(_) => true,
+            Self::Api(err) => err.status == StatusCode::NOT_FOUND || err.code == "not_found",
+            _ => false,
+        }
+    }
+
+    /// Whether the token was missing, invalid, or lacks permission for the request.
+    pub fn is_auth_error(&self) -> bool {
+        self.api_error().is_some_and(|err| {
+            matches!(err.status, StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN)
+                || matches!(
+                    err.code.as_str(),
+                    "unauthorized" | "forbidden" | "token_readonly"
+                )
+        })
+    }
+}
+
+impl std::error::Error for HetznerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Http(err) => Some(err),
+            Self::Serialization(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// The kind of a [`HetznerError::Http`] failure, for retry and alerting
+/// policies that treat e.g. timeouts differently from certificate problems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransportErrorKind {
+    /// The client's or request's timeout elapsed.
+    Timeout,
+    /// The TLS handshake failed, e.g. an untrusted certificate. Detected
+    /// from the error's description, as `reqwest` has no TLS error kind.
+    Tls,
+    /// No connection could be established (DNS resolution, refused, ...).
+    Connect,
+    /// The request failed after the connection was made, e.g. it was reset.
+    Request,
+    /// The response body could not be read.
+    Body,
+    /// The response could not be decoded, e.g. a broken gzip stream.
+    Decode,
+    /// Too many redirects, or a redirect loop.
+    Redirect,
+    /// The client or request was misconfigured, e.g. an invalid proxy URL.
+    Builder,
+    Other,
+}
+
+impl TransportErrorKind {
+    pub fn of(err: &reqwest::Error) -> Self {
+        if err.is_timeout() {
+            Self::Timeout
+        } else if err.is_connect() {
+            if is_tls_failure(err) {
+                Self::Tls
+            } else {
+                Self::Connect
+            }
+        } else if err.is_builder() {
+            Self::Builder
+        } else if err.is_redirect() {
+            Self::Redirect
+        } else if err.is_body() {
+            Self::Body
+        } else if err.is_decode() {
+            Self::Decode
+        } else if err.is_request() {
+            Self::Request
+        } else {
+            Self::Other
+        }
+    }
+}
+
+impl fmt::Display for TransportErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Timeout => "timeout",
+            Self::Tls => "tls",
+            Self::Connect => "connect",
+            Self::Request => "request",
+            Self::Body => "body",
+            Self::Decode => "decode",
+            Self::Redirect => "redirect",
+            Self::Builder => "builder",
+            Self::Other => "other",
+        })
+    }
+}
+
+fn is_tls_failure(err: &reqwest::Error) -> bool {
+    let mut source = std::error::Error::source(err);
+    while let Some(cause) = source {
+        let message = cause.to_string().to_ascii_lowercase();
+        if ["certificate", "tls", "ssl", "handshake"]
+            .iter()
+            .any(|needle| message.contains(needle))
+        {
+            return true;
+        }
+        source = cause.source();
+    }
+    false
+}
 
 impl From<reqwest::Error> for HetznerError {
     fn from(value: reqwest::Error) -> Self {
@@ -48,6 +303,98 @@ pub struct ApiError {
     pub code: String,
     pub message: String,
     pub details: Option<Value>,
+    /// The raw response body, truncated to 1024 characters.
+    pub body: String,
+    /// The `X-Request-Id` response header, when present.
+    pub request_id: Option<String>,
+    /// The id of the logical operation the failed request belonged to; see
+    /// [`HetznerClient::operation`](crate::HetznerClient::operation).
+    pub correlation_id: Option<String>,
+    /// Whether `code` was derived from the status because the response
+    /// carried no machine-readable one (the DNS API sends the bare status).
+    pub(crate) code_from_status: bool,
+}
+
+impl ApiError {
+    /// The typed form of [`code`](Self::code). DNS API validation errors only
+    /// carry the HTTP status, so their reason is taken from the message.
+    pub fn error_code(&self) -> ApiErrorCode {
+        if self.code_from_status {
+            ApiErrorCode::from_message(&self.message)
+                .unwrap_or_else(|| ApiErrorCode::parse(&self.code, ""))
+        } else {
+            ApiErrorCode::parse(&self.code, &self.message)
+        }
+    }
+}
+
+/// Error codes the Hetzner APIs are known to return. Codes are added as the
+/// APIs grow, so matches need a wildcard arm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ApiErrorCode {
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    InvalidInput,
+    JsonError,
+    UniquenessError,
+    Conflict,
+    Locked,
+    RateLimitExceeded,
+    ResourceLimitExceeded,
+    ServerError,
+    Unavailable,
+    Timeout,
+    TakenName,
+    InvalidTtl,
+    InvalidRecordValue,
+    ZoneNotFound,
+    RecordNotFound,
+    Unknown(String),
+}
+
+impl ApiErrorCode {
+    /// Reads the structured `code` first; only a code this crate does not
+    /// know (such as the bare status numbers of DNS API validation errors)
+    /// falls back to recognizing the message.
+    pub fn parse(code: &str, message: &str) -> Self {
+        match code {
+            "unauthorized" => Self::Unauthorized,
+            "forbidden" | "token_readonly" => Self::Forbidden,
+            "not_found" => Self::NotFound,
+            "invalid_input" => Self::InvalidInput,
+            "json_error" => Self::JsonError,
+            "uniqueness_error" => Self::UniquenessError,
+            "conflict" => Self::Conflict,
+            "locked" => Self::Locked,
+            "rate_limit_exceeded" => Self::RateLimitExceeded,
+            "resource_limit_exceeded" => Self::ResourceLimitExceeded,
+            "server_error" => Self::ServerError,
+            "unavailable" => Self::Unavailable,
+            "timeout" => Self::Timeout,
+            other => {
+                Self::from_message(message).unwrap_or_else(|| Self::Unknown(other.to_string()))
+            }
+        }
+    }
+
+    fn from_message(message: &str) -> Option<Self> {
+        let message = message.to_ascii_lowercase();
+        if message.contains("taken name") || message.contains("already exists") {
+            Some(Self::TakenName)
+        } else if message.contains("invalid ttl") {
+            Some(Self::InvalidTtl)
+        } else if message.contains("zone not found") {
+            Some(Self::ZoneNotFound)
+        } else if message.contains("record not found") {
+            Some(Self::RecordNotFound)
+        } else if message.contains("invalid value") || message.contains("invalid record") {
+            Some(Self::InvalidRecordValue)
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -57,10 +404,57 @@ pub struct ApiErrorEnvelope {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ApiErrorBody {
+    #[serde(default, deserialize_with = "string_or_number")]
     pub code: String,
     pub message: String,
     #[serde(default)]
     pub details: Option<Value>,
 }
 
+fn string_or_number<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<String, D::Error> {
+    Ok(match Value::deserialize(deserializer)? {
+        Value::String(code) => code,
+        Value::Number(code) => code.to_string(),
+        _ => String::new(),
+    })
+}
+
 pub type Result<T> = std::result::Result<T, HetznerError>;
+
+/// The request behind an error passed to the hook installed with
+/// [`HetznerClient::with_on_error`](crate::HetznerClient::with_on_error).
+#[derive(Debug, Clone)]
+pub struct ErrorContext {
+    pub method: Method,
+    /// The request URL without its query string.
+    pub url: String,
+    /// How many times the request was sent, including retries.
+    pub attempts: u32,
+    /// Time from the first attempt until the request was given up.
+    pub elapsed: Duration,
+    /// The id of the logical operation the request belonged to, if any; see
+    /// [`HetznerClient::operation`](crate::HetznerClient::operation).
+    pub correlation_id: Option<String>,
+}
+
+type ErrorCallback = dyn Fn(&HetznerError, &ErrorContext) + Send + Sync;
+
+#[derive(Clone)]
+pub(crate) struct ErrorHook(pub(crate) Arc<ErrorCallback>);
+
+impl fmt::Debug for ErrorHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ErrorHook")
+    }
+}
+
+/// Maps a not-found error to `Ok(None)` for the `*_opt` getters.
+pub(crate) fn not_found_as_none<T>(result: Result<T>) -> Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(err) if err.is_not_found() => Ok(None),
+        Err(err) => Err(err),
+    }
+}
@@ -0,0 +1,158 @@
+//! A local journal of DNS record writes that can be reverted.
+//!
+//! Attach an [`UndoJournal`] with
+//! [`HetznerClient::with_undo_journal`](crate::HetznerClient::with_undo_journal)
+//! and every successful record create, update or delete is stored together
+//! with the record's prior state, so accidental changes can be undone with
+//! [`HetznerClient::undo_last`](crate::HetznerClient::undo_last).
+
+use crate::HetznerClient;
+use crate::api::dns::records::UpdateRecordInput;
+use crate::error::{HetznerError, Result};
+use crate::mutation::{MutationEvent, MutationOperation};
+use crate::types::Record;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::UNIX_EPOCH;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct JournalEntry {
+    pub id: u64,
+    pub operation: MutationOperation,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub zone_id: Option<String>,
+    pub before: Option<Record>,
+    pub after: Option<Record>,
+    #[serde(default)]
+    pub reverted: bool,
+}
+
+#[derive(Debug)]
+pub struct UndoJournal {
+    path: PathBuf,
+    entries: Mutex<Vec<JournalEntry>>,
+}
+
+impl UndoJournal {
+    /// Opens the journal at `path`, continuing an existing file if present.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let failed = |err: &dyn std::fmt::Display| {
+            HetznerError::Journal(format!("failed to read {}: {err}", path.display()))
+        };
+        let entries = match fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).map_err(|err| failed(&err))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(failed(&err)),
+        };
+        Ok(Self {
+            path,
+            entries: Mutex::new(entries),
+        })
+    }
+
+    pub fn entries(&self) -> Vec<JournalEntry> {
+        self.entries.lock().unwrap().clone()
+    }
+
+    /// Undoes `entry`: deletes a created record, restores an updated record's
+    /// previous fields, or re-creates a deleted record (under a new id).
+    /// Reverting is not itself journaled.
+    pub async fn revert(&self, client: &HetznerClient, entry: &JournalEntry) -> Result<()> {
+        let client = HetznerClient {
+            undo_journal: None,
+            ..client.correlated().into_owned()
+        };
+        let missing =
+            |what: &str| HetznerError::Journal(format!("entry {} has no {what} state", entry.id));
+
+        match entry.operation {
+            MutationOperation::CreateRecord => {
+                let after = entry.after.as_ref().ok_or_else(|| missing("resulting"))?;
+                client.dns().record(&after.id).delete().await?;
+            }
+            MutationOperation::UpdateRecord => {
+                let before = entry.before.as_ref().ok_or_else(|| missing("prior"))?;
+                client
+                    .dns()
+                    .record(&before.id)
+                    .update(UpdateRecordInput::from(before))
+                    .await?;
+            }
+            MutationOperation::DeleteRecord => {
+                let before = entry.before.as_ref().ok_or_else(|| missing("prior"))?;
+                client
+                    .dns()
+                    .records(&before.zone_id)
+                    .create(
+                        before.name.clone(),
+                        before.record_type.clone(),
+                        before.value.clone(),
+                        before.ttl,
+                    )
+                    .await?;
+            }
+            other => {
+                return Err(HetznerError::Journal(format!(
+                    "{other:?} entries cannot be reverted"
+                )));
+            }
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+        if let Some(stored) = entries.iter_mut().find(|e| e.id == entry.id) {
+            stored.reverted = true;
+        }
+        self.persist(&entries)
+    }
+
+    pub(crate) fn last_unreverted(&self) -> Option<JournalEntry> {
+        let entries = self.entries.lock().unwrap();
+        entries.iter().rev().find(|e| !e.reverted).cloned()
+    }
+
+    pub(crate) fn append(&self, event: &MutationEvent) -> Result<()> {
+        let mut entries = self.entries.lock().unwrap();
+        let id = entries.last().map_or(1, |entry| entry.id + 1);
+        entries.push(JournalEntry {
+            id,
+            operation: event.operation,
+            timestamp: event
+                .timestamp
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            zone_id: event.zone_id.clone(),
+            before: event.before.clone(),
+            after: event.after.clone(),
+            reverted: false,
+        });
+        self.persist(&entries)
+    }
+
+    fn persist(&self, entries: &[JournalEntry]) -> Result<()> {
+        let serialized = serde_json::to_string_pretty(entries)?;
+        fs::write(&self.path, serialized).map_err(|err| {
+            HetznerError::Journal(format!("failed to write {}: {err}", self.path.display()))
+        })
+    }
+}
+
+impl HetznerClient {
+    /// Reverts the most recent journal entry that has not been reverted yet,
+    /// returning it, or `None` when there is nothing left to undo.
+    pub async fn undo_last(&self) -> Result<Option<JournalEntry>> {
+        let journal = self
+            .undo_journal
+            .as_ref()
+            .ok_or_else(|| HetznerError::Journal("no undo journal configured".to_string()))?;
+        let Some(entry) = journal.last_unreverted() else {
+            return Ok(None);
+        };
+        journal.revert(self, &entry).await?;
+        Ok(Some(entry))
+    }
+}
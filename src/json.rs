@@ -0,0 +1,21 @@
+//! Decoding of API response bodies.
+//!
+//! Bodies are deserialized straight into the requested type, without a
+//! `serde_json::Value` in between. With the `simd-json` feature they are
+//! parsed with simd-json first; a body it rejects is parsed again with
+//! serde_json, so decoding errors are always `serde_json::Error`s.
+
+use serde::de::DeserializeOwned;
+
+pub(crate) fn from_slice<T: DeserializeOwned>(body: &[u8]) -> serde_json::Result<T> {
+    #[cfg(feature = "simd-json")]
+    {
+        // simd-json parses in place, and the original body is still needed
+        // for the fallback and for logging a failed parse.
+        let mut scratch = body.to_vec();
+        if let Ok(parsed) = simd_json::serde::from_slice(&mut scratch) {
+            return Ok(parsed);
+        }
+    }
+    serde_json::from_slice(body)
+}
@@ -3,11 +3,31 @@
 //! This crate currently ships DNS support and is structured for additional
 //! Hetzner APIs over time.
 
+pub mod accounts;
 pub mod api;
+pub mod circuit_breaker;
 pub mod client;
+pub mod dry_run;
 pub mod error;
+pub mod journal;
+mod json;
+mod logging;
+pub mod mutation;
+pub mod policy;
+pub mod prelude;
+pub mod progress;
+pub mod rate_limit;
+pub mod read_cache;
+pub mod retry;
+pub mod state;
+#[cfg(feature = "table")]
+pub mod table;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod types;
+pub mod vcr;
 
+pub use accounts::HetznerAccounts;
 pub use api::cloud::{
     actions::ListActionsParams,
     domains::DomainsApi,
@@ -19,11 +39,73 @@ pub use api::cloud::{
     servers_api::ServersFullApi,
     storage::StorageApi,
 };
-pub use client::HetznerClient;
-pub use error::{ApiError, HetznerError, Result};
+#[cfg(feature = "acme")]
+pub use api::dns::acme::Dns01Solver;
+pub use api::dns::acme::{AcmeChallenge, AcmeHookAction};
+#[cfg(feature = "axfr")]
+pub use api::dns::axfr::ZoneComparison;
+pub use api::dns::backup::{
+    BackupFailure, BackupOptions, BackupProgress, BackupReport, RestorePlan, ZoneBackup,
+    ZoneRestorePlan,
+};
+pub use api::dns::batch::{
+    AppliedChange, Batch, BatchFailure, BatchOperation, BatchResult, RollbackReport,
+};
+#[cfg(feature = "dyndns")]
+pub use api::dns::dyndns::{DynDns, DynDnsUpdate, replace_prefix};
+pub use api::dns::filter::RecordFilter;
+#[cfg(feature = "health")]
+pub use api::dns::health::{HealthReport, NameserverStatus, ResolutionFailure};
+pub use api::dns::hetzner_dns_api::HetznerDnsApi;
+#[cfg(feature = "dyndns")]
+pub use api::dns::ip_source::{
+    Consensus, HttpsSource, InterfaceAddress, InterfaceSource, IpSource, IpVersion, UpnpSource,
+};
+pub use api::dns::lease::Lease;
+pub use api::dns::lint::{LintFinding, LintRule, Severity};
+pub use api::dns::maintenance::MaintenanceSnapshot;
+pub use api::dns::names::{RecordName, ZoneName};
+pub use api::dns::page::Page;
+pub use api::dns::presets::{Preset, PresetPlan};
+#[cfg(feature = "propagation")]
+pub use api::dns::propagation::{PropagationStatus, ResolverAnswer};
+pub use api::dns::provider::{DnsProvider, DnsRecord};
+#[cfg(any(feature = "mqtt", feature = "nats"))]
+pub use api::dns::publisher::EventPublisher;
+pub use api::dns::record_ref::RecordRef;
+pub use api::dns::record_value::{ParseIssue, RecordValue, TypedRecord, TypedRecords};
+pub use api::dns::records::Ttl;
+#[cfg(feature = "scheduler")]
+pub use api::dns::scheduler::{HealthProbe, ScheduleStatus, ScheduledChange, Scheduler};
+pub use api::dns::search::{RecordMatch, SearchScope};
+pub use api::dns::secondary::SecondaryZone;
+pub use api::dns::sort::{Sort, SortKey};
+pub use api::dns::stats::{TxtPayload, ZoneStats};
+pub use api::dns::template::RecordTemplate;
+pub use api::dns::watch::{ChangeEvent, ChangeKind};
+#[cfg(feature = "webhook-emitter")]
+pub use api::dns::webhook_emitter::{WebhookEmitter, WebhookEvent};
+#[cfg(feature = "webhook-server")]
+pub use api::dns::webhook_server::{UpdateRequest, WebhookServer};
+pub use api::dns::zone_ref::ZoneRef;
+pub use api::dns::zones::ZoneVerification;
+pub use circuit_breaker::CircuitBreaker;
+pub use client::{HetznerClient, HetznerClientBuilder};
+pub use dry_run::DryRunRequest;
+pub use error::{ApiError, ApiErrorCode, ErrorContext, HetznerError, Result, TransportErrorKind};
+pub use journal::{JournalEntry, UndoJournal};
+pub use mutation::{MutationEvent, MutationOperation, MutationOutcome};
+pub use progress::Progress;
+pub use rate_limit::{RateLimit, RateLimitStatus};
+pub use read_cache::{ReadCache, ReadCacheStats};
+pub use retry::{BackoffPolicy, ConstantBackoff, ExponentialBackoff, FibonacciBackoff};
+pub use state::{DriftReport, StateFile};
+pub use tokio_util::sync::CancellationToken;
 pub use types::{
-    Action, ActionEnvelope, ActionError, ActionResource, ActionsEnvelope, CloudServer,
-    CloudServerEnvelope, CloudServersEnvelope, CreateServerResponse, CreatedRecord, Meta,
-    Pagination, Record, RecordEnvelope, RecordsEnvelope, TxtVerification, Zone, ZoneType,
-    ZonesEnvelope,
+    Action, ActionEnvelope, ActionError, ActionResource, ActionsEnvelope, BillingPeriod,
+    CloudServer, CloudServerEnvelope, CloudServersEnvelope, CreateServerResponse, CreatedRecord,
+    Meta, Pagination, PrimaryServer, PrimaryServerEnvelope, PrimaryServersEnvelope, Record,
+    RecordEnvelope, RecordKey, RecordType, RecordsEnvelope, TxtVerification, Zone, ZoneBuilder,
+    ZoneEnvelope, ZonePrice, ZoneType, ZonesEnvelope, normalize_value,
 };
+pub use vcr::{Cassette, VcrMode};
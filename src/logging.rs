@@ -0,0 +1,41 @@
+//! The logging macros used throughout the crate: `tracing`'s with the
+//! `tracing` feature (on by default), otherwise stand-ins that compile to
+//! nothing, so minimal binaries don't link a logging stack they never read.
+
+#[cfg(feature = "tracing")]
+#[allow(unused_imports)]
+pub(crate) use tracing::{Instrument, debug, debug_span, error, info, info_span, warn};
+
+#[cfg(not(feature = "tracing"))]
+mod disabled {
+    macro_rules! discard {
+        ($($tokens:tt)*) => {{}};
+    }
+
+    macro_rules! span {
+        ($($tokens:tt)*) => {
+            $crate::logging::Span
+        };
+    }
+
+    #[allow(unused_imports)]
+    pub(crate) use {
+        discard as debug, discard as error, discard as info, discard as warn, span as debug_span,
+        span as info_span,
+    };
+
+    /// Stands in for `tracing::Span`.
+    pub(crate) struct Span;
+
+    /// Stands in for `tracing::Instrument`.
+    pub(crate) trait Instrument: Sized {
+        fn instrument(self, _span: Span) -> Self {
+            self
+        }
+    }
+
+    impl<T> Instrument for T {}
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) use disabled::*;
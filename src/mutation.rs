@@ -0,0 +1,210 @@
+//! Structured events for DNS writes, delivered to the hook installed with
+//! [`HetznerClient::with_on_mutation`](crate::HetznerClient::with_on_mutation).
+//!
+//! With the `json-logs` feature every write is also logged as one tracing
+//! event with target `hetzner::mutation` and flat fields (`operation`,
+//! `zone_id`, `resource_id`, `record_name`, `record_type`, `record_value`,
+//! `result`, `error`, `latency_ms`, `correlation_id`), ready for a JSON
+//! subscriber such as `tracing_subscriber::fmt().json()`.
+
+use crate::HetznerClient;
+use crate::error::Result;
+use crate::logging::warn;
+use crate::types::Record;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MutationOperation {
+    CreateRecord,
+    UpdateRecord,
+    DeleteRecord,
+    CreateZone,
+    CreatePrimaryServer,
+    UpdatePrimaryServer,
+    DeletePrimaryServer,
+}
+
+impl MutationOperation {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::CreateRecord => "create_record",
+            Self::UpdateRecord => "update_record",
+            Self::DeleteRecord => "delete_record",
+            Self::CreateZone => "create_zone",
+            Self::CreatePrimaryServer => "create_primary_server",
+            Self::UpdatePrimaryServer => "update_primary_server",
+            Self::DeletePrimaryServer => "delete_primary_server",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MutationOutcome {
+    Succeeded,
+    Failed(String),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MutationEvent {
+    pub operation: MutationOperation,
+    pub zone_id: Option<String>,
+    /// The id of the record, zone or primary server written to.
+    pub resource_id: Option<String>,
+    /// The record as it was before an update or delete, when it could be read.
+    pub before: Option<Record>,
+    /// The record as returned by a create or update.
+    pub after: Option<Record>,
+    pub timestamp: SystemTime,
+    /// How long the write request took.
+    #[serde(default)]
+    pub elapsed: Duration,
+    pub outcome: MutationOutcome,
+    /// The correlation id shared by the requests behind this write.
+    pub correlation_id: Option<String>,
+}
+
+type MutationCallback = dyn Fn(&MutationEvent) + Send + Sync;
+
+#[derive(Clone)]
+pub(crate) struct MutationHook(Arc<MutationCallback>);
+
+impl MutationHook {
+    pub(crate) fn new(callback: impl Fn(&MutationEvent) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+}
+
+impl fmt::Debug for MutationHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MutationHook")
+    }
+}
+
+/// What a write call site knows about the mutation besides its result.
+pub(crate) struct Mutation {
+    /// When the write request was sent.
+    pub(crate) started: Instant,
+    pub(crate) operation: MutationOperation,
+    pub(crate) zone_id: Option<String>,
+    pub(crate) resource_id: Option<String>,
+    pub(crate) before: Option<Record>,
+}
+
+impl HetznerClient {
+    pub(crate) fn observes_mutations(&self) -> bool {
+        self.dry_run.is_none() && (self.on_mutation.is_some() || self.undo_journal.is_some())
+    }
+
+    /// Reads a record's current state ahead of an update or delete, but only
+    /// when someone is listening or a policy checks it. A failed read leaves
+    /// `before` empty for hooks, but fails the write when a policy needs it.
+    pub(crate) async fn state_before_mutation(&self, record_id: &str) -> Result<Option<Record>> {
+        if !self.needs_prior_state() {
+            return Ok(None);
+        }
+        match self.dns().record(record_id).get_opt().await {
+            Ok(record) => Ok(record),
+            Err(err) if self.policy.is_some() => Err(err),
+            Err(_) => Ok(None),
+        }
+    }
+
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn emit_mutation<T>(
+        &self,
+        mutation: Mutation,
+        result: &Result<T>,
+        after: impl FnOnce(&T) -> Option<Record>,
+    ) {
+        if let Some(cache) = &self.read_cache
+            && result.is_ok()
+            && !self.is_dry_run()
+        {
+            let zone_id = mutation.zone_id.as_deref().or_else(|| {
+                mutation
+                    .before
+                    .as_ref()
+                    .map(|record| record.zone_id.as_str())
+            });
+            cache.invalidate(mutation.operation, zone_id);
+        }
+        // A dry run changed nothing, so there is nothing to report.
+        if self.is_dry_run() || !self.observes_mutations() && !cfg!(feature = "json-logs") {
+            return;
+        }
+        let (after, outcome) = match result {
+            Ok(value) => (after(value), MutationOutcome::Succeeded),
+            Err(err) => (None, MutationOutcome::Failed(err.to_string())),
+        };
+        let zone_id = mutation
+            .zone_id
+            .or_else(|| after.as_ref().map(|record| record.zone_id.clone()))
+            .or_else(|| {
+                mutation
+                    .before
+                    .as_ref()
+                    .map(|record| record.zone_id.clone())
+            });
+        let event = MutationEvent {
+            operation: mutation.operation,
+            zone_id,
+            resource_id: mutation.resource_id,
+            before: mutation.before,
+            after,
+            timestamp: SystemTime::now(),
+            elapsed: mutation.started.elapsed(),
+            outcome,
+            correlation_id: self.correlation_id.clone(),
+        };
+        #[cfg(feature = "json-logs")]
+        log_mutation(&event);
+        if let Some(hook) = &self.on_mutation {
+            (hook.0)(&event);
+        }
+        if let Some(journal) = &self.undo_journal
+            && event.outcome == MutationOutcome::Succeeded
+            && matches!(
+                event.operation,
+                MutationOperation::CreateRecord
+                    | MutationOperation::UpdateRecord
+                    | MutationOperation::DeleteRecord
+            )
+            && let Err(err) = journal.append(&event)
+        {
+            warn!("failed to journal {:?}: {err}", event.operation);
+        }
+    }
+}
+
+#[cfg(feature = "json-logs")]
+fn log_mutation(event: &MutationEvent) {
+    let record = event.after.as_ref().or(event.before.as_ref());
+    // The level of a tracing event must be known at compile time.
+    macro_rules! log {
+        ($level:ident, $result:literal, $error:expr) => {
+            tracing::$level!(
+                target: "hetzner::mutation",
+                operation = event.operation.as_str(),
+                zone_id = event.zone_id.as_deref(),
+                resource_id = event.resource_id.as_deref(),
+                record_name = record.map(|record| record.name.as_str()),
+                record_type = record.map(|record| record.record_type.as_str()),
+                record_value = record.map(|record| record.value.as_str()),
+                result = $result,
+                error = $error,
+                latency_ms = event.elapsed.as_millis() as u64,
+                correlation_id = event.correlation_id.as_deref(),
+                "dns mutation"
+            )
+        };
+    }
+    match &event.outcome {
+        MutationOutcome::Succeeded => log!(info, "succeeded", None::<&str>),
+        MutationOutcome::Failed(message) => log!(warn, "failed", message.as_str()),
+    }
+}
@@ -0,0 +1,376 @@
+//! Rules checked before DNS writes (records, zone creation and primary
+//! servers) are sent; installed with
+//! [`HetznerClient::with_policy`](crate::HetznerClient::with_policy).
+//!
+//! A change whose zone, or for records whose type, cannot be determined is
+//! denied, and a failed read of that data fails the write, so a transient
+//! error never lets a change slip past a rule.
+//!
+//! ```
+//! use hetzner::policy::{MinTtl, NoDelete, PolicySet, ZoneRequiresFlag};
+//! use hetzner::{HetznerClient, RecordType, Ttl};
+//!
+//! let client = HetznerClient::new("token").with_policy(
+//!     PolicySet::new()
+//!         .with(MinTtl(Ttl::FIVE_MINUTES))
+//!         .with(NoDelete::of([RecordType::Mx, RecordType::Ns]))
+//!         .with(ZoneRequiresFlag::new("example.com", "prod-approved")),
+//! );
+//! // Changes to example.com are only allowed on a client carrying the flag.
+//! let approved = client.clone().with_flag("prod-approved");
+//! ```
+
+use crate::HetznerClient;
+use crate::api::dns::provider::DnsRecord;
+use crate::api::dns::records::Ttl;
+use crate::error::{HetznerError, Result};
+use crate::logging::warn;
+use crate::mutation::MutationOperation;
+use crate::types::{Record, RecordType};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::Arc;
+
+/// A change about to be sent.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ProposedChange {
+    pub operation: MutationOperation,
+    /// `None` for zone creation, whose zone has no id yet.
+    pub zone_id: Option<String>,
+    pub zone_name: Option<String>,
+    /// The record as it will be, for creates and updates.
+    pub record: Option<DnsRecord>,
+    /// The record as it is, for updates and deletes, when it could be read.
+    pub before: Option<Record>,
+    /// The [flags](crate::HetznerClient::with_flag) of the client making
+    /// the change.
+    pub flags: BTreeSet<String>,
+}
+
+impl ProposedChange {
+    pub(crate) fn new(
+        operation: MutationOperation,
+        zone_id: Option<String>,
+        record: Option<DnsRecord>,
+        before: Option<Record>,
+    ) -> Self {
+        let zone_id = zone_id.or_else(|| before.as_ref().map(|record| record.zone_id.clone()));
+        Self {
+            operation,
+            zone_id,
+            zone_name: None,
+            record,
+            before,
+            flags: BTreeSet::new(),
+        }
+    }
+
+    /// Names the zone of a change that has no zone id yet.
+    pub(crate) fn with_zone_name(mut self, zone_name: impl Into<String>) -> Self {
+        self.zone_name = Some(zone_name.into());
+        self
+    }
+
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags.contains(flag)
+    }
+
+    /// Whether the change is in `zone`, given by id or name.
+    pub fn in_zone(&self, zone: &str) -> bool {
+        let zone = zone.trim_end_matches('.');
+        self.zone_id.as_deref() == Some(zone)
+            || self
+                .zone_name
+                .as_deref()
+                .is_some_and(|name| name.trim_end_matches('.').eq_ignore_ascii_case(zone))
+    }
+
+    /// The type of the record being changed.
+    pub fn record_type(&self) -> Option<RecordType> {
+        self.record
+            .as_ref()
+            .map(|record| RecordType::from(record.record_type.as_str()))
+            .or_else(|| self.before.as_ref().map(Record::kind))
+    }
+
+    /// What the policies may need to know about the change but can't be
+    /// determined, e.g. the zone of a record that doesn't exist.
+    fn unknown(&self) -> Option<&'static str> {
+        let record_change = matches!(
+            self.operation,
+            MutationOperation::CreateRecord
+                | MutationOperation::UpdateRecord
+                | MutationOperation::DeleteRecord
+        );
+        if self.zone_name.is_none() {
+            Some("its zone")
+        } else if record_change && self.record_type().is_none() {
+            Some("its record type")
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for ProposedChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.operation.as_str())?;
+        if let Some(record) = self.record.as_ref() {
+            write!(
+                f,
+                " {} {} {}",
+                record.name, record.record_type, record.value
+            )?;
+        } else if let Some(record) = &self.before {
+            write!(
+                f,
+                " {} {} {}",
+                record.name, record.record_type, record.value
+            )?;
+        }
+        match (&self.zone_name, &self.zone_id) {
+            (Some(zone), _) | (None, Some(zone)) => write!(f, " in {zone}"),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Decision {
+    Allow,
+    /// Allowed, but logged as a warning.
+    Warn(String),
+    /// Not sent; the call fails with [`HetznerError::PolicyDenied`].
+    Deny(String),
+}
+
+/// Decides whether a record change may be made.
+pub trait Policy: Send + Sync {
+    fn evaluate(&self, change: &ProposedChange) -> Decision;
+}
+
+impl<F> Policy for F
+where
+    F: Fn(&ProposedChange) -> Decision + Send + Sync,
+{
+    fn evaluate(&self, change: &ProposedChange) -> Decision {
+        self(change)
+    }
+}
+
+/// Several policies together: a change is denied if any of them denies it,
+/// and every warning is reported.
+#[derive(Default)]
+pub struct PolicySet {
+    policies: Vec<Box<dyn Policy>>,
+}
+
+impl PolicySet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, policy: impl Policy + 'static) -> Self {
+        self.policies.push(Box::new(policy));
+        self
+    }
+}
+
+impl fmt::Debug for PolicySet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PolicySet")
+            .field("policies", &self.policies.len())
+            .finish()
+    }
+}
+
+impl Policy for PolicySet {
+    fn evaluate(&self, change: &ProposedChange) -> Decision {
+        let mut denials = Vec::new();
+        let mut warnings = Vec::new();
+        for policy in &self.policies {
+            match policy.evaluate(change) {
+                Decision::Allow => {}
+                Decision::Warn(reason) => warnings.push(reason),
+                Decision::Deny(reason) => denials.push(reason),
+            }
+        }
+        if !denials.is_empty() {
+            Decision::Deny(denials.join("; "))
+        } else if !warnings.is_empty() {
+            Decision::Warn(warnings.join("; "))
+        } else {
+            Decision::Allow
+        }
+    }
+}
+
+/// Reports what `policy` would deny as a warning instead, e.g. while
+/// introducing a rule.
+#[derive(Debug, Clone)]
+pub struct WarnOnly<P>(pub P);
+
+impl<P: Policy> Policy for WarnOnly<P> {
+    fn evaluate(&self, change: &ProposedChange) -> Decision {
+        match self.0.evaluate(change) {
+            Decision::Deny(reason) => Decision::Warn(reason),
+            decision => decision,
+        }
+    }
+}
+
+/// Denies explicit TTLs below the given one; records inheriting the zone
+/// default pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinTtl(pub Ttl);
+
+impl Policy for MinTtl {
+    fn evaluate(&self, change: &ProposedChange) -> Decision {
+        match change.record.as_ref().and_then(|record| record.ttl) {
+            Some(ttl) if ttl < self.0 => Decision::Deny(format!(
+                "TTL {}s is below the minimum of {}s",
+                ttl.seconds(),
+                self.0.seconds()
+            )),
+            _ => Decision::Allow,
+        }
+    }
+}
+
+/// Denies deleting records of the given types, e.g. MX records whose loss
+/// stops mail delivery.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoDelete {
+    pub record_types: Vec<RecordType>,
+}
+
+impl NoDelete {
+    pub fn of(record_types: impl IntoIterator<Item = RecordType>) -> Self {
+        Self {
+            record_types: record_types.into_iter().collect(),
+        }
+    }
+}
+
+impl Policy for NoDelete {
+    fn evaluate(&self, change: &ProposedChange) -> Decision {
+        if change.operation != MutationOperation::DeleteRecord {
+            return Decision::Allow;
+        }
+        match change.record_type() {
+            Some(record_type) if self.record_types.contains(&record_type) => {
+                Decision::Deny(format!("deleting {record_type} records is not allowed"))
+            }
+            _ => Decision::Allow,
+        }
+    }
+}
+
+/// Denies changes in a zone (given by id or name) unless the client carries
+/// `flag`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneRequiresFlag {
+    pub zone: String,
+    pub flag: String,
+}
+
+impl ZoneRequiresFlag {
+    pub fn new(zone: impl Into<String>, flag: impl Into<String>) -> Self {
+        Self {
+            zone: zone.into(),
+            flag: flag.into(),
+        }
+    }
+}
+
+impl Policy for ZoneRequiresFlag {
+    fn evaluate(&self, change: &ProposedChange) -> Decision {
+        if change.in_zone(&self.zone) && !change.has_flag(&self.flag) {
+            Decision::Deny(format!(
+                "changes to {} require the {:?} flag",
+                self.zone, self.flag
+            ))
+        } else {
+            Decision::Allow
+        }
+    }
+}
+
+/// The policy installed on a client.
+#[derive(Clone)]
+pub(crate) struct PolicyHook(pub(crate) Arc<dyn Policy>);
+
+impl fmt::Debug for PolicyHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PolicyHook(..)")
+    }
+}
+
+impl HetznerClient {
+    /// Whether writes need the record's prior state, for hooks or policies.
+    pub(crate) fn needs_prior_state(&self) -> bool {
+        self.observes_mutations() || self.policy.is_some()
+    }
+
+    /// Evaluates the client's policy for all `changes` before any is sent:
+    /// logs warnings and fails with [`HetznerError::PolicyDenied`] listing
+    /// every denied change. The changes are only built when the client has
+    /// a policy.
+    pub(crate) async fn enforce_policy(
+        &self,
+        changes: impl FnOnce() -> Vec<ProposedChange>,
+    ) -> Result<()> {
+        let Some(policy) = &self.policy else {
+            return Ok(());
+        };
+        let mut denials = Vec::new();
+        for mut change in changes() {
+            change.flags.clone_from(&self.flags);
+            if let Some(zone_id) = &change.zone_id
+                && change.zone_name.is_none()
+            {
+                change.zone_name = self.zone_name(zone_id).await?;
+            }
+            if let Some(unknown) = change.unknown() {
+                denials.push(format!("{change}: {unknown} could not be determined"));
+                continue;
+            }
+            match policy.0.evaluate(&change) {
+                Decision::Allow => {}
+                #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+                Decision::Warn(reason) => {
+                    warn!(target: "hetzner::policy", change = %change, %reason, "policy warning");
+                }
+                Decision::Deny(reason) => denials.push(format!("{change}: {reason}")),
+            }
+        }
+        if denials.is_empty() {
+            Ok(())
+        } else {
+            Err(HetznerError::PolicyDenied(denials.join("; ")))
+        }
+    }
+
+    /// The name of zone `zone_id`, from the cache or else read from the API;
+    /// `None` if there is no such zone.
+    async fn zone_name(&self, zone_id: &str) -> Result<Option<String>> {
+        if let Some(name) = self.zone_ids.name_of(zone_id) {
+            return Ok(Some(name));
+        }
+        let Some(envelope) = self.dns().zone(zone_id).get_opt().await? else {
+            return Ok(None);
+        };
+        self.zone_ids.insert(&envelope.zone);
+        Ok(Some(envelope.zone.name))
+    }
+
+    /// A copy that skips policy checks, for changes already checked as a
+    /// whole.
+    pub(crate) fn without_policy(&self) -> Self {
+        let mut client = self.clone();
+        client.policy = None;
+        client
+    }
+}
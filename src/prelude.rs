@@ -0,0 +1,49 @@
+//! The types most applications need, in one import:
+//!
+//! ```no_run
+//! use hetzner::prelude::*;
+//!
+//! # async fn run() -> Result<()> {
+//! let client = HetznerClient::new("token");
+//! let record = client
+//!     .dns()
+//!     .records("example.com")
+//!     .create("www", RecordType::A, "192.0.2.1", Ttl::HOUR)
+//!     .await?
+//!     .record;
+//! println!("{record}");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Besides the client, its errors and [`Result`], it brings in the DNS and
+//! Cloud models, the request builders and inputs, and the traits whose
+//! methods callers use ([`HetznerDnsApi`], [`DnsProvider`]) or implement
+//! ([`Policy`], [`BackoffPolicy`]).
+
+pub use crate::api::cloud::{
+    actions::ListActionsParams,
+    servers::{CreateServerInput, ListServersParams},
+};
+pub use crate::api::dns::batch::{Batch, BatchResult};
+pub use crate::api::dns::filter::RecordFilter;
+pub use crate::api::dns::hetzner_dns_api::HetznerDnsApi;
+pub use crate::api::dns::page::Page;
+pub use crate::api::dns::primary_servers::PrimaryServerInput;
+pub use crate::api::dns::provider::{DnsProvider, DnsRecord};
+pub use crate::api::dns::record_ref::RecordRef;
+pub use crate::api::dns::record_value::{RecordValue, TypedRecord};
+pub use crate::api::dns::records::{CreateRecordInput, Ttl, UpdateRecordInput};
+pub use crate::api::dns::search::{RecordMatch, SearchScope};
+pub use crate::api::dns::sort::{Sort, SortKey};
+pub use crate::api::dns::zone_ref::ZoneRef;
+pub use crate::api::dns::zones::{CreateZoneInput, ListZonesParams};
+pub use crate::client::{HetznerClient, HetznerClientBuilder};
+pub use crate::error::{ApiError, ApiErrorCode, HetznerError, Result};
+pub use crate::policy::Policy;
+pub use crate::progress::Progress;
+pub use crate::retry::BackoffPolicy;
+pub use crate::types::{
+    Action, CloudServer, CreatedRecord, PrimaryServer, Record, RecordKey, RecordType, Zone,
+    ZoneBuilder,
+};
@@ -0,0 +1,109 @@
+//! Progress reporting for long-running operations: [batch
+//! commits](crate::Batch::on_progress), [restores](crate::RestorePlan::apply_with_progress)
+//! and, through [`BackupProgress`](crate::BackupProgress), account backups.
+//!
+//! ```no_run
+//! # async fn run(client: hetzner::HetznerClient, plan: hetzner::RestorePlan) {
+//! let outcomes = plan
+//!     .apply_with_progress(&client, |progress| {
+//!         eprintln!(
+//!             "{}/{} {} (eta {:?})",
+//!             progress.done,
+//!             progress.total,
+//!             progress.zone.as_deref().unwrap_or(""),
+//!             progress.eta()
+//!         )
+//!     })
+//!     .await;
+//! # }
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How far an operation has come, reported after each step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Progress {
+    /// Items finished so far, successfully or not.
+    pub done: usize,
+    pub total: usize,
+    /// The zone the last finished item belonged to.
+    pub zone: Option<String>,
+    /// Time since the operation started.
+    pub elapsed: Duration,
+}
+
+impl Progress {
+    /// The remaining time if the remaining items take as long as the
+    /// finished ones did on average; `None` before the first item.
+    pub fn eta(&self) -> Option<Duration> {
+        eta(self.done, self.total, self.elapsed)
+    }
+
+    /// `done / total` between 0 and 1; 1 for an empty operation.
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.done as f64 / self.total as f64
+        }
+    }
+}
+
+pub(crate) fn eta(done: usize, total: usize, elapsed: Duration) -> Option<Duration> {
+    if done == 0 {
+        return None;
+    }
+    let remaining = total.saturating_sub(done) as u32;
+    Some(elapsed / done as u32 * remaining)
+}
+
+/// A progress callback, shared by clones of the operation it was given to.
+#[derive(Clone)]
+pub(crate) struct ProgressHook(Arc<dyn Fn(&Progress) + Send + Sync>);
+
+impl ProgressHook {
+    pub(crate) fn new(callback: impl Fn(&Progress) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+}
+
+impl fmt::Debug for ProgressHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ProgressHook(..)")
+    }
+}
+
+/// Counts finished items of one operation and reports them to a hook.
+#[derive(Debug)]
+pub(crate) struct ProgressTracker<'a> {
+    hook: Option<&'a ProgressHook>,
+    total: usize,
+    done: usize,
+    started: Instant,
+}
+
+impl<'a> ProgressTracker<'a> {
+    pub(crate) fn new(hook: Option<&'a ProgressHook>, total: usize) -> Self {
+        Self {
+            hook,
+            total,
+            done: 0,
+            started: Instant::now(),
+        }
+    }
+
+    /// Marks `items` more as finished and reports the new state.
+    pub(crate) fn advance(&mut self, items: usize, zone: Option<&str>) {
+        self.done = (self.done + items).min(self.total);
+        if let Some(hook) = self.hook {
+            (hook.0)(&Progress {
+                done: self.done,
+                total: self.total,
+                zone: zone.map(str::to_string),
+                elapsed: self.started.elapsed(),
+            });
+        }
+    }
+}
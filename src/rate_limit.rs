@@ -0,0 +1,205 @@
+//! Rate-limit telemetry gathered from responses, exposed through
+//! [`HetznerClient::rate_limit_status`](crate::HetznerClient::rate_limit_status),
+//! and client-side pacing set up with
+//! [`HetznerClient::with_rate_limit`](crate::HetznerClient::with_rate_limit).
+
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime};
+
+const REQUEST_WINDOW: Duration = Duration::from_secs(60);
+
+/// The most recent rate-limit headers seen by a client (and its clones),
+/// plus how many requests it sent in the last minute.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RateLimitStatus {
+    /// `RateLimit-Limit`: requests allowed per window.
+    pub limit: Option<u64>,
+    /// `RateLimit-Remaining`: requests left in the current window.
+    pub remaining: Option<u64>,
+    /// `RateLimit-Reset`: unix timestamp at which the limit is fully restored.
+    pub reset: Option<u64>,
+    /// `Retry-After` of the last rate-limited response, in seconds.
+    pub retry_after: Option<u64>,
+    /// When the headers above were received.
+    pub observed_at: Option<SystemTime>,
+    pub requests_last_minute: usize,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct RateLimitSnapshot {
+    pub(crate) limit: Option<u64>,
+    pub(crate) remaining: Option<u64>,
+    pub(crate) reset: Option<u64>,
+    pub(crate) retry_after: Option<u64>,
+}
+
+impl RateLimitSnapshot {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Self {
+        Self {
+            limit: header_u64(headers, "RateLimit-Limit"),
+            remaining: header_u64(headers, "RateLimit-Remaining"),
+            reset: header_u64(headers, "RateLimit-Reset"),
+            retry_after: header_u64(headers, "Retry-After"),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.limit.is_none()
+            && self.remaining.is_none()
+            && self.reset.is_none()
+            && self.retry_after.is_none()
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct RateLimitTracker {
+    state: Mutex<TrackerState>,
+}
+
+#[derive(Debug, Default)]
+struct TrackerState {
+    last: RateLimitSnapshot,
+    observed_at: Option<SystemTime>,
+    sent: VecDeque<Instant>,
+}
+
+impl RateLimitTracker {
+    pub(crate) fn record(&self, snapshot: RateLimitSnapshot) {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        state.sent.push_back(now);
+        prune(&mut state.sent, now);
+        if !snapshot.is_empty() {
+            state.last = snapshot;
+            state.observed_at = Some(SystemTime::now());
+        }
+    }
+
+    pub(crate) fn status(&self) -> RateLimitStatus {
+        let mut state = self.state.lock().unwrap();
+        prune(&mut state.sent, Instant::now());
+        RateLimitStatus {
+            limit: state.last.limit,
+            remaining: state.last.remaining,
+            reset: state.last.reset,
+            retry_after: state.last.retry_after,
+            observed_at: state.observed_at,
+            requests_last_minute: state.sent.len(),
+        }
+    }
+}
+
+/// A request budget: at most `requests` within any span of `per`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RateLimit {
+    pub requests: u32,
+    pub per: Duration,
+}
+
+impl RateLimit {
+    pub fn new(requests: u32, per: Duration) -> Self {
+        Self { requests, per }
+    }
+
+    pub fn per_second(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(1))
+    }
+
+    pub fn per_minute(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(60))
+    }
+}
+
+/// The budgets of a client and its clones: an optional one for all requests
+/// and one per configured zone.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Limiter {
+    default: Option<Window>,
+    /// Keyed by the zone id or name as configured.
+    zones: Vec<(String, Window)>,
+}
+
+impl Limiter {
+    pub(crate) fn set_default(&mut self, limit: RateLimit) {
+        self.default = Some(Window::new(limit));
+    }
+
+    pub(crate) fn set_zone(&mut self, zone: String, limit: RateLimit) {
+        self.zones.retain(|(configured, _)| *configured != zone);
+        self.zones.push((zone, Window::new(limit)));
+    }
+
+    /// The window of the zone a request is for; `matches` tells whether a
+    /// configured zone id or name is that zone.
+    pub(crate) fn zone_window(&self, matches: impl Fn(&str) -> bool) -> Option<&Window> {
+        self.zones
+            .iter()
+            .find(|(zone, _)| matches(zone))
+            .map(|(_, window)| window)
+    }
+
+    pub(crate) fn default_window(&self) -> Option<&Window> {
+        self.default.as_ref()
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Window {
+    limit: RateLimit,
+    sent: Mutex<VecDeque<Instant>>,
+}
+
+impl Window {
+    fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            sent: Mutex::default(),
+        }
+    }
+
+    /// Takes a slot for a request now, or says how long to wait for one.
+    pub(crate) fn reserve(&self) -> Result<(), Duration> {
+        let mut sent = self.sent.lock().unwrap();
+        let now = Instant::now();
+        while sent
+            .front()
+            .is_some_and(|sent_at| now.duration_since(*sent_at) >= self.limit.per)
+        {
+            sent.pop_front();
+        }
+        if sent.len() < self.limit.requests.max(1) as usize {
+            sent.push_back(now);
+            return Ok(());
+        }
+        let oldest = sent.front().copied().unwrap_or(now);
+        Err(self.limit.per.saturating_sub(now.duration_since(oldest)))
+    }
+}
+
+impl Clone for Window {
+    fn clone(&self) -> Self {
+        Self {
+            limit: self.limit,
+            sent: Mutex::new(self.sent.lock().unwrap().clone()),
+        }
+    }
+}
+
+fn prune(sent: &mut VecDeque<Instant>, now: Instant) {
+    while sent
+        .front()
+        .is_some_and(|sent_at| now.duration_since(*sent_at) > REQUEST_WINDOW)
+    {
+        sent.pop_front();
+    }
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|s| s.parse::<u64>().ok())
+}
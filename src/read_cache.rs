@@ -0,0 +1,277 @@
+//! Stale-while-revalidate caching of zone and record listings for
+//! read-heavy consumers such as exporters and dashboards; enabled with
+//! [`HetznerClient::with_read_cache`](crate::HetznerClient::with_read_cache)
+//! and used by [`DnsApi::list_zones_cached`](crate::api::dns::DnsApi::list_zones_cached)
+//! and [`RecordsApi::list_cached`](crate::api::dns::records::RecordsApi::list_cached).
+//!
+//! ```no_run
+//! use hetzner::{HetznerClient, ReadCache};
+//! use std::time::Duration;
+//!
+//! # async fn run() -> hetzner::error::Result<()> {
+//! let client = HetznerClient::new("token").with_read_cache(
+//!     ReadCache::new(Duration::from_secs(30)).max_stale(Duration::from_secs(600)),
+//! );
+//! // Fetched once, then served from memory; after 30 seconds the cached
+//! // list is still returned while a fresh one is fetched in the background.
+//! let zones = client.dns().list_zones_cached().await?;
+//! println!("{} zones, {:?}", zones.len(), client.read_cache_stats());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::HetznerClient;
+use crate::error::Result;
+use crate::logging::{debug, warn};
+use crate::mutation::MutationOperation;
+use crate::types::{Record, Zone};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// How long cached listings are served.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadCache {
+    /// Age up to which a listing is served without refreshing it.
+    pub fresh_for: Duration,
+    /// Age beyond which a listing is no longer served: the read waits for a
+    /// fresh one instead. `None` serves listings of any age.
+    pub max_stale: Option<Duration>,
+}
+
+impl ReadCache {
+    pub fn new(fresh_for: Duration) -> Self {
+        Self {
+            fresh_for,
+            max_stale: None,
+        }
+    }
+
+    pub fn max_stale(mut self, max_stale: Duration) -> Self {
+        self.max_stale = Some(max_stale);
+        self
+    }
+}
+
+/// Counters of a client's read cache, shared by the client and its clones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ReadCacheStats {
+    /// Reads served from a fresh listing.
+    pub hits: u64,
+    /// Reads served from a stale listing while it was being refreshed.
+    pub stale_hits: u64,
+    /// Reads that had to wait for the API.
+    pub misses: u64,
+    /// Background refreshes that replaced a listing.
+    pub refreshes: u64,
+    /// Background refreshes that failed; the stale listing is kept.
+    pub refresh_failures: u64,
+}
+
+struct Entry<T> {
+    value: T,
+    fetched_at: Instant,
+    refreshing: bool,
+}
+
+enum Lookup<T> {
+    Fresh(T),
+    /// A stale value, and whether the caller should start refreshing it.
+    Stale(T, bool),
+    Miss,
+}
+
+/// Cached listings of one kind, by key.
+pub(crate) struct Slots<T>(Mutex<HashMap<String, Entry<T>>>);
+
+impl<T: Clone> Slots<T> {
+    fn lookup(&self, key: &str, config: ReadCache) -> Lookup<T> {
+        let mut entries = self.0.lock().unwrap();
+        let Some(entry) = entries.get_mut(key) else {
+            return Lookup::Miss;
+        };
+        let age = entry.fetched_at.elapsed();
+        if age < config.fresh_for {
+            return Lookup::Fresh(entry.value.clone());
+        }
+        if config.max_stale.is_some_and(|max_stale| age >= max_stale) {
+            return Lookup::Miss;
+        }
+        let refresh = !entry.refreshing;
+        entry.refreshing = true;
+        Lookup::Stale(entry.value.clone(), refresh)
+    }
+
+    fn store(&self, key: String, value: T) {
+        let entry = Entry {
+            value,
+            fetched_at: Instant::now(),
+            refreshing: false,
+        };
+        self.0.lock().unwrap().insert(key, entry);
+    }
+
+    /// Stores a refreshed value, unless the entry was invalidated while the
+    /// refresh ran: the result may then predate a write.
+    fn finish_refresh(&self, key: &str, value: Option<T>) -> bool {
+        let mut entries = self.0.lock().unwrap();
+        let Some(entry) = entries.get_mut(key).filter(|entry| entry.refreshing) else {
+            return false;
+        };
+        entry.refreshing = false;
+        if let Some(value) = value {
+            entry.value = value;
+            entry.fetched_at = Instant::now();
+        }
+        true
+    }
+
+    fn remove(&self, key: Option<&str>) {
+        let mut entries = self.0.lock().unwrap();
+        match key {
+            Some(key) => {
+                entries.remove(key);
+            }
+            None => entries.clear(),
+        }
+    }
+}
+
+impl<T> Default for Slots<T> {
+    fn default() -> Self {
+        Self(Mutex::new(HashMap::new()))
+    }
+}
+
+/// The read cache installed on a client, shared by its clones.
+pub(crate) struct ReadCacheState {
+    config: ReadCache,
+    pub(crate) zones: Slots<Vec<Zone>>,
+    /// Record listings by zone id.
+    pub(crate) records: Slots<Vec<Record>>,
+    stats: Mutex<ReadCacheStats>,
+}
+
+impl ReadCacheState {
+    pub(crate) fn new(config: ReadCache) -> Self {
+        Self {
+            config,
+            zones: Slots::default(),
+            records: Slots::default(),
+            stats: Mutex::new(ReadCacheStats::default()),
+        }
+    }
+
+    pub(crate) fn stats(&self) -> ReadCacheStats {
+        *self.stats.lock().unwrap()
+    }
+
+    fn count(&self, counter: impl FnOnce(&mut ReadCacheStats) -> &mut u64) {
+        *counter(&mut self.stats.lock().unwrap()) += 1;
+    }
+
+    /// Drops the listings a successful write may have changed: the zone's
+    /// records (all records when the zone is unknown), and the zone list
+    /// when a zone was created.
+    pub(crate) fn invalidate(&self, operation: MutationOperation, zone_id: Option<&str>) {
+        match operation {
+            MutationOperation::CreateRecord
+            | MutationOperation::UpdateRecord
+            | MutationOperation::DeleteRecord => self.records.remove(zone_id),
+            MutationOperation::CreateZone => self.zones.remove(None),
+            MutationOperation::CreatePrimaryServer
+            | MutationOperation::UpdatePrimaryServer
+            | MutationOperation::DeletePrimaryServer => {}
+        }
+    }
+}
+
+impl fmt::Debug for ReadCacheState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadCacheState")
+            .field("config", &self.config)
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+impl HetznerClient {
+    /// Serves listings from a stale-while-revalidate cache; see
+    /// [`ReadCache`]. Only the `*_cached` reads use it, so writes that read
+    /// before changing something always see the API's current state. Record
+    /// writes and zone creations made through this client or its clones drop
+    /// the listings they affect.
+    pub fn with_read_cache(mut self, config: ReadCache) -> Self {
+        self.read_cache = Some(Arc::new(ReadCacheState::new(config)));
+        self
+    }
+
+    /// The read cache's counters; all zero without
+    /// [`with_read_cache`](Self::with_read_cache).
+    pub fn read_cache_stats(&self) -> ReadCacheStats {
+        self.read_cache
+            .as_ref()
+            .map(|cache| cache.stats())
+            .unwrap_or_default()
+    }
+
+    /// Reads the listing `key` of the kind `slots` selects through the read
+    /// cache, calling `fetch` on a miss and, in a background task, to
+    /// refresh a stale listing. Without a cache `fetch` is called directly.
+    pub(crate) async fn cached_read<T, F, Fut>(
+        &self,
+        slots: fn(&ReadCacheState) -> &Slots<T>,
+        key: String,
+        fetch: F,
+    ) -> Result<T>
+    where
+        T: Clone + Send + 'static,
+        F: FnOnce(HetznerClient) -> Fut,
+        Fut: Future<Output = Result<T>> + Send + 'static,
+    {
+        let Some(cache) = &self.read_cache else {
+            return fetch(self.clone()).await;
+        };
+        match slots(cache).lookup(&key, cache.config) {
+            Lookup::Fresh(value) => {
+                cache.count(|stats| &mut stats.hits);
+                debug!(target: "hetzner::read_cache", key, "cache hit");
+                Ok(value)
+            }
+            Lookup::Stale(value, refresh) => {
+                cache.count(|stats| &mut stats.stale_hits);
+                debug!(target: "hetzner::read_cache", key, refresh, "stale cache hit");
+                if refresh {
+                    let refreshed = fetch(self.clone());
+                    let cache = cache.clone();
+                    tokio::spawn(async move {
+                        match refreshed.await {
+                            Ok(value) => {
+                                if slots(&cache).finish_refresh(&key, Some(value)) {
+                                    cache.count(|stats| &mut stats.refreshes);
+                                }
+                            }
+                            #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+                            Err(err) => {
+                                slots(&cache).finish_refresh(&key, None);
+                                cache.count(|stats| &mut stats.refresh_failures);
+                                warn!(target: "hetzner::read_cache", key, error = %err, "cache refresh failed");
+                            }
+                        }
+                    });
+                }
+                Ok(value)
+            }
+            Lookup::Miss => {
+                cache.count(|stats| &mut stats.misses);
+                debug!(target: "hetzner::read_cache", key, "cache miss");
+                let value = fetch(self.clone()).await?;
+                slots(cache).store(key, value.clone());
+                Ok(value)
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Backoff policies deciding whether and when a failed request is retried;
+//! installed with [`HetznerClient::with_backoff`](crate::HetznerClient::with_backoff).
+
+use crate::error::HetznerError;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Decides how long to wait before retrying a failed request.
+///
+/// Only errors that are [retryable](HetznerError::is_retryable) reach the
+/// policy, and POST requests are only retried when rate limited, since the
+/// API may have applied them.
+pub trait BackoffPolicy: Send + Sync {
+    /// The delay before retry number `attempt` (starting at 1), or `None` to
+    /// give up and return `error`.
+    fn next_delay(&self, attempt: u32, error: &HetznerError) -> Option<Duration>;
+}
+
+/// Doubles the delay after every attempt, starting at `initial`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExponentialBackoff {
+    pub initial: Duration,
+    pub max_delay: Duration,
+    pub max_retries: u32,
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self {
+            initial: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            max_retries: 3,
+        }
+    }
+}
+
+impl BackoffPolicy for ExponentialBackoff {
+    fn next_delay(&self, attempt: u32, _error: &HetznerError) -> Option<Duration> {
+        if attempt > self.max_retries {
+            return None;
+        }
+        let factor = 2u32.saturating_pow(attempt - 1);
+        Some(self.initial.saturating_mul(factor).min(self.max_delay))
+    }
+}
+
+/// Waits `unit` times the attempt's Fibonacci number (1, 1, 2, 3, 5, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FibonacciBackoff {
+    pub unit: Duration,
+    pub max_delay: Duration,
+    pub max_retries: u32,
+}
+
+impl Default for FibonacciBackoff {
+    fn default() -> Self {
+        Self {
+            unit: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            max_retries: 5,
+        }
+    }
+}
+
+impl BackoffPolicy for FibonacciBackoff {
+    fn next_delay(&self, attempt: u32, _error: &HetznerError) -> Option<Duration> {
+        if attempt > self.max_retries {
+            return None;
+        }
+        let (mut previous, mut current) = (0u32, 1u32);
+        for _ in 1..attempt {
+            (previous, current) = (current, previous.saturating_add(current));
+        }
+        Some(self.unit.saturating_mul(current).min(self.max_delay))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConstantBackoff {
+    pub delay: Duration,
+    pub max_retries: u32,
+}
+
+impl Default for ConstantBackoff {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_secs(1),
+            max_retries: 3,
+        }
+    }
+}
+
+impl BackoffPolicy for ConstantBackoff {
+    fn next_delay(&self, attempt: u32, _error: &HetznerError) -> Option<Duration> {
+        (attempt <= self.max_retries).then_some(self.delay)
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct Backoff(pub(crate) Arc<dyn BackoffPolicy>);
+
+impl fmt::Debug for Backoff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Backoff")
+    }
+}
@@ -0,0 +1,145 @@
+//! A local record of what was last applied to each zone, for telling changes
+//! made outside this tool apart from changes not applied yet.
+
+use crate::api::dns::provider::{DnsProvider, DnsRecord};
+use crate::error::{HetznerError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Last-applied record sets per zone name, persisted as JSON (a small
+/// terraform-style state file).
+#[derive(Debug)]
+pub struct StateFile {
+    path: PathBuf,
+    zones: Mutex<BTreeMap<String, Vec<DnsRecord>>>,
+}
+
+/// How a zone differs from its last-applied state.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DriftReport {
+    pub zone: String,
+    /// Live records added outside this tool since the last apply.
+    pub external_additions: Vec<DnsRecord>,
+    /// Applied records that have since been removed or changed live.
+    pub external_removals: Vec<DnsRecord>,
+    /// Spec records not applied yet.
+    pub pending_additions: Vec<DnsRecord>,
+    /// Applied records the spec no longer contains.
+    pub pending_removals: Vec<DnsRecord>,
+}
+
+impl DriftReport {
+    pub fn has_external_changes(&self) -> bool {
+        !self.external_additions.is_empty() || !self.external_removals.is_empty()
+    }
+
+    pub fn has_pending_changes(&self) -> bool {
+        !self.pending_additions.is_empty() || !self.pending_removals.is_empty()
+    }
+
+    pub fn is_clean(&self) -> bool {
+        !self.has_external_changes() && !self.has_pending_changes()
+    }
+}
+
+impl StateFile {
+    /// Opens the state at `path`, continuing an existing file if present.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let failed = |err: &dyn std::fmt::Display| {
+            HetznerError::State(format!("failed to read {}: {err}", path.display()))
+        };
+        let zones = match fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).map_err(|err| failed(&err))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(failed(&err)),
+        };
+        Ok(Self {
+            path,
+            zones: Mutex::new(zones),
+        })
+    }
+
+    /// The record set last applied to `zone`, if any.
+    pub fn zone(&self, zone: &str) -> Option<Vec<DnsRecord>> {
+        self.zones.lock().unwrap().get(&zone_key(zone)).cloned()
+    }
+
+    /// Stores `records` as the applied state of `zone`.
+    pub fn set_zone(&self, zone: &str, records: Vec<DnsRecord>) -> Result<()> {
+        let mut zones = self.zones.lock().unwrap();
+        zones.insert(zone_key(zone), records);
+        self.persist(&zones)
+    }
+
+    /// Stores the zone's current live records as its applied state, typically
+    /// right after applying a spec.
+    pub async fn snapshot<P>(&self, provider: &P, zone: &str) -> Result<()>
+    where
+        P: DnsProvider<Error = HetznerError>,
+    {
+        let records = provider.list_records(zone).await?;
+        self.set_zone(zone, records)
+    }
+
+    /// Compares the live zone and `spec` with the stored state. A zone without
+    /// stored state counts as never applied.
+    pub async fn drift_report<P: DnsProvider>(
+        &self,
+        provider: &P,
+        zone: &str,
+        spec: &[DnsRecord],
+    ) -> std::result::Result<DriftReport, P::Error> {
+        let live = provider.list_records(zone).await?;
+        let state = self.zone(zone).unwrap_or_default();
+        Ok(DriftReport {
+            zone: zone_key(zone),
+            external_additions: difference(&live, &state),
+            external_removals: difference(&state, &live),
+            pending_additions: difference(spec, &state),
+            pending_removals: difference(&state, spec),
+        })
+    }
+
+    fn persist(&self, zones: &BTreeMap<String, Vec<DnsRecord>>) -> Result<()> {
+        let serialized = serde_json::to_string_pretty(zones)?;
+        fs::write(&self.path, serialized).map_err(|err| {
+            HetznerError::State(format!("failed to write {}: {err}", self.path.display()))
+        })
+    }
+}
+
+fn zone_key(zone: &str) -> String {
+    zone.trim_end_matches('.').to_ascii_lowercase()
+}
+
+/// Records of `left` without an equal record in `right`, comparing names and
+/// types case-insensitively.
+fn difference(left: &[DnsRecord], right: &[DnsRecord]) -> Vec<DnsRecord> {
+    let key = |record: &DnsRecord| {
+        (
+            record.name.to_ascii_lowercase(),
+            record.record_type.to_ascii_uppercase(),
+            record.value.clone(),
+            record.ttl,
+        )
+    };
+    let mut remaining: BTreeMap<_, usize> = BTreeMap::new();
+    for record in right {
+        *remaining.entry(key(record)).or_default() += 1;
+    }
+    left.iter()
+        .filter(|record| match remaining.get_mut(&key(record)) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                false
+            }
+            _ => true,
+        })
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,204 @@
+//! Aligned plain-text tables for CLI and log output (`table` feature).
+//!
+//! [`render`] pads columns with spaces; [`render_table`] draws the table
+//! with [comfy-table](https://docs.rs/comfy-table), picking columns and
+//! wrapping cells to fit a width:
+//!
+//! ```
+//! use hetzner::table::{TableOptions, TableStyle, render_table};
+//! # let records: Vec<hetzner::Record> = Vec::new();
+//! let options = TableOptions::new()
+//!     .columns(["name", "type", "value"])
+//!     .max_width(80)
+//!     .style(TableStyle::Ascii);
+//! print!("{}", render_table(&records, &options));
+//! ```
+
+use crate::api::dns::provider::DnsRecord;
+use crate::api::dns::records::Ttl;
+use crate::types::{Record, Zone};
+use comfy_table::{ContentArrangement, Table, presets};
+
+/// A value that renders as one table row.
+pub trait TableRow {
+    const HEADERS: &'static [&'static str];
+
+    fn cells(&self) -> Vec<String>;
+}
+
+/// Renders `rows` under a header line, each column padded to its widest
+/// cell. The last column is not padded, so long values don't add trailing
+/// whitespace.
+pub fn render<T: TableRow>(rows: &[T]) -> String {
+    let rows: Vec<Vec<String>> = rows.iter().map(TableRow::cells).collect();
+    let mut widths: Vec<usize> = T::HEADERS.iter().map(|h| h.chars().count()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut output = String::new();
+    let headers = T::HEADERS.iter().map(|h| h.to_string()).collect();
+    for row in std::iter::once(headers).chain(rows) {
+        let last = row.len().saturating_sub(1);
+        for (i, (cell, width)) in row.iter().zip(&widths).enumerate() {
+            if i == last {
+                output.push_str(cell);
+            } else {
+                output.push_str(cell);
+                output.extend(std::iter::repeat_n(' ', width - cell.chars().count() + 2));
+            }
+        }
+        output.push('\n');
+    }
+    output
+}
+
+/// How [`render_table`] draws lines.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TableStyle {
+    /// Box-drawing borders.
+    #[default]
+    Utf8,
+    /// Borders drawn with `+`, `-` and `|`.
+    Ascii,
+    /// A Markdown table.
+    Markdown,
+    /// No lines, columns separated by spaces like [`render`].
+    Plain,
+}
+
+impl TableStyle {
+    fn preset(self) -> &'static str {
+        match self {
+            Self::Utf8 => presets::UTF8_FULL_CONDENSED,
+            Self::Ascii => presets::ASCII_FULL_CONDENSED,
+            Self::Markdown => presets::ASCII_MARKDOWN,
+            Self::Plain => presets::NOTHING,
+        }
+    }
+}
+
+/// Options of [`render_table`]; the default shows every column unwrapped
+/// with [`TableStyle::Utf8`] borders.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TableOptions {
+    columns: Option<Vec<String>>,
+    max_width: Option<u16>,
+    style: TableStyle,
+}
+
+impl TableOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Shows only these columns, in this order, named as in the header
+    /// without regard to case. Names the row type has no column for are
+    /// skipped; see [`TableRow::HEADERS`].
+    pub fn columns<I, S>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.columns = Some(columns.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Wraps cells so no line is wider than `width` characters, e.g. the
+    /// terminal's width.
+    pub fn max_width(mut self, width: u16) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    pub fn style(mut self, style: TableStyle) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// Renders `rows` as a table drawn as `options` say. Lines carry no
+/// trailing whitespace.
+pub fn render_table<T: TableRow>(rows: &[T], options: &TableOptions) -> String {
+    let indexes: Vec<usize> = match &options.columns {
+        Some(columns) => columns
+            .iter()
+            .filter_map(|column| {
+                T::HEADERS
+                    .iter()
+                    .position(|header| header.eq_ignore_ascii_case(column))
+            })
+            .collect(),
+        None => (0..T::HEADERS.len()).collect(),
+    };
+    let pick = |cells: Vec<String>| -> Vec<String> {
+        indexes.iter().map(|&index| cells[index].clone()).collect()
+    };
+
+    let mut table = Table::new();
+    table.load_preset(options.style.preset());
+    if let Some(width) = options.max_width {
+        table
+            .set_width(width)
+            .set_content_arrangement(ContentArrangement::Dynamic);
+    }
+    table.set_header(pick(T::HEADERS.iter().map(|h| h.to_string()).collect()));
+    for row in rows {
+        table.add_row(pick(row.cells()));
+    }
+    if options.style == TableStyle::Plain {
+        for column in table.column_iter_mut() {
+            column.set_padding((0, 2));
+        }
+    }
+    let mut output = table.trim_fmt();
+    output.push('\n');
+    output
+}
+
+fn ttl_cell(ttl: Option<Ttl>) -> String {
+    ttl.map_or_else(|| "-".to_string(), |ttl| ttl.seconds().to_string())
+}
+
+impl TableRow for Record {
+    const HEADERS: &'static [&'static str] = &["ID", "NAME", "TYPE", "TTL", "VALUE"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.name.clone(),
+            self.record_type.clone(),
+            ttl_cell(self.ttl),
+            self.value.clone(),
+        ]
+    }
+}
+
+impl TableRow for DnsRecord {
+    const HEADERS: &'static [&'static str] = &["NAME", "TYPE", "TTL", "VALUE"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            self.record_type.clone(),
+            ttl_cell(self.ttl),
+            self.value.clone(),
+        ]
+    }
+}
+
+impl TableRow for Zone {
+    const HEADERS: &'static [&'static str] = &["ID", "NAME", "STATUS", "RECORDS", "TTL"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.name.clone(),
+            self.status.clone().unwrap_or_else(|| "-".to_string()),
+            self.records_count.to_string(),
+            self.default_ttl().seconds().to_string(),
+        ]
+    }
+}
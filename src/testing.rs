@@ -0,0 +1,909 @@
+//! In-memory stand-ins for the Hetzner DNS and Cloud APIs.
+//!
+//! [`FakeDnsApi`] binds to a local port and serves the zone and record
+//! endpoints from memory, so code built on this crate can be tested without a
+//! real token:
+//!
+//! ```no_run
+//! # async fn example() -> std::io::Result<()> {
+//! use hetzner::testing::FakeDnsApi;
+//!
+//! let fake = FakeDnsApi::start().await?;
+//! let zone = fake.add_zone("example.com");
+//! let client = fake.client();
+//! let records = client.dns().records(&zone.id).list().await;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Like the API, zone and primary-server listings are paged at 100 entries,
+//! while record listings return every record unless a `per_page` is given.
+//!
+//! Code written against the [`HetznerDnsApi`] trait can use
+//! [`MockHetznerClient`] instead, which runs the same in-memory logic without
+//! a socket.
+//!
+//! [`FakeCloudApi`] does the same for the Cloud API's resource endpoints.
+
+mod cloud;
+
+pub use cloud::FakeCloudApi;
+
+use crate::HetznerClient;
+use crate::api::dns::hetzner_dns_api::HetznerDnsApi;
+use crate::api::dns::records::{Ttl, UpdateRecordInput};
+use crate::client::parse_api_error;
+use crate::error::{HetznerError, Result};
+use crate::types::{
+    CreatedRecord, DEFAULT_ZONE_TTL, Meta, Pagination, PrimaryServer, Record, RecordEnvelope,
+    RecordsEnvelope, Zone, ZonesEnvelope,
+};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+const FAKE_TOKEN: &str = "fake-dns-token";
+const DEFAULT_PER_PAGE: usize = 100;
+const KNOWN_RECORD_TYPES: &[&str] = &[
+    "A", "AAAA", "NS", "MX", "CNAME", "RP", "TXT", "SOA", "HINFO", "SRV", "DANE", "TLSA", "DS",
+    "CAA",
+];
+
+#[derive(Debug, Default)]
+struct FakeState {
+    next_id: u64,
+    zones: BTreeMap<String, Zone>,
+    records: BTreeMap<String, Record>,
+    primary_servers: BTreeMap<String, PrimaryServer>,
+}
+
+impl FakeState {
+    fn next_id(&mut self) -> String {
+        self.next_id += 1;
+        format!("{:032x}", self.next_id)
+    }
+
+    fn add_zone(&mut self, name: &str) -> Zone {
+        let zone = new_zone(self, name, DEFAULT_ZONE_TTL);
+        self.zones.insert(zone.id.clone(), zone.clone());
+        zone
+    }
+
+    fn add_record(&mut self, zone_id: &str, name: &str, record_type: &str, value: &str) -> Record {
+        let now = now_timestamp();
+        let record = Record {
+            id: self.next_id(),
+            name: name.to_string(),
+            ttl: None,
+            record_type: record_type.to_string(),
+            value: value.to_string(),
+            zone_id: zone_id.to_string(),
+            created: Some(now.clone()),
+            modified: Some(now),
+            proxied: None,
+        };
+        self.records.insert(record.id.clone(), record.clone());
+        self.refresh_records_count(zone_id);
+        record
+    }
+
+    fn refresh_records_count(&mut self, zone_id: &str) {
+        let count = self
+            .records
+            .values()
+            .filter(|record| record.zone_id == zone_id)
+            .count();
+        if let Some(zone) = self.zones.get_mut(zone_id) {
+            zone.records_count = count as i64;
+        }
+    }
+}
+
+/// A running fake DNS API. The server stops when this value is dropped.
+#[derive(Debug)]
+pub struct FakeDnsApi {
+    addr: SocketAddr,
+    state: Arc<Mutex<FakeState>>,
+    task: JoinHandle<()>,
+}
+
+impl FakeDnsApi {
+    pub async fn start() -> std::io::Result<Self> {
+        let state = Arc::new(Mutex::new(FakeState::default()));
+        let (addr, task) = serve(state.clone(), handle).await?;
+        Ok(Self { addr, state, task })
+    }
+
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    pub fn token(&self) -> &str {
+        FAKE_TOKEN
+    }
+
+    /// A client authenticated against, and pointed at, this fake.
+    pub fn client(&self) -> HetznerClient {
+        HetznerClient::new(FAKE_TOKEN).with_dns_base_url(self.base_url())
+    }
+
+    pub fn add_zone(&self, name: &str) -> Zone {
+        self.state.lock().unwrap().add_zone(name)
+    }
+
+    /// Adds a record without a TTL of its own (`ttl: None`), so it inherits
+    /// the zone default like records the API creates without one.
+    pub fn add_record(&self, zone_id: &str, name: &str, record_type: &str, value: &str) -> Record {
+        self.state
+            .lock()
+            .unwrap()
+            .add_record(zone_id, name, record_type, value)
+    }
+
+    /// Overrides a zone's verification status, e.g. to simulate a zone
+    /// going from `pending` to `verified`.
+    pub fn set_zone_status(&self, zone_id: &str, status: &str) {
+        if let Some(zone) = self.state.lock().unwrap().zones.get_mut(zone_id) {
+            zone.status = Some(status.to_string());
+        }
+    }
+
+    pub fn zones(&self) -> Vec<Zone> {
+        self.state.lock().unwrap().zones.values().cloned().collect()
+    }
+
+    pub fn records(&self, zone_id: &str) -> Vec<Record> {
+        self.state
+            .lock()
+            .unwrap()
+            .records
+            .values()
+            .filter(|record| record.zone_id == zone_id)
+            .cloned()
+            .collect()
+    }
+}
+
+impl Drop for FakeDnsApi {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// An in-process [`HetznerDnsApi`] implementation with the same semantics as
+/// [`FakeDnsApi`], for unit tests that should not open sockets at all.
+///
+/// Every call is appended to [`calls`](Self::calls) as `"METHOD path"`;
+/// listings request every page, so a long listing shows up as several calls.
+#[derive(Debug, Default)]
+pub struct MockHetznerClient {
+    state: Mutex<FakeState>,
+    calls: Mutex<Vec<String>>,
+}
+
+impl MockHetznerClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_zone(&self, name: &str) -> Zone {
+        self.state.lock().unwrap().add_zone(name)
+    }
+
+    /// Adds a record without a TTL of its own (`ttl: None`), so it inherits
+    /// the zone default like records the API creates without one.
+    pub fn add_record(&self, zone_id: &str, name: &str, record_type: &str, value: &str) -> Record {
+        self.state
+            .lock()
+            .unwrap()
+            .add_record(zone_id, name, record_type, value)
+    }
+
+    pub fn calls(&self) -> Vec<String> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    fn dispatch<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> Result<T> {
+        self.calls.lock().unwrap().push(format!("{method} {path}"));
+        let request = FakeRequest {
+            method: method.to_string(),
+            path: path.to_string(),
+            query: query
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            token: Some(FAKE_TOKEN.to_string()),
+            bearer: None,
+            body: body.map(|b| b.to_string().into_bytes()).unwrap_or_default(),
+        };
+
+        let (status, body) = handle(&mut self.state.lock().unwrap(), &request);
+        let status = StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        if !status.is_success() {
+            return Err(HetznerError::Api(Box::new(parse_api_error(
+                status,
+                &body.to_string(),
+                None,
+            ))));
+        }
+        Ok(serde_json::from_value(body)?)
+    }
+}
+
+impl HetznerDnsApi for MockHetznerClient {
+    async fn list_zones(&self) -> Result<Vec<Zone>> {
+        let mut zones = Vec::new();
+        let mut page = Some(1);
+        while let Some(current) = page {
+            let current = current.to_string();
+            let response: ZonesEnvelope =
+                self.dispatch("GET", "zones", &[("page", &current)], None)?;
+            zones.extend(response.zones);
+            page = next_page(response.meta);
+        }
+        Ok(zones)
+    }
+
+    async fn list_records(&self, zone_id: &str) -> Result<Vec<Record>> {
+        let mut records = Vec::new();
+        let mut page = Some(1);
+        while let Some(current) = page {
+            let current = current.to_string();
+            let query = [("zone_id", zone_id), ("page", &current)];
+            let response: RecordsEnvelope = self.dispatch("GET", "records", &query, None)?;
+            records.extend(response.records);
+            page = next_page(response.meta);
+        }
+        Ok(records)
+    }
+
+    async fn create_record(
+        &self,
+        zone_id: &str,
+        name: &str,
+        record_type: &str,
+        value: &str,
+        ttl: impl Into<Option<Ttl>> + Send,
+    ) -> Result<CreatedRecord> {
+        let mut body = json!({
+            "zone_id": zone_id,
+            "name": name,
+            "type": record_type,
+            "value": value,
+        });
+        if let Some(ttl) = ttl.into() {
+            body["ttl"] = json!(ttl);
+        }
+        self.dispatch("POST", "records", &[], Some(body))
+    }
+
+    async fn get_record(&self, record_id: &str) -> Result<Record> {
+        let envelope: RecordEnvelope =
+            self.dispatch("GET", &format!("records/{record_id}"), &[], None)?;
+        Ok(envelope.record)
+    }
+
+    async fn get_record_raw(&self, record_id: &str) -> Result<Value> {
+        let envelope: Value = self.dispatch("GET", &format!("records/{record_id}"), &[], None)?;
+        crate::api::dns::records::record_of(envelope)
+    }
+
+    async fn update_record(
+        &self,
+        record_id: &str,
+        input: UpdateRecordInput,
+    ) -> Result<RecordEnvelope> {
+        let path = format!("records/{record_id}");
+        self.dispatch("PUT", &path, &[], Some(serde_json::to_value(input)?))
+    }
+
+    async fn delete_record(&self, record_id: &str) -> Result<()> {
+        let _: Value = self.dispatch("DELETE", &format!("records/{record_id}"), &[], None)?;
+        Ok(())
+    }
+}
+
+fn next_page(meta: Option<Meta>) -> Option<u32> {
+    meta.and_then(|meta| meta.pagination.next_page)
+}
+
+struct FakeRequest {
+    method: String,
+    path: String,
+    query: Vec<(String, String)>,
+    /// The DNS API's `Auth-API-Token` header.
+    token: Option<String>,
+    /// The Cloud API's `Authorization: Bearer` token.
+    bearer: Option<String>,
+    body: Vec<u8>,
+}
+
+impl FakeRequest {
+    fn query(&self, key: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn json(&self) -> Option<Value> {
+        serde_json::from_slice(&self.body).ok()
+    }
+}
+
+/// Answers a request from the fake's state with a status and JSON body.
+type Handler<S> = fn(&mut S, &FakeRequest) -> (u16, Value);
+
+/// Accepts connections on a local port until the returned task is aborted.
+async fn serve<S: Send + 'static>(
+    state: Arc<Mutex<S>>,
+    handle: Handler<S>,
+) -> std::io::Result<(SocketAddr, JoinHandle<()>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let task = tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let state = state.clone();
+            tokio::spawn(async move {
+                let _ = serve_connection(stream, state, handle).await;
+            });
+        }
+    });
+    Ok((addr, task))
+}
+
+async fn serve_connection<S>(
+    stream: TcpStream,
+    state: Arc<Mutex<S>>,
+    handle: Handler<S>,
+) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default().to_string();
+
+    let mut content_length = 0;
+    let mut token = None;
+    let mut bearer = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).await? == 0 || line == "\r\n" {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            let value = value.trim();
+            match name.trim().to_ascii_lowercase().as_str() {
+                "content-length" => content_length = value.parse().unwrap_or(0),
+                "auth-api-token" => token = Some(value.to_string()),
+                "authorization" => bearer = value.strip_prefix("Bearer ").map(str::to_string),
+                _ => {}
+            }
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).await?;
+
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), parse_query(query)),
+        None => (target, Vec::new()),
+    };
+    let request = FakeRequest {
+        method,
+        path,
+        query,
+        token,
+        bearer,
+        body,
+    };
+
+    let (status, body) = {
+        let mut state = state.lock().unwrap();
+        handle(&mut state, &request)
+    };
+    let body = body.to_string();
+    let response = format!(
+        "HTTP/1.1 {status} {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+        reason_phrase(status),
+        body.len()
+    );
+
+    let mut stream = reader.into_inner();
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+fn handle(state: &mut FakeState, request: &FakeRequest) -> (u16, Value) {
+    if request.token.as_deref() != Some(FAKE_TOKEN) {
+        return (
+            401,
+            json!({"message": "Invalid authentication credentials"}),
+        );
+    }
+
+    let segments: Vec<&str> = request
+        .path
+        .trim_matches('/')
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["zones"]) => list_zones(state, request),
+        ("POST", ["zones"]) => create_zone(state, request),
+        ("GET", ["zones", id]) => match state.zones.get(*id) {
+            Some(zone) => (200, json!({"zone": zone})),
+            None => not_found("zone"),
+        },
+        ("PUT", ["zones", id]) => update_zone(state, id, request),
+        ("DELETE", ["zones", id]) => {
+            if state.zones.remove(*id).is_none() {
+                return not_found("zone");
+            }
+            state.records.retain(|_, record| record.zone_id != *id);
+            state
+                .primary_servers
+                .retain(|_, server| server.zone_id != *id);
+            (200, json!({}))
+        }
+        ("GET", ["primary_servers"]) => {
+            let zone_id = request.query("zone_id");
+            let servers: Vec<&PrimaryServer> = state
+                .primary_servers
+                .values()
+                .filter(|server| zone_id.is_none_or(|zone_id| server.zone_id == zone_id))
+                .collect();
+            let (page, meta) = paginate(&servers, request, DEFAULT_PER_PAGE);
+            (
+                200,
+                json!({"primary_servers": page, "meta": {"pagination": meta}}),
+            )
+        }
+        ("POST", ["primary_servers"]) => upsert_primary_server(state, None, request),
+        ("GET", ["primary_servers", id]) => match state.primary_servers.get(*id) {
+            Some(server) => (200, json!({"primary_server": server})),
+            None => not_found("primary server"),
+        },
+        ("PUT", ["primary_servers", id]) => upsert_primary_server(state, Some(id), request),
+        ("DELETE", ["primary_servers", id]) => match state.primary_servers.remove(*id) {
+            Some(server) => {
+                let secondary = state
+                    .primary_servers
+                    .values()
+                    .any(|other| other.zone_id == server.zone_id);
+                if let Some(zone) = state.zones.get_mut(&server.zone_id) {
+                    zone.is_secondary_dns = secondary;
+                }
+                (200, json!({}))
+            }
+            None => not_found("primary server"),
+        },
+        ("GET", ["records"]) => list_records(state, request),
+        ("POST", ["records"]) => match request.json() {
+            Some(body) => match upsert_record(state, None, &body) {
+                Ok(record) => (200, json!({"record": record})),
+                Err(err) => err,
+            },
+            None => invalid_input("invalid json body"),
+        },
+        ("POST", ["records", "bulk"]) => bulk_create_records(state, request),
+        ("PUT", ["records", "bulk"]) => bulk_update_records(state, request),
+        ("GET", ["records", id]) => match state.records.get(*id) {
+            Some(record) => (200, json!({"record": record})),
+            None => not_found("record"),
+        },
+        ("PUT", ["records", id]) => match request.json() {
+            Some(body) => match upsert_record(state, Some(id), &body) {
+                Ok(record) => (200, json!({"record": record})),
+                Err(err) => err,
+            },
+            None => invalid_input("invalid json body"),
+        },
+        ("DELETE", ["records", id]) => match state.records.remove(*id) {
+            Some(record) => {
+                state.refresh_records_count(&record.zone_id);
+                (200, json!({}))
+            }
+            None => not_found("record"),
+        },
+        _ => (
+            404,
+            json!({"error": {"message": "404 page not found", "code": 404}}),
+        ),
+    }
+}
+
+fn list_zones(state: &FakeState, request: &FakeRequest) -> (u16, Value) {
+    let name = request.query("name");
+    let search = request.query("search_name");
+    let zones: Vec<&Zone> = state
+        .zones
+        .values()
+        .filter(|zone| name.is_none_or(|name| zone.name == name))
+        .filter(|zone| search.is_none_or(|search| zone.name.contains(search)))
+        .collect();
+
+    let (page, meta) = paginate(&zones, request, DEFAULT_PER_PAGE);
+    (200, json!({"zones": page, "meta": {"pagination": meta}}))
+}
+
+fn create_zone(state: &mut FakeState, request: &FakeRequest) -> (u16, Value) {
+    let Some(body) = request.json() else {
+        return invalid_input("invalid json body");
+    };
+    let Some(name) = body.get("name").and_then(Value::as_str) else {
+        return invalid_input("name is required");
+    };
+    if !name.contains('.') {
+        return invalid_input("invalid zone name");
+    }
+    if state.zones.values().any(|zone| zone.name == name) {
+        return invalid_input("zone name taken");
+    }
+    let ttl = match body.get("ttl").and_then(Value::as_u64) {
+        Some(ttl) => match Ttl::try_from(ttl) {
+            Ok(ttl) => ttl,
+            Err(_) => return invalid_input("invalid TTL"),
+        },
+        None => DEFAULT_ZONE_TTL,
+    };
+    let zone = new_zone(state, name, ttl);
+    state.zones.insert(zone.id.clone(), zone.clone());
+    (200, json!({"zone": zone}))
+}
+
+fn update_zone(state: &mut FakeState, id: &str, request: &FakeRequest) -> (u16, Value) {
+    let Some(body) = request.json() else {
+        return invalid_input("invalid json body");
+    };
+    let Some(zone) = state.zones.get_mut(id) else {
+        return not_found("zone");
+    };
+    if let Some(name) = body.get("name").and_then(Value::as_str) {
+        zone.name = name.to_string();
+    }
+    if let Some(ttl) = body.get("ttl").and_then(Value::as_u64) {
+        match Ttl::try_from(ttl) {
+            Ok(ttl) => zone.ttl = Some(ttl),
+            Err(_) => return invalid_input("invalid TTL"),
+        }
+    }
+    zone.modified = Some(now_timestamp());
+    (200, json!({"zone": zone}))
+}
+
+fn upsert_primary_server(
+    state: &mut FakeState,
+    id: Option<&str>,
+    request: &FakeRequest,
+) -> (u16, Value) {
+    let Some(body) = request.json() else {
+        return invalid_input("invalid json body");
+    };
+    let address = body
+        .get("address")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let zone_id = body
+        .get("zone_id")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let Some(port) = body
+        .get("port")
+        .and_then(Value::as_u64)
+        .and_then(|port| u16::try_from(port).ok())
+    else {
+        return invalid_input("invalid port");
+    };
+    if address.parse::<std::net::IpAddr>().is_err() {
+        return invalid_input("invalid address");
+    }
+    let Some(zone) = state.zones.get_mut(zone_id) else {
+        return invalid_input("zone not found");
+    };
+    zone.is_secondary_dns = true;
+
+    let now = now_timestamp();
+    let created = match id {
+        Some(id) => match state.primary_servers.get(id) {
+            Some(existing) => existing.created.clone(),
+            None => return not_found("primary server"),
+        },
+        None => Some(now.clone()),
+    };
+    let server = PrimaryServer {
+        id: id.map_or_else(|| state.next_id(), str::to_string),
+        address: address.to_string(),
+        port,
+        zone_id: zone_id.to_string(),
+        created,
+        modified: Some(now),
+    };
+    state
+        .primary_servers
+        .insert(server.id.clone(), server.clone());
+    (200, json!({"primary_server": server}))
+}
+
+fn list_records(state: &FakeState, request: &FakeRequest) -> (u16, Value) {
+    let zone_id = request.query("zone_id");
+    if let Some(zone_id) = zone_id
+        && !state.zones.contains_key(zone_id)
+    {
+        return not_found("zone");
+    }
+    let records: Vec<&Record> = state
+        .records
+        .values()
+        .filter(|record| zone_id.is_none_or(|zone_id| record.zone_id == zone_id))
+        .collect();
+
+    // Like the real API, records come unpaged unless a page size is asked for.
+    let (page, meta) = paginate(&records, request, records.len().max(1));
+    (200, json!({"records": page, "meta": {"pagination": meta}}))
+}
+
+fn bulk_create_records(state: &mut FakeState, request: &FakeRequest) -> (u16, Value) {
+    let Some(items) = request
+        .json()
+        .and_then(|body| body.get("records").and_then(Value::as_array).cloned())
+    else {
+        return invalid_input("records is required");
+    };
+
+    let mut created = Vec::new();
+    let mut invalid = Vec::new();
+    for item in items {
+        match upsert_record(state, None, &item) {
+            Ok(record) => created.push(record),
+            Err(_) => invalid.push(item),
+        }
+    }
+    (
+        200,
+        json!({"records": created, "valid_records": created, "invalid_records": invalid}),
+    )
+}
+
+fn bulk_update_records(state: &mut FakeState, request: &FakeRequest) -> (u16, Value) {
+    let Some(items) = request
+        .json()
+        .and_then(|body| body.get("records").and_then(Value::as_array).cloned())
+    else {
+        return invalid_input("records is required");
+    };
+
+    let mut updated = Vec::new();
+    let mut failed = Vec::new();
+    for item in items {
+        let id = item.get("id").and_then(Value::as_str).map(str::to_string);
+        match id.map(|id| upsert_record(state, Some(&id), &item)) {
+            Some(Ok(record)) => updated.push(record),
+            _ => failed.push(item),
+        }
+    }
+    (200, json!({"records": updated, "failed_records": failed}))
+}
+
+fn upsert_record(
+    state: &mut FakeState,
+    id: Option<&str>,
+    body: &Value,
+) -> std::result::Result<Record, (u16, Value)> {
+    let field = |name: &str| body.get(name).and_then(Value::as_str).unwrap_or_default();
+    let (zone_id, name, record_type, value) = (
+        field("zone_id"),
+        field("name"),
+        field("type"),
+        field("value"),
+    );
+
+    if !state.zones.contains_key(zone_id) {
+        return Err(invalid_input("zone not found"));
+    }
+    if name.is_empty() {
+        return Err(invalid_input("name is required"));
+    }
+    if value.is_empty() {
+        return Err(invalid_input("value is required"));
+    }
+    if !KNOWN_RECORD_TYPES.contains(&record_type) {
+        return Err(invalid_input("invalid record type"));
+    }
+    let ttl = match body.get("ttl").and_then(Value::as_u64) {
+        Some(ttl) => Some(Ttl::try_from(ttl).map_err(|_| invalid_input("invalid TTL"))?),
+        None => None,
+    };
+
+    let now = now_timestamp();
+    let record = match id {
+        Some(id) => {
+            let Some(existing) = state.records.get(id) else {
+                return Err(not_found("record"));
+            };
+            Record {
+                id: id.to_string(),
+                name: name.to_string(),
+                ttl,
+                record_type: record_type.to_string(),
+                value: value.to_string(),
+                zone_id: zone_id.to_string(),
+                created: existing.created.clone(),
+                modified: Some(now),
+                proxied: existing.proxied,
+            }
+        }
+        None => Record {
+            id: state.next_id(),
+            name: name.to_string(),
+            ttl,
+            record_type: record_type.to_string(),
+            value: value.to_string(),
+            zone_id: zone_id.to_string(),
+            created: Some(now.clone()),
+            modified: Some(now),
+            proxied: None,
+        },
+    };
+
+    state.records.insert(record.id.clone(), record.clone());
+    state.refresh_records_count(zone_id);
+    Ok(record)
+}
+
+fn paginate<'a, T>(
+    items: &'a [T],
+    request: &FakeRequest,
+    default_per_page: usize,
+) -> (&'a [T], Pagination) {
+    let per_page = request
+        .query("per_page")
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|v| *v > 0)
+        .unwrap_or(default_per_page);
+    let page = request
+        .query("page")
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|v| *v > 0)
+        .unwrap_or(1);
+    let last_page = items.len().div_ceil(per_page).max(1);
+
+    let start = ((page - 1) * per_page).min(items.len());
+    let end = (start + per_page).min(items.len());
+    let meta = Pagination {
+        page: page as u32,
+        per_page: per_page as u32,
+        previous_page: (page > 1).then(|| page as u32 - 1),
+        next_page: (page < last_page).then(|| page as u32 + 1),
+        last_page: Some(last_page as u32),
+        total_entries: Some(items.len() as u32),
+    };
+    (&items[start..end], meta)
+}
+
+fn new_zone(state: &mut FakeState, name: &str, ttl: Ttl) -> Zone {
+    let now = now_timestamp();
+    Zone::builder(state.next_id(), name)
+        .created(now.clone())
+        .modified(now)
+        .ns([
+            "hydrogen.ns.hetzner.com",
+            "oxygen.ns.hetzner.com",
+            "helium.ns.hetzner.de",
+        ])
+        .owner("fake")
+        .status("verified")
+        .ttl(ttl)
+        .txt_verification("", "")
+        .build()
+}
+
+fn not_found(resource: &str) -> (u16, Value) {
+    (
+        404,
+        json!({"error": {"message": format!("{resource} not found"), "code": 404}}),
+    )
+}
+
+fn invalid_input(message: &str) -> (u16, Value) {
+    (
+        422,
+        json!({"error": {"message": format!("422 Unprocessable Entity: {message}"), "code": 422}}),
+    )
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        409 => "Conflict",
+        422 => "Unprocessable Entity",
+        _ => "Unknown",
+    }
+}
+
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(key), percent_decode(value))
+        })
+        .collect()
+}
+
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or_default();
+                match u8::from_str_radix(hex, 16) {
+                    Ok(byte) => {
+                        out.push(byte);
+                        i += 2;
+                    }
+                    Err(_) => out.push(b'%'),
+                }
+            }
+            byte => out.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Current time in the `2006-01-02 15:04:05.000 +0000 UTC` layout the DNS API uses.
+fn now_timestamp() -> String {
+    let (year, month, day, rem, millis) = utc_now();
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02}.{millis:03} +0000 UTC",
+        rem / 3600,
+        (rem % 3600) / 60,
+        rem % 60,
+    )
+}
+
+/// Current UTC time as year, month, day, second of the day and millisecond.
+fn utc_now() -> (i64, i64, i64, u64, u32) {
+    let elapsed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let secs = elapsed.as_secs();
+    let (days, rem) = (secs / 86400, secs % 86400);
+
+    // Civil-from-days conversion (Howard Hinnant's algorithm).
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day, rem, elapsed.subsec_millis())
+}
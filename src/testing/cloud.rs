@@ -0,0 +1,518 @@
+use super::{DEFAULT_PER_PAGE, FakeRequest, paginate, serve, utc_now};
+use crate::HetznerClient;
+use serde_json::{Map, Value, json};
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinHandle;
+
+const FAKE_CLOUD_TOKEN: &str = "fake-cloud-token";
+
+#[derive(Debug, Default)]
+struct CloudState {
+    next_id: u64,
+    /// Resources by collection (`servers`, `networks`, ...) and id. Actions
+    /// live in the `actions` collection like any other resource.
+    resources: BTreeMap<String, BTreeMap<u64, Value>>,
+}
+
+impl CloudState {
+    fn next_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    fn add(&mut self, collection: &str, fields: Map<String, Value>) -> Value {
+        let id = self.next_id();
+        let mut resource = fields;
+        resource.insert("id".to_string(), json!(id));
+        resource
+            .entry("created")
+            .or_insert_with(|| json!(rfc3339_now()));
+        resource.entry("labels").or_insert_with(|| json!({}));
+        if collection == "servers" {
+            let started = resource.get("start_after_create") != Some(&json!(false));
+            resource.insert(
+                "status".to_string(),
+                json!(if started { "running" } else { "off" }),
+            );
+        }
+        let resource = Value::Object(resource);
+        self.collection(collection).insert(id, resource.clone());
+        resource
+    }
+
+    fn collection(&mut self, collection: &str) -> &mut BTreeMap<u64, Value> {
+        self.resources.entry(collection.to_string()).or_default()
+    }
+
+    /// The id of the resource a path segment names: its numeric id, or its
+    /// name for endpoints that take either.
+    fn find(&self, collection: &str, key: &str) -> Option<u64> {
+        let resources = self.resources.get(collection)?;
+        match key.parse::<u64>() {
+            Ok(id) if resources.contains_key(&id) => Some(id),
+            _ => resources
+                .iter()
+                .find(|(_, resource)| resource["name"] == key)
+                .map(|(id, _)| *id),
+        }
+    }
+
+    fn add_rrset(&mut self, zone_id: u64, fields: Map<String, Value>) -> Value {
+        let key = self.next_id();
+        let mut rrset = fields;
+        let id = format!(
+            "{}/{}",
+            rrset["name"].as_str().unwrap_or_default(),
+            rrset["type"].as_str().unwrap_or_default()
+        );
+        rrset.insert("id".to_string(), json!(id));
+        rrset.insert("zone".to_string(), json!(zone_id));
+        rrset.entry("ttl").or_insert(Value::Null);
+        rrset.entry("records").or_insert_with(|| json!([]));
+        rrset.entry("labels").or_insert_with(|| json!({}));
+        rrset
+            .entry("protection")
+            .or_insert_with(|| json!({ "change": false }));
+        let rrset = Value::Object(rrset);
+        self.collection("rrsets").insert(key, rrset.clone());
+        rrset
+    }
+
+    /// The storage key of the RRSet `name`/`rr_type` in the zone `zone_id`.
+    fn find_rrset(&self, zone_id: u64, name: &str, rr_type: &str) -> Option<u64> {
+        self.resources
+            .get("rrsets")?
+            .iter()
+            .find(|(_, rrset)| {
+                rrset["zone"] == zone_id && rrset["name"] == name && rrset["type"] == rr_type
+            })
+            .map(|(key, _)| *key)
+    }
+
+    /// Records a finished action on the resource `id` of `collection`.
+    fn action(&mut self, command: &str, collection: &str, id: u64) -> Value {
+        let now = rfc3339_now();
+        let mut action = Map::new();
+        action.insert("command".to_string(), json!(command));
+        action.insert("status".to_string(), json!("success"));
+        action.insert("progress".to_string(), json!(100));
+        action.insert("started".to_string(), json!(now));
+        action.insert("finished".to_string(), json!(now));
+        action.insert(
+            "resources".to_string(),
+            json!([{ "id": id, "type": singular(collection) }]),
+        );
+        action.insert("error".to_string(), Value::Null);
+        self.add("actions", action)
+    }
+}
+
+/// A running fake of the Cloud API's resource endpoints. The server stops
+/// when this value is dropped.
+///
+/// Every collection behaves alike: `GET /{collection}` lists (paged at 100,
+/// filtered by a `name` query), `POST` creates from the JSON body, and
+/// `GET`, `PUT` and `DELETE /{collection}/{id}` read, merge into and remove
+/// one resource. `POST /{collection}/{id}/actions/{command}` records a
+/// finished action, readable under `/actions/{id}`. Zones also serve their
+/// RRSets under `/zones/{id}/rrsets` and a `zonefile`; `metrics` and
+/// `/pricing` answer with empty data. Unknown resources answer `not_found`,
+/// and bodies that are not a JSON object `json_error`, with the Cloud API's
+/// error envelope.
+#[derive(Debug)]
+pub struct FakeCloudApi {
+    addr: SocketAddr,
+    state: Arc<Mutex<CloudState>>,
+    task: JoinHandle<()>,
+}
+
+impl FakeCloudApi {
+    pub async fn start() -> std::io::Result<Self> {
+        let state = Arc::new(Mutex::new(CloudState::default()));
+        let (addr, task) = serve(state.clone(), handle).await?;
+        Ok(Self { addr, state, task })
+    }
+
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    pub fn token(&self) -> &str {
+        FAKE_CLOUD_TOKEN
+    }
+
+    /// A client authenticated against, and pointed at, this fake.
+    pub fn client(&self) -> HetznerClient {
+        HetznerClient::new(FAKE_CLOUD_TOKEN).with_cloud_base_url(self.base_url())
+    }
+
+    /// Adds a resource with the given fields to `collection` and returns it
+    /// with its assigned `id`.
+    pub fn add(&self, collection: &str, fields: Value) -> Value {
+        let fields = match fields {
+            Value::Object(fields) => fields,
+            _ => Map::new(),
+        };
+        self.state.lock().unwrap().add(collection, fields)
+    }
+
+    /// Adds a finished action named `command` on the resource `id` of
+    /// `collection`, as listed under `/{collection}/{id}/actions`.
+    pub fn add_action(&self, collection: &str, id: u64, command: &str) -> Value {
+        self.state.lock().unwrap().action(command, collection, id)
+    }
+
+    /// Adds the RRSet `name`/`rr_type` holding `values` to the zone `zone_id`.
+    pub fn add_rrset(&self, zone_id: u64, name: &str, rr_type: &str, values: &[&str]) -> Value {
+        let records: Vec<Value> = values
+            .iter()
+            .map(|value| json!({ "value": value, "comment": null }))
+            .collect();
+        let fields = json!({ "name": name, "type": rr_type, "records": records });
+        let Value::Object(fields) = fields else {
+            unreachable!()
+        };
+        self.state.lock().unwrap().add_rrset(zone_id, fields)
+    }
+
+    pub fn resources(&self, collection: &str) -> Vec<Value> {
+        self.state
+            .lock()
+            .unwrap()
+            .resources
+            .get(collection)
+            .map(|resources| resources.values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn resource(&self, collection: &str, id: u64) -> Option<Value> {
+        self.state
+            .lock()
+            .unwrap()
+            .resources
+            .get(collection)?
+            .get(&id)
+            .cloned()
+    }
+}
+
+impl Drop for FakeCloudApi {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+fn handle(state: &mut CloudState, request: &FakeRequest) -> (u16, Value) {
+    if request.bearer.as_deref() != Some(FAKE_CLOUD_TOKEN) {
+        return error(401, "unauthorized", "unable to authenticate");
+    }
+
+    let segments: Vec<&str> = request
+        .path
+        .trim_matches('/')
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["pricing"]) => (200, json!({ "pricing": pricing() })),
+        ("GET", [collection]) => list(state, collection, request),
+        ("POST", [collection]) => create(state, collection, request),
+        ("GET", [collection, "actions"]) => {
+            let actions = resource_actions(state, collection, None);
+            listing("actions", actions, request)
+        }
+        ("GET", [_, "actions", id]) => get(state, "actions", id),
+        ("GET", [collection, key]) => get(state, collection, key),
+        ("PUT", [collection, key]) => update(state, collection, key, request),
+        ("DELETE", [collection, key]) => match state.find(collection, key) {
+            Some(id) => {
+                state.collection(collection).remove(&id);
+                let action = state.action("delete", collection, id);
+                (200, json!({ "action": action }))
+            }
+            None => not_found(collection),
+        },
+        (method, [collection, key, rest @ ..]) => {
+            let Some(id) = state.find(collection, key) else {
+                return not_found(collection);
+            };
+            match (method, rest) {
+                ("POST", ["actions", command]) => run_action(state, collection, id, command),
+                ("GET", ["actions"]) => {
+                    let actions = resource_actions(state, collection, Some(id));
+                    listing("actions", actions, request)
+                }
+                ("GET", ["actions", action_id]) => get(state, "actions", action_id),
+                ("GET", ["metrics"]) => metrics(request),
+                ("GET", ["zonefile"]) if *collection == "zones" => zonefile(state, id),
+                (method, ["rrsets", rest @ ..]) if *collection == "zones" => {
+                    rrsets(state, id, method, rest, request)
+                }
+                ("GET", [sub]) => listing(sub, Vec::new(), request),
+                _ => not_found(rest.first().copied().unwrap_or(collection)),
+            }
+        }
+        _ => not_found("resource"),
+    }
+}
+
+fn list(state: &mut CloudState, collection: &str, request: &FakeRequest) -> (u16, Value) {
+    let name = request.query("name");
+    let resources: Vec<Value> = state
+        .collection(collection)
+        .values()
+        .filter(|resource| name.is_none_or(|name| resource["name"] == name))
+        .cloned()
+        .collect();
+    listing(collection, resources, request)
+}
+
+fn listing(collection: &str, items: Vec<Value>, request: &FakeRequest) -> (u16, Value) {
+    let (page, meta) = paginate(&items, request, DEFAULT_PER_PAGE);
+    (
+        200,
+        json!({ collection: page, "meta": { "pagination": meta } }),
+    )
+}
+
+fn create(state: &mut CloudState, collection: &str, request: &FakeRequest) -> (u16, Value) {
+    let Some(Value::Object(fields)) = request.json() else {
+        return error(400, "json_error", "request body is not a JSON object");
+    };
+    if let Some(name) = fields.get("name")
+        && state
+            .collection(collection)
+            .values()
+            .any(|resource| &resource["name"] == name)
+    {
+        return error(409, "uniqueness_error", "name is already used");
+    }
+    let resource = state.add(collection, fields);
+    let id = resource["id"].as_u64().unwrap_or_default();
+    let action = state.action(&format!("create_{}", singular(collection)), collection, id);
+    (
+        201,
+        json!({ singular(collection): resource, "action": action, "next_actions": [] }),
+    )
+}
+
+fn get(state: &mut CloudState, collection: &str, key: &str) -> (u16, Value) {
+    match state.find(collection, key) {
+        Some(id) => (
+            200,
+            json!({ singular(collection): state.collection(collection)[&id] }),
+        ),
+        None => not_found(collection),
+    }
+}
+
+fn update(
+    state: &mut CloudState,
+    collection: &str,
+    key: &str,
+    request: &FakeRequest,
+) -> (u16, Value) {
+    let Some(id) = state.find(collection, key) else {
+        return not_found(collection);
+    };
+    let Some(Value::Object(fields)) = request.json() else {
+        return error(400, "json_error", "request body is not a JSON object");
+    };
+    let resource = state.collection(collection).get_mut(&id).unwrap();
+    for (field, value) in fields {
+        if field != "id" {
+            resource[field] = value;
+        }
+    }
+    (200, json!({ singular(collection): resource }))
+}
+
+fn run_action(state: &mut CloudState, collection: &str, id: u64, command: &str) -> (u16, Value) {
+    let status = match command {
+        "poweron" | "reboot" | "reset" => Some("running"),
+        "poweroff" | "shutdown" => Some("off"),
+        _ => None,
+    };
+    if let Some(status) = status.filter(|_| collection == "servers") {
+        state.collection(collection).get_mut(&id).unwrap()["status"] = json!(status);
+    }
+    let action = state.action(command, collection, id);
+    (201, json!({ "action": action }))
+}
+
+fn resource_actions(state: &mut CloudState, collection: &str, id: Option<u64>) -> Vec<Value> {
+    let resource_type = singular(collection);
+    state
+        .collection("actions")
+        .values()
+        .filter(|action| {
+            action["resources"].as_array().is_some_and(|resources| {
+                resources.iter().any(|resource| {
+                    resource["type"] == resource_type.as_str()
+                        && id.is_none_or(|id| resource["id"] == id)
+                })
+            })
+        })
+        .cloned()
+        .collect()
+}
+
+fn rrsets(
+    state: &mut CloudState,
+    zone_id: u64,
+    method: &str,
+    rest: &[&str],
+    request: &FakeRequest,
+) -> (u16, Value) {
+    match (method, rest) {
+        ("GET", []) => {
+            let rrsets = state
+                .collection("rrsets")
+                .values()
+                .filter(|rrset| rrset["zone"] == zone_id)
+                .cloned()
+                .collect();
+            listing("rrsets", rrsets, request)
+        }
+        ("POST", []) => {
+            let Some(Value::Object(fields)) = request.json() else {
+                return error(400, "json_error", "request body is not a JSON object");
+            };
+            let (Some(name), Some(rr_type)) = (fields["name"].as_str(), fields["type"].as_str())
+            else {
+                return error(422, "invalid_input", "name and type are required");
+            };
+            if state.find_rrset(zone_id, name, rr_type).is_some() {
+                return error(409, "uniqueness_error", "rrset already exists");
+            }
+            let rrset = state.add_rrset(zone_id, fields);
+            let action = state.action("create_rrset", "zones", zone_id);
+            (201, json!({ "rrset": rrset, "action": action }))
+        }
+        (method, [name, rr_type, tail @ ..]) => {
+            let Some(key) = state.find_rrset(zone_id, name, rr_type) else {
+                return not_found("rrsets");
+            };
+            match (method, tail) {
+                ("GET", []) => (200, json!({ "rrset": state.collection("rrsets")[&key] })),
+                ("PUT", []) => {
+                    let Some(Value::Object(fields)) = request.json() else {
+                        return error(400, "json_error", "request body is not a JSON object");
+                    };
+                    let rrset = state.collection("rrsets").get_mut(&key).unwrap();
+                    for (field, value) in fields {
+                        if !matches!(field.as_str(), "id" | "name" | "type" | "zone") {
+                            rrset[field] = value;
+                        }
+                    }
+                    (200, json!({ "rrset": rrset }))
+                }
+                ("DELETE", []) => {
+                    state.collection("rrsets").remove(&key);
+                    let action = state.action("delete_rrset", "zones", zone_id);
+                    (200, json!({ "action": action }))
+                }
+                ("POST", ["actions", command]) => {
+                    let action = state.action(command, "zones", zone_id);
+                    (201, json!({ "action": action }))
+                }
+                _ => not_found("rrsets"),
+            }
+        }
+        _ => not_found("rrsets"),
+    }
+}
+
+/// The zone's RRSets in zone file syntax.
+fn zonefile(state: &mut CloudState, zone_id: u64) -> (u16, Value) {
+    let zone = &state.collection("zones")[&zone_id];
+    let origin = zone["name"].as_str().unwrap_or_default().to_string();
+    let default_ttl = zone["ttl"].as_u64().unwrap_or(3600);
+    let mut lines = vec![format!("$ORIGIN {origin}."), format!("$TTL {default_ttl}")];
+    for rrset in state
+        .collection("rrsets")
+        .values()
+        .filter(|rrset| rrset["zone"] == zone_id)
+    {
+        let ttl = rrset["ttl"].as_u64().unwrap_or(default_ttl);
+        for record in rrset["records"].as_array().into_iter().flatten() {
+            lines.push(format!(
+                "{} {ttl} IN {} {}",
+                rrset["name"].as_str().unwrap_or("@"),
+                rrset["type"].as_str().unwrap_or_default(),
+                record["value"].as_str().unwrap_or_default()
+            ));
+        }
+    }
+    (200, json!({ "zonefile": lines.join("\n") + "\n" }))
+}
+
+/// An empty time series for the requested window; the fake records no
+/// usage.
+fn metrics(request: &FakeRequest) -> (u16, Value) {
+    let step = request
+        .query("step")
+        .and_then(|step| step.parse::<u64>().ok())
+        .unwrap_or(60);
+    (
+        200,
+        json!({ "metrics": {
+            "start": request.query("start"),
+            "end": request.query("end"),
+            "step": step,
+            "time_series": {},
+        } }),
+    )
+}
+
+/// A price list with the currency and VAT fields but no products.
+fn pricing() -> Value {
+    json!({
+        "currency": "EUR",
+        "vat_rate": "19.00",
+        "floating_ips": [],
+        "image": { "price_per_gb_month": { "gross": "0.0119", "net": "0.0100" } },
+        "load_balancer_types": [],
+        "primary_ips": [],
+        "server_backup": { "percentage": "20.00" },
+        "server_types": [],
+        "volume": { "price_per_gb_month": { "gross": "0.0571", "net": "0.0480" } },
+    })
+}
+
+/// `servers` → `server`, the key a single resource is wrapped in.
+fn singular(collection: &str) -> String {
+    collection
+        .strip_suffix('s')
+        .unwrap_or(collection)
+        .to_string()
+}
+
+fn not_found(collection: &str) -> (u16, Value) {
+    error(
+        404,
+        "not_found",
+        &format!("{} not found", singular(collection)),
+    )
+}
+
+fn error(status: u16, code: &str, message: &str) -> (u16, Value) {
+    (
+        status,
+        json!({ "error": { "code": code, "message": message } }),
+    )
+}
+
+/// Current time in the RFC 3339 layout the Cloud API uses.
+fn rfc3339_now() -> String {
+    let (year, month, day, rem, _) = utc_now();
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}+00:00",
+        rem / 3600,
+        (rem % 3600) / 60,
+        rem % 60,
+    )
+}
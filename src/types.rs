@@ -1,13 +1,40 @@
 use crate::api::cloud::enums::{ActionStatus, ServerStatus};
-use serde::{Deserialize, Serialize};
+use crate::api::dns::records::Ttl;
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 
+/// Treats an explicit `null` like a missing field.
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Option::unwrap_or_default)
+}
+
+/// Reads a TTL the API returned, treating one outside [`Ttl`]'s limits (or
+/// not a number at all) as unset rather than failing the whole response.
+fn lenient_ttl<'de, D>(deserializer: D) -> Result<Option<Ttl>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Option::<Value>::deserialize(deserializer)?;
+    Ok(value
+        .as_ref()
+        .and_then(Value::as_u64)
+        .and_then(|seconds| Ttl::validate(seconds).ok()))
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Meta {
     pub pagination: Pagination,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Pagination {
     pub page: u32,
     pub per_page: u32,
@@ -17,68 +44,751 @@ pub struct Pagination {
     pub total_entries: Option<u32>,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Record {
     pub id: String,
     pub name: String,
-    #[serde(default)]
-    pub ttl: u64,
+    /// `None` when the record inherits the zone's default TTL. A TTL outside
+    /// [`Ttl`]'s limits in a response is read as `None` too.
+    #[serde(default, deserialize_with = "lenient_ttl")]
+    pub ttl: Option<Ttl>,
     #[serde(rename = "type")]
     pub record_type: String,
     pub value: String,
     pub zone_id: String,
-    pub created: String,
-    pub modified: String,
+    #[serde(default)]
+    pub created: Option<String>,
+    #[serde(default)]
+    pub modified: Option<String>,
+    /// Whether the record is proxied, where the API reports it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxied: Option<bool>,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+impl Record {
+    /// The record's type; unknown types are [`RecordType::Other`].
+    pub fn kind(&self) -> RecordType {
+        RecordType::from(self.record_type.as_str())
+    }
+
+    /// The record's name relative to `zone`, lower-cased, with `@` for the
+    /// apex. Names given absolute, such as `www.example.com.`, are made
+    /// relative.
+    pub fn relative_name(&self, zone: &Zone) -> String {
+        crate::api::dns::names::relative_name(&self.name, &zone.name)
+    }
+
+    /// The record's absolute name with a trailing dot: `www.example.com.`,
+    /// or `example.com.` for the apex.
+    pub fn fqdn(&self, zone: &Zone) -> String {
+        crate::api::dns::names::absolute_name(&self.name, &zone.name)
+    }
+
+    /// The TTL the record is served with: its own, or else the zone default.
+    pub fn effective_ttl(&self, zone: &Zone) -> Ttl {
+        self.ttl.unwrap_or_else(|| zone.default_ttl())
+    }
+
+    pub fn key(&self) -> RecordKey {
+        RecordKey::new(&self.name, &self.record_type, &self.value)
+    }
+}
+
+/// Records order by their [`key`](Record::key) (name, type, value), then by
+/// the remaining fields so the order agrees with `==`.
+impl Ord for Record {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key()
+            .cmp(&other.key())
+            .then_with(|| self.ttl.cmp(&other.ttl))
+            .then_with(|| self.id.cmp(&other.id))
+            .then_with(|| self.zone_id.cmp(&other.zone_id))
+            .then_with(|| {
+                (&self.name, &self.record_type, &self.value).cmp(&(
+                    &other.name,
+                    &other.record_type,
+                    &other.value,
+                ))
+            })
+            .then_with(|| self.created.cmp(&other.created))
+            .then_with(|| self.modified.cmp(&other.modified))
+            .then_with(|| self.proxied.cmp(&other.proxied))
+    }
+}
+
+impl PartialOrd for Record {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// What a record says, with spelling differences the DNS ignores normalised
+/// away: names are trimmed, lower-cased and stripped of a trailing dot (an
+/// empty name becomes `@`), types upper-cased, hostnames in values
+/// lower-cased without trailing dot, IP addresses canonical and TXT data
+/// unquoted. Names are taken as given, so a fully qualified name and its
+/// relative form make different keys. Two records of one zone with equal keys
+/// serve the same data, so keys can be put in sets and diffed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize, Serialize)]
+pub struct RecordKey {
+    pub name: String,
+    pub record_type: String,
+    pub value: String,
+}
+
+impl RecordKey {
+    pub fn new(name: &str, record_type: &str, value: &str) -> Self {
+        let record_type = record_type.trim().to_ascii_uppercase();
+        let value = normalize_value(&record_type, value);
+        let name = match name.trim().trim_end_matches('.').to_ascii_lowercase() {
+            name if name.is_empty() => "@".to_string(),
+            name => name,
+        };
+        Self {
+            name,
+            record_type,
+            value,
+        }
+    }
+}
+
+/// `value` of a `record_type` record spelled the one way this crate compares
+/// values in, so cosmetic differences don't count as changes: IP addresses
+/// canonical (IPv6 compressed and lower-cased), hostnames in CNAME, MX, NS,
+/// PTR and SRV values lower-cased without trailing dot, TXT data unquoted
+/// with escapes resolved and split strings joined, CAA tags lower-cased with the value unquoted,
+/// and DS and TLSA digests lower-cased. Runs of whitespace between fields
+/// are collapsed; other values are only trimmed.
+pub fn normalize_value(record_type: &str, value: &str) -> String {
+    let value = value.trim();
+    let record_type = RecordType::from(record_type);
+    let fields = |map: fn(&str) -> String| value.split_whitespace().map(map).collect::<Vec<_>>();
+    match record_type {
+        RecordType::A => value
+            .parse::<std::net::Ipv4Addr>()
+            .map_or_else(|_| value.to_string(), |ip| ip.to_string()),
+        RecordType::Aaaa => value
+            .parse::<std::net::Ipv6Addr>()
+            .map_or_else(|_| value.to_ascii_lowercase(), |ip| ip.to_string()),
+        RecordType::Txt => txt_data(value),
+        _ if record_type.requires_fqdn_value() => {
+            fields(|part| part.trim_end_matches('.').to_ascii_lowercase()).join(" ")
+        }
+        RecordType::Caa => match value.splitn(3, char::is_whitespace).collect::<Vec<_>>()[..] {
+            [flags, tag, data] => format!(
+                "{flags} {} {}",
+                tag.to_ascii_lowercase(),
+                data.trim().trim_matches('"')
+            ),
+            _ => value.to_string(),
+        },
+        RecordType::Ds | RecordType::Tlsa => fields(str::to_ascii_lowercase).join(" "),
+        _ => value.to_string(),
+    }
+}
+
+/// TXT record data with quoting removed and split strings joined. Quoted
+/// values are read as zone-file character strings, where `\"` and `\\`
+/// stand for a literal quote and backslash; other escapes are kept as they
+/// are. Values not starting with a quote are taken as the data itself.
+pub(crate) fn txt_data(value: &str) -> String {
+    let value = value.trim();
+    if !value.starts_with('"') {
+        return value.to_string();
+    }
+    let mut data = String::with_capacity(value.len());
+    let mut quoted = false;
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => quoted = !quoted,
+            '\\' if quoted => match chars.next() {
+                Some(escaped @ ('"' | '\\')) => data.push(escaped),
+                Some(other) => {
+                    data.push('\\');
+                    data.push(other);
+                }
+                None => data.push('\\'),
+            },
+            c if quoted => data.push(c),
+            _ => {}
+        }
+    }
+    data
+}
+
+/// A zone-file line as `dig` prints it; the TTL column is left out when the
+/// record inherits the zone default.
+pub(crate) fn fmt_record_line(
+    f: &mut std::fmt::Formatter<'_>,
+    name: &str,
+    ttl: Option<Ttl>,
+    record_type: &str,
+    value: &str,
+) -> std::fmt::Result {
+    match ttl {
+        Some(ttl) => write!(f, "{name}\t{}\tIN\t{record_type}\t{value}", ttl.seconds()),
+        None => write!(f, "{name}\tIN\t{record_type}\t{value}"),
+    }
+}
+
+impl std::fmt::Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_record_line(f, &self.name, self.ttl, &self.record_type, &self.value)
+    }
+}
+
+/// A DNS zone as the API returns it. To make one locally, e.g. for tests,
+/// use [`Zone::builder`]; [`Default`] gives a zone with every field empty.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Zone {
-    pub created: String,
+    #[serde(default)]
+    pub created: Option<String>,
     pub id: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub is_secondary_dns: bool,
-    pub legacy_dns_host: String,
+    #[serde(default)]
+    pub legacy_dns_host: Option<String>,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub legacy_ns: Vec<String>,
-    pub modified: String,
+    #[serde(default)]
+    pub modified: Option<String>,
     pub name: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub ns: Vec<String>,
-    pub owner: String,
+    #[serde(default)]
+    pub owner: Option<String>,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub paused: bool,
-    pub permission: String,
-    pub project: String,
+    #[serde(default)]
+    pub permission: Option<String>,
+    #[serde(default)]
+    pub project: Option<String>,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub records_count: i64,
-    pub registrar: String,
-    pub status: String,
-    pub ttl: u32,
-    pub txt_verification: TxtVerification,
-    pub verified: String,
-    pub zone_type: ZoneType,
+    #[serde(default)]
+    pub registrar: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default, deserialize_with = "lenient_ttl")]
+    pub ttl: Option<Ttl>,
+    #[serde(default)]
+    pub txt_verification: Option<TxtVerification>,
+    #[serde(default)]
+    pub verified: Option<String>,
+    #[serde(default)]
+    pub zone_type: Option<ZoneType>,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct TxtVerification {
+    #[serde(default, deserialize_with = "null_as_default")]
     pub name: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub token: String,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ZoneType {
+    #[serde(default, deserialize_with = "null_as_default")]
     pub description: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub id: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub name: String,
-    pub prices: Option<Value>,
+    #[serde(default, deserialize_with = "zone_prices")]
+    pub prices: Vec<ZonePrice>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BillingPeriod {
+    Hourly,
+    Monthly,
+    Yearly,
+}
+
+/// The price of a zone type for one billing period. Amounts are decimal
+/// strings as sent by the API (e.g. `"1.1900"`) to avoid rounding.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ZonePrice {
+    pub currency: String,
+    pub vat_rate: Option<String>,
+    pub net: String,
+    pub gross: String,
+    pub billing_period: BillingPeriod,
+}
+
+/// A net/gross pair, sent as strings or numbers.
+#[derive(Deserialize)]
+struct RawAmount {
+    #[serde(deserialize_with = "decimal_string")]
+    net: String,
+    #[serde(deserialize_with = "decimal_string")]
+    gross: String,
+}
+
+/// One entry of `prices`, either flat (`net`, `gross`, `billing_period`) or
+/// with an amount per period (`price_monthly`, ...) as in the Cloud API.
+#[derive(Deserialize)]
+struct RawZonePrice {
+    #[serde(default)]
+    currency: String,
+    #[serde(default, deserialize_with = "optional_decimal_string")]
+    vat_rate: Option<String>,
+    #[serde(flatten)]
+    flat: Option<RawAmount>,
+    billing_period: Option<BillingPeriod>,
+    price_hourly: Option<RawAmount>,
+    price_monthly: Option<RawAmount>,
+    price_yearly: Option<RawAmount>,
+}
+
+impl RawZonePrice {
+    fn into_prices(self) -> Vec<ZonePrice> {
+        let periodic = [
+            (BillingPeriod::Hourly, self.price_hourly),
+            (BillingPeriod::Monthly, self.price_monthly),
+            (BillingPeriod::Yearly, self.price_yearly),
+        ];
+        let flat = self.flat.map(|amount| {
+            (
+                self.billing_period.unwrap_or(BillingPeriod::Monthly),
+                amount,
+            )
+        });
+        periodic
+            .into_iter()
+            .filter_map(|(period, amount)| amount.map(|amount| (period, amount)))
+            .chain(flat)
+            .map(|(billing_period, amount)| ZonePrice {
+                currency: self.currency.clone(),
+                vat_rate: self.vat_rate.clone(),
+                net: amount.net,
+                gross: amount.gross,
+                billing_period,
+            })
+            .collect()
+    }
+}
+
+/// Accepts `null`, a single price object, or a list of them.
+fn zone_prices<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<ZonePrice>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(Box<RawZonePrice>),
+        Many(Vec<RawZonePrice>),
+    }
+
+    Ok(match Option::<OneOrMany>::deserialize(deserializer)? {
+        None => Vec::new(),
+        Some(OneOrMany::One(price)) => price.into_prices(),
+        Some(OneOrMany::Many(prices)) => prices
+            .into_iter()
+            .flat_map(RawZonePrice::into_prices)
+            .collect(),
+    })
+}
+
+fn decimal_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    match Value::deserialize(deserializer)? {
+        Value::String(amount) => Ok(amount),
+        Value::Number(amount) => Ok(amount.to_string()),
+        other => Err(serde::de::Error::custom(format!(
+            "expected a decimal amount, got {other}"
+        ))),
+    }
+}
+
+fn optional_decimal_string<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(rate)) => Ok(Some(rate)),
+        Some(Value::Number(rate)) => Ok(Some(rate.to_string())),
+        Some(other) => Err(serde::de::Error::custom(format!(
+            "expected a decimal rate, got {other}"
+        ))),
+    }
+}
+
+/// A DNS record type. Types this crate does not know yet, e.g. ones Hetzner
+/// adds later, are kept as [`Other`](Self::Other) with their upper-cased name
+/// instead of failing deserialization.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RecordType {
+    A,
+    Aaaa,
+    Caa,
+    Cname,
+    Ds,
+    Hinfo,
+    Mx,
+    Ns,
+    Ptr,
+    Rp,
+    Soa,
+    Srv,
+    Tlsa,
+    Txt,
+    Other(String),
+}
+
+/// The TTL Hetzner applies to zones created without one.
+pub const DEFAULT_ZONE_TTL: Ttl = Ttl::DAY;
+
+impl Zone {
+    /// Starts a zone with `id` and `name`; every other field is empty until
+    /// set.
+    ///
+    /// ```
+    /// let zone = hetzner::Zone::builder("zone-1", "example.com")
+    ///     .ttl(hetzner::Ttl::HOUR)
+    ///     .status("verified")
+    ///     .records_count(2)
+    ///     .build();
+    /// assert_eq!(zone.default_ttl(), hetzner::Ttl::HOUR);
+    /// ```
+    pub fn builder(id: impl Into<String>, name: impl Into<String>) -> ZoneBuilder {
+        ZoneBuilder {
+            zone: Zone {
+                id: id.into(),
+                name: name.into(),
+                ..Zone::default()
+            },
+        }
+    }
+
+    /// The TTL inherited by records that do not set their own.
+    pub fn default_ttl(&self) -> Ttl {
+        self.ttl.unwrap_or(DEFAULT_ZONE_TTL)
+    }
+}
+
+/// Builds a [`Zone`]; see [`Zone::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ZoneBuilder {
+    zone: Zone,
+}
+
+impl ZoneBuilder {
+    pub fn ttl(mut self, ttl: Ttl) -> Self {
+        self.zone.ttl = Some(ttl);
+        self
+    }
+
+    /// E.g. `verified` or `pending`.
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.zone.status = Some(status.into());
+        self
+    }
+
+    pub fn records_count(mut self, count: i64) -> Self {
+        self.zone.records_count = count;
+        self
+    }
+
+    pub fn ns<I, S>(mut self, ns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.zone.ns = ns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn legacy_ns<I, S>(mut self, ns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.zone.legacy_ns = ns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn legacy_dns_host(mut self, host: impl Into<String>) -> Self {
+        self.zone.legacy_dns_host = Some(host.into());
+        self
+    }
+
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.zone.paused = paused;
+        self
+    }
+
+    pub fn secondary_dns(mut self, secondary: bool) -> Self {
+        self.zone.is_secondary_dns = secondary;
+        self
+    }
+
+    pub fn created(mut self, timestamp: impl Into<String>) -> Self {
+        self.zone.created = Some(timestamp.into());
+        self
+    }
+
+    pub fn modified(mut self, timestamp: impl Into<String>) -> Self {
+        self.zone.modified = Some(timestamp.into());
+        self
+    }
+
+    pub fn verified(mut self, timestamp: impl Into<String>) -> Self {
+        self.zone.verified = Some(timestamp.into());
+        self
+    }
+
+    pub fn owner(mut self, owner: impl Into<String>) -> Self {
+        self.zone.owner = Some(owner.into());
+        self
+    }
+
+    pub fn project(mut self, project: impl Into<String>) -> Self {
+        self.zone.project = Some(project.into());
+        self
+    }
+
+    pub fn permission(mut self, permission: impl Into<String>) -> Self {
+        self.zone.permission = Some(permission.into());
+        self
+    }
+
+    pub fn registrar(mut self, registrar: impl Into<String>) -> Self {
+        self.zone.registrar = Some(registrar.into());
+        self
+    }
+
+    /// The TXT record proving ownership of a pending zone.
+    pub fn txt_verification(mut self, name: impl Into<String>, token: impl Into<String>) -> Self {
+        self.zone.txt_verification = Some(TxtVerification {
+            name: name.into(),
+            token: token.into(),
+        });
+        self
+    }
+
+    pub fn zone_type(mut self, zone_type: ZoneType) -> Self {
+        self.zone.zone_type = Some(zone_type);
+        self
+    }
+
+    pub fn build(self) -> Zone {
+        self.zone
+    }
+}
+
+impl std::fmt::Display for Zone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({} records, ttl {}, {}",
+            self.name,
+            self.records_count,
+            self.default_ttl().seconds(),
+            self.status.as_deref().unwrap_or("unknown status")
+        )?;
+        if self.is_secondary_dns {
+            f.write_str(", secondary")?;
+        }
+        if self.paused {
+            f.write_str(", paused")?;
+        }
+        write!(f, ") [{}]", self.id)
+    }
+}
+
+impl RecordType {
+    pub const ALL: [Self; 14] = [
+        Self::A,
+        Self::Aaaa,
+        Self::Caa,
+        Self::Cname,
+        Self::Ds,
+        Self::Hinfo,
+        Self::Mx,
+        Self::Ns,
+        Self::Ptr,
+        Self::Rp,
+        Self::Soa,
+        Self::Srv,
+        Self::Tlsa,
+        Self::Txt,
+    ];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::A => "A",
+            Self::Aaaa => "AAAA",
+            Self::Caa => "CAA",
+            Self::Cname => "CNAME",
+            Self::Ds => "DS",
+            Self::Hinfo => "HINFO",
+            Self::Mx => "MX",
+            Self::Ns => "NS",
+            Self::Ptr => "PTR",
+            Self::Rp => "RP",
+            Self::Soa => "SOA",
+            Self::Srv => "SRV",
+            Self::Tlsa => "TLSA",
+            Self::Txt => "TXT",
+            Self::Other(name) => name,
+        }
+    }
+
+    /// Whether this is one of the types in [`ALL`](Self::ALL).
+    pub fn is_known(&self) -> bool {
+        !matches!(self, Self::Other(_))
+    }
+
+    /// A or AAAA: the value is an IP address.
+    pub fn is_address(&self) -> bool {
+        matches!(self, Self::A | Self::Aaaa)
+    }
+
+    /// Whether a name may hold several records of this type, e.g. round-robin
+    /// A records. CNAME and SOA records are singletons.
+    pub fn allows_multiple_values(&self) -> bool {
+        !matches!(self, Self::Cname | Self::Soa)
+    }
+
+    /// Whether the value names a host (the target of CNAME, NS, PTR, MX and
+    /// SRV records), which is fully qualified when it ends with a dot and
+    /// compared without case.
+    pub fn requires_fqdn_value(&self) -> bool {
+        matches!(
+            self,
+            Self::Cname | Self::Mx | Self::Ns | Self::Ptr | Self::Srv
+        )
+    }
+
+    /// A sensible TTL for new records of this type: an hour for records that
+    /// tend to change, a day for delegation and mail routing.
+    pub fn default_ttl_hint(&self) -> Ttl {
+        match self {
+            Self::Ns | Self::Soa | Self::Mx | Self::Ds | Self::Caa => Ttl::DAY,
+            _ => Ttl::HOUR,
+        }
+    }
+}
+
+impl std::fmt::Display for RecordType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for RecordType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Only accepts the known types, so typos in user input are caught; use
+/// [`RecordType::from`] to accept any type name.
+impl std::str::FromStr for RecordType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|record_type| record_type.as_str().eq_ignore_ascii_case(value))
+            .ok_or_else(|| format!("unknown record type {value}"))
+    }
+}
+
+impl From<&str> for RecordType {
+    fn from(value: &str) -> Self {
+        value
+            .parse()
+            .unwrap_or_else(|_| Self::Other(value.trim().to_ascii_uppercase()))
+    }
+}
+
+impl From<String> for RecordType {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl From<RecordType> for String {
+    fn from(value: RecordType) -> Self {
+        match value {
+            RecordType::Other(name) => name,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl From<RecordType> for std::borrow::Cow<'static, str> {
+    fn from(value: RecordType) -> Self {
+        Self::Owned(value.into())
+    }
+}
+
+impl Serialize for RecordType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::from(String::deserialize(deserializer)?))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PrimaryServer {
+    pub id: String,
+    pub address: String,
+    pub port: u16,
+    pub zone_id: String,
+    #[serde(default)]
+    pub created: Option<String>,
+    #[serde(default)]
+    pub modified: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PrimaryServerEnvelope {
+    pub primary_server: PrimaryServer,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PrimaryServersEnvelope {
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub primary_servers: Vec<PrimaryServer>,
+    #[serde(default)]
+    pub meta: Option<Meta>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CreatedRecord {
     pub record: Record,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct RecordEnvelope {
     pub record: Record,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct RecordsEnvelope {
     pub records: Vec<Record>,
     #[serde(default)]
@@ -86,6 +796,13 @@ pub struct RecordsEnvelope {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ZoneEnvelope {
+    pub zone: Zone,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ZonesEnvelope {
     pub zones: Vec<Zone>,
     #[serde(default)]
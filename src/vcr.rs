@@ -0,0 +1,132 @@
+//! Record/replay of HTTP interactions ("cassettes") for deterministic tests.
+//!
+//! In [`VcrMode::Record`] every response the client receives is appended to a
+//! JSON fixture file; in [`VcrMode::Replay`] requests are answered from that
+//! file without touching the network.
+
+use crate::error::{HetznerError, Result};
+use reqwest::{Method, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VcrMode {
+    Record,
+    Replay,
+}
+
+impl VcrMode {
+    /// `HETZNER_VCR=record` selects recording; anything else replays.
+    pub fn from_env() -> Self {
+        match std::env::var("HETZNER_VCR").as_deref() {
+            Ok("record") => Self::Record,
+            _ => Self::Replay,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Interaction {
+    pub method: String,
+    pub url: String,
+    #[serde(default)]
+    pub request_body: Option<Value>,
+    pub status: u16,
+    pub response_body: String,
+    #[serde(default, skip_serializing)]
+    replayed: bool,
+}
+
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: VcrMode,
+    interactions: Mutex<Vec<Interaction>>,
+}
+
+impl Cassette {
+    /// Opens a cassette. Replaying requires the file to exist; recording
+    /// starts from an empty cassette and overwrites the file as it goes.
+    pub fn open(path: impl AsRef<Path>, mode: VcrMode) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let failed = |err: &dyn std::fmt::Display| {
+            HetznerError::Cassette(format!("failed to read {}: {err}", path.display()))
+        };
+        let interactions = match mode {
+            VcrMode::Record => Vec::new(),
+            VcrMode::Replay => {
+                let content = fs::read_to_string(&path).map_err(|err| failed(&err))?;
+                serde_json::from_str(&content).map_err(|err| failed(&err))?
+            }
+        };
+        Ok(Self {
+            path,
+            mode,
+            interactions: Mutex::new(interactions),
+        })
+    }
+
+    pub fn mode(&self) -> VcrMode {
+        self.mode
+    }
+
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.interactions.lock().unwrap().clone()
+    }
+
+    /// Returns the first not-yet-replayed interaction matching the request.
+    pub(crate) fn replay(
+        &self,
+        method: &Method,
+        url: &str,
+        body: Option<&Value>,
+    ) -> Result<(StatusCode, Vec<u8>)> {
+        let mut interactions = self.interactions.lock().unwrap();
+        let interaction = interactions
+            .iter_mut()
+            .find(|i| {
+                !i.replayed
+                    && i.method == method.as_str()
+                    && i.url == url
+                    && i.request_body.as_ref() == body
+            })
+            .ok_or_else(|| {
+                HetznerError::Cassette(format!(
+                    "no recorded interaction for {method} {url} in {}",
+                    self.path.display()
+                ))
+            })?;
+
+        interaction.replayed = true;
+        let status = StatusCode::from_u16(interaction.status)
+            .map_err(|err| HetznerError::Cassette(err.to_string()))?;
+        Ok((status, interaction.response_body.clone().into_bytes()))
+    }
+
+    pub(crate) fn record(
+        &self,
+        method: &Method,
+        url: &str,
+        body: Option<&Value>,
+        status: StatusCode,
+        response_body: &[u8],
+    ) -> Result<()> {
+        let mut interactions = self.interactions.lock().unwrap();
+        interactions.push(Interaction {
+            method: method.to_string(),
+            url: url.to_string(),
+            request_body: body.cloned(),
+            status: status.as_u16(),
+            response_body: String::from_utf8_lossy(response_body).into_owned(),
+            replayed: false,
+        });
+
+        let serialized = serde_json::to_string_pretty(&*interactions)?;
+        fs::write(&self.path, serialized).map_err(|err| {
+            HetznerError::Cassette(format!("failed to write {}: {err}", self.path.display()))
+        })
+    }
+}
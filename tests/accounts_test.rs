@@ -0,0 +1,62 @@
+use hetzner::HetznerAccounts;
+use hetzner::testing::FakeDnsApi;
+
+#[tokio::test]
+async fn test_accounts_route_zones_to_projects() {
+    let customer_a = FakeDnsApi::start().await.unwrap();
+    customer_a.add_zone("example.com");
+    let customer_b = FakeDnsApi::start().await.unwrap();
+    customer_b.add_zone("example.org");
+    customer_b.add_zone("shop.example.com");
+
+    let mut accounts = HetznerAccounts::new()
+        .with_project("customer-a", customer_a.client())
+        .with_project("customer-b", customer_b.client())
+        .with_zone("static.example.net", "customer-a");
+    accounts.discover_zones().await.unwrap();
+
+    assert_eq!(accounts.project_for_zone("example.com"), Some("customer-a"));
+    assert_eq!(
+        accounts.project_for_zone("Example.ORG."),
+        Some("customer-b")
+    );
+    assert_eq!(
+        accounts.project_for_zone("www.shop.example.com"),
+        Some("customer-b")
+    );
+    assert_eq!(
+        accounts.project_for_zone("www.example.com"),
+        Some("customer-a")
+    );
+    assert_eq!(
+        accounts.project_for_zone("static.example.net"),
+        Some("customer-a")
+    );
+    assert_eq!(accounts.project_for_zone("notexample.com"), None);
+
+    let zones = accounts
+        .for_zone("example.org")
+        .unwrap()
+        .dns()
+        .list_zones()
+        .await
+        .unwrap();
+    assert_eq!(zones.len(), 2);
+    assert_eq!(
+        accounts.projects().collect::<Vec<_>>(),
+        ["customer-a", "customer-b"]
+    );
+}
+
+#[tokio::test]
+async fn test_accounts_discover_every_page_of_zones() {
+    let project = FakeDnsApi::start().await.unwrap();
+    for i in 0..120 {
+        project.add_zone(&format!("filler{i:03}.com"));
+    }
+    project.add_zone("late.org");
+
+    let mut accounts = HetznerAccounts::new().with_project("customer", project.client());
+    accounts.discover_zones().await.unwrap();
+    assert_eq!(accounts.project_for_zone("www.late.org"), Some("customer"));
+}
@@ -0,0 +1,1085 @@
+// This file is auto-generated by scripts/generate_cloud_models.mjs.
+// Do not edit manually.
+
+use hetzner::api::cloud::generated_models::*;
+use serde_json::json;
+
+/// Every response model parses the example response of its operation and
+/// survives a serialization round trip.
+#[test]
+fn test_generated_models_parse_spec_examples() {
+    // GET /actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}]});
+    let model: GetActionsResponse = serde_json::from_value(value).unwrap();
+    let again: GetActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /actions/{id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /certificates
+    let value = json!({"certificates":[{"id":42,"name":"my-resource","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"type":"uploaded","certificate":"-----BEGIN CERTIFICATE-----\n...","created":"2016-01-30T23:55:00Z","not_valid_before":"2019-01-08T10:00:00Z","not_valid_after":"2019-07-08T09:59:59Z","domain_names":["string"],"fingerprint":"03:c7:55:9b:2a:d1:04:17:09:f6:d0:7f:18:34:63:d4:3e:5f","status":{"issuance":"completed","renewal":"scheduled","error":{"code":"string","message":"string"}},"used_by":[{"id":4711,"type":"load_balancer"}]}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListCertificatesResponse = serde_json::from_value(value).unwrap();
+    let again: ListCertificatesResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /certificates
+    let value = json!({"certificate":{"id":42,"name":"my-resource","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"type":"uploaded","certificate":"-----BEGIN CERTIFICATE-----\n...","created":"2016-01-30T23:55:00Z","not_valid_before":"2019-01-08T10:00:00Z","not_valid_after":"2019-07-08T09:59:59Z","domain_names":["string"],"fingerprint":"03:c7:55:9b:2a:d1:04:17:09:f6:d0:7f:18:34:63:d4:3e:5f","status":{"issuance":"completed","renewal":"scheduled","error":{"code":"string","message":"string"}},"used_by":[{"id":4711,"type":"load_balancer"}]},"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: CreateCertificateResponse = serde_json::from_value(value).unwrap();
+    let again: CreateCertificateResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /certificates/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListCertificatesActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListCertificatesActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /certificates/actions/{id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetCertificatesActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetCertificatesActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /certificates/{id}
+    let value = json!({"certificate":{"id":42,"name":"my-resource","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"type":"uploaded","certificate":"-----BEGIN CERTIFICATE-----\n...","created":"2016-01-30T23:55:00Z","not_valid_before":"2019-01-08T10:00:00Z","not_valid_after":"2019-07-08T09:59:59Z","domain_names":["string"],"fingerprint":"03:c7:55:9b:2a:d1:04:17:09:f6:d0:7f:18:34:63:d4:3e:5f","status":{"issuance":"completed","renewal":"scheduled","error":{"code":"string","message":"string"}},"used_by":[{"id":4711,"type":"load_balancer"}]}});
+    let model: GetCertificateResponse = serde_json::from_value(value).unwrap();
+    let again: GetCertificateResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // PUT /certificates/{id}
+    let value = json!({"certificate":{"id":42,"name":"my-resource","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"type":"uploaded","certificate":"-----BEGIN CERTIFICATE-----\n...","created":"2016-01-30T23:55:00Z","not_valid_before":"2019-01-08T10:00:00Z","not_valid_after":"2019-07-08T09:59:59Z","domain_names":["string"],"fingerprint":"03:c7:55:9b:2a:d1:04:17:09:f6:d0:7f:18:34:63:d4:3e:5f","status":{"issuance":"completed","renewal":"scheduled","error":{"code":"string","message":"string"}},"used_by":[{"id":4711,"type":"load_balancer"}]}});
+    let model: UpdateCertificateResponse = serde_json::from_value(value).unwrap();
+    let again: UpdateCertificateResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /certificates/{id}/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListCertificateActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListCertificateActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /certificates/{id}/actions/retry
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: RetryCertificateResponse = serde_json::from_value(value).unwrap();
+    let again: RetryCertificateResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /certificates/{id}/actions/{action_id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetCertificateActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetCertificateActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /datacenters
+    let value = json!({"datacenters":[{"id":42,"name":"fsn1-dc8","description":"Falkenstein DC Park 8","location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"server_types":{"supported":[1],"available":[1],"available_for_migration":[1]}}],"recommendation":1,"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListDatacentersResponse = serde_json::from_value(value).unwrap();
+    let again: ListDatacentersResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /datacenters/{id}
+    let value = json!({"datacenter":{"id":42,"name":"fsn1-dc8","description":"Falkenstein DC Park 8","location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"server_types":{"supported":[1],"available":[1],"available_for_migration":[1]}}});
+    let model: GetDatacenterResponse = serde_json::from_value(value).unwrap();
+    let again: GetDatacenterResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /firewalls
+    let value = json!({"firewalls":[{"id":42,"name":"new-name","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z","rules":[{"description":"string","direction":"in","source_ips":["string"],"destination_ips":["string"],"protocol":"tcp","port":"80"}],"applied_to":[{"type":"server","server":{"id":42},"label_selector":{"selector":"env=prod"},"applied_to_resources":[{"type":"server","server":{"id":42}}]}]}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListFirewallsResponse = serde_json::from_value(value).unwrap();
+    let again: ListFirewallsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /firewalls
+    let value = json!({"firewall":{"id":42,"name":"new-name","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z","rules":[{"description":"string","direction":"in","source_ips":["string"],"destination_ips":["string"],"protocol":"tcp","port":"80"}],"applied_to":[{"type":"server","server":{"id":42},"label_selector":{"selector":"env=prod"},"applied_to_resources":[{"type":"server","server":{"id":42}}]}]},"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}]});
+    let model: CreateFirewallResponse = serde_json::from_value(value).unwrap();
+    let again: CreateFirewallResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /firewalls/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListFirewallsActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListFirewallsActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /firewalls/actions/{id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetFirewallsActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetFirewallsActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /firewalls/{id}
+    let value = json!({"firewall":{"id":42,"name":"new-name","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z","rules":[{"description":"string","direction":"in","source_ips":["string"],"destination_ips":["string"],"protocol":"tcp","port":"80"}],"applied_to":[{"type":"server","server":{"id":42},"label_selector":{"selector":"env=prod"},"applied_to_resources":[{"type":"server","server":{"id":42}}]}]}});
+    let model: GetFirewallResponse = serde_json::from_value(value).unwrap();
+    let again: GetFirewallResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // PUT /firewalls/{id}
+    let value = json!({"firewall":{"id":42,"name":"new-name","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z","rules":[{"description":"string","direction":"in","source_ips":["string"],"destination_ips":["string"],"protocol":"tcp","port":"80"}],"applied_to":[{"type":"server","server":{"id":42},"label_selector":{"selector":"env=prod"},"applied_to_resources":[{"type":"server","server":{"id":42}}]}]}});
+    let model: UpdateFirewallResponse = serde_json::from_value(value).unwrap();
+    let again: UpdateFirewallResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /firewalls/{id}/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListFirewallActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListFirewallActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /firewalls/{id}/actions/apply_to_resources
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}]});
+    let model: ApplyFirewallToResourcesResponse = serde_json::from_value(value).unwrap();
+    let again: ApplyFirewallToResourcesResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /firewalls/{id}/actions/remove_from_resources
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}]});
+    let model: RemoveFirewallFromResourcesResponse = serde_json::from_value(value).unwrap();
+    let again: RemoveFirewallFromResourcesResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /firewalls/{id}/actions/set_rules
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}]});
+    let model: SetFirewallRulesResponse = serde_json::from_value(value).unwrap();
+    let again: SetFirewallRulesResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /firewalls/{id}/actions/{action_id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetFirewallActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetFirewallActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /floating_ips
+    let value = json!({"floating_ips":[{"id":42,"name":"my-resource","description":"This describes my resource","ip":"2001:db8::/64","type":"ipv6","server":42,"dns_ptr":[{"ip":"2001:db8::1","dns_ptr":"server.example.com"}],"home_location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"blocked":false,"protection":{"delete":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z"}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListFloatingIpsResponse = serde_json::from_value(value).unwrap();
+    let again: ListFloatingIpsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /floating_ips
+    let value = json!({"floating_ip":{"id":42,"name":"my-resource","description":"This describes my resource","ip":"2001:db8::/64","type":"ipv6","server":42,"dns_ptr":[{"ip":"2001:db8::1","dns_ptr":"server.example.com"}],"home_location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"blocked":false,"protection":{"delete":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z"},"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: CreateFloatingIpResponse = serde_json::from_value(value).unwrap();
+    let again: CreateFloatingIpResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /floating_ips/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListFloatingIpsActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListFloatingIpsActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /floating_ips/actions/{id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetFloatingIpsActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetFloatingIpsActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /floating_ips/{id}
+    let value = json!({"floating_ip":{"id":42,"name":"my-resource","description":"This describes my resource","ip":"2001:db8::/64","type":"ipv6","server":42,"dns_ptr":[{"ip":"2001:db8::1","dns_ptr":"server.example.com"}],"home_location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"blocked":false,"protection":{"delete":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z"}});
+    let model: GetFloatingIpResponse = serde_json::from_value(value).unwrap();
+    let again: GetFloatingIpResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // PUT /floating_ips/{id}
+    let value = json!({"floating_ip":{"id":42,"name":"my-resource","description":"This describes my resource","ip":"2001:db8::/64","type":"ipv6","server":42,"dns_ptr":[{"ip":"2001:db8::1","dns_ptr":"server.example.com"}],"home_location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"blocked":false,"protection":{"delete":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z"}});
+    let model: UpdateFloatingIpResponse = serde_json::from_value(value).unwrap();
+    let again: UpdateFloatingIpResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /floating_ips/{id}/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListFloatingIpActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListFloatingIpActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /floating_ips/{id}/actions/assign
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: AssignFloatingIpResponse = serde_json::from_value(value).unwrap();
+    let again: AssignFloatingIpResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /floating_ips/{id}/actions/change_dns_ptr
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeFloatingIpDnsPtrResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeFloatingIpDnsPtrResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /floating_ips/{id}/actions/change_protection
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeFloatingIpProtectionResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeFloatingIpProtectionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /floating_ips/{id}/actions/unassign
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: UnassignFloatingIpResponse = serde_json::from_value(value).unwrap();
+    let again: UnassignFloatingIpResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /floating_ips/{id}/actions/{action_id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetFloatingIpActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetFloatingIpActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /images
+    let value = json!({"images":[{"id":42,"type":"snapshot","status":"available","name":"ubuntu-24.04","description":"Ubuntu 24.04 Standard 64 bit","image_size":2.3,"disk_size":10,"created":"2016-01-30T23:55:00Z","created_from":{"id":1,"name":"Server"},"bound_to":null,"os_flavor":"ubuntu","os_version":"24.04","rapid_deploy":false,"protection":{"delete":false},"deprecated":"2018-02-28T00:00:00Z","deleted":null,"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"architecture":"x86"}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListImagesResponse = serde_json::from_value(value).unwrap();
+    let again: ListImagesResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /images/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListImagesActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListImagesActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /images/actions/{id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetImagesActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetImagesActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /images/{id}
+    let value = json!({"image":{"id":42,"type":"snapshot","status":"available","name":"ubuntu-24.04","description":"Ubuntu 24.04 Standard 64 bit","image_size":2.3,"disk_size":10,"created":"2016-01-30T23:55:00Z","created_from":{"id":1,"name":"Server"},"bound_to":null,"os_flavor":"ubuntu","os_version":"24.04","rapid_deploy":false,"protection":{"delete":false},"deprecated":"2018-02-28T00:00:00Z","deleted":null,"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"architecture":"x86"}});
+    let model: GetImageResponse = serde_json::from_value(value).unwrap();
+    let again: GetImageResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // PUT /images/{id}
+    let value = json!({"image":{"id":42,"type":"snapshot","status":"available","name":"ubuntu-24.04","description":"Ubuntu 24.04 Standard 64 bit","image_size":2.3,"disk_size":10,"created":"2016-01-30T23:55:00Z","created_from":{"id":1,"name":"Server"},"bound_to":null,"os_flavor":"ubuntu","os_version":"24.04","rapid_deploy":false,"protection":{"delete":false},"deprecated":"2018-02-28T00:00:00Z","deleted":null,"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"architecture":"x86"}});
+    let model: UpdateImageResponse = serde_json::from_value(value).unwrap();
+    let again: UpdateImageResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /images/{id}/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListImageActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListImageActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /images/{id}/actions/change_protection
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeImageProtectionResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeImageProtectionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /images/{id}/actions/{action_id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetImageActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetImageActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /isos
+    let value = json!({"isos":[{"id":42,"name":"FreeBSD-11.0-RELEASE-amd64-dvd1","description":"FreeBSD 11.0 x64","type":"public","deprecation":{"unavailable_after":"2023-09-01T00:00:00Z","announced":"2023-06-01T00:00:00Z"},"architecture":"x86"}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListIsosResponse = serde_json::from_value(value).unwrap();
+    let again: ListIsosResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /isos/{id}
+    let value = json!({"iso":{"id":42,"name":"FreeBSD-11.0-RELEASE-amd64-dvd1","description":"FreeBSD 11.0 x64","type":"public","deprecation":{"unavailable_after":"2023-09-01T00:00:00Z","announced":"2023-06-01T00:00:00Z"},"architecture":"x86"}});
+    let model: GetIsoResponse = serde_json::from_value(value).unwrap();
+    let again: GetIsoResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /load_balancer_types
+    let value = json!({"load_balancer_types":[{"id":1,"name":"lb11","description":"LB11","max_connections":20000,"max_services":5,"max_targets":25,"max_assigned_certificates":10,"deprecated":"2016-01-30T23:50:00Z","prices":[{"location":"fsn1","price_hourly":{"net":"1.0000","gross":"1.1900"},"price_monthly":{"net":"1.0000","gross":"1.1900"},"included_traffic":654321,"price_per_tb_traffic":{"net":"1.0000","gross":"1.1900"}}]}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListLoadBalancerTypesResponse = serde_json::from_value(value).unwrap();
+    let again: ListLoadBalancerTypesResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /load_balancer_types/{id}
+    let value = json!({"load_balancer_type":{"id":1,"name":"lb11","description":"LB11","max_connections":20000,"max_services":5,"max_targets":25,"max_assigned_certificates":10,"deprecated":"2016-01-30T23:50:00Z","prices":[{"location":"fsn1","price_hourly":{"net":"1.0000","gross":"1.1900"},"price_monthly":{"net":"1.0000","gross":"1.1900"},"included_traffic":654321,"price_per_tb_traffic":{"net":"1.0000","gross":"1.1900"}}]}});
+    let model: GetLoadBalancerTypeResponse = serde_json::from_value(value).unwrap();
+    let again: GetLoadBalancerTypeResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /load_balancers
+    let value = json!({"load_balancers":[{"id":42,"name":"my-resource","public_net":{"enabled":true,"ipv4":{"ip":"1.2.3.4","dns_ptr":"lb1.example.com"},"ipv6":{"ip":"2001:db8::1","dns_ptr":"lb1.example.com"}},"private_net":[{"network":4711,"ip":"10.0.0.2"}],"location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"load_balancer_type":{"id":1,"name":"lb11","description":"LB11","max_connections":20000,"max_services":5,"max_targets":25,"max_assigned_certificates":10,"deprecated":"2016-01-30T23:50:00Z","prices":[{"location":"fsn1","price_hourly":{"net":"1.0000","gross":"1.1900"},"price_monthly":{"net":"1.0000","gross":"1.1900"},"included_traffic":654321,"price_per_tb_traffic":{"net":"1.0000","gross":"1.1900"}}]},"protection":{"delete":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z","services":[{"protocol":"https","listen_port":443,"destination_port":80,"proxyprotocol":false,"health_check":{"protocol":"http","port":4711,"interval":15,"timeout":10,"retries":3,"http":{"domain":"example.com","path":"/","response":"{\"status\": \"ok\"}","status_codes":["string"],"tls":false}},"http":{"cookie_name":"HCLBSTICKY","cookie_lifetime":300,"certificates":[1],"redirect_http":true,"sticky_sessions":true}}],"targets":[{"type":"server","server":{"id":80},"label_selector":{"selector":"env=prod"},"ip":{"ip":"203.0.113.1"},"health_status":[{"listen_port":443,"status":"healthy"}],"use_private_ip":true,"targets":[{"type":"server","server":{"id":80},"health_status":[{"listen_port":443,"status":"healthy"}],"use_private_ip":true}]}],"algorithm":{"type":"round_robin"},"outgoing_traffic":1,"ingoing_traffic":1,"included_traffic":10000}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListLoadBalancersResponse = serde_json::from_value(value).unwrap();
+    let again: ListLoadBalancersResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /load_balancers
+    let value = json!({"load_balancer":{"id":42,"name":"my-resource","public_net":{"enabled":true,"ipv4":{"ip":"1.2.3.4","dns_ptr":"lb1.example.com"},"ipv6":{"ip":"2001:db8::1","dns_ptr":"lb1.example.com"}},"private_net":[{"network":4711,"ip":"10.0.0.2"}],"location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"load_balancer_type":{"id":1,"name":"lb11","description":"LB11","max_connections":20000,"max_services":5,"max_targets":25,"max_assigned_certificates":10,"deprecated":"2016-01-30T23:50:00Z","prices":[{"location":"fsn1","price_hourly":{"net":"1.0000","gross":"1.1900"},"price_monthly":{"net":"1.0000","gross":"1.1900"},"included_traffic":654321,"price_per_tb_traffic":{"net":"1.0000","gross":"1.1900"}}]},"protection":{"delete":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z","services":[{"protocol":"https","listen_port":443,"destination_port":80,"proxyprotocol":false,"health_check":{"protocol":"http","port":4711,"interval":15,"timeout":10,"retries":3,"http":{"domain":"example.com","path":"/","response":"{\"status\": \"ok\"}","status_codes":["string"],"tls":false}},"http":{"cookie_name":"HCLBSTICKY","cookie_lifetime":300,"certificates":[1],"redirect_http":true,"sticky_sessions":true}}],"targets":[{"type":"server","server":{"id":80},"label_selector":{"selector":"env=prod"},"ip":{"ip":"203.0.113.1"},"health_status":[{"listen_port":443,"status":"healthy"}],"use_private_ip":true,"targets":[{"type":"server","server":{"id":80},"health_status":[{"listen_port":443,"status":"healthy"}],"use_private_ip":true}]}],"algorithm":{"type":"round_robin"},"outgoing_traffic":1,"ingoing_traffic":1,"included_traffic":10000},"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: CreateLoadBalancerResponse = serde_json::from_value(value).unwrap();
+    let again: CreateLoadBalancerResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /load_balancers/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListLoadBalancersActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListLoadBalancersActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /load_balancers/actions/{id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetLoadBalancersActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetLoadBalancersActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /load_balancers/{id}
+    let value = json!({"load_balancer":{"id":42,"name":"my-resource","public_net":{"enabled":true,"ipv4":{"ip":"1.2.3.4","dns_ptr":"lb1.example.com"},"ipv6":{"ip":"2001:db8::1","dns_ptr":"lb1.example.com"}},"private_net":[{"network":4711,"ip":"10.0.0.2"}],"location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"load_balancer_type":{"id":1,"name":"lb11","description":"LB11","max_connections":20000,"max_services":5,"max_targets":25,"max_assigned_certificates":10,"deprecated":"2016-01-30T23:50:00Z","prices":[{"location":"fsn1","price_hourly":{"net":"1.0000","gross":"1.1900"},"price_monthly":{"net":"1.0000","gross":"1.1900"},"included_traffic":654321,"price_per_tb_traffic":{"net":"1.0000","gross":"1.1900"}}]},"protection":{"delete":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z","services":[{"protocol":"https","listen_port":443,"destination_port":80,"proxyprotocol":false,"health_check":{"protocol":"http","port":4711,"interval":15,"timeout":10,"retries":3,"http":{"domain":"example.com","path":"/","response":"{\"status\": \"ok\"}","status_codes":["string"],"tls":false}},"http":{"cookie_name":"HCLBSTICKY","cookie_lifetime":300,"certificates":[1],"redirect_http":true,"sticky_sessions":true}}],"targets":[{"type":"server","server":{"id":80},"label_selector":{"selector":"env=prod"},"ip":{"ip":"203.0.113.1"},"health_status":[{"listen_port":443,"status":"healthy"}],"use_private_ip":true,"targets":[{"type":"server","server":{"id":80},"health_status":[{"listen_port":443,"status":"healthy"}],"use_private_ip":true}]}],"algorithm":{"type":"round_robin"},"outgoing_traffic":1,"ingoing_traffic":1,"included_traffic":10000}});
+    let model: GetLoadBalancerResponse = serde_json::from_value(value).unwrap();
+    let again: GetLoadBalancerResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // PUT /load_balancers/{id}
+    let value = json!({"load_balancer":{"id":42,"name":"my-resource","public_net":{"enabled":true,"ipv4":{"ip":"1.2.3.4","dns_ptr":"lb1.example.com"},"ipv6":{"ip":"2001:db8::1","dns_ptr":"lb1.example.com"}},"private_net":[{"network":4711,"ip":"10.0.0.2"}],"location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"load_balancer_type":{"id":1,"name":"lb11","description":"LB11","max_connections":20000,"max_services":5,"max_targets":25,"max_assigned_certificates":10,"deprecated":"2016-01-30T23:50:00Z","prices":[{"location":"fsn1","price_hourly":{"net":"1.0000","gross":"1.1900"},"price_monthly":{"net":"1.0000","gross":"1.1900"},"included_traffic":654321,"price_per_tb_traffic":{"net":"1.0000","gross":"1.1900"}}]},"protection":{"delete":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z","services":[{"protocol":"https","listen_port":443,"destination_port":80,"proxyprotocol":false,"health_check":{"protocol":"http","port":4711,"interval":15,"timeout":10,"retries":3,"http":{"domain":"example.com","path":"/","response":"{\"status\": \"ok\"}","status_codes":["string"],"tls":false}},"http":{"cookie_name":"HCLBSTICKY","cookie_lifetime":300,"certificates":[1],"redirect_http":true,"sticky_sessions":true}}],"targets":[{"type":"server","server":{"id":80},"label_selector":{"selector":"env=prod"},"ip":{"ip":"203.0.113.1"},"health_status":[{"listen_port":443,"status":"healthy"}],"use_private_ip":true,"targets":[{"type":"server","server":{"id":80},"health_status":[{"listen_port":443,"status":"healthy"}],"use_private_ip":true}]}],"algorithm":{"type":"round_robin"},"outgoing_traffic":1,"ingoing_traffic":1,"included_traffic":10000}});
+    let model: UpdateLoadBalancerResponse = serde_json::from_value(value).unwrap();
+    let again: UpdateLoadBalancerResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /load_balancers/{id}/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListLoadBalancerActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListLoadBalancerActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /load_balancers/{id}/actions/add_service
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: AddLoadBalancerServiceResponse = serde_json::from_value(value).unwrap();
+    let again: AddLoadBalancerServiceResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /load_balancers/{id}/actions/add_target
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: AddLoadBalancerTargetResponse = serde_json::from_value(value).unwrap();
+    let again: AddLoadBalancerTargetResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /load_balancers/{id}/actions/attach_to_network
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: AttachLoadBalancerToNetworkResponse = serde_json::from_value(value).unwrap();
+    let again: AttachLoadBalancerToNetworkResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /load_balancers/{id}/actions/change_algorithm
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeLoadBalancerAlgorithmResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeLoadBalancerAlgorithmResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /load_balancers/{id}/actions/change_dns_ptr
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeLoadBalancerDnsPtrResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeLoadBalancerDnsPtrResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /load_balancers/{id}/actions/change_protection
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeLoadBalancerProtectionResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeLoadBalancerProtectionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /load_balancers/{id}/actions/change_type
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeLoadBalancerTypeResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeLoadBalancerTypeResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /load_balancers/{id}/actions/delete_service
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: DeleteLoadBalancerServiceResponse = serde_json::from_value(value).unwrap();
+    let again: DeleteLoadBalancerServiceResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /load_balancers/{id}/actions/detach_from_network
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: DetachLoadBalancerFromNetworkResponse = serde_json::from_value(value).unwrap();
+    let again: DetachLoadBalancerFromNetworkResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /load_balancers/{id}/actions/disable_public_interface
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: DisableLoadBalancerPublicInterfaceResponse = serde_json::from_value(value).unwrap();
+    let again: DisableLoadBalancerPublicInterfaceResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /load_balancers/{id}/actions/enable_public_interface
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: EnableLoadBalancerPublicInterfaceResponse = serde_json::from_value(value).unwrap();
+    let again: EnableLoadBalancerPublicInterfaceResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /load_balancers/{id}/actions/remove_target
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: RemoveLoadBalancerTargetResponse = serde_json::from_value(value).unwrap();
+    let again: RemoveLoadBalancerTargetResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /load_balancers/{id}/actions/update_service
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: UpdateLoadBalancerServiceResponse = serde_json::from_value(value).unwrap();
+    let again: UpdateLoadBalancerServiceResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /load_balancers/{id}/actions/{action_id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetLoadBalancerActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetLoadBalancerActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /load_balancers/{id}/metrics
+    let value = json!({"metrics":{"start":"2017-01-01T00:00:00Z","end":"2017-01-01T23:00:00Z","step":60,"time_series":{"name_of_timeseries":{"values":[[1435781470.622,"42"],[1435781471.622,"43"]]}}}});
+    let model: GetLoadBalancerMetricsResponse = serde_json::from_value(value).unwrap();
+    let again: GetLoadBalancerMetricsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /locations
+    let value = json!({"locations":[{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListLocationsResponse = serde_json::from_value(value).unwrap();
+    let again: ListLocationsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /locations/{id}
+    let value = json!({"location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"}});
+    let model: GetLocationResponse = serde_json::from_value(value).unwrap();
+    let again: GetLocationResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /networks
+    let value = json!({"networks":[{"id":4711,"name":"mynet","ip_range":"10.0.0.0/16","subnets":[{"type":"cloud","ip_range":"10.0.1.0/24","network_zone":"eu-central","gateway":"10.0.0.1","vswitch_id":1000}],"routes":[{"destination":"10.100.1.0/24","gateway":"10.0.1.1"}],"servers":[1],"load_balancers":[1],"protection":{"delete":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z","expose_routes_to_vswitch":false}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListNetworksResponse = serde_json::from_value(value).unwrap();
+    let again: ListNetworksResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /networks
+    let value = json!({"network":{"id":4711,"name":"mynet","ip_range":"10.0.0.0/16","subnets":[{"type":"cloud","ip_range":"10.0.1.0/24","network_zone":"eu-central","gateway":"10.0.0.1","vswitch_id":1000}],"routes":[{"destination":"10.100.1.0/24","gateway":"10.0.1.1"}],"servers":[1],"load_balancers":[1],"protection":{"delete":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z","expose_routes_to_vswitch":false}});
+    let model: CreateNetworkResponse = serde_json::from_value(value).unwrap();
+    let again: CreateNetworkResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /networks/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListNetworksActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListNetworksActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /networks/actions/{id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetNetworksActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetNetworksActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /networks/{id}
+    let value = json!({"network":{"id":4711,"name":"mynet","ip_range":"10.0.0.0/16","subnets":[{"type":"cloud","ip_range":"10.0.1.0/24","network_zone":"eu-central","gateway":"10.0.0.1","vswitch_id":1000}],"routes":[{"destination":"10.100.1.0/24","gateway":"10.0.1.1"}],"servers":[1],"load_balancers":[1],"protection":{"delete":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z","expose_routes_to_vswitch":false}});
+    let model: GetNetworkResponse = serde_json::from_value(value).unwrap();
+    let again: GetNetworkResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // PUT /networks/{id}
+    let value = json!({"network":{"id":4711,"name":"mynet","ip_range":"10.0.0.0/16","subnets":[{"type":"cloud","ip_range":"10.0.1.0/24","network_zone":"eu-central","gateway":"10.0.0.1","vswitch_id":1000}],"routes":[{"destination":"10.100.1.0/24","gateway":"10.0.1.1"}],"servers":[1],"load_balancers":[1],"protection":{"delete":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z","expose_routes_to_vswitch":false}});
+    let model: UpdateNetworkResponse = serde_json::from_value(value).unwrap();
+    let again: UpdateNetworkResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /networks/{id}/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListNetworkActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListNetworkActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /networks/{id}/actions/add_route
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: AddNetworkRouteResponse = serde_json::from_value(value).unwrap();
+    let again: AddNetworkRouteResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /networks/{id}/actions/add_subnet
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: AddNetworkSubnetResponse = serde_json::from_value(value).unwrap();
+    let again: AddNetworkSubnetResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /networks/{id}/actions/change_ip_range
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeNetworkIpRangeResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeNetworkIpRangeResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /networks/{id}/actions/change_protection
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeNetworkProtectionResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeNetworkProtectionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /networks/{id}/actions/delete_route
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: DeleteNetworkRouteResponse = serde_json::from_value(value).unwrap();
+    let again: DeleteNetworkRouteResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /networks/{id}/actions/delete_subnet
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: DeleteNetworkSubnetResponse = serde_json::from_value(value).unwrap();
+    let again: DeleteNetworkSubnetResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /networks/{id}/actions/{action_id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetNetworkActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetNetworkActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /placement_groups
+    let value = json!({"placement_groups":[{"id":42,"name":"my-resource","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"type":"spread","created":"2016-01-30T23:55:00Z","servers":[1]}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListPlacementGroupsResponse = serde_json::from_value(value).unwrap();
+    let again: ListPlacementGroupsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /placement_groups
+    let value = json!({"placement_group":{"id":42,"name":"my-resource","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"type":"spread","created":"2016-01-30T23:55:00Z","servers":[1]},"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: CreatePlacementGroupResponse = serde_json::from_value(value).unwrap();
+    let again: CreatePlacementGroupResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /placement_groups/{id}
+    let value = json!({"placement_group":{"id":42,"name":"my-resource","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"type":"spread","created":"2016-01-30T23:55:00Z","servers":[1]}});
+    let model: GetPlacementGroupResponse = serde_json::from_value(value).unwrap();
+    let again: GetPlacementGroupResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // PUT /placement_groups/{id}
+    let value = json!({"placement_group":{"id":42,"name":"my-resource","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"type":"spread","created":"2016-01-30T23:55:00Z","servers":[1]}});
+    let model: UpdatePlacementGroupResponse = serde_json::from_value(value).unwrap();
+    let again: UpdatePlacementGroupResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /pricing
+    let value = json!({"pricing":{"currency":"EUR","vat_rate":"19.00","primary_ips":[{"type":"ipv4","prices":[{"location":"fsn1","price_hourly":{"net":"1.0000","gross":"1.1900"},"price_monthly":{"net":"1.0000","gross":"1.1900"}}]}],"floating_ips":[{"type":"ipv4","prices":[{"location":"fsn1","price_monthly":{"net":"1.0000","gross":"1.1900"}}]}],"image":{"price_per_gb_month":{"net":"1.0000","gross":"1.1900"}},"volume":{"price_per_gb_month":{"net":"1.0000","gross":"1.1900"}},"server_backup":{"percentage":"20.00"},"server_types":[{"id":104,"name":"cpx22","prices":[{"location":"fsn1","price_hourly":{"net":"1.0000","gross":"1.1900"},"price_monthly":{"net":"1.0000","gross":"1.1900"},"included_traffic":654321,"price_per_tb_traffic":{"net":"1.0000","gross":"1.1900"}}]}],"load_balancer_types":[{"id":1,"name":"lb11","prices":[{"location":"fsn1","price_hourly":{"net":"1.0000","gross":"1.1900"},"price_monthly":{"net":"1.0000","gross":"1.1900"},"included_traffic":654321,"price_per_tb_traffic":{"net":"1.0000","gross":"1.1900"}}]}],"floating_ip":{"price_monthly":{"net":"1.0000","gross":"1.1900"}}}});
+    let model: GetPricingResponse = serde_json::from_value(value).unwrap();
+    let again: GetPricingResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /primary_ips
+    let value = json!({"primary_ips":[{"id":42,"name":"my-resource","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z","blocked":false,"datacenter":{"id":42,"name":"fsn1-dc8","description":"Falkenstein DC Park 8","location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"server_types":{"supported":[1],"available":[1],"available_for_migration":[1]}},"location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"ip":"2001:db8::/64","dns_ptr":[{"ip":"2001:db8::1","dns_ptr":"server.example.com"}],"protection":{"delete":false},"type":"ipv6","auto_delete":true,"assignee_type":"server","assignee_id":17}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListPrimaryIpsResponse = serde_json::from_value(value).unwrap();
+    let again: ListPrimaryIpsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /primary_ips
+    let value = json!({"primary_ip":{"id":42,"name":"my-resource","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z","blocked":false,"datacenter":{"id":42,"name":"fsn1-dc8","description":"Falkenstein DC Park 8","location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"server_types":{"supported":[1],"available":[1],"available_for_migration":[1]}},"location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"ip":"2001:db8::/64","dns_ptr":[{"ip":"2001:db8::1","dns_ptr":"server.example.com"}],"protection":{"delete":false},"type":"ipv6","auto_delete":true,"assignee_type":"server","assignee_id":17},"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: CreatePrimaryIpResponse = serde_json::from_value(value).unwrap();
+    let again: CreatePrimaryIpResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /primary_ips/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListPrimaryIpsActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListPrimaryIpsActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /primary_ips/actions/{id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetPrimaryIpsActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetPrimaryIpsActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /primary_ips/{id}
+    let value = json!({"primary_ip":{"id":42,"name":"my-resource","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z","blocked":false,"datacenter":{"id":42,"name":"fsn1-dc8","description":"Falkenstein DC Park 8","location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"server_types":{"supported":[1],"available":[1],"available_for_migration":[1]}},"location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"ip":"2001:db8::/64","dns_ptr":[{"ip":"2001:db8::1","dns_ptr":"server.example.com"}],"protection":{"delete":false},"type":"ipv6","auto_delete":true,"assignee_type":"server","assignee_id":17}});
+    let model: GetPrimaryIpResponse = serde_json::from_value(value).unwrap();
+    let again: GetPrimaryIpResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // PUT /primary_ips/{id}
+    let value = json!({"primary_ip":{"id":42,"name":"my-resource","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z","blocked":false,"datacenter":{"id":42,"name":"fsn1-dc8","description":"Falkenstein DC Park 8","location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"server_types":{"supported":[1],"available":[1],"available_for_migration":[1]}},"location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"ip":"2001:db8::/64","dns_ptr":[{"ip":"2001:db8::1","dns_ptr":"server.example.com"}],"protection":{"delete":false},"type":"ipv6","auto_delete":true,"assignee_type":"server","assignee_id":17}});
+    let model: UpdatePrimaryIpResponse = serde_json::from_value(value).unwrap();
+    let again: UpdatePrimaryIpResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /primary_ips/{id}/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListPrimaryIpActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListPrimaryIpActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /primary_ips/{id}/actions/assign
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: AssignPrimaryIpResponse = serde_json::from_value(value).unwrap();
+    let again: AssignPrimaryIpResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /primary_ips/{id}/actions/change_dns_ptr
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangePrimaryIpDnsPtrResponse = serde_json::from_value(value).unwrap();
+    let again: ChangePrimaryIpDnsPtrResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /primary_ips/{id}/actions/change_protection
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangePrimaryIpProtectionResponse = serde_json::from_value(value).unwrap();
+    let again: ChangePrimaryIpProtectionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /primary_ips/{id}/actions/unassign
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: UnassignPrimaryIpResponse = serde_json::from_value(value).unwrap();
+    let again: UnassignPrimaryIpResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /primary_ips/{id}/actions/{action_id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetPrimaryIpActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetPrimaryIpActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /server_types
+    let value = json!({"server_types":[{"id":1,"name":"cpx22","description":"CPX22","cores":2,"memory":4,"disk":80,"deprecated":false,"prices":[{"location":"fsn1","price_hourly":{"net":"1.0000","gross":"1.1900"},"price_monthly":{"net":"1.0000","gross":"1.1900"},"included_traffic":654321,"price_per_tb_traffic":{"net":"1.0000","gross":"1.1900"}}],"storage_type":"local","cpu_type":"shared","category":"Shared vCPU","architecture":"x86","deprecation":{"unavailable_after":"2023-09-01T00:00:00Z","announced":"2023-06-01T00:00:00Z"},"locations":[{"id":42,"name":"fsn1","deprecation":{"unavailable_after":"2023-09-01T00:00:00Z","announced":"2023-06-01T00:00:00Z"}}]}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListServerTypesResponse = serde_json::from_value(value).unwrap();
+    let again: ListServerTypesResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /server_types/{id}
+    let value = json!({"server_type":{"id":1,"name":"cpx22","description":"CPX22","cores":2,"memory":4,"disk":80,"deprecated":false,"prices":[{"location":"fsn1","price_hourly":{"net":"1.0000","gross":"1.1900"},"price_monthly":{"net":"1.0000","gross":"1.1900"},"included_traffic":654321,"price_per_tb_traffic":{"net":"1.0000","gross":"1.1900"}}],"storage_type":"local","cpu_type":"shared","category":"Shared vCPU","architecture":"x86","deprecation":{"unavailable_after":"2023-09-01T00:00:00Z","announced":"2023-06-01T00:00:00Z"},"locations":[{"id":42,"name":"fsn1","deprecation":{"unavailable_after":"2023-09-01T00:00:00Z","announced":"2023-06-01T00:00:00Z"}}]}});
+    let model: GetServerTypeResponse = serde_json::from_value(value).unwrap();
+    let again: GetServerTypeResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /servers
+    let value = json!({"servers":[{"id":42,"name":"my-resource","status":"running","created":"2016-01-30T23:55:00Z","public_net":{"ipv4":{"id":42,"ip":"1.2.3.4","blocked":false,"dns_ptr":"server01.example.com"},"ipv6":{"id":42,"ip":"2001:db8::/64","blocked":false,"dns_ptr":[{"ip":"2001:db8::1","dns_ptr":"server.example.com"}]},"floating_ips":[1],"firewalls":[{"id":42,"status":"applied"}]},"private_net":[{"network":4711,"ip":"10.0.0.2","alias_ips":["string"],"mac_address":"86:00:ff:2a:7d:e1"}],"server_type":{"id":1,"name":"cpx22","description":"CPX22","cores":2,"memory":4,"disk":80,"deprecated":false,"prices":[{"location":"fsn1","price_hourly":{"net":"1.0000","gross":"1.1900"},"price_monthly":{"net":"1.0000","gross":"1.1900"},"included_traffic":654321,"price_per_tb_traffic":{"net":"1.0000","gross":"1.1900"}}],"storage_type":"local","cpu_type":"shared","category":"Shared vCPU","architecture":"x86","deprecation":{"unavailable_after":"2023-09-01T00:00:00Z","announced":"2023-06-01T00:00:00Z"},"locations":[{"id":42,"name":"fsn1","deprecation":{"unavailable_after":"2023-09-01T00:00:00Z","announced":"2023-06-01T00:00:00Z"}}]},"datacenter":{"id":42,"name":"fsn1-dc8","description":"Falkenstein DC Park 8","location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"server_types":{"supported":[1],"available":[1],"available_for_migration":[1]}},"location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"image":{"id":42,"type":"snapshot","status":"available","name":"ubuntu-24.04","description":"Ubuntu 24.04 Standard 64 bit","image_size":2.3,"disk_size":10,"created":"2016-01-30T23:55:00Z","created_from":{"id":1,"name":"Server"},"bound_to":null,"os_flavor":"ubuntu","os_version":"24.04","rapid_deploy":false,"protection":{"delete":false},"deprecated":"2018-02-28T00:00:00Z","deleted":null,"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"architecture":"x86"},"iso":{"id":42,"name":"FreeBSD-11.0-RELEASE-amd64-dvd1","description":"FreeBSD 11.0 x64","type":"public","deprecation":{"unavailable_after":"2023-09-01T00:00:00Z","announced":"2023-06-01T00:00:00Z"},"architecture":"x86"},"rescue_enabled":false,"locked":false,"backup_window":"22-02","outgoing_traffic":123456,"ingoing_traffic":123456,"included_traffic":654321,"protection":{"delete":false,"rebuild":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"volumes":[1],"load_balancers":[1],"primary_disk_size":50,"placement_group":{"id":42,"name":"my-resource","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"type":"spread","created":"2016-01-30T23:55:00Z","servers":[1]}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListServersResponse = serde_json::from_value(value).unwrap();
+    let again: ListServersResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers
+    let value = json!({"server":{"id":42,"name":"my-resource","status":"running","created":"2016-01-30T23:55:00Z","public_net":{"ipv4":{"id":42,"ip":"1.2.3.4","blocked":false,"dns_ptr":"server01.example.com"},"ipv6":{"id":42,"ip":"2001:db8::/64","blocked":false,"dns_ptr":[{"ip":"2001:db8::1","dns_ptr":"server.example.com"}]},"floating_ips":[1],"firewalls":[{"id":42,"status":"applied"}]},"private_net":[{"network":4711,"ip":"10.0.0.2","alias_ips":["string"],"mac_address":"86:00:ff:2a:7d:e1"}],"server_type":{"id":1,"name":"cpx22","description":"CPX22","cores":2,"memory":4,"disk":80,"deprecated":false,"prices":[{"location":"fsn1","price_hourly":{"net":"1.0000","gross":"1.1900"},"price_monthly":{"net":"1.0000","gross":"1.1900"},"included_traffic":654321,"price_per_tb_traffic":{"net":"1.0000","gross":"1.1900"}}],"storage_type":"local","cpu_type":"shared","category":"Shared vCPU","architecture":"x86","deprecation":{"unavailable_after":"2023-09-01T00:00:00Z","announced":"2023-06-01T00:00:00Z"},"locations":[{"id":42,"name":"fsn1","deprecation":{"unavailable_after":"2023-09-01T00:00:00Z","announced":"2023-06-01T00:00:00Z"}}]},"datacenter":{"id":42,"name":"fsn1-dc8","description":"Falkenstein DC Park 8","location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"server_types":{"supported":[1],"available":[1],"available_for_migration":[1]}},"location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"image":{"id":42,"type":"snapshot","status":"available","name":"ubuntu-24.04","description":"Ubuntu 24.04 Standard 64 bit","image_size":2.3,"disk_size":10,"created":"2016-01-30T23:55:00Z","created_from":{"id":1,"name":"Server"},"bound_to":null,"os_flavor":"ubuntu","os_version":"24.04","rapid_deploy":false,"protection":{"delete":false},"deprecated":"2018-02-28T00:00:00Z","deleted":null,"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"architecture":"x86"},"iso":{"id":42,"name":"FreeBSD-11.0-RELEASE-amd64-dvd1","description":"FreeBSD 11.0 x64","type":"public","deprecation":{"unavailable_after":"2023-09-01T00:00:00Z","announced":"2023-06-01T00:00:00Z"},"architecture":"x86"},"rescue_enabled":false,"locked":false,"backup_window":"22-02","outgoing_traffic":123456,"ingoing_traffic":123456,"included_traffic":654321,"protection":{"delete":false,"rebuild":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"volumes":[1],"load_balancers":[1],"primary_disk_size":50,"placement_group":{"id":42,"name":"my-resource","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"type":"spread","created":"2016-01-30T23:55:00Z","servers":[1]}},"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}},"next_actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"root_password":"YItygq1v3GYjjMomLaKc"});
+    let model: CreateServerResponse = serde_json::from_value(value).unwrap();
+    let again: CreateServerResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /servers/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListServersActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListServersActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /servers/actions/{id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetServersActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetServersActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /servers/{id}
+    let value = json!({"server":{"id":42,"name":"my-resource","status":"running","created":"2016-01-30T23:55:00Z","public_net":{"ipv4":{"id":42,"ip":"1.2.3.4","blocked":false,"dns_ptr":"server01.example.com"},"ipv6":{"id":42,"ip":"2001:db8::/64","blocked":false,"dns_ptr":[{"ip":"2001:db8::1","dns_ptr":"server.example.com"}]},"floating_ips":[1],"firewalls":[{"id":42,"status":"applied"}]},"private_net":[{"network":4711,"ip":"10.0.0.2","alias_ips":["string"],"mac_address":"86:00:ff:2a:7d:e1"}],"server_type":{"id":1,"name":"cpx22","description":"CPX22","cores":2,"memory":4,"disk":80,"deprecated":false,"prices":[{"location":"fsn1","price_hourly":{"net":"1.0000","gross":"1.1900"},"price_monthly":{"net":"1.0000","gross":"1.1900"},"included_traffic":654321,"price_per_tb_traffic":{"net":"1.0000","gross":"1.1900"}}],"storage_type":"local","cpu_type":"shared","category":"Shared vCPU","architecture":"x86","deprecation":{"unavailable_after":"2023-09-01T00:00:00Z","announced":"2023-06-01T00:00:00Z"},"locations":[{"id":42,"name":"fsn1","deprecation":{"unavailable_after":"2023-09-01T00:00:00Z","announced":"2023-06-01T00:00:00Z"}}]},"datacenter":{"id":42,"name":"fsn1-dc8","description":"Falkenstein DC Park 8","location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"server_types":{"supported":[1],"available":[1],"available_for_migration":[1]}},"location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"image":{"id":42,"type":"snapshot","status":"available","name":"ubuntu-24.04","description":"Ubuntu 24.04 Standard 64 bit","image_size":2.3,"disk_size":10,"created":"2016-01-30T23:55:00Z","created_from":{"id":1,"name":"Server"},"bound_to":null,"os_flavor":"ubuntu","os_version":"24.04","rapid_deploy":false,"protection":{"delete":false},"deprecated":"2018-02-28T00:00:00Z","deleted":null,"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"architecture":"x86"},"iso":{"id":42,"name":"FreeBSD-11.0-RELEASE-amd64-dvd1","description":"FreeBSD 11.0 x64","type":"public","deprecation":{"unavailable_after":"2023-09-01T00:00:00Z","announced":"2023-06-01T00:00:00Z"},"architecture":"x86"},"rescue_enabled":false,"locked":false,"backup_window":"22-02","outgoing_traffic":123456,"ingoing_traffic":123456,"included_traffic":654321,"protection":{"delete":false,"rebuild":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"volumes":[1],"load_balancers":[1],"primary_disk_size":50,"placement_group":{"id":42,"name":"my-resource","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"type":"spread","created":"2016-01-30T23:55:00Z","servers":[1]}}});
+    let model: GetServerResponse = serde_json::from_value(value).unwrap();
+    let again: GetServerResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // PUT /servers/{id}
+    let value = json!({"server":{"id":42,"name":"my-resource","status":"running","created":"2016-01-30T23:55:00Z","public_net":{"ipv4":{"id":42,"ip":"1.2.3.4","blocked":false,"dns_ptr":"server01.example.com"},"ipv6":{"id":42,"ip":"2001:db8::/64","blocked":false,"dns_ptr":[{"ip":"2001:db8::1","dns_ptr":"server.example.com"}]},"floating_ips":[1],"firewalls":[{"id":42,"status":"applied"}]},"private_net":[{"network":4711,"ip":"10.0.0.2","alias_ips":["string"],"mac_address":"86:00:ff:2a:7d:e1"}],"server_type":{"id":1,"name":"cpx22","description":"CPX22","cores":2,"memory":4,"disk":80,"deprecated":false,"prices":[{"location":"fsn1","price_hourly":{"net":"1.0000","gross":"1.1900"},"price_monthly":{"net":"1.0000","gross":"1.1900"},"included_traffic":654321,"price_per_tb_traffic":{"net":"1.0000","gross":"1.1900"}}],"storage_type":"local","cpu_type":"shared","category":"Shared vCPU","architecture":"x86","deprecation":{"unavailable_after":"2023-09-01T00:00:00Z","announced":"2023-06-01T00:00:00Z"},"locations":[{"id":42,"name":"fsn1","deprecation":{"unavailable_after":"2023-09-01T00:00:00Z","announced":"2023-06-01T00:00:00Z"}}]},"datacenter":{"id":42,"name":"fsn1-dc8","description":"Falkenstein DC Park 8","location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"server_types":{"supported":[1],"available":[1],"available_for_migration":[1]}},"location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"image":{"id":42,"type":"snapshot","status":"available","name":"ubuntu-24.04","description":"Ubuntu 24.04 Standard 64 bit","image_size":2.3,"disk_size":10,"created":"2016-01-30T23:55:00Z","created_from":{"id":1,"name":"Server"},"bound_to":null,"os_flavor":"ubuntu","os_version":"24.04","rapid_deploy":false,"protection":{"delete":false},"deprecated":"2018-02-28T00:00:00Z","deleted":null,"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"architecture":"x86"},"iso":{"id":42,"name":"FreeBSD-11.0-RELEASE-amd64-dvd1","description":"FreeBSD 11.0 x64","type":"public","deprecation":{"unavailable_after":"2023-09-01T00:00:00Z","announced":"2023-06-01T00:00:00Z"},"architecture":"x86"},"rescue_enabled":false,"locked":false,"backup_window":"22-02","outgoing_traffic":123456,"ingoing_traffic":123456,"included_traffic":654321,"protection":{"delete":false,"rebuild":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"volumes":[1],"load_balancers":[1],"primary_disk_size":50,"placement_group":{"id":42,"name":"my-resource","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"type":"spread","created":"2016-01-30T23:55:00Z","servers":[1]}}});
+    let model: UpdateServerResponse = serde_json::from_value(value).unwrap();
+    let again: UpdateServerResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // DELETE /servers/{id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: DeleteServerResponse = serde_json::from_value(value).unwrap();
+    let again: DeleteServerResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /servers/{id}/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListServerActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListServerActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/add_to_placement_group
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: AddServerToPlacementGroupResponse = serde_json::from_value(value).unwrap();
+    let again: AddServerToPlacementGroupResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/attach_iso
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: AttachServerIsoResponse = serde_json::from_value(value).unwrap();
+    let again: AttachServerIsoResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/attach_to_network
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: AttachServerToNetworkResponse = serde_json::from_value(value).unwrap();
+    let again: AttachServerToNetworkResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/change_alias_ips
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeServerAliasIpsResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeServerAliasIpsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/change_dns_ptr
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeServerDnsPtrResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeServerDnsPtrResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/change_protection
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeServerProtectionResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeServerProtectionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/change_type
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeServerTypeResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeServerTypeResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/create_image
+    let value = json!({"image":{"id":42,"type":"snapshot","status":"available","name":"ubuntu-24.04","description":"Ubuntu 24.04 Standard 64 bit","image_size":2.3,"disk_size":10,"created":"2016-01-30T23:55:00Z","created_from":{"id":1,"name":"Server"},"bound_to":null,"os_flavor":"ubuntu","os_version":"24.04","rapid_deploy":false,"protection":{"delete":false},"deprecated":"2018-02-28T00:00:00Z","deleted":null,"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"architecture":"x86"},"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: CreateServerImageResponse = serde_json::from_value(value).unwrap();
+    let again: CreateServerImageResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/detach_from_network
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: DetachServerFromNetworkResponse = serde_json::from_value(value).unwrap();
+    let again: DetachServerFromNetworkResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/detach_iso
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: DetachServerIsoResponse = serde_json::from_value(value).unwrap();
+    let again: DetachServerIsoResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/disable_backup
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: DisableServerBackupResponse = serde_json::from_value(value).unwrap();
+    let again: DisableServerBackupResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/disable_rescue
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: DisableServerRescueResponse = serde_json::from_value(value).unwrap();
+    let again: DisableServerRescueResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/enable_backup
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: EnableServerBackupResponse = serde_json::from_value(value).unwrap();
+    let again: EnableServerBackupResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/enable_rescue
+    let value = json!({"root_password":"zCWbFhnu950dUTko5f40","action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: EnableServerRescueResponse = serde_json::from_value(value).unwrap();
+    let again: EnableServerRescueResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/poweroff
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: PoweroffServerResponse = serde_json::from_value(value).unwrap();
+    let again: PoweroffServerResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/poweron
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: PoweronServerResponse = serde_json::from_value(value).unwrap();
+    let again: PoweronServerResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/reboot
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: RebootServerResponse = serde_json::from_value(value).unwrap();
+    let again: RebootServerResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/rebuild
+    let value = json!({"root_password":"string","action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: RebuildServerResponse = serde_json::from_value(value).unwrap();
+    let again: RebuildServerResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/remove_from_placement_group
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: RemoveServerFromPlacementGroupResponse = serde_json::from_value(value).unwrap();
+    let again: RemoveServerFromPlacementGroupResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/request_console
+    let value = json!({"wss_url":"wss://console.hetzner.cloud/?server_id=1&token=3db32d15-af2f-459c-8bf8-dee1fd05f49c","password":"9MQaTg2VAGI0FIpc10k3UpRXcHj2wQ6x","action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: RequestServerConsoleResponse = serde_json::from_value(value).unwrap();
+    let again: RequestServerConsoleResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/reset
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ResetServerResponse = serde_json::from_value(value).unwrap();
+    let again: ResetServerResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/reset_password
+    let value = json!({"root_password":"zCWbFhnu950dUTko5f40","action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ResetServerPasswordResponse = serde_json::from_value(value).unwrap();
+    let again: ResetServerPasswordResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /servers/{id}/actions/shutdown
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ShutdownServerResponse = serde_json::from_value(value).unwrap();
+    let again: ShutdownServerResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /servers/{id}/actions/{action_id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetServerActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetServerActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /servers/{id}/metrics
+    let value = json!({"metrics":{"start":"2017-01-01T00:00:00Z","end":"2017-01-01T23:00:00Z","step":60,"time_series":{"name_of_timeseries":{"values":[[1435781470.622,"42"],[1435781471.622,"43"]]}}}});
+    let model: GetServerMetricsResponse = serde_json::from_value(value).unwrap();
+    let again: GetServerMetricsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /ssh_keys
+    let value = json!({"ssh_keys":[{"id":42,"name":"my-resource","fingerprint":"b7:2f:30:a0:2f:6c:58:6c:21:04:58:61:ba:06:3b:2f","public_key":"ssh-rsa AAAjjk76kgf...Xt","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z"}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListSshKeysResponse = serde_json::from_value(value).unwrap();
+    let again: ListSshKeysResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /ssh_keys
+    let value = json!({"ssh_key":{"id":42,"name":"my-resource","fingerprint":"b7:2f:30:a0:2f:6c:58:6c:21:04:58:61:ba:06:3b:2f","public_key":"ssh-rsa AAAjjk76kgf...Xt","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z"}});
+    let model: CreateSshKeyResponse = serde_json::from_value(value).unwrap();
+    let again: CreateSshKeyResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /ssh_keys/{id}
+    let value = json!({"ssh_key":{"id":42,"name":"my-resource","fingerprint":"b7:2f:30:a0:2f:6c:58:6c:21:04:58:61:ba:06:3b:2f","public_key":"ssh-rsa AAAjjk76kgf...Xt","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z"}});
+    let model: GetSshKeyResponse = serde_json::from_value(value).unwrap();
+    let again: GetSshKeyResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // PUT /ssh_keys/{id}
+    let value = json!({"ssh_key":{"id":42,"name":"my-resource","fingerprint":"b7:2f:30:a0:2f:6c:58:6c:21:04:58:61:ba:06:3b:2f","public_key":"ssh-rsa AAAjjk76kgf...Xt","labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"created":"2016-01-30T23:55:00Z"}});
+    let model: UpdateSshKeyResponse = serde_json::from_value(value).unwrap();
+    let again: UpdateSshKeyResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /volumes
+    let value = json!({"volumes":[{"id":42,"created":"2016-01-30T23:55:00Z","name":"my-resource","server":12,"location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"size":42,"linux_device":"/dev/disk/by-id/scsi-0HC_Volume_4711","protection":{"delete":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"status":"available","format":"xfs"}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListVolumesResponse = serde_json::from_value(value).unwrap();
+    let again: ListVolumesResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /volumes
+    let value = json!({"volume":{"id":42,"created":"2016-01-30T23:55:00Z","name":"my-resource","server":12,"location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"size":42,"linux_device":"/dev/disk/by-id/scsi-0HC_Volume_4711","protection":{"delete":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"status":"available","format":"xfs"},"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}},"next_actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}]});
+    let model: CreateVolumeResponse = serde_json::from_value(value).unwrap();
+    let again: CreateVolumeResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /volumes/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListVolumesActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListVolumesActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /volumes/actions/{id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetVolumesActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetVolumesActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /volumes/{id}
+    let value = json!({"volume":{"id":42,"created":"2016-01-30T23:55:00Z","name":"my-resource","server":12,"location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"size":42,"linux_device":"/dev/disk/by-id/scsi-0HC_Volume_4711","protection":{"delete":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"status":"available","format":"xfs"}});
+    let model: GetVolumeResponse = serde_json::from_value(value).unwrap();
+    let again: GetVolumeResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // PUT /volumes/{id}
+    let value = json!({"volume":{"id":42,"created":"2016-01-30T23:55:00Z","name":"my-resource","server":12,"location":{"id":42,"name":"fsn1","description":"Falkenstein DC Park 1","country":"DE","city":"Falkenstein","latitude":50.47612,"longitude":12.370071,"network_zone":"eu-central"},"size":42,"linux_device":"/dev/disk/by-id/scsi-0HC_Volume_4711","protection":{"delete":false},"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"status":"available","format":"xfs"}});
+    let model: UpdateVolumeResponse = serde_json::from_value(value).unwrap();
+    let again: UpdateVolumeResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /volumes/{id}/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListVolumeActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListVolumeActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /volumes/{id}/actions/attach
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: AttachVolumeResponse = serde_json::from_value(value).unwrap();
+    let again: AttachVolumeResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /volumes/{id}/actions/change_protection
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeVolumeProtectionResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeVolumeProtectionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /volumes/{id}/actions/detach
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: DetachVolumeResponse = serde_json::from_value(value).unwrap();
+    let again: DetachVolumeResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /volumes/{id}/actions/resize
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ResizeVolumeResponse = serde_json::from_value(value).unwrap();
+    let again: ResizeVolumeResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /volumes/{id}/actions/{action_id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetVolumeActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetVolumeActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /zones
+    let value = json!({"zones":[{"id":42,"name":"example.com","created":"2016-01-30T23:55:00Z","primary_nameservers":[{"address":"198.51.100.1","port":5353,"tsig_key":"string","tsig_algorithm":"hmac-md5"}],"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"protection":{"delete":false},"ttl":10800,"status":"ok","record_count":1,"authoritative_nameservers":{"assigned":["string"],"delegated":["string"],"delegation_last_check":"2016-01-30T23:55:00Z","delegation_status":"valid"},"registrar":"hetzner","mode":"primary"}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListZonesResponse = serde_json::from_value(value).unwrap();
+    let again: ListZonesResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /zones
+    let value = json!({"zone":{"id":42,"name":"example.com","created":"2016-01-30T23:55:00Z","primary_nameservers":[{"address":"198.51.100.1","port":5353,"tsig_key":"string","tsig_algorithm":"hmac-md5"}],"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"protection":{"delete":false},"ttl":10800,"status":"ok","record_count":1,"authoritative_nameservers":{"assigned":["string"],"delegated":["string"],"delegation_last_check":"2016-01-30T23:55:00Z","delegation_status":"valid"},"registrar":"hetzner","mode":"primary"},"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: CreateZoneResponse = serde_json::from_value(value).unwrap();
+    let again: CreateZoneResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /zones/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListZonesActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListZonesActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /zones/actions/{id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetZonesActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetZonesActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /zones/{id_or_name}
+    let value = json!({"zone":{"id":42,"name":"example.com","created":"2016-01-30T23:55:00Z","primary_nameservers":[{"address":"198.51.100.1","port":5353,"tsig_key":"string","tsig_algorithm":"hmac-md5"}],"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"protection":{"delete":false},"ttl":10800,"status":"ok","record_count":1,"authoritative_nameservers":{"assigned":["string"],"delegated":["string"],"delegation_last_check":"2016-01-30T23:55:00Z","delegation_status":"valid"},"registrar":"hetzner","mode":"primary"}});
+    let model: GetZoneResponse = serde_json::from_value(value).unwrap();
+    let again: GetZoneResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // PUT /zones/{id_or_name}
+    let value = json!({"zone":{"id":42,"name":"example.com","created":"2016-01-30T23:55:00Z","primary_nameservers":[{"address":"198.51.100.1","port":5353,"tsig_key":"string","tsig_algorithm":"hmac-md5"}],"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"protection":{"delete":false},"ttl":10800,"status":"ok","record_count":1,"authoritative_nameservers":{"assigned":["string"],"delegated":["string"],"delegation_last_check":"2016-01-30T23:55:00Z","delegation_status":"valid"},"registrar":"hetzner","mode":"primary"}});
+    let model: UpdateZoneResponse = serde_json::from_value(value).unwrap();
+    let again: UpdateZoneResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // DELETE /zones/{id_or_name}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: DeleteZoneResponse = serde_json::from_value(value).unwrap();
+    let again: DeleteZoneResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /zones/{id_or_name}/actions
+    let value = json!({"actions":[{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListZoneActionsResponse = serde_json::from_value(value).unwrap();
+    let again: ListZoneActionsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /zones/{id_or_name}/actions/change_primary_nameservers
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeZonePrimaryNameserversResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeZonePrimaryNameserversResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /zones/{id_or_name}/actions/change_protection
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeZoneProtectionResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeZoneProtectionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /zones/{id_or_name}/actions/change_ttl
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeZoneTtlResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeZoneTtlResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /zones/{id_or_name}/actions/import_zonefile
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ImportZoneZonefileResponse = serde_json::from_value(value).unwrap();
+    let again: ImportZoneZonefileResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /zones/{id_or_name}/actions/{action_id}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: GetZoneActionResponse = serde_json::from_value(value).unwrap();
+    let again: GetZoneActionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /zones/{id_or_name}/rrsets
+    let value = json!({"rrsets":[{"id":"string","name":"www","type":"A","ttl":3600,"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"protection":{"change":false},"records":[{"value":"198.51.100.1","comment":"My web server at Hetzner Cloud."}],"zone":42}],"meta":{"pagination":{"page":3,"per_page":25,"previous_page":2,"next_page":4,"last_page":4,"total_entries":100}}});
+    let model: ListZoneRrsetsResponse = serde_json::from_value(value).unwrap();
+    let again: ListZoneRrsetsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /zones/{id_or_name}/rrsets
+    let value = json!({"rrset":{"id":"string","name":"www","type":"A","ttl":3600,"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"protection":{"change":false},"records":[{"value":"198.51.100.1","comment":"My web server at Hetzner Cloud."}],"zone":42},"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: CreateZoneRrsetResponse = serde_json::from_value(value).unwrap();
+    let again: CreateZoneRrsetResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}
+    let value = json!({"rrset":{"id":"string","name":"www","type":"A","ttl":3600,"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"protection":{"change":false},"records":[{"value":"198.51.100.1","comment":"My web server at Hetzner Cloud."}],"zone":42}});
+    let model: GetZoneRrsetResponse = serde_json::from_value(value).unwrap();
+    let again: GetZoneRrsetResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // PUT /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}
+    let value = json!({"rrset":{"id":"string","name":"www","type":"A","ttl":3600,"labels":{"environment":"prod","example.com/my":"label","just-a-key":""},"protection":{"change":false},"records":[{"value":"198.51.100.1","comment":"My web server at Hetzner Cloud."}],"zone":42}});
+    let model: UpdateZoneRrsetResponse = serde_json::from_value(value).unwrap();
+    let again: UpdateZoneRrsetResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // DELETE /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: DeleteZoneRrsetResponse = serde_json::from_value(value).unwrap();
+    let again: DeleteZoneRrsetResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}/actions/add_records
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: AddZoneRrsetRecordsResponse = serde_json::from_value(value).unwrap();
+    let again: AddZoneRrsetRecordsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}/actions/change_protection
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeZoneRrsetProtectionResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeZoneRrsetProtectionResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}/actions/change_ttl
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: ChangeZoneRrsetTtlResponse = serde_json::from_value(value).unwrap();
+    let again: ChangeZoneRrsetTtlResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}/actions/remove_records
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: RemoveZoneRrsetRecordsResponse = serde_json::from_value(value).unwrap();
+    let again: RemoveZoneRrsetRecordsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}/actions/set_records
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: SetZoneRrsetRecordsResponse = serde_json::from_value(value).unwrap();
+    let again: SetZoneRrsetRecordsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // POST /zones/{id_or_name}/rrsets/{rr_name}/{rr_type}/actions/update_records
+    let value = json!({"action":{"id":42,"command":"start_resource","status":"running","started":"2016-01-30T23:55:00Z","finished":"2016-01-30T23:55:00Z","progress":100,"resources":[{"id":42,"type":"server"}],"error":{"code":"action_failed","message":"Action failed"}}});
+    let model: UpdateZoneRrsetRecordsResponse = serde_json::from_value(value).unwrap();
+    let again: UpdateZoneRrsetRecordsResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+    // GET /zones/{id_or_name}/zonefile
+    let value = json!({"zonefile":"$ORIGIN\texample.com.\n$TTL\t3600\n\n@\tIN\tSOA\thydrogen.ns.hetzner.com. dns.hetzner.com. 2024010100 86400 10800 3600000 3600\n\n@\tIN\t10800\tNS\thydrogen.ns.hetzner.com. ; Some comment.\n@\tIN\t10800\tNS\toxygen.ns.hetzner.com.\n@\tIN\t10800\tNS\thelium.ns.hetzner.de.\n"});
+    let model: GetZoneZonefileResponse = serde_json::from_value(value).unwrap();
+    let again: GetZoneZonefileResponse =
+        serde_json::from_value(serde_json::to_value(&model).unwrap()).unwrap();
+    assert_eq!(again, model);
+}
@@ -1,3021 +1,6538 @@
-//! Integration tests against the real Hetzner Cloud API.
-//! Set HETZNER_CLOUD_API_TOKEN to run these tests.
+//! Integration tests for every Hetzner Cloud API operation.
+//! `test_<op>` seeds a `FakeCloudApi`, calls the operation with the seeded
+//! ids and checks the response. `live_test_<op>` runs it against the real
+//! API and is ignored by default; set HETZNER_CLOUD_API_TOKEN and run
+//! `cargo test --test cloud_integration_test -- --ignored`.
 //!
 //! Auto-generated by scripts/generate_cloud_integration_tests.mjs
 
 use hetzner::HetznerClient;
+use hetzner::testing::FakeCloudApi;
+use serde_json::{Value, json};
 use std::env;
 
-fn api_token() -> Option<String> {
+fn live_client() -> Option<HetznerClient> {
     dotenv::dotenv().ok();
     dotenv::from_filename(".env.local").ok();
-    env::var("HETZNER_CLOUD_API_TOKEN").ok()
+    env::var("HETZNER_CLOUD_API_TOKEN")
+        .ok()
+        .map(HetznerClient::new)
+}
+
+/// Adds a resource called `name` to `collection` and returns its id.
+fn seed(fake: &FakeCloudApi, collection: &str, name: &str) -> u64 {
+    fake.add(collection, json!({ "name": name }))["id"]
+        .as_u64()
+        .unwrap()
+}
+
+/// Adds a finished action on the resource `id` and returns its id.
+fn seed_action(fake: &FakeCloudApi, collection: &str, id: u64) -> u64 {
+    fake.add_action(collection, id, "fake_action")["id"]
+        .as_u64()
+        .unwrap()
+}
+
+fn ids(items: &Value) -> Vec<Value> {
+    items
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["id"].clone())
+        .collect()
 }
 
 #[tokio::test]
 async fn test_get_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let action_id = seed_action(&fake, "servers", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_action(action_id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_action: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_action(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_action: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_certificates() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "certificates", "fake-certificate");
+    let result = fake.client().cloud().list_certificates(None, None).await;
+    assert!(result.is_ok(), "list_certificates: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["certificates"]), [json!(id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_certificates() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_certificates(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_certificates: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_create_certificate() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let result = fake
+        .client()
+        .cloud()
+        .create_certificate(None, Some(json!({ "name": "new-certificate" })))
+        .await;
+    assert!(result.is_ok(), "create_certificate: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["certificate"]["name"], "new-certificate");
+    assert_eq!(fake.resources("certificates").len(), 1);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_create_certificate() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().create_certificate(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "create_certificate: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_certificates_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "certificates", "fake-certificate");
+    let action_id = seed_action(&fake, "certificates", id);
+    let result = fake
+        .client()
+        .cloud()
+        .list_certificates_actions(None, None)
+        .await;
+    assert!(
+        result.is_ok(),
+        "list_certificates_actions: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_certificates_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_certificates_actions(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
-    assert!(result.is_ok(), "list_certificates_actions: {:?}", result.err());
+    assert!(
+        result.is_ok(),
+        "list_certificates_actions: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_get_certificates_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "certificates", "fake-certificate");
+    let action_id = seed_action(&fake, "certificates", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_certificates_action(action_id, None, None)
+        .await;
+    assert!(
+        result.is_ok(),
+        "get_certificates_action: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_certificates_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_certificates_action(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
-    assert!(result.is_ok(), "get_certificates_action: {:?}", result.err());
+    assert!(
+        result.is_ok(),
+        "get_certificates_action: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_get_certificate() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "certificates", "fake-certificate");
+    let result = fake.client().cloud().get_certificate(id, None, None).await;
+    assert!(result.is_ok(), "get_certificate: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["certificate"]["id"], id);
+    assert_eq!(body["certificate"]["name"], "fake-certificate");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_certificate() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_certificate(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_certificate: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_update_certificate() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "certificates", "fake-certificate");
+    let result = fake
+        .client()
+        .cloud()
+        .update_certificate(id, None, Some(json!({ "name": "renamed-certificate" })))
+        .await;
+    assert!(result.is_ok(), "update_certificate: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["certificate"]["name"], "renamed-certificate");
+    assert_eq!(
+        fake.resource("certificates", id).unwrap()["name"],
+        "renamed-certificate"
+    );
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_update_certificate() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().update_certificate(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "update_certificate: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_delete_certificate() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "certificates", "fake-certificate");
+    let result = fake
+        .client()
+        .cloud()
+        .delete_certificate(id, None, None)
+        .await;
+    assert!(result.is_ok(), "delete_certificate: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+    assert!(fake.resource("certificates", id).is_none());
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_delete_certificate() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().delete_certificate(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "delete_certificate: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_certificate_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "certificates", "fake-certificate");
+    let action_id = seed_action(&fake, "certificates", id);
+    let result = fake
+        .client()
+        .cloud()
+        .list_certificate_actions(id, None, None)
+        .await;
+    assert!(
+        result.is_ok(),
+        "list_certificate_actions: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_certificate_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_certificate_actions(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
-    assert!(result.is_ok(), "list_certificate_actions: {:?}", result.err());
+    assert!(
+        result.is_ok(),
+        "list_certificate_actions: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_retry_certificate() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "certificates", "fake-certificate");
+    let result = fake
+        .client()
+        .cloud()
+        .retry_certificate(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "retry_certificate: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "retry");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_retry_certificate() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().retry_certificate(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "retry_certificate: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_certificate_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "certificates", "fake-certificate");
+    let action_id = seed_action(&fake, "certificates", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_certificate_action(id, action_id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_certificate_action: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_certificate_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().get_certificate_action(1, 1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    let result = client
+        .cloud()
+        .get_certificate_action(1, 1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_certificate_action: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_datacenters() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "datacenters", "fake-datacenter");
+    let result = fake.client().cloud().list_datacenters(None, None).await;
+    assert!(result.is_ok(), "list_datacenters: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["datacenters"]), [json!(id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_datacenters() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_datacenters(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_datacenters: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_datacenter() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "datacenters", "fake-datacenter");
+    let result = fake.client().cloud().get_datacenter(id, None, None).await;
+    assert!(result.is_ok(), "get_datacenter: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["datacenter"]["id"], id);
+    assert_eq!(body["datacenter"]["name"], "fake-datacenter");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_datacenter() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_datacenter("fsn1-dc3", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_datacenter: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_firewalls() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "firewalls", "fake-firewall");
+    let result = fake.client().cloud().list_firewalls(None, None).await;
+    assert!(result.is_ok(), "list_firewalls: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["firewalls"]), [json!(id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_firewalls() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_firewalls(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_firewalls: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_create_firewall() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let result = fake
+        .client()
+        .cloud()
+        .create_firewall(None, Some(json!({ "name": "new-firewall" })))
+        .await;
+    assert!(result.is_ok(), "create_firewall: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["firewall"]["name"], "new-firewall");
+    assert_eq!(fake.resources("firewalls").len(), 1);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_create_firewall() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().create_firewall(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "create_firewall: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_firewalls_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "firewalls", "fake-firewall");
+    let action_id = seed_action(&fake, "firewalls", id);
+    let result = fake
+        .client()
+        .cloud()
+        .list_firewalls_actions(None, None)
+        .await;
+    assert!(result.is_ok(), "list_firewalls_actions: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_firewalls_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_firewalls_actions(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_firewalls_actions: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_firewalls_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "firewalls", "fake-firewall");
+    let action_id = seed_action(&fake, "firewalls", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_firewalls_action(action_id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_firewalls_action: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_firewalls_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_firewalls_action(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_firewalls_action: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_firewall() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "firewalls", "fake-firewall");
+    let result = fake.client().cloud().get_firewall(id, None, None).await;
+    assert!(result.is_ok(), "get_firewall: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["firewall"]["id"], id);
+    assert_eq!(body["firewall"]["name"], "fake-firewall");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_firewall() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_firewall(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_firewall: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_update_firewall() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "firewalls", "fake-firewall");
+    let result = fake
+        .client()
+        .cloud()
+        .update_firewall(id, None, Some(json!({ "name": "renamed-firewall" })))
+        .await;
+    assert!(result.is_ok(), "update_firewall: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["firewall"]["name"], "renamed-firewall");
+    assert_eq!(
+        fake.resource("firewalls", id).unwrap()["name"],
+        "renamed-firewall"
+    );
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_update_firewall() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().update_firewall(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "update_firewall: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_delete_firewall() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "firewalls", "fake-firewall");
+    let result = fake.client().cloud().delete_firewall(id, None, None).await;
+    assert!(result.is_ok(), "delete_firewall: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+    assert!(fake.resource("firewalls", id).is_none());
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_delete_firewall() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().delete_firewall(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "delete_firewall: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_firewall_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "firewalls", "fake-firewall");
+    let action_id = seed_action(&fake, "firewalls", id);
+    let result = fake
+        .client()
+        .cloud()
+        .list_firewall_actions(id, None, None)
+        .await;
+    assert!(result.is_ok(), "list_firewall_actions: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_firewall_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_firewall_actions(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_firewall_actions: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_apply_firewall_to_resources() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().apply_firewall_to_resources(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "apply_firewall_to_resources: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "firewalls", "fake-firewall");
+    let result = fake
+        .client()
+        .cloud()
+        .apply_firewall_to_resources(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "apply_firewall_to_resources: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "apply_to_resources");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_apply_firewall_to_resources() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .apply_firewall_to_resources(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "apply_firewall_to_resources: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_remove_firewall_from_resources() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().remove_firewall_from_resources(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "remove_firewall_from_resources: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "firewalls", "fake-firewall");
+    let result = fake
+        .client()
+        .cloud()
+        .remove_firewall_from_resources(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "remove_firewall_from_resources: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "remove_from_resources");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_remove_firewall_from_resources() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .remove_firewall_from_resources(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "remove_firewall_from_resources: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_set_firewall_rules() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "firewalls", "fake-firewall");
+    let result = fake
+        .client()
+        .cloud()
+        .set_firewall_rules(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "set_firewall_rules: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "set_rules");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_set_firewall_rules() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().set_firewall_rules(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "set_firewall_rules: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_firewall_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "firewalls", "fake-firewall");
+    let action_id = seed_action(&fake, "firewalls", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_firewall_action(id, action_id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_firewall_action: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_firewall_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_firewall_action(1, 1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_firewall_action: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_floating_ips() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "floating_ips", "fake-floating_ip");
+    let result = fake.client().cloud().list_floating_ips(None, None).await;
+    assert!(result.is_ok(), "list_floating_ips: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["floating_ips"]), [json!(id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_floating_ips() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_floating_ips(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_floating_ips: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_create_floating_ip() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let result = fake
+        .client()
+        .cloud()
+        .create_floating_ip(None, Some(json!({ "name": "new-floating_ip" })))
+        .await;
+    assert!(result.is_ok(), "create_floating_ip: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["floating_ip"]["name"], "new-floating_ip");
+    assert_eq!(fake.resources("floating_ips").len(), 1);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_create_floating_ip() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().create_floating_ip(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "create_floating_ip: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_floating_ips_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "floating_ips", "fake-floating_ip");
+    let action_id = seed_action(&fake, "floating_ips", id);
+    let result = fake
+        .client()
+        .cloud()
+        .list_floating_ips_actions(None, None)
+        .await;
+    assert!(
+        result.is_ok(),
+        "list_floating_ips_actions: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_floating_ips_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_floating_ips_actions(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
-    assert!(result.is_ok(), "list_floating_ips_actions: {:?}", result.err());
+    assert!(
+        result.is_ok(),
+        "list_floating_ips_actions: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_get_floating_ips_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "floating_ips", "fake-floating_ip");
+    let action_id = seed_action(&fake, "floating_ips", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_floating_ips_action(action_id, None, None)
+        .await;
+    assert!(
+        result.is_ok(),
+        "get_floating_ips_action: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_floating_ips_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_floating_ips_action(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
-    assert!(result.is_ok(), "get_floating_ips_action: {:?}", result.err());
+    assert!(
+        result.is_ok(),
+        "get_floating_ips_action: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_get_floating_ip() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "floating_ips", "fake-floating_ip");
+    let result = fake.client().cloud().get_floating_ip(id, None, None).await;
+    assert!(result.is_ok(), "get_floating_ip: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["floating_ip"]["id"], id);
+    assert_eq!(body["floating_ip"]["name"], "fake-floating_ip");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_floating_ip() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_floating_ip(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_floating_ip: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_update_floating_ip() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "floating_ips", "fake-floating_ip");
+    let result = fake
+        .client()
+        .cloud()
+        .update_floating_ip(id, None, Some(json!({ "name": "renamed-floating_ip" })))
+        .await;
+    assert!(result.is_ok(), "update_floating_ip: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["floating_ip"]["name"], "renamed-floating_ip");
+    assert_eq!(
+        fake.resource("floating_ips", id).unwrap()["name"],
+        "renamed-floating_ip"
+    );
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_update_floating_ip() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().update_floating_ip(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "update_floating_ip: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_delete_floating_ip() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "floating_ips", "fake-floating_ip");
+    let result = fake
+        .client()
+        .cloud()
+        .delete_floating_ip(id, None, None)
+        .await;
+    assert!(result.is_ok(), "delete_floating_ip: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+    assert!(fake.resource("floating_ips", id).is_none());
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_delete_floating_ip() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().delete_floating_ip(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "delete_floating_ip: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_floating_ip_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "floating_ips", "fake-floating_ip");
+    let action_id = seed_action(&fake, "floating_ips", id);
+    let result = fake
+        .client()
+        .cloud()
+        .list_floating_ip_actions(id, None, None)
+        .await;
+    assert!(
+        result.is_ok(),
+        "list_floating_ip_actions: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_floating_ip_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_floating_ip_actions(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
-    assert!(result.is_ok(), "list_floating_ip_actions: {:?}", result.err());
+    assert!(
+        result.is_ok(),
+        "list_floating_ip_actions: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_assign_floating_ip() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "floating_ips", "fake-floating_ip");
+    let result = fake
+        .client()
+        .cloud()
+        .assign_floating_ip(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "assign_floating_ip: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "assign");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_assign_floating_ip() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().assign_floating_ip(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "assign_floating_ip: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_change_floating_ip_dns_ptr() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().change_floating_ip_dns_ptr(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "change_floating_ip_dns_ptr: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "floating_ips", "fake-floating_ip");
+    let result = fake
+        .client()
+        .cloud()
+        .change_floating_ip_dns_ptr(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "change_floating_ip_dns_ptr: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_dns_ptr");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_floating_ip_dns_ptr() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .change_floating_ip_dns_ptr(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "change_floating_ip_dns_ptr: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_change_floating_ip_protection() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().change_floating_ip_protection(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "change_floating_ip_protection: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "floating_ips", "fake-floating_ip");
+    let result = fake
+        .client()
+        .cloud()
+        .change_floating_ip_protection(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "change_floating_ip_protection: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_protection");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_floating_ip_protection() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .change_floating_ip_protection(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "change_floating_ip_protection: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_unassign_floating_ip() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "floating_ips", "fake-floating_ip");
+    let result = fake
+        .client()
+        .cloud()
+        .unassign_floating_ip(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "unassign_floating_ip: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "unassign");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_unassign_floating_ip() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().unassign_floating_ip(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "unassign_floating_ip: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_floating_ip_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "floating_ips", "fake-floating_ip");
+    let action_id = seed_action(&fake, "floating_ips", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_floating_ip_action(id, action_id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_floating_ip_action: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_floating_ip_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().get_floating_ip_action(1, 1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    let result = client
+        .cloud()
+        .get_floating_ip_action(1, 1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_floating_ip_action: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_images() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "images", "fake-image");
+    let result = fake.client().cloud().list_images(None, None).await;
+    assert!(result.is_ok(), "list_images: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["images"]), [json!(id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_images() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_images(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_images: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_images_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "images", "fake-image");
+    let action_id = seed_action(&fake, "images", id);
+    let result = fake.client().cloud().list_images_actions(None, None).await;
+    assert!(result.is_ok(), "list_images_actions: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_images_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_images_actions(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_images_actions: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_images_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "images", "fake-image");
+    let action_id = seed_action(&fake, "images", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_images_action(action_id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_images_action: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_images_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_images_action(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_images_action: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_image() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "images", "fake-image");
+    let result = fake.client().cloud().get_image(id, None, None).await;
+    assert!(result.is_ok(), "get_image: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["image"]["id"], id);
+    assert_eq!(body["image"]["name"], "fake-image");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_image() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_image(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_image: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_update_image() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "images", "fake-image");
+    let result = fake
+        .client()
+        .cloud()
+        .update_image(id, None, Some(json!({ "name": "renamed-image" })))
+        .await;
+    assert!(result.is_ok(), "update_image: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["image"]["name"], "renamed-image");
+    assert_eq!(
+        fake.resource("images", id).unwrap()["name"],
+        "renamed-image"
+    );
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_update_image() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().update_image(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "update_image: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_delete_image() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "images", "fake-image");
+    let result = fake.client().cloud().delete_image(id, None, None).await;
+    assert!(result.is_ok(), "delete_image: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+    assert!(fake.resource("images", id).is_none());
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_delete_image() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().delete_image(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "delete_image: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_image_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "images", "fake-image");
+    let action_id = seed_action(&fake, "images", id);
+    let result = fake
+        .client()
+        .cloud()
+        .list_image_actions(id, None, None)
+        .await;
+    assert!(result.is_ok(), "list_image_actions: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_image_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_image_actions(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_image_actions: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_change_image_protection() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "images", "fake-image");
+    let result = fake
+        .client()
+        .cloud()
+        .change_image_protection(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "change_image_protection: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_protection");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_image_protection() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().change_image_protection(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
-    assert!(result.is_ok(), "change_image_protection: {:?}", result.err());
+    assert!(
+        result.is_ok(),
+        "change_image_protection: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_get_image_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "images", "fake-image");
+    let action_id = seed_action(&fake, "images", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_image_action(id, action_id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_image_action: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_image_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_image_action(1, 1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_image_action: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_isos() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "isos", "fake-iso");
+    let result = fake.client().cloud().list_isos(None, None).await;
+    assert!(result.is_ok(), "list_isos: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["isos"]), [json!(id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_isos() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_isos(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_isos: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_iso() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "isos", "fake-iso");
+    let result = fake.client().cloud().get_iso(id, None, None).await;
+    assert!(result.is_ok(), "get_iso: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["iso"]["id"], id);
+    assert_eq!(body["iso"]["name"], "fake-iso");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_iso() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_iso(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_iso: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_load_balancer_types() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancer_types", "fake-load_balancer_type");
+    let result = fake
+        .client()
+        .cloud()
+        .list_load_balancer_types(None, None)
+        .await;
+    assert!(
+        result.is_ok(),
+        "list_load_balancer_types: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(ids(&body["load_balancer_types"]), [json!(id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_load_balancer_types() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_load_balancer_types(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
-    assert!(result.is_ok(), "list_load_balancer_types: {:?}", result.err());
+    assert!(
+        result.is_ok(),
+        "list_load_balancer_types: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_get_load_balancer_type() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().get_load_balancer_type("lb11", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancer_types", "fake-load_balancer_type");
+    let result = fake
+        .client()
+        .cloud()
+        .get_load_balancer_type(id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_load_balancer_type: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["load_balancer_type"]["id"], id);
+    assert_eq!(
+        body["load_balancer_type"]["name"],
+        "fake-load_balancer_type"
+    );
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_load_balancer_type() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .get_load_balancer_type("lb11", None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_load_balancer_type: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_load_balancers() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let result = fake.client().cloud().list_load_balancers(None, None).await;
+    assert!(result.is_ok(), "list_load_balancers: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["load_balancers"]), [json!(id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_load_balancers() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_load_balancers(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_load_balancers: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_create_load_balancer() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let result = fake
+        .client()
+        .cloud()
+        .create_load_balancer(None, Some(json!({ "name": "new-load_balancer" })))
+        .await;
+    assert!(result.is_ok(), "create_load_balancer: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["load_balancer"]["name"], "new-load_balancer");
+    assert_eq!(fake.resources("load_balancers").len(), 1);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_create_load_balancer() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().create_load_balancer(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "create_load_balancer: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_load_balancers_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let action_id = seed_action(&fake, "load_balancers", id);
+    let result = fake
+        .client()
+        .cloud()
+        .list_load_balancers_actions(None, None)
+        .await;
+    assert!(
+        result.is_ok(),
+        "list_load_balancers_actions: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_load_balancers_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_load_balancers_actions(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
-    assert!(result.is_ok(), "list_load_balancers_actions: {:?}", result.err());
+    assert!(
+        result.is_ok(),
+        "list_load_balancers_actions: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_get_load_balancers_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().get_load_balancers_action(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "get_load_balancers_action: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let action_id = seed_action(&fake, "load_balancers", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_load_balancers_action(action_id, None, None)
+        .await;
+    assert!(
+        result.is_ok(),
+        "get_load_balancers_action: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_load_balancers_action() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .get_load_balancers_action(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "get_load_balancers_action: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_get_load_balancer() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let result = fake
+        .client()
+        .cloud()
+        .get_load_balancer(id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_load_balancer: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["load_balancer"]["id"], id);
+    assert_eq!(body["load_balancer"]["name"], "fake-load_balancer");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_load_balancer() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_load_balancer(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_load_balancer: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_update_load_balancer() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let result = fake
+        .client()
+        .cloud()
+        .update_load_balancer(id, None, Some(json!({ "name": "renamed-load_balancer" })))
+        .await;
+    assert!(result.is_ok(), "update_load_balancer: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["load_balancer"]["name"], "renamed-load_balancer");
+    assert_eq!(
+        fake.resource("load_balancers", id).unwrap()["name"],
+        "renamed-load_balancer"
+    );
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_update_load_balancer() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().update_load_balancer(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "update_load_balancer: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_delete_load_balancer() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let result = fake
+        .client()
+        .cloud()
+        .delete_load_balancer(id, None, None)
+        .await;
+    assert!(result.is_ok(), "delete_load_balancer: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+    assert!(fake.resource("load_balancers", id).is_none());
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_delete_load_balancer() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().delete_load_balancer(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "delete_load_balancer: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_load_balancer_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().list_load_balancer_actions(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "list_load_balancer_actions: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let action_id = seed_action(&fake, "load_balancers", id);
+    let result = fake
+        .client()
+        .cloud()
+        .list_load_balancer_actions(id, None, None)
+        .await;
+    assert!(
+        result.is_ok(),
+        "list_load_balancer_actions: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_load_balancer_actions() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .list_load_balancer_actions(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "list_load_balancer_actions: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_add_load_balancer_service() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().add_load_balancer_service(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "add_load_balancer_service: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let result = fake
+        .client()
+        .cloud()
+        .add_load_balancer_service(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "add_load_balancer_service: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "add_service");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_add_load_balancer_service() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .add_load_balancer_service(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "add_load_balancer_service: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_add_load_balancer_target() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let result = fake
+        .client()
+        .cloud()
+        .add_load_balancer_target(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "add_load_balancer_target: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "add_target");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_add_load_balancer_target() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().add_load_balancer_target(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
-    assert!(result.is_ok(), "add_load_balancer_target: {:?}", result.err());
+    assert!(
+        result.is_ok(),
+        "add_load_balancer_target: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_attach_load_balancer_to_network() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().attach_load_balancer_to_network(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "attach_load_balancer_to_network: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let result = fake
+        .client()
+        .cloud()
+        .attach_load_balancer_to_network(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "attach_load_balancer_to_network: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "attach_to_network");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_attach_load_balancer_to_network() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .attach_load_balancer_to_network(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "attach_load_balancer_to_network: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_change_load_balancer_algorithm() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().change_load_balancer_algorithm(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "change_load_balancer_algorithm: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let result = fake
+        .client()
+        .cloud()
+        .change_load_balancer_algorithm(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "change_load_balancer_algorithm: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_algorithm");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_load_balancer_algorithm() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .change_load_balancer_algorithm(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "change_load_balancer_algorithm: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_change_load_balancer_dns_ptr() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().change_load_balancer_dns_ptr(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "change_load_balancer_dns_ptr: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let result = fake
+        .client()
+        .cloud()
+        .change_load_balancer_dns_ptr(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "change_load_balancer_dns_ptr: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_dns_ptr");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_load_balancer_dns_ptr() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .change_load_balancer_dns_ptr(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "change_load_balancer_dns_ptr: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_change_load_balancer_protection() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().change_load_balancer_protection(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "change_load_balancer_protection: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let result = fake
+        .client()
+        .cloud()
+        .change_load_balancer_protection(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "change_load_balancer_protection: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_protection");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_load_balancer_protection() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .change_load_balancer_protection(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "change_load_balancer_protection: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_change_load_balancer_type() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().change_load_balancer_type(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "change_load_balancer_type: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let result = fake
+        .client()
+        .cloud()
+        .change_load_balancer_type(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "change_load_balancer_type: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_type");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_load_balancer_type() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .change_load_balancer_type(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "change_load_balancer_type: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_delete_load_balancer_service() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().delete_load_balancer_service(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "delete_load_balancer_service: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let result = fake
+        .client()
+        .cloud()
+        .delete_load_balancer_service(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "delete_load_balancer_service: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "delete_service");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_delete_load_balancer_service() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .delete_load_balancer_service(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "delete_load_balancer_service: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_detach_load_balancer_from_network() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().detach_load_balancer_from_network(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "detach_load_balancer_from_network: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let result = fake
+        .client()
+        .cloud()
+        .detach_load_balancer_from_network(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "detach_load_balancer_from_network: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "detach_from_network");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_detach_load_balancer_from_network() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .detach_load_balancer_from_network(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "detach_load_balancer_from_network: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_disable_load_balancer_public_interface() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().disable_load_balancer_public_interface(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "disable_load_balancer_public_interface: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let result = fake
+        .client()
+        .cloud()
+        .disable_load_balancer_public_interface(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "disable_load_balancer_public_interface: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "disable_public_interface");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_disable_load_balancer_public_interface() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .disable_load_balancer_public_interface(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "disable_load_balancer_public_interface: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_enable_load_balancer_public_interface() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().enable_load_balancer_public_interface(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "enable_load_balancer_public_interface: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let result = fake
+        .client()
+        .cloud()
+        .enable_load_balancer_public_interface(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "enable_load_balancer_public_interface: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "enable_public_interface");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_enable_load_balancer_public_interface() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .enable_load_balancer_public_interface(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "enable_load_balancer_public_interface: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_remove_load_balancer_target() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().remove_load_balancer_target(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "remove_load_balancer_target: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let result = fake
+        .client()
+        .cloud()
+        .remove_load_balancer_target(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "remove_load_balancer_target: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "remove_target");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_remove_load_balancer_target() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .remove_load_balancer_target(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "remove_load_balancer_target: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_update_load_balancer_service() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().update_load_balancer_service(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "update_load_balancer_service: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let result = fake
+        .client()
+        .cloud()
+        .update_load_balancer_service(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "update_load_balancer_service: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "update_service");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_update_load_balancer_service() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .update_load_balancer_service(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "update_load_balancer_service: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_get_load_balancer_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().get_load_balancer_action(1, 1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "get_load_balancer_action: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let action_id = seed_action(&fake, "load_balancers", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_load_balancer_action(id, action_id, None, None)
+        .await;
+    assert!(
+        result.is_ok(),
+        "get_load_balancer_action: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_load_balancer_action() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .get_load_balancer_action(1, 1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "get_load_balancer_action: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_get_load_balancer_metrics() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().get_load_balancer_metrics(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "get_load_balancer_metrics: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "load_balancers", "fake-load_balancer");
+    let result = fake
+        .client()
+        .cloud()
+        .get_load_balancer_metrics(id, None, None)
+        .await;
+    assert!(
+        result.is_ok(),
+        "get_load_balancer_metrics: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert!(body["metrics"]["time_series"].is_object());
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_load_balancer_metrics() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .get_load_balancer_metrics(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "get_load_balancer_metrics: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_list_locations() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "locations", "fake-location");
+    let result = fake.client().cloud().list_locations(None, None).await;
+    assert!(result.is_ok(), "list_locations: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["locations"]), [json!(id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_locations() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_locations(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_locations: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_location() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "locations", "fake-location");
+    let result = fake.client().cloud().get_location(id, None, None).await;
+    assert!(result.is_ok(), "get_location: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["location"]["id"], id);
+    assert_eq!(body["location"]["name"], "fake-location");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_location() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_location("fsn1", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_location: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_networks() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "networks", "fake-network");
+    let result = fake.client().cloud().list_networks(None, None).await;
+    assert!(result.is_ok(), "list_networks: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["networks"]), [json!(id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_networks() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_networks(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_networks: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_create_network() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let result = fake
+        .client()
+        .cloud()
+        .create_network(None, Some(json!({ "name": "new-network" })))
+        .await;
+    assert!(result.is_ok(), "create_network: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["network"]["name"], "new-network");
+    assert_eq!(fake.resources("networks").len(), 1);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_create_network() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().create_network(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "create_network: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_networks_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "networks", "fake-network");
+    let action_id = seed_action(&fake, "networks", id);
+    let result = fake
+        .client()
+        .cloud()
+        .list_networks_actions(None, None)
+        .await;
+    assert!(result.is_ok(), "list_networks_actions: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_networks_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_networks_actions(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_networks_actions: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_networks_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "networks", "fake-network");
+    let action_id = seed_action(&fake, "networks", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_networks_action(action_id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_networks_action: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_networks_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_networks_action(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_networks_action: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_network() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "networks", "fake-network");
+    let result = fake.client().cloud().get_network(id, None, None).await;
+    assert!(result.is_ok(), "get_network: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["network"]["id"], id);
+    assert_eq!(body["network"]["name"], "fake-network");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_network() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_network(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_network: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_update_network() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "networks", "fake-network");
+    let result = fake
+        .client()
+        .cloud()
+        .update_network(id, None, Some(json!({ "name": "renamed-network" })))
+        .await;
+    assert!(result.is_ok(), "update_network: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["network"]["name"], "renamed-network");
+    assert_eq!(
+        fake.resource("networks", id).unwrap()["name"],
+        "renamed-network"
+    );
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_update_network() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().update_network(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "update_network: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_delete_network() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "networks", "fake-network");
+    let result = fake.client().cloud().delete_network(id, None, None).await;
+    assert!(result.is_ok(), "delete_network: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+    assert!(fake.resource("networks", id).is_none());
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_delete_network() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().delete_network(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "delete_network: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_network_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "networks", "fake-network");
+    let action_id = seed_action(&fake, "networks", id);
+    let result = fake
+        .client()
+        .cloud()
+        .list_network_actions(id, None, None)
+        .await;
+    assert!(result.is_ok(), "list_network_actions: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_network_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_network_actions(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_network_actions: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_add_network_route() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "networks", "fake-network");
+    let result = fake
+        .client()
+        .cloud()
+        .add_network_route(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "add_network_route: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "add_route");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_add_network_route() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().add_network_route(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "add_network_route: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_add_network_subnet() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "networks", "fake-network");
+    let result = fake
+        .client()
+        .cloud()
+        .add_network_subnet(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "add_network_subnet: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "add_subnet");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_add_network_subnet() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().add_network_subnet(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "add_network_subnet: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_change_network_ip_range() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "networks", "fake-network");
+    let result = fake
+        .client()
+        .cloud()
+        .change_network_ip_range(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "change_network_ip_range: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_ip_range");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_network_ip_range() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().change_network_ip_range(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
-    assert!(result.is_ok(), "change_network_ip_range: {:?}", result.err());
+    assert!(
+        result.is_ok(),
+        "change_network_ip_range: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_change_network_protection() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().change_network_protection(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "change_network_protection: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "networks", "fake-network");
+    let result = fake
+        .client()
+        .cloud()
+        .change_network_protection(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "change_network_protection: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_protection");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_network_protection() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .change_network_protection(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "change_network_protection: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_delete_network_route() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "networks", "fake-network");
+    let result = fake
+        .client()
+        .cloud()
+        .delete_network_route(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "delete_network_route: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "delete_route");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_delete_network_route() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().delete_network_route(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "delete_network_route: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_delete_network_subnet() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "networks", "fake-network");
+    let result = fake
+        .client()
+        .cloud()
+        .delete_network_subnet(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "delete_network_subnet: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "delete_subnet");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_delete_network_subnet() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().delete_network_subnet(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "delete_network_subnet: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_network_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "networks", "fake-network");
+    let action_id = seed_action(&fake, "networks", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_network_action(id, action_id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_network_action: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_network_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_network_action(1, 1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_network_action: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_placement_groups() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "placement_groups", "fake-placement_group");
+    let result = fake
+        .client()
+        .cloud()
+        .list_placement_groups(None, None)
+        .await;
+    assert!(result.is_ok(), "list_placement_groups: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["placement_groups"]), [json!(id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_placement_groups() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_placement_groups(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_placement_groups: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_create_placement_group() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let result = fake
+        .client()
+        .cloud()
+        .create_placement_group(None, Some(json!({ "name": "new-placement_group" })))
+        .await;
+    assert!(result.is_ok(), "create_placement_group: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["placement_group"]["name"], "new-placement_group");
+    assert_eq!(fake.resources("placement_groups").len(), 1);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_create_placement_group() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().create_placement_group(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "create_placement_group: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_placement_group() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "placement_groups", "fake-placement_group");
+    let result = fake
+        .client()
+        .cloud()
+        .get_placement_group(id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_placement_group: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["placement_group"]["id"], id);
+    assert_eq!(body["placement_group"]["name"], "fake-placement_group");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_placement_group() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_placement_group(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_placement_group: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_update_placement_group() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "placement_groups", "fake-placement_group");
+    let result = fake
+        .client()
+        .cloud()
+        .update_placement_group(id, None, Some(json!({ "name": "renamed-placement_group" })))
+        .await;
+    assert!(result.is_ok(), "update_placement_group: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["placement_group"]["name"], "renamed-placement_group");
+    assert_eq!(
+        fake.resource("placement_groups", id).unwrap()["name"],
+        "renamed-placement_group"
+    );
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_update_placement_group() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().update_placement_group(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "update_placement_group: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_delete_placement_group() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "placement_groups", "fake-placement_group");
+    let result = fake
+        .client()
+        .cloud()
+        .delete_placement_group(id, None, None)
+        .await;
+    assert!(result.is_ok(), "delete_placement_group: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+    assert!(fake.resource("placement_groups", id).is_none());
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_delete_placement_group() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().delete_placement_group(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "delete_placement_group: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_pricing() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let result = fake.client().cloud().get_pricing(None, None).await;
+    assert!(result.is_ok(), "get_pricing: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["pricing"]["currency"], "EUR");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_pricing() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_pricing(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_pricing: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_primary_ips() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "primary_ips", "fake-primary_ip");
+    let result = fake.client().cloud().list_primary_ips(None, None).await;
+    assert!(result.is_ok(), "list_primary_ips: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["primary_ips"]), [json!(id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_primary_ips() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_primary_ips(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_primary_ips: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_create_primary_ip() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let result = fake
+        .client()
+        .cloud()
+        .create_primary_ip(None, Some(json!({ "name": "new-primary_ip" })))
+        .await;
+    assert!(result.is_ok(), "create_primary_ip: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["primary_ip"]["name"], "new-primary_ip");
+    assert_eq!(fake.resources("primary_ips").len(), 1);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_create_primary_ip() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().create_primary_ip(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "create_primary_ip: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_primary_ips_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "primary_ips", "fake-primary_ip");
+    let action_id = seed_action(&fake, "primary_ips", id);
+    let result = fake
+        .client()
+        .cloud()
+        .list_primary_ips_actions(None, None)
+        .await;
+    assert!(
+        result.is_ok(),
+        "list_primary_ips_actions: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_primary_ips_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_primary_ips_actions(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
-    assert!(result.is_ok(), "list_primary_ips_actions: {:?}", result.err());
+    assert!(
+        result.is_ok(),
+        "list_primary_ips_actions: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_get_primary_ips_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "primary_ips", "fake-primary_ip");
+    let action_id = seed_action(&fake, "primary_ips", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_primary_ips_action(action_id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_primary_ips_action: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_primary_ips_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_primary_ips_action(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_primary_ips_action: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_primary_ip() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "primary_ips", "fake-primary_ip");
+    let result = fake.client().cloud().get_primary_ip(id, None, None).await;
+    assert!(result.is_ok(), "get_primary_ip: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["primary_ip"]["id"], id);
+    assert_eq!(body["primary_ip"]["name"], "fake-primary_ip");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_primary_ip() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_primary_ip(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_primary_ip: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_update_primary_ip() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "primary_ips", "fake-primary_ip");
+    let result = fake
+        .client()
+        .cloud()
+        .update_primary_ip(id, None, Some(json!({ "name": "renamed-primary_ip" })))
+        .await;
+    assert!(result.is_ok(), "update_primary_ip: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["primary_ip"]["name"], "renamed-primary_ip");
+    assert_eq!(
+        fake.resource("primary_ips", id).unwrap()["name"],
+        "renamed-primary_ip"
+    );
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_update_primary_ip() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().update_primary_ip(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "update_primary_ip: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_delete_primary_ip() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "primary_ips", "fake-primary_ip");
+    let result = fake
+        .client()
+        .cloud()
+        .delete_primary_ip(id, None, None)
+        .await;
+    assert!(result.is_ok(), "delete_primary_ip: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+    assert!(fake.resource("primary_ips", id).is_none());
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_delete_primary_ip() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().delete_primary_ip(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "delete_primary_ip: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_primary_ip_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "primary_ips", "fake-primary_ip");
+    let action_id = seed_action(&fake, "primary_ips", id);
+    let result = fake
+        .client()
+        .cloud()
+        .list_primary_ip_actions(id, None, None)
+        .await;
+    assert!(
+        result.is_ok(),
+        "list_primary_ip_actions: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_primary_ip_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_primary_ip_actions(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
-    assert!(result.is_ok(), "list_primary_ip_actions: {:?}", result.err());
+    assert!(
+        result.is_ok(),
+        "list_primary_ip_actions: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_assign_primary_ip() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "primary_ips", "fake-primary_ip");
+    let result = fake
+        .client()
+        .cloud()
+        .assign_primary_ip(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "assign_primary_ip: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "assign");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_assign_primary_ip() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().assign_primary_ip(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "assign_primary_ip: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_change_primary_ip_dns_ptr() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().change_primary_ip_dns_ptr(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "change_primary_ip_dns_ptr: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "primary_ips", "fake-primary_ip");
+    let result = fake
+        .client()
+        .cloud()
+        .change_primary_ip_dns_ptr(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "change_primary_ip_dns_ptr: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_dns_ptr");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_primary_ip_dns_ptr() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .change_primary_ip_dns_ptr(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "change_primary_ip_dns_ptr: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_change_primary_ip_protection() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().change_primary_ip_protection(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "change_primary_ip_protection: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "primary_ips", "fake-primary_ip");
+    let result = fake
+        .client()
+        .cloud()
+        .change_primary_ip_protection(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "change_primary_ip_protection: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_protection");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_primary_ip_protection() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .change_primary_ip_protection(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "change_primary_ip_protection: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_unassign_primary_ip() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "primary_ips", "fake-primary_ip");
+    let result = fake
+        .client()
+        .cloud()
+        .unassign_primary_ip(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "unassign_primary_ip: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "unassign");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_unassign_primary_ip() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().unassign_primary_ip(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "unassign_primary_ip: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_primary_ip_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "primary_ips", "fake-primary_ip");
+    let action_id = seed_action(&fake, "primary_ips", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_primary_ip_action(id, action_id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_primary_ip_action: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_primary_ip_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_primary_ip_action(1, 1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_primary_ip_action: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_server_types() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "server_types", "fake-server_type");
+    let result = fake.client().cloud().list_server_types(None, None).await;
+    assert!(result.is_ok(), "list_server_types: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["server_types"]), [json!(id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_server_types() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_server_types(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_server_types: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_server_type() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "server_types", "fake-server_type");
+    let result = fake.client().cloud().get_server_type(id, None, None).await;
+    assert!(result.is_ok(), "get_server_type: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["server_type"]["id"], id);
+    assert_eq!(body["server_type"]["name"], "fake-server_type");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_server_type() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_server_type("cpx22", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_server_type: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_servers() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake.client().cloud().list_servers(None, None).await;
+    assert!(result.is_ok(), "list_servers: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["servers"]), [json!(id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_servers() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_servers(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_servers: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_create_server() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let result = fake
+        .client()
+        .cloud()
+        .create_server(None, Some(json!({ "name": "new-server" })))
+        .await;
+    assert!(result.is_ok(), "create_server: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["server"]["name"], "new-server");
+    assert_eq!(fake.resources("servers").len(), 1);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_create_server() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().create_server(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "create_server: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_servers_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let action_id = seed_action(&fake, "servers", id);
+    let result = fake.client().cloud().list_servers_actions(None, None).await;
+    assert!(result.is_ok(), "list_servers_actions: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_servers_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_servers_actions(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_servers_actions: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_servers_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let action_id = seed_action(&fake, "servers", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_servers_action(action_id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_servers_action: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_servers_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_servers_action(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_servers_action: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_server() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake.client().cloud().get_server(id, None, None).await;
+    assert!(result.is_ok(), "get_server: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["server"]["id"], id);
+    assert_eq!(body["server"]["name"], "fake-server");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_server() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_server(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_server: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_update_server() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .update_server(id, None, Some(json!({ "name": "renamed-server" })))
+        .await;
+    assert!(result.is_ok(), "update_server: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["server"]["name"], "renamed-server");
+    assert_eq!(
+        fake.resource("servers", id).unwrap()["name"],
+        "renamed-server"
+    );
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_update_server() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().update_server(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "update_server: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_delete_server() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake.client().cloud().delete_server(id, None, None).await;
+    assert!(result.is_ok(), "delete_server: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+    assert!(fake.resource("servers", id).is_none());
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_delete_server() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().delete_server(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "delete_server: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_server_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let action_id = seed_action(&fake, "servers", id);
+    let result = fake
+        .client()
+        .cloud()
+        .list_server_actions(id, None, None)
+        .await;
+    assert!(result.is_ok(), "list_server_actions: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_server_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_server_actions(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_server_actions: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_add_server_to_placement_group() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().add_server_to_placement_group(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "add_server_to_placement_group: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .add_server_to_placement_group(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "add_server_to_placement_group: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "add_to_placement_group");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_add_server_to_placement_group() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .add_server_to_placement_group(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "add_server_to_placement_group: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_attach_server_iso() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .attach_server_iso(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "attach_server_iso: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "attach_iso");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_attach_server_iso() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().attach_server_iso(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "attach_server_iso: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_attach_server_to_network() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .attach_server_to_network(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "attach_server_to_network: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "attach_to_network");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_attach_server_to_network() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().attach_server_to_network(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
-    assert!(result.is_ok(), "attach_server_to_network: {:?}", result.err());
+    assert!(
+        result.is_ok(),
+        "attach_server_to_network: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_change_server_alias_ips() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .change_server_alias_ips(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "change_server_alias_ips: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_alias_ips");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_server_alias_ips() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().change_server_alias_ips(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
-    assert!(result.is_ok(), "change_server_alias_ips: {:?}", result.err());
+    assert!(
+        result.is_ok(),
+        "change_server_alias_ips: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_change_server_dns_ptr() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .change_server_dns_ptr(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "change_server_dns_ptr: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_dns_ptr");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_server_dns_ptr() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().change_server_dns_ptr(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "change_server_dns_ptr: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_change_server_protection() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .change_server_protection(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "change_server_protection: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_protection");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_server_protection() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().change_server_protection(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
-    assert!(result.is_ok(), "change_server_protection: {:?}", result.err());
+    assert!(
+        result.is_ok(),
+        "change_server_protection: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_change_server_type() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .change_server_type(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "change_server_type: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_type");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_server_type() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().change_server_type(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "change_server_type: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_create_server_image() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .create_server_image(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "create_server_image: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "create_image");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_create_server_image() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().create_server_image(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "create_server_image: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_detach_server_from_network() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().detach_server_from_network(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "detach_server_from_network: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .detach_server_from_network(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "detach_server_from_network: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "detach_from_network");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_detach_server_from_network() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .detach_server_from_network(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "detach_server_from_network: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_detach_server_iso() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .detach_server_iso(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "detach_server_iso: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "detach_iso");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_detach_server_iso() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().detach_server_iso(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "detach_server_iso: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_disable_server_backup() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .disable_server_backup(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "disable_server_backup: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "disable_backup");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_disable_server_backup() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().disable_server_backup(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "disable_server_backup: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_disable_server_rescue() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .disable_server_rescue(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "disable_server_rescue: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "disable_rescue");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_disable_server_rescue() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().disable_server_rescue(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "disable_server_rescue: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_enable_server_backup() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .enable_server_backup(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "enable_server_backup: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "enable_backup");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_enable_server_backup() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().enable_server_backup(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "enable_server_backup: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_enable_server_rescue() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .enable_server_rescue(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "enable_server_rescue: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "enable_rescue");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_enable_server_rescue() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().enable_server_rescue(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "enable_server_rescue: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_poweroff_server() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .poweroff_server(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "poweroff_server: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "poweroff");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_poweroff_server() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().poweroff_server(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "poweroff_server: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_poweron_server() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .poweron_server(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "poweron_server: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "poweron");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_poweron_server() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().poweron_server(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "poweron_server: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_reboot_server() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .reboot_server(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "reboot_server: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "reboot");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_reboot_server() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().reboot_server(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "reboot_server: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_rebuild_server() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .rebuild_server(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "rebuild_server: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "rebuild");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_rebuild_server() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().rebuild_server(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "rebuild_server: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_remove_server_from_placement_group() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().remove_server_from_placement_group(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "remove_server_from_placement_group: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .remove_server_from_placement_group(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "remove_server_from_placement_group: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "remove_from_placement_group");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_remove_server_from_placement_group() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .remove_server_from_placement_group(1, None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "remove_server_from_placement_group: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_request_server_console() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .request_server_console(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "request_server_console: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "request_console");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_request_server_console() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().request_server_console(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "request_server_console: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_reset_server() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .reset_server(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "reset_server: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "reset");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_reset_server() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().reset_server(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "reset_server: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_reset_server_password() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .reset_server_password(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "reset_server_password: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "reset_password");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_reset_server_password() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().reset_server_password(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "reset_server_password: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_shutdown_server() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .shutdown_server(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "shutdown_server: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "shutdown");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_shutdown_server() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().shutdown_server(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "shutdown_server: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_server_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let action_id = seed_action(&fake, "servers", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_server_action(id, action_id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_server_action: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_server_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_server_action(1, 1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_server_action: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_server_metrics() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "servers", "fake-server");
+    let result = fake
+        .client()
+        .cloud()
+        .get_server_metrics(id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_server_metrics: {:?}", result.err());
+    let body = result.unwrap();
+    assert!(body["metrics"]["time_series"].is_object());
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_server_metrics() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_server_metrics(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_server_metrics: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_ssh_keys() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "ssh_keys", "fake-ssh_key");
+    let result = fake.client().cloud().list_ssh_keys(None, None).await;
+    assert!(result.is_ok(), "list_ssh_keys: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["ssh_keys"]), [json!(id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_ssh_keys() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_ssh_keys(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_ssh_keys: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_create_ssh_key() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let result = fake
+        .client()
+        .cloud()
+        .create_ssh_key(None, Some(json!({ "name": "new-ssh_key" })))
+        .await;
+    assert!(result.is_ok(), "create_ssh_key: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["ssh_key"]["name"], "new-ssh_key");
+    assert_eq!(fake.resources("ssh_keys").len(), 1);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_create_ssh_key() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().create_ssh_key(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "create_ssh_key: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_ssh_key() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "ssh_keys", "fake-ssh_key");
+    let result = fake.client().cloud().get_ssh_key(id, None, None).await;
+    assert!(result.is_ok(), "get_ssh_key: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["ssh_key"]["id"], id);
+    assert_eq!(body["ssh_key"]["name"], "fake-ssh_key");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_ssh_key() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_ssh_key(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_ssh_key: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_update_ssh_key() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "ssh_keys", "fake-ssh_key");
+    let result = fake
+        .client()
+        .cloud()
+        .update_ssh_key(id, None, Some(json!({ "name": "renamed-ssh_key" })))
+        .await;
+    assert!(result.is_ok(), "update_ssh_key: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["ssh_key"]["name"], "renamed-ssh_key");
+    assert_eq!(
+        fake.resource("ssh_keys", id).unwrap()["name"],
+        "renamed-ssh_key"
+    );
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_update_ssh_key() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().update_ssh_key(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "update_ssh_key: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_delete_ssh_key() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "ssh_keys", "fake-ssh_key");
+    let result = fake.client().cloud().delete_ssh_key(id, None, None).await;
+    assert!(result.is_ok(), "delete_ssh_key: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+    assert!(fake.resource("ssh_keys", id).is_none());
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_delete_ssh_key() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().delete_ssh_key(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "delete_ssh_key: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_volumes() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "volumes", "fake-volume");
+    let result = fake.client().cloud().list_volumes(None, None).await;
+    assert!(result.is_ok(), "list_volumes: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["volumes"]), [json!(id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_volumes() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_volumes(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_volumes: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_create_volume() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let result = fake
+        .client()
+        .cloud()
+        .create_volume(None, Some(json!({ "name": "new-volume" })))
+        .await;
+    assert!(result.is_ok(), "create_volume: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["volume"]["name"], "new-volume");
+    assert_eq!(fake.resources("volumes").len(), 1);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_create_volume() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().create_volume(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "create_volume: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_volumes_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "volumes", "fake-volume");
+    let action_id = seed_action(&fake, "volumes", id);
+    let result = fake.client().cloud().list_volumes_actions(None, None).await;
+    assert!(result.is_ok(), "list_volumes_actions: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_volumes_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_volumes_actions(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_volumes_actions: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_volumes_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "volumes", "fake-volume");
+    let action_id = seed_action(&fake, "volumes", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_volumes_action(action_id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_volumes_action: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_volumes_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_volumes_action(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_volumes_action: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_volume() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "volumes", "fake-volume");
+    let result = fake.client().cloud().get_volume(id, None, None).await;
+    assert!(result.is_ok(), "get_volume: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["volume"]["id"], id);
+    assert_eq!(body["volume"]["name"], "fake-volume");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_volume() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_volume(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_volume: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_update_volume() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "volumes", "fake-volume");
+    let result = fake
+        .client()
+        .cloud()
+        .update_volume(id, None, Some(json!({ "name": "renamed-volume" })))
+        .await;
+    assert!(result.is_ok(), "update_volume: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["volume"]["name"], "renamed-volume");
+    assert_eq!(
+        fake.resource("volumes", id).unwrap()["name"],
+        "renamed-volume"
+    );
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_update_volume() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().update_volume(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "update_volume: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_delete_volume() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "volumes", "fake-volume");
+    let result = fake.client().cloud().delete_volume(id, None, None).await;
+    assert!(result.is_ok(), "delete_volume: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+    assert!(fake.resource("volumes", id).is_none());
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_delete_volume() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().delete_volume(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "delete_volume: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_volume_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "volumes", "fake-volume");
+    let action_id = seed_action(&fake, "volumes", id);
+    let result = fake
+        .client()
+        .cloud()
+        .list_volume_actions(id, None, None)
+        .await;
+    assert!(result.is_ok(), "list_volume_actions: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_volume_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_volume_actions(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_volume_actions: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_attach_volume() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "volumes", "fake-volume");
+    let result = fake
+        .client()
+        .cloud()
+        .attach_volume(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "attach_volume: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "attach");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_attach_volume() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().attach_volume(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "attach_volume: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_change_volume_protection() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "volumes", "fake-volume");
+    let result = fake
+        .client()
+        .cloud()
+        .change_volume_protection(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "change_volume_protection: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_protection");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_volume_protection() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().change_volume_protection(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
-    assert!(result.is_ok(), "change_volume_protection: {:?}", result.err());
+    assert!(
+        result.is_ok(),
+        "change_volume_protection: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_detach_volume() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "volumes", "fake-volume");
+    let result = fake
+        .client()
+        .cloud()
+        .detach_volume(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "detach_volume: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "detach");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_detach_volume() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().detach_volume(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "detach_volume: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_resize_volume() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "volumes", "fake-volume");
+    let result = fake
+        .client()
+        .cloud()
+        .resize_volume(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "resize_volume: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "resize");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_resize_volume() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().resize_volume(1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "resize_volume: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_volume_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "volumes", "fake-volume");
+    let action_id = seed_action(&fake, "volumes", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_volume_action(id, action_id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_volume_action: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_volume_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_volume_action(1, 1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_volume_action: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_zones() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    let result = fake.client().cloud().list_zones(None, None).await;
+    assert!(result.is_ok(), "list_zones: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["zones"]), [json!(id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_zones() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_zones(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_zones: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_create_zone() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let result = fake
+        .client()
+        .cloud()
+        .create_zone(None, Some(json!({ "name": "new-zone" })))
+        .await;
+    assert!(result.is_ok(), "create_zone: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["zone"]["name"], "new-zone");
+    assert_eq!(fake.resources("zones").len(), 1);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_create_zone() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().create_zone(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "create_zone: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_zones_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    let action_id = seed_action(&fake, "zones", id);
+    let result = fake.client().cloud().list_zones_actions(None, None).await;
+    assert!(result.is_ok(), "list_zones_actions: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_zones_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_zones_actions(None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_zones_actions: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_zones_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    let action_id = seed_action(&fake, "zones", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_zones_action(action_id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_zones_action: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_zones_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_zones_action("1", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_zones_action: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_zone() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    let result = fake.client().cloud().get_zone(id, None, None).await;
+    assert!(result.is_ok(), "get_zone: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["zone"]["id"], id);
+    assert_eq!(body["zone"]["name"], "example.com");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_zone() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_zone("1", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_zone: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_update_zone() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    let result = fake
+        .client()
+        .cloud()
+        .update_zone(id, None, Some(json!({ "name": "renamed-zone" })))
+        .await;
+    assert!(result.is_ok(), "update_zone: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["zone"]["name"], "renamed-zone");
+    assert_eq!(fake.resource("zones", id).unwrap()["name"], "renamed-zone");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_update_zone() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().update_zone("1", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "update_zone: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_delete_zone() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    let result = fake.client().cloud().delete_zone(id, None, None).await;
+    assert!(result.is_ok(), "delete_zone: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+    assert!(fake.resource("zones", id).is_none());
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_delete_zone() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().delete_zone("1", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "delete_zone: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_zone_actions() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    let action_id = seed_action(&fake, "zones", id);
+    let result = fake
+        .client()
+        .cloud()
+        .list_zone_actions(id, None, None)
+        .await;
+    assert!(result.is_ok(), "list_zone_actions: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["actions"]), [json!(action_id)]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_zone_actions() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_zone_actions("1", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_zone_actions: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_change_zone_primary_nameservers() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().change_zone_primary_nameservers("1", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "change_zone_primary_nameservers: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    let result = fake
+        .client()
+        .cloud()
+        .change_zone_primary_nameservers(id, None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "change_zone_primary_nameservers: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_primary_nameservers");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_zone_primary_nameservers() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .change_zone_primary_nameservers("1", None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "change_zone_primary_nameservers: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_change_zone_protection() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    let result = fake
+        .client()
+        .cloud()
+        .change_zone_protection(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "change_zone_protection: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_protection");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_zone_protection() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().change_zone_protection("1", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "change_zone_protection: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_change_zone_ttl() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    let result = fake
+        .client()
+        .cloud()
+        .change_zone_ttl(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "change_zone_ttl: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_ttl");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_zone_ttl() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().change_zone_ttl("1", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "change_zone_ttl: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_import_zone_zonefile() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    let result = fake
+        .client()
+        .cloud()
+        .import_zone_zonefile(id, None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "import_zone_zonefile: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "import_zonefile");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_import_zone_zonefile() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().import_zone_zonefile("1", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "import_zone_zonefile: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_zone_action() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    let action_id = seed_action(&fake, "zones", id);
+    let result = fake
+        .client()
+        .cloud()
+        .get_zone_action(id, action_id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_zone_action: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["id"], action_id);
+    assert_eq!(body["action"]["command"], "fake_action");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_zone_action() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_zone_action("1", 1, None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_zone_action: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_list_zone_rrsets() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    fake.add_rrset(id, "www", "A", &["192.0.2.1"]);
+    let result = fake.client().cloud().list_zone_rrsets(id, None, None).await;
+    assert!(result.is_ok(), "list_zone_rrsets: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(ids(&body["rrsets"]), [json!("www/A")]);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_list_zone_rrsets() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().list_zone_rrsets("1", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "list_zone_rrsets: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_create_zone_rrset() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    let result = fake
+        .client()
+        .cloud()
+        .create_zone_rrset(
+            id,
+            None,
+            Some(json!({ "name": "www", "type": "A", "records": [{ "value": "192.0.2.1" }] })),
+        )
+        .await;
+    assert!(result.is_ok(), "create_zone_rrset: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["rrset"]["id"], "www/A");
+    assert_eq!(body["rrset"]["records"][0]["value"], "192.0.2.1");
+    assert_eq!(fake.resources("rrsets").len(), 1);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_create_zone_rrset() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().create_zone_rrset("1", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "create_zone_rrset: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_get_zone_rrset() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    fake.add_rrset(id, "www", "A", &["192.0.2.1"]);
+    let result = fake
+        .client()
+        .cloud()
+        .get_zone_rrset(id, "www", "A", None, None)
+        .await;
+    assert!(result.is_ok(), "get_zone_rrset: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["rrset"]["id"], "www/A");
+    assert_eq!(body["rrset"]["zone"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_zone_rrset() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().get_zone_rrset("1", "@", "A", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    let result = client
+        .cloud()
+        .get_zone_rrset("1", "@", "A", None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_zone_rrset: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_update_zone_rrset() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    fake.add_rrset(id, "www", "A", &["192.0.2.1"]);
+    let result = fake
+        .client()
+        .cloud()
+        .update_zone_rrset(
+            id,
+            "www",
+            "A",
+            None,
+            Some(json!({ "labels": { "env": "test" } })),
+        )
+        .await;
+    assert!(result.is_ok(), "update_zone_rrset: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["rrset"]["labels"]["env"], "test");
+    assert_eq!(fake.resources("rrsets")[0]["labels"]["env"], "test");
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_update_zone_rrset() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().update_zone_rrset("1", "@", "A", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    let result = client
+        .cloud()
+        .update_zone_rrset("1", "@", "A", None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "update_zone_rrset: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_delete_zone_rrset() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    fake.add_rrset(id, "www", "A", &["192.0.2.1"]);
+    let result = fake
+        .client()
+        .cloud()
+        .delete_zone_rrset(id, "www", "A", None, None)
+        .await;
+    assert!(result.is_ok(), "delete_zone_rrset: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+    assert!(fake.resources("rrsets").is_empty());
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_delete_zone_rrset() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().delete_zone_rrset("1", "@", "A", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    let result = client
+        .cloud()
+        .delete_zone_rrset("1", "@", "A", None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "delete_zone_rrset: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_add_zone_rrset_records() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    fake.add_rrset(id, "www", "A", &["192.0.2.1"]);
+    let result = fake
+        .client()
+        .cloud()
+        .add_zone_rrset_records(id, "www", "A", None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "add_zone_rrset_records: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "add_records");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_add_zone_rrset_records() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().add_zone_rrset_records("1", "@", "A", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    let result = client
+        .cloud()
+        .add_zone_rrset_records("1", "@", "A", None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "add_zone_rrset_records: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_change_zone_rrset_protection() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().change_zone_rrset_protection("1", "@", "A", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "change_zone_rrset_protection: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    fake.add_rrset(id, "www", "A", &["192.0.2.1"]);
+    let result = fake
+        .client()
+        .cloud()
+        .change_zone_rrset_protection(id, "www", "A", None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "change_zone_rrset_protection: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_protection");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_zone_rrset_protection() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .change_zone_rrset_protection("1", "@", "A", None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "change_zone_rrset_protection: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_change_zone_rrset_ttl() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    fake.add_rrset(id, "www", "A", &["192.0.2.1"]);
+    let result = fake
+        .client()
+        .cloud()
+        .change_zone_rrset_ttl(id, "www", "A", None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "change_zone_rrset_ttl: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "change_ttl");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_change_zone_rrset_ttl() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().change_zone_rrset_ttl("1", "@", "A", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    let result = client
+        .cloud()
+        .change_zone_rrset_ttl("1", "@", "A", None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "change_zone_rrset_ttl: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_remove_zone_rrset_records() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().remove_zone_rrset_records("1", "@", "A", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "remove_zone_rrset_records: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    fake.add_rrset(id, "www", "A", &["192.0.2.1"]);
+    let result = fake
+        .client()
+        .cloud()
+        .remove_zone_rrset_records(id, "www", "A", None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "remove_zone_rrset_records: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "remove_records");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_remove_zone_rrset_records() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .remove_zone_rrset_records("1", "@", "A", None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "remove_zone_rrset_records: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_set_zone_rrset_records() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    fake.add_rrset(id, "www", "A", &["192.0.2.1"]);
+    let result = fake
+        .client()
+        .cloud()
+        .set_zone_rrset_records(id, "www", "A", None, Some(json!({})))
+        .await;
+    assert!(result.is_ok(), "set_zone_rrset_records: {:?}", result.err());
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "set_records");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_set_zone_rrset_records() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().set_zone_rrset_records("1", "@", "A", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
+    let result = client
+        .cloud()
+        .set_zone_rrset_records("1", "@", "A", None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "set_zone_rrset_records: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_update_zone_rrset_records() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
-    };
-    let client = HetznerClient::new(token);
-    let result = client.cloud().update_zone_rrset_records("1", "@", "A", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "json_error" || api_err.code == "service_error" { return; }
-        }
-    }
-    assert!(result.is_ok(), "update_zone_rrset_records: {:?}", result.err());
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    fake.add_rrset(id, "www", "A", &["192.0.2.1"]);
+    let result = fake
+        .client()
+        .cloud()
+        .update_zone_rrset_records(id, "www", "A", None, Some(json!({})))
+        .await;
+    assert!(
+        result.is_ok(),
+        "update_zone_rrset_records: {:?}",
+        result.err()
+    );
+    let body = result.unwrap();
+    assert_eq!(body["action"]["command"], "update_records");
+    assert_eq!(body["action"]["resources"][0]["id"], id);
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_update_zone_rrset_records() {
+    let Some(client) = live_client() else {
+        return;
+    };
+    let result = client
+        .cloud()
+        .update_zone_rrset_records("1", "@", "A", None, None)
+        .await;
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "json_error"
+            || api_err.code == "service_error")
+    {
+        return;
+    }
+    assert!(
+        result.is_ok(),
+        "update_zone_rrset_records: {:?}",
+        result.err()
+    );
 }
 
 #[tokio::test]
 async fn test_get_zone_zonefile() {
-    let token = match api_token() {
-        Some(t) => t,
-        None => return,
+    let fake = FakeCloudApi::start().await.unwrap();
+    let id = seed(&fake, "zones", "example.com");
+    fake.add_rrset(id, "www", "A", &["192.0.2.1"]);
+    let result = fake
+        .client()
+        .cloud()
+        .get_zone_zonefile(id, None, None)
+        .await;
+    assert!(result.is_ok(), "get_zone_zonefile: {:?}", result.err());
+    let body = result.unwrap();
+    let zonefile = body["zonefile"].as_str().unwrap();
+    assert!(zonefile.contains("$ORIGIN example.com."));
+    assert!(zonefile.contains("www 3600 IN A 192.0.2.1"));
+}
+
+#[tokio::test]
+#[ignore = "hits the live Hetzner Cloud API; needs HETZNER_CLOUD_API_TOKEN"]
+async fn live_test_get_zone_zonefile() {
+    let Some(client) = live_client() else {
+        return;
     };
-    let client = HetznerClient::new(token);
     let result = client.cloud().get_zone_zonefile("1", None, None).await;
-    if let Err(e) = &result {
-        if let hetzner::HetznerError::Api(api_err) = e {
-            if api_err.code == "not_found" || api_err.code == "invalid_input" || api_err.code == "service_error" { return; }
-        }
+    if let Err(hetzner::HetznerError::Api(api_err)) = &result
+        && (api_err.code == "not_found"
+            || api_err.code == "invalid_input"
+            || api_err.code == "service_error")
+    {
+        return;
     }
     assert!(result.is_ok(), "get_zone_zonefile: {:?}", result.err());
 }
@@ -0,0 +1,224 @@
+use hetzner::{AcmeChallenge, AcmeHookAction, HetznerClient};
+use httpmock::prelude::*;
+use serde_json::json;
+
+fn zone_json(id: &str, name: &str) -> serde_json::Value {
+    json!({
+        "created": "2024-01-01T00:00:00Z",
+        "id": id,
+        "is_secondary_dns": false,
+        "legacy_dns_host": "",
+        "legacy_ns": [],
+        "modified": "2024-01-01T00:00:00Z",
+        "name": name,
+        "ns": ["hydrogen.ns.hetzner.com"],
+        "owner": "owner-1",
+        "paused": false,
+        "permission": "read_write",
+        "project": "project-1",
+        "records_count": 1,
+        "registrar": "none",
+        "status": "verified",
+        "ttl": 3600,
+        "txt_verification": {"name": "", "token": ""},
+        "verified": "verified",
+        "zone_type": {"description": "primary", "id": "zone-type-1", "name": "primary", "prices": null}
+    })
+}
+
+fn txt_record_json(id: &str, name: &str, value: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "name": name,
+        "ttl": 60,
+        "type": "TXT",
+        "value": value,
+        "zone_id": "zone-2",
+        "created": "2024-01-01T00:00:00Z",
+        "modified": "2024-01-01T00:00:00Z"
+    })
+}
+
+#[test]
+fn test_acme_challenge_parsing() {
+    let challenge = AcmeChallenge::new("*.Sub.Example.com.", "token");
+    assert_eq!(challenge.fqdn, "_acme-challenge.sub.example.com");
+
+    let (action, challenge) =
+        AcmeChallenge::from_lego_args(&["cleanup", "_acme-challenge.example.com.", "abc"]).unwrap();
+    assert_eq!(action, AcmeHookAction::Cleanup);
+    assert_eq!(challenge.fqdn, "_acme-challenge.example.com");
+    assert_eq!(challenge.value, "abc");
+
+    assert!(AcmeChallenge::from_lego_args(&["timeout"]).is_none());
+}
+
+#[tokio::test]
+async fn test_acme_present_and_cleanup() {
+    let server = MockServer::start();
+    let client = HetznerClient::new("dns-token").with_dns_base_url(server.base_url());
+    let challenge = AcmeChallenge::new("www.sub.example.com", "validation-token");
+
+    // Candidate zones are looked up by name, longest first.
+    let misses = ["_acme-challenge.www.sub.example.com", "www.sub.example.com"].map(|name| {
+        server.mock(|when, then| {
+            when.method(GET).path("/zones").query_param("name", name);
+            then.status(200).json_body(json!({ "zones": [] }));
+        })
+    });
+    let zones_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/zones")
+            .query_param("name", "sub.example.com");
+        then.status(200)
+            .json_body(json!({ "zones": [zone_json("zone-2", "sub.example.com")] }));
+    });
+
+    let create_mock = server.mock(|when, then| {
+        when.method(POST).path("/records").json_body_partial(
+            json!({
+                "zone_id": "zone-2",
+                "type": "TXT",
+                "name": "_acme-challenge.www",
+                "value": "validation-token"
+            })
+            .to_string(),
+        );
+        then.status(200).json_body(json!({
+            "record": txt_record_json("record-1", "_acme-challenge.www", "validation-token")
+        }));
+    });
+
+    let created = client.dns().acme_present(&challenge).await.unwrap();
+    assert_eq!(created.record.id, "record-1");
+    create_mock.assert();
+
+    let list_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/records")
+            .query_param("zone_id", "zone-2");
+        then.status(200).json_body(json!({
+            "records": [
+                txt_record_json("record-1", "_acme-challenge.www", "\"validation-token\""),
+                txt_record_json("record-2", "_acme-challenge.www", "other-token")
+            ]
+        }));
+    });
+    let delete_mock = server.mock(|when, then| {
+        when.method(DELETE).path("/records/record-1");
+        then.status(200).body("{}");
+    });
+
+    let removed = client.dns().acme_cleanup(&challenge).await.unwrap();
+    assert_eq!(removed, 1);
+    list_mock.assert();
+    delete_mock.assert();
+    zones_mock.assert_hits(2);
+    for miss in misses {
+        miss.assert_hits(2);
+    }
+}
+
+#[tokio::test]
+async fn test_acme_present_without_matching_zone() {
+    let server = MockServer::start();
+    let client = HetznerClient::new("dns-token").with_dns_base_url(server.base_url());
+
+    let zones_mock = server.mock(|when, then| {
+        when.method(GET).path("/zones").query_param_exists("name");
+        then.status(200).json_body(json!({"zones": []}));
+    });
+
+    let err = client
+        .dns()
+        .acme_present(&AcmeChallenge::new("example.org", "token"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, hetzner::HetznerError::ZoneNotFound(_)));
+    // `_acme-challenge.example.org` and `example.org`; never the bare TLD.
+    zones_mock.assert_hits(2);
+}
+
+#[tokio::test]
+async fn test_acme_present_finds_zones_beyond_the_first_page() {
+    let fake = hetzner::testing::FakeDnsApi::start().await.unwrap();
+    for i in 0..120 {
+        fake.add_zone(&format!("filler{i:03}.com"));
+    }
+    let zone = fake.add_zone("example.org");
+    let client = fake.client();
+
+    client
+        .dns()
+        .acme_present(&AcmeChallenge::new("www.example.org", "token"))
+        .await
+        .unwrap();
+    assert_eq!(fake.records(&zone.id)[0].name, "_acme-challenge.www");
+}
+
+#[cfg(feature = "acme")]
+#[tokio::test]
+async fn test_dns01_solver_presents_and_cleans_up() {
+    let fake = hetzner::testing::FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let stale = fake.add_record(&zone.id, "_acme-challenge", "TXT", "stale-token");
+    let client = fake.client();
+
+    let solver = client.dns().dns01_solver();
+    let apex = solver.present("example.com", "apex-token").await.unwrap();
+    assert_eq!(apex.name, "_acme-challenge");
+    assert_eq!(apex.ttl, Some(hetzner::Ttl::MINUTE));
+    solver
+        .present("*.example.com", "wildcard-token")
+        .await
+        .unwrap();
+    assert_eq!(solver.challenges().len(), 2);
+    // A record with the same name and value the solver did not create.
+    let foreign = fake.add_record(&zone.id, "_acme-challenge", "TXT", "apex-token");
+    assert_eq!(fake.records(&zone.id).len(), 4);
+
+    assert_eq!(solver.cleanup().await.unwrap(), 2);
+    assert!(solver.challenges().is_empty());
+    let mut left: Vec<_> = fake.records(&zone.id).into_iter().map(|r| r.id).collect();
+    left.sort();
+    let mut kept = vec![stale.id, foreign.id];
+    kept.sort();
+    assert_eq!(left, kept);
+}
+
+#[cfg(feature = "acme")]
+#[tokio::test]
+async fn test_dns01_solver_cleans_up_per_lers_token() {
+    use lers::solver::Solver;
+
+    let fake = hetzner::testing::FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let solver = fake.client().dns().dns01_solver();
+
+    Solver::present(&solver, "example.com".into(), "t1".into(), "v1".into())
+        .await
+        .unwrap();
+    Solver::present(&solver, "www.example.com".into(), "t2".into(), "v2".into())
+        .await
+        .unwrap();
+    let foreign = fake.add_record(&zone.id, "_acme-challenge", "TXT", "v1");
+
+    Solver::cleanup(&solver, "t1").await.unwrap();
+    let mut left: Vec<_> = fake
+        .records(&zone.id)
+        .into_iter()
+        .map(|r| (r.name, r.value))
+        .collect();
+    left.sort();
+    assert_eq!(
+        left,
+        [
+            ("_acme-challenge".to_string(), "v1".to_string()),
+            ("_acme-challenge.www".to_string(), "v2".to_string()),
+        ]
+    );
+    assert!(fake.records(&zone.id).iter().any(|r| r.id == foreign.id));
+    // Cleaning up a token twice, or one never presented, is a no-op.
+    Solver::cleanup(&solver, "t1").await.unwrap();
+    assert_eq!(solver.challenges().len(), 1);
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "axfr")]
+
+use hetzner::testing::FakeDnsApi;
+use hickory_proto::op::{Message, OpCode};
+use hickory_proto::rr::rdata::{A, CNAME, SOA, TXT};
+use hickory_proto::rr::{Name, RData, Record};
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Serves a single AXFR of `origin` with `records` over TCP.
+async fn start_primary(origin: &str, records: Vec<(&str, RData)>) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let origin = Name::from_str(origin).unwrap();
+    let soa = Record::from_rdata(
+        origin.clone(),
+        3600,
+        RData::SOA(SOA::new(
+            Name::from_str("ns1.example.com.").unwrap(),
+            Name::from_str("hostmaster.example.com.").unwrap(),
+            7,
+            3600,
+            600,
+            86400,
+            300,
+        )),
+    );
+    let mut answers = vec![soa.clone()];
+    for (name, data) in records {
+        let name = Name::from_str(name)
+            .unwrap()
+            .append_domain(&origin)
+            .unwrap();
+        answers.push(Record::from_rdata(name, 86400, data));
+    }
+    answers.push(soa);
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let len = stream.read_u16().await.unwrap();
+        let mut buf = vec![0; len as usize];
+        stream.read_exact(&mut buf).await.unwrap();
+        let request = Message::from_vec(&buf).unwrap();
+
+        let mut response = Message::response(request.metadata.id, OpCode::Query);
+        for query in request.queries {
+            response.add_query(query);
+        }
+        response.insert_answers(answers);
+        let bytes = response.to_vec().unwrap();
+        stream.write_u16(bytes.len() as u16).await.unwrap();
+        stream.write_all(&bytes).await.unwrap();
+        let _ = stream.read_u16().await;
+    });
+    addr
+}
+
+#[tokio::test]
+async fn test_compare_with_primary() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    fake.add_record(&zone.id, "api", "CNAME", "old.example.net.");
+    fake.add_record(&zone.id, "legacy", "A", "192.0.2.9");
+
+    let primary = start_primary(
+        "example.com.",
+        vec![
+            ("www", RData::A(A::new(192, 0, 2, 1))),
+            (
+                "api",
+                RData::CNAME(CNAME(Name::from_str("new.example.net.").unwrap())),
+            ),
+            ("_spf", RData::TXT(TXT::new(vec!["v=spf1 -all".into()]))),
+        ],
+    )
+    .await;
+
+    let comparison = fake
+        .client()
+        .dns()
+        .zone(&zone.id)
+        .compare_with_primary(primary, Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    assert!(!comparison.is_in_sync());
+    assert_eq!(comparison.missing.len(), 1);
+    assert_eq!(comparison.missing[0].name, "_spf");
+    assert_eq!(comparison.missing[0].record_type, "TXT");
+
+    assert_eq!(comparison.stale.len(), 1);
+    assert_eq!(comparison.stale[0].name, "api");
+    assert_eq!(comparison.stale[0].primary_values, ["new.example.net"]);
+    assert_eq!(comparison.stale[0].hetzner_values, ["old.example.net"]);
+
+    assert_eq!(comparison.extra.len(), 1);
+    assert_eq!(comparison.extra[0].name, "legacy");
+}
@@ -0,0 +1,151 @@
+use hetzner::testing::FakeDnsApi;
+use hetzner::{BackupOptions, BackupProgress, Progress, ZoneBackup};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn backup_dir() -> std::path::PathBuf {
+    let suffix = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("hetzner-backup-{suffix}"))
+}
+
+#[tokio::test]
+async fn test_backup_account_to_dir() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let names: Vec<String> = (0..120).map(|i| format!("zone{i:03}.example")).collect();
+    for name in &names {
+        let zone = fake.add_zone(name);
+        fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    }
+    let client = fake.client();
+    let dir = backup_dir();
+
+    let progress: Arc<Mutex<Vec<BackupProgress>>> = Arc::default();
+    let seen = progress.clone();
+    let options = BackupOptions {
+        concurrency: 16,
+        on_progress: Some(Arc::new(move |p| seen.lock().unwrap().push(p.clone()))),
+    };
+    let report = client
+        .dns()
+        .backup_account_to_dir(&dir, options)
+        .await
+        .unwrap();
+    assert!(report.is_complete());
+    assert_eq!(report.zones, names);
+
+    let progress = progress.lock().unwrap();
+    assert_eq!(progress.len(), 120);
+    assert!(progress.iter().all(|p| p.total == 120 && p.error.is_none()));
+    let last = progress.iter().max_by_key(|p| p.completed).unwrap();
+    assert_eq!(last.eta(), Some(std::time::Duration::ZERO));
+    let mut completed: Vec<usize> = progress.iter().map(|p| p.completed).collect();
+    completed.sort();
+    assert_eq!(completed, (1..=120).collect::<Vec<_>>());
+
+    let backup = ZoneBackup::read(dir.join("zone007.example.json")).unwrap();
+    assert_eq!(backup.zone.name, "zone007.example");
+    assert_eq!(backup.records.len(), 1);
+    let zone_file = std::fs::read_to_string(dir.join("zone007.example.zone")).unwrap();
+    assert!(zone_file.starts_with("$ORIGIN zone007.example.\n$TTL "));
+    assert!(zone_file.contains("www\t"));
+    assert!(zone_file.ends_with("IN\tA\t192.0.2.1\n"));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_restore_account_plans_before_applying() {
+    let source = FakeDnsApi::start().await.unwrap();
+    let shop = source.add_zone("shop.example");
+    source.add_record(&shop.id, "www", "A", "192.0.2.1");
+    source.add_record(&shop.id, "@", "MX", "10 mail.shop.example.");
+    let blog = source.add_zone("blog.example");
+    source.add_record(&blog.id, "@", "A", "192.0.2.9");
+    source.add_record(&blog.id, "@", "NS", "hydrogen.ns.hetzner.com.");
+    let dir = backup_dir();
+    let report = source
+        .client()
+        .dns()
+        .backup_account_to_dir(&dir, BackupOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(report.zones, ["blog.example", "shop.example"]);
+
+    let target = FakeDnsApi::start().await.unwrap();
+    let live_shop = target.add_zone("shop.example");
+    target.add_record(&live_shop.id, "www", "A", "192.0.2.1");
+    target.add_record(&live_shop.id, "www", "A", "198.51.100.7");
+    let client = target.client();
+
+    let plan = client.dns().restore_account_from_dir(&dir).await.unwrap();
+    assert_eq!(plan.zones.len(), 2);
+    let blog_plan = &plan.zones[0];
+    assert_eq!(blog_plan.zone_name, "blog.example");
+    assert!(blog_plan.creates_zone());
+    assert_eq!(
+        blog_plan.changes.create.len(),
+        1,
+        "apex NS is left to Hetzner"
+    );
+    let shop_plan = &plan.zones[1];
+    assert_eq!(shop_plan.zone_id.as_deref(), Some(live_shop.id.as_str()));
+    assert_eq!(shop_plan.changes.create[0].record_type, "MX");
+    assert_eq!(shop_plan.changes.delete[0].value, "198.51.100.7");
+    assert_eq!(shop_plan.changes.unchanged.len(), 1);
+    assert_eq!(target.zones().len(), 1, "planning changes nothing");
+
+    let outcomes = plan.apply(&client).await;
+    assert_eq!(outcomes.len(), 2);
+    assert!(outcomes.iter().all(|(_, outcome)| outcome.is_ok()));
+    let again = client.dns().restore_account_from_dir(&dir).await.unwrap();
+    assert_eq!(again.pending().count(), 0);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_restore_reports_progress_per_zone() {
+    let source = FakeDnsApi::start().await.unwrap();
+    for name in ["a.example", "b.example", "c.example"] {
+        let zone = source.add_zone(name);
+        source.add_record(&zone.id, "www", "A", "192.0.2.1");
+    }
+    let dir = backup_dir();
+    source
+        .client()
+        .dns()
+        .backup_account_to_dir(&dir, BackupOptions::default())
+        .await
+        .unwrap();
+
+    let target = FakeDnsApi::start().await.unwrap();
+    let client = target.client();
+    let plan = client.dns().restore_account_from_dir(&dir).await.unwrap();
+    let progress: Arc<Mutex<Vec<Progress>>> = Arc::default();
+    let seen = progress.clone();
+    let outcomes = plan
+        .apply_with_progress(&client, move |p| seen.lock().unwrap().push(p.clone()))
+        .await;
+    assert!(outcomes.iter().all(|(_, outcome)| outcome.is_ok()));
+
+    let progress = progress.lock().unwrap();
+    let done: Vec<(usize, usize, Option<&str>)> = progress
+        .iter()
+        .map(|p| (p.done, p.total, p.zone.as_deref()))
+        .collect();
+    assert_eq!(
+        done,
+        [
+            (1, 3, Some("a.example")),
+            (2, 3, Some("b.example")),
+            (3, 3, Some("c.example"))
+        ]
+    );
+    assert_eq!(progress[2].eta(), Some(std::time::Duration::ZERO));
+    assert_eq!(progress[2].fraction(), 1.0);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,71 @@
+use hetzner::Ttl;
+use hetzner::testing::FakeDnsApi;
+
+#[tokio::test]
+async fn test_export_dnscontrol_renders_zone_stanzas() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.add_record(&zone.id, "@", "NS", "hydrogen.ns.hetzner.com.");
+    fake.add_record(&zone.id, "@", "A", "192.0.2.1");
+    fake.add_record(&zone.id, "www", "CNAME", "example.com.");
+    fake.add_record(&zone.id, "@", "MX", "10 mail.example.com.");
+    fake.add_record(&zone.id, "@", "TXT", "\"v=spf1 mx -all\"");
+    fake.add_record(&zone.id, "long", "TXT", "\"part \\\"one\\\"\" \"part two\"");
+    fake.add_record(&zone.id, "_sip._tcp", "SRV", "10 60 5060 sip.example.com.");
+    fake.add_record(&zone.id, "@", "CAA", "128 issue \"letsencrypt.org\"");
+    fake.add_record(&zone.id, "_443._tcp", "TLSA", "3 1 1 abcdef");
+    fake.add_record(&zone.id, "sub", "NS", "ns1.other.example.");
+    fake.add_record(&zone.id, "@", "RP", "admin.example.com. .");
+    fake.add_record(&zone.id, "bad", "MX", "mail.example.com.");
+    let client = fake.client();
+    client
+        .dns()
+        .records("example.com")
+        .create("short", "AAAA", "2001:db8::1", Ttl::FIVE_MINUTES)
+        .await
+        .unwrap();
+
+    let stanza = client.dns().zone("example.com").dnscontrol().await.unwrap();
+    assert_eq!(
+        stanza,
+        r#"// not exported: @ RP admin.example.com. .
+// not exported: bad MX mail.example.com.
+D("example.com", REG_NONE, DnsProvider(DSP_HETZNER),
+	DefaultTTL(86400),
+	A("@", "192.0.2.1"),
+	CNAME("www", "example.com."),
+	MX("@", 10, "mail.example.com."),
+	TXT("@", "v=spf1 mx -all"),
+	TXT("long", ["part \"one\"", "part two"]),
+	SRV("_sip._tcp", 10, 60, 5060, "sip.example.com."),
+	CAA("@", "issue", "letsencrypt.org", CAA_CRITICAL),
+	TLSA("_443._tcp", 3, 1, 1, "abcdef"),
+	NS("sub", "ns1.other.example."),
+	AAAA("short", "2001:db8::1", TTL(300))
+);
+"#
+    );
+
+    let config = client.dns().export_dnscontrol().await.unwrap();
+    assert_eq!(
+        config,
+        format!(
+            "var REG_NONE = NewRegistrar(\"none\");\n\
+             var DSP_HETZNER = NewDnsProvider(\"hetzner\");\n\n{stanza}"
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_export_dnscontrol_covers_every_page_of_zones() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    for i in 0..120 {
+        fake.add_zone(&format!("filler{i:03}.com"));
+    }
+    fake.add_zone("late.org");
+    let client = fake.client();
+
+    let config = client.dns().export_dnscontrol().await.unwrap();
+    assert_eq!(config.matches("\nD(\"").count(), 121);
+    assert!(config.contains("D(\"late.org\""));
+}
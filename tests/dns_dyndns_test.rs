@@ -0,0 +1,218 @@
+#![cfg(feature = "dyndns")]
+
+use hetzner::testing::FakeDnsApi;
+use hetzner::{
+    Consensus, DynDnsUpdate, HetznerError, HttpsSource, InterfaceAddress, InterfaceSource,
+    IpSource, IpVersion, Ttl, UpnpSource, replace_prefix,
+};
+use httpmock::prelude::*;
+use std::net::{IpAddr, Ipv6Addr, SocketAddr};
+
+fn ip_service(server: &MockServer, path: &str, body: &str) -> HttpsSource {
+    server.mock(|when, then| {
+        when.method(GET).path(path);
+        then.status(200).body(body);
+    });
+    HttpsSource::new(server.url(path))
+}
+
+#[tokio::test]
+async fn test_https_source_and_consensus() {
+    let server = MockServer::start();
+    let first = ip_service(&server, "/a", "203.0.113.7\n");
+    let second = ip_service(&server, "/b", "203.0.113.7");
+    let liar = ip_service(&server, "/c", "198.51.100.1");
+    let broken = ip_service(&server, "/d", "<html>rate limited</html>");
+
+    let ip: IpAddr = "203.0.113.7".parse().unwrap();
+    assert_eq!(first.detect(IpVersion::V4).await.unwrap(), ip);
+    let err = first.detect(IpVersion::V6).await.unwrap_err();
+    assert!(matches!(err, HetznerError::IpDetection(_)), "{err}");
+    assert!(broken.detect(IpVersion::V4).await.is_err());
+
+    let consensus = Consensus::new(2)
+        .source(first.clone())
+        .source(second.clone())
+        .source(liar.clone())
+        .source(broken.clone());
+    assert_eq!(consensus.detect(IpVersion::V4).await.unwrap(), ip);
+
+    let split = Consensus::new(1)
+        .source(first.clone())
+        .source(liar.clone())
+        .source(second.clone())
+        .source(ip_service(&server, "/e", "198.51.100.1"));
+    let err = split.detect(IpVersion::V4).await.unwrap_err();
+    assert!(
+        matches!(&err, HetznerError::IpDetection(reason) if reason.contains("disagree")),
+        "{err}"
+    );
+
+    let strict = Consensus::new(3).source(first).source(liar).source(broken);
+    let err = strict.detect(IpVersion::V4).await.unwrap_err();
+    assert!(
+        err.to_string().contains("fewer than 3 sources agree"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn test_upnp_source_with_known_gateway() {
+    let router = MockServer::start();
+    router.mock(|when, then| {
+        when.method(GET).path("/rootDesc.xml");
+        then.status(200).body(
+            "<root><device><serviceList>\
+             <service><serviceType>urn:schemas-upnp-org:service:Layer3Forwarding:1</serviceType>\
+             <controlURL>/ctl/L3F</controlURL></service>\
+             <service><serviceType>urn:schemas-upnp-org:service:WANIPConnection:1</serviceType>\
+             <controlURL>/ctl/IPConn</controlURL></service>\
+             </serviceList></device></root>",
+        );
+    });
+    let soap = router.mock(|when, then| {
+        when.method(POST)
+            .path("/ctl/IPConn")
+            .header(
+                "soapaction",
+                "\"urn:schemas-upnp-org:service:WANIPConnection:1#GetExternalIPAddress\"",
+            )
+            .body_contains("GetExternalIPAddress");
+        then.status(200).body(
+            "<s:Envelope><s:Body><u:GetExternalIPAddressResponse>\
+             <NewExternalIPAddress>203.0.113.42</NewExternalIPAddress>\
+             </u:GetExternalIPAddressResponse></s:Body></s:Envelope>",
+        );
+    });
+
+    let upnp = UpnpSource::new().gateway(router.url("/rootDesc.xml"));
+    let ip = upnp.detect(IpVersion::V4).await.unwrap();
+    assert_eq!(ip, "203.0.113.42".parse::<IpAddr>().unwrap());
+    soap.assert();
+    assert!(upnp.detect(IpVersion::V6).await.is_err());
+}
+
+#[tokio::test]
+async fn test_interface_source() {
+    let loopback: SocketAddr = "127.0.0.1:9".parse().unwrap();
+    let source = InterfaceSource::new().target(loopback);
+    let ip = source.detect(IpVersion::V4).await.unwrap();
+    assert!(ip.is_loopback());
+}
+
+#[tokio::test]
+async fn test_dyndns_update() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let client = fake.client();
+    let server = MockServer::start();
+    let source = ip_service(&server, "/ip", "203.0.113.7");
+
+    let home = || client.dns().dyndns(&zone.id, "home").ttl(Ttl::MINUTE);
+    let created = home().update(&source).await.unwrap();
+    assert!(matches!(&created, DynDnsUpdate::Created(r) if r.value == "203.0.113.7"));
+    assert_eq!(created.record().ttl, Some(Ttl::MINUTE));
+
+    let again = home().update(&source).await.unwrap();
+    assert!(!again.changed());
+
+    let moved = home().set("203.0.113.8".parse().unwrap()).await.unwrap();
+    let DynDnsUpdate::Updated { before, after } = moved else {
+        panic!("expected an update, got {moved:?}");
+    };
+    assert_eq!(before.value, "203.0.113.7");
+    assert_eq!(after.id, before.id);
+    assert_eq!(after.value, "203.0.113.8");
+    assert_eq!(fake.records(&zone.id).len(), 1);
+}
+
+#[test]
+fn test_interface_addresses_and_prefixes() {
+    let addresses = InterfaceAddress::parse_if_inet6(
+        "00000000000000000000000000000001 01 80 10 80       lo\n\
+         20010db8000100000000000000000010 02 40 00 80     eth0\n\
+         20010db80001000034a1c2ffe4d5b6c7 02 40 00 01     eth0\n\
+         fd000000000000000000000000000002 02 40 00 80     eth0\n\
+         fe80000000000000021122fffe334455 02 40 20 80     eth0\n\
+         not a line\n",
+    );
+    assert_eq!(addresses.len(), 5);
+    let stable = &addresses[1];
+    assert_eq!(stable.interface, "eth0");
+    assert_eq!(
+        stable.address,
+        "2001:db8:1::10".parse::<Ipv6Addr>().unwrap()
+    );
+    assert_eq!(stable.prefix_len, 64);
+    assert!(stable.is_global() && !stable.is_temporary());
+    assert!(addresses[2].is_global() && addresses[2].is_temporary());
+    assert!(!addresses[0].is_global(), "loopback");
+    assert!(!addresses[3].is_global(), "unique local");
+    assert!(!addresses[4].is_global(), "link local");
+
+    let prefix: Ipv6Addr = "2001:db8:aaaa:bbbb:1:2:3:4".parse().unwrap();
+    let host: Ipv6Addr = "2001:db8:1:2::10".parse().unwrap();
+    assert_eq!(
+        replace_prefix(prefix, 56, host),
+        "2001:db8:aaaa:bb02::10".parse::<Ipv6Addr>().unwrap()
+    );
+    assert_eq!(replace_prefix(prefix, 128, host), prefix);
+    assert_eq!(replace_prefix(prefix, 0, host), host);
+}
+
+#[tokio::test]
+async fn test_dyndns_prefix_delegation() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.add_record(&zone.id, "nas", "AAAA", "2001:db8:1:2::10");
+    let client = fake.client();
+    let detected: IpAddr = "2001:db8:ffff:2:abcd::1".parse().unwrap();
+
+    let nas = || {
+        client
+            .dns()
+            .dyndns(&zone.id, "nas")
+            .version(IpVersion::V6)
+            .prefix_len(48)
+    };
+    let renumbered = nas().set(detected).await.unwrap();
+    let DynDnsUpdate::Updated { after, .. } = &renumbered else {
+        panic!("expected an update, got {renumbered:?}");
+    };
+    assert_eq!(after.record_type, "AAAA");
+    assert_eq!(after.value, "2001:db8:ffff:2::10");
+    assert!(!nas().set(detected).await.unwrap().changed());
+
+    let printer = client
+        .dns()
+        .dyndns(&zone.id, "printer")
+        .prefix_len(64)
+        .suffix("::7".parse().unwrap())
+        .set(detected)
+        .await
+        .unwrap();
+    assert!(matches!(&printer, DynDnsUpdate::Created(r) if r.value == "2001:db8:ffff:2::7"));
+}
+
+#[tokio::test]
+async fn test_dyndns_matches_apex_and_dotted_names() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.add_record(&zone.id, "@", "A", "198.51.100.1");
+    fake.add_record(&zone.id, "Home", "A", "198.51.100.2");
+    let client = fake.client();
+
+    for (name, before) in [("", "198.51.100.1"), ("home.", "198.51.100.2")] {
+        let update = client
+            .dns()
+            .dyndns(&zone.id, name)
+            .set("203.0.113.7".parse().unwrap())
+            .await
+            .unwrap();
+        let DynDnsUpdate::Updated { before: old, .. } = &update else {
+            panic!("expected an update of {name:?}, got {update:?}");
+        };
+        assert_eq!(old.value, before);
+    }
+    assert_eq!(fake.records(&zone.id).len(), 2);
+}
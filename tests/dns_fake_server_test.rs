@@ -0,0 +1,992 @@
+use hetzner::api::dns::records::{Ttl, UpdateRecordInput};
+use hetzner::testing::FakeDnsApi;
+use hetzner::{HetznerClient, HetznerError, SearchScope};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_fake_dns_api_record_lifecycle() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let client = fake.client();
+
+    let zones = client.dns().list_zones().await.unwrap();
+    assert_eq!(zones.len(), 1);
+    assert_eq!(zones[0].name, "example.com");
+
+    let created = client
+        .dns()
+        .records(&zone.id)
+        .create("www", "A", "192.0.2.1", Ttl::FIVE_MINUTES)
+        .await
+        .unwrap();
+    assert_eq!(fake.records(&zone.id).len(), 1);
+
+    let updated = client
+        .dns()
+        .record(&created.record.id)
+        .update(UpdateRecordInput {
+            zone_id: zone.id.clone(),
+            record_type: "A".to_string(),
+            name: "www".to_string(),
+            value: "192.0.2.2".to_string(),
+            ttl: Some(Ttl::new(600).unwrap()),
+        })
+        .await
+        .unwrap();
+    assert_eq!(updated.record.value, "192.0.2.2");
+    assert_eq!(updated.record.ttl, Some(Ttl::new(600).unwrap()));
+
+    let got = client.dns().record(&created.record.id).get().await.unwrap();
+    assert_eq!(got.value, "192.0.2.2");
+
+    client
+        .dns()
+        .record(&created.record.id)
+        .delete()
+        .await
+        .unwrap();
+    assert!(
+        client
+            .dns()
+            .records(&zone.id)
+            .list()
+            .await
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn test_partial_record_updates() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let client = fake.client();
+    let created = client
+        .dns()
+        .records(&zone.id)
+        .create("mail", "MX", "10 mx1.example.net.", Ttl::FIVE_MINUTES)
+        .await
+        .unwrap();
+    let record = client.dns().record(&created.record.id);
+
+    let updated = record.update_ttl(Ttl::HOUR).await.unwrap().record;
+    assert_eq!(updated.ttl, Some(Ttl::HOUR));
+    assert_eq!(updated.value, "10 mx1.example.net.");
+
+    let updated = record
+        .update_value("20 mx2.example.net.")
+        .await
+        .unwrap()
+        .record;
+    assert_eq!(updated.value, "20 mx2.example.net.");
+    assert_eq!(
+        (updated.name.as_str(), updated.ttl),
+        ("mail", Some(Ttl::HOUR))
+    );
+
+    let updated = record.update_ttl(None).await.unwrap().record;
+    assert_eq!(updated.ttl, None);
+    assert_eq!(updated.record_type, "MX");
+
+    let input = |value: &str| UpdateRecordInput {
+        zone_id: zone.id.clone(),
+        record_type: "MX".to_string(),
+        name: "Mail".to_string(),
+        value: value.to_string(),
+        ttl: None,
+    };
+    let unchanged = record
+        .update_if_changed(input("20  MX2.Example.NET"))
+        .await
+        .unwrap();
+    assert!(unchanged.is_none());
+    assert_eq!(fake.records(&zone.id)[0].value, "20 mx2.example.net.");
+    let changed = record
+        .update_if_changed(input("30 mx2.example.net."))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(changed.record.value, "30 mx2.example.net.");
+
+    let err = client
+        .dns()
+        .record("missing")
+        .update_ttl(Ttl::MINUTE)
+        .await
+        .unwrap_err();
+    assert!(err.is_not_found());
+}
+
+#[tokio::test]
+async fn test_ensure_zone() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let existing = fake.add_zone("example.com");
+    let client = fake.client();
+
+    let zone = client
+        .dns()
+        .ensure_zone("Example.com.", Some(Ttl::new(600).unwrap()))
+        .await
+        .unwrap();
+    assert_eq!(zone.id, existing.id);
+
+    let created = client
+        .dns()
+        .ensure_zone("example.org", Some(Ttl::new(600).unwrap()))
+        .await
+        .unwrap();
+    assert_eq!(created.name, "example.org");
+    assert_eq!(created.ttl, Some(Ttl::new(600).unwrap()));
+    let again = client.dns().ensure_zone("example.org", None).await.unwrap();
+    assert_eq!(again.id, created.id);
+    assert_eq!(fake.zones().len(), 2);
+
+    // Found by name even beyond the first page of a listing.
+    for i in 0..120 {
+        fake.add_zone(&format!("filler{i:03}.com"));
+    }
+    let late = fake.add_zone("late.org");
+    let zone = client.dns().ensure_zone("late.org", None).await.unwrap();
+    assert_eq!(zone.id, late.id);
+    assert_eq!(fake.zones().len(), 123);
+}
+
+#[tokio::test]
+async fn test_fake_dns_api_errors() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let client = fake.client();
+
+    let err = client
+        .dns()
+        .records(&zone.id)
+        .create("www", "BOGUS", "192.0.2.1", Ttl::FIVE_MINUTES)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HetznerError::Api(api) if api.status.as_u16() == 422));
+
+    let err = client.dns().record("missing").get().await.unwrap_err();
+    assert!(matches!(err, HetznerError::Api(api) if api.status.as_u16() == 404));
+
+    let unauthorized = HetznerClient::new("wrong").with_dns_base_url(fake.base_url());
+    let err = unauthorized.dns().list_zones().await.unwrap_err();
+    assert!(matches!(err, HetznerError::Api(api) if api.status.as_u16() == 401));
+}
+
+#[tokio::test]
+async fn test_fake_dns_api_opt_getters() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let record = fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    let client = fake.client();
+
+    let found = client.dns().zone(&zone.id).get_opt().await.unwrap();
+    assert_eq!(found.unwrap().zone.name, "example.com");
+    assert!(
+        client
+            .dns()
+            .zone("missing")
+            .get_opt()
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    let found = client.dns().record(&record.id).get_opt().await.unwrap();
+    assert_eq!(found.unwrap().value, "192.0.2.1");
+    assert!(
+        client
+            .dns()
+            .record("missing")
+            .get_opt()
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    let unauthorized = HetznerClient::new("wrong").with_dns_base_url(fake.base_url());
+    let err = unauthorized
+        .dns()
+        .zone(&zone.id)
+        .get_opt()
+        .await
+        .unwrap_err();
+    assert!(err.is_auth_error());
+}
+
+#[tokio::test]
+async fn test_fake_dns_api_page_navigation() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    for name in ["a.example", "b.example", "c.example"] {
+        fake.add_zone(name);
+    }
+    let zone = fake.zones()[0].clone();
+    for i in 0..3 {
+        fake.add_record(&zone.id, &format!("host{i}"), "A", "192.0.2.1");
+    }
+    let client = fake.client();
+
+    let first = client.dns().zones_page(1, 2).await.unwrap();
+    assert_eq!(first.items.len(), 2);
+    assert!(first.has_next() && !first.has_prev());
+    assert!(first.prev(&client).await.unwrap().is_none());
+
+    let second = first.next(&client).await.unwrap().unwrap();
+    assert_eq!(second.page(), 2);
+    assert_eq!(second.items.len(), 1);
+    assert!(second.next(&client).await.unwrap().is_none());
+
+    let back = second.prev(&client).await.unwrap().unwrap();
+    assert_eq!(back.items[0].id, first.items[0].id);
+
+    let records = client.dns().records(&zone.id).page(2, 2).await.unwrap();
+    assert_eq!(records.items.len(), 1);
+    assert_eq!(records.pagination.unwrap().total_entries, Some(3));
+
+    let records = client.dns().get_records_page(&zone.id, 1, 2).await.unwrap();
+    assert_eq!(records.items.len(), 2);
+    assert_eq!(records.total_entries(), Some(3));
+    assert_eq!(records.last_page(), Some(2));
+}
+
+#[tokio::test]
+async fn test_zone_and_record_streams() {
+    use futures_util::{StreamExt, TryStreamExt};
+
+    let fake = FakeDnsApi::start().await.unwrap();
+    for name in ["a.example", "b.example", "c.example"] {
+        fake.add_zone(name);
+    }
+    let zone = fake.zones()[0].clone();
+    for i in 0..5 {
+        fake.add_record(&zone.id, &format!("host{i}"), "A", "192.0.2.1");
+    }
+    fake.add_record(&zone.id, "mail", "MX", "10 mail.example.com.");
+    let client = fake.client();
+
+    let mut names: Vec<String> = client
+        .dns()
+        .zones_stream()
+        .map_ok(|zone| zone.name)
+        .try_collect()
+        .await
+        .unwrap();
+    names.sort();
+    assert_eq!(names, ["a.example", "b.example", "c.example"]);
+
+    // Walks every page of a small page size, lazily.
+    let first = client.dns().zones_page(1, 1).await.unwrap();
+    let all: Vec<_> = first.into_stream(&client).try_collect().await.unwrap();
+    assert_eq!(all.len(), 3);
+    let first = client.dns().records(&zone.id).page(1, 2).await.unwrap();
+    let two: Vec<_> = first
+        .into_stream(&client)
+        .take(2)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(two.len(), 2);
+
+    let hosts = client
+        .dns()
+        .records(&zone.id)
+        .stream()
+        .try_filter(|record| std::future::ready(record.record_type == "A"))
+        .map_ok(|record| record.name)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(hosts.len(), 5);
+
+    let counts: Vec<usize> = client
+        .dns()
+        .zones_stream()
+        .map_ok(|zone| {
+            let client = client.clone();
+            async move { client.dns().records(&zone.id).list().await.map(|r| r.len()) }
+        })
+        .try_buffer_unordered(2)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(counts.iter().sum::<usize>(), 6);
+
+    let missing: Vec<_> = client
+        .dns()
+        .records("missing.example")
+        .stream()
+        .collect()
+        .await;
+    assert!(matches!(missing[..], [Err(_)]));
+}
+
+#[tokio::test]
+async fn test_search_zones() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    for name in ["shop.example", "shop.test", "blog.example"] {
+        fake.add_zone(name);
+    }
+    let client = fake.client();
+
+    let matches = client.dns().search_zones("shop").await.unwrap();
+    assert_eq!(matches.total_entries(), Some(2));
+    let mut names: Vec<_> = matches.items.iter().map(|z| z.name.as_str()).collect();
+    names.sort();
+    assert_eq!(names, ["shop.example", "shop.test"]);
+
+    let first = client
+        .dns()
+        .search_zones_page("example", 1, 1)
+        .await
+        .unwrap();
+    assert_eq!(first.items.len(), 1);
+    assert_eq!(first.total_entries(), Some(2));
+    let second = first.next(&client).await.unwrap().unwrap();
+    assert!(second.items[0].name.ends_with(".example"));
+    assert_ne!(second.items[0].id, first.items[0].id);
+
+    let none = client.dns().search_zones("nothing").await.unwrap();
+    assert!(none.items.is_empty());
+    assert_eq!(none.total_entries(), Some(0));
+}
+
+#[tokio::test]
+async fn test_request_builders() {
+    use hetzner::api::dns::records::CreateRecordInput;
+    use hetzner::api::dns::zones::ListZonesParams;
+
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("shop.example");
+    fake.add_zone("shop.test");
+    let client = fake.client();
+
+    let params = ListZonesParams::default().search_name("shop").per_page(1);
+    let page = client.dns().list_zones_with(&params).await.unwrap();
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.total_entries(), Some(2));
+    let exact = ListZonesParams::default().name("shop.test");
+    let page = client.dns().list_zones_with(&exact).await.unwrap();
+    assert_eq!(page.items[0].name, "shop.test");
+
+    let input = CreateRecordInput::default()
+        .zone_id(&zone.id)
+        .name("www")
+        .record_type("A")
+        .value("192.0.2.1")
+        .ttl(Ttl::FIVE_MINUTES);
+    let created = client
+        .dns()
+        .records(&zone.id)
+        .bulk_create(vec![input])
+        .await
+        .unwrap();
+    let record = &created.records[0];
+    assert_eq!(record.ttl, Some(Ttl::FIVE_MINUTES));
+
+    let updated = client
+        .dns()
+        .record(&record.id)
+        .update(UpdateRecordInput::from(record).ttl(Ttl::MINUTE))
+        .await
+        .unwrap()
+        .record;
+    assert_eq!(updated.ttl, Some(Ttl::MINUTE));
+    assert_eq!(updated.value, "192.0.2.1");
+    let inherited = UpdateRecordInput::from(&updated).ttl(None);
+    assert_eq!(inherited.ttl, None);
+}
+
+#[tokio::test]
+async fn test_fake_dns_api_zone_default_ttl() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let client = fake.client();
+    let records = client.dns().records(&zone.id);
+
+    let inherited = records
+        .create("www", "A", "192.0.2.1", None)
+        .await
+        .unwrap()
+        .record;
+    assert_eq!(inherited.ttl, None);
+    assert_eq!(inherited.effective_ttl(&zone), zone.default_ttl());
+
+    let explicit = records
+        .create("api", "A", "192.0.2.2", Ttl::FIVE_MINUTES)
+        .await
+        .unwrap()
+        .record;
+    assert_eq!(explicit.effective_ttl(&zone), Ttl::FIVE_MINUTES);
+}
+
+#[tokio::test]
+async fn test_fake_dns_api_zone_verification() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.set_zone_status(&zone.id, "pending");
+    let client = fake.client();
+
+    let verification = client.dns().zone(&zone.id).verify().await.unwrap();
+    assert!(!verification.verified);
+    assert_eq!(verification.status.as_deref(), Some("pending"));
+    assert_eq!(verification.nameservers.len(), 3);
+
+    let err = client
+        .dns()
+        .zone(&zone.id)
+        .wait_until_verified(Duration::from_millis(200))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HetznerError::Timeout(_)));
+
+    let flip = async {
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        fake.set_zone_status(&zone.id, "verified");
+    };
+    let (verified, ()) = tokio::join!(
+        client
+            .dns()
+            .zone(&zone.id)
+            .wait_until_verified(Duration::from_secs(5)),
+        flip
+    );
+    assert_eq!(verified.unwrap().status.as_deref(), Some("verified"));
+}
+
+#[tokio::test]
+async fn test_fake_dns_api_secondary_zone_setup() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let client = fake.client();
+
+    // Stands in for the first zone transfer from the primaries.
+    let transfer = async {
+        loop {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            if let Some(zone) = fake.zones().into_iter().find(|z| z.name == "example.com") {
+                fake.add_record(&zone.id, "@", "A", "192.0.2.1");
+                break;
+            }
+        }
+    };
+    let (setup, ()) = tokio::join!(
+        client.dns().setup_secondary_zone(
+            "example.com",
+            &[("192.0.2.53", 53), ("2001:db8::53", 5353)],
+            Duration::from_secs(5),
+        ),
+        transfer
+    );
+    let setup = setup.unwrap();
+    assert!(setup.zone.is_secondary_dns);
+    assert_eq!(setup.zone.records_count, 1);
+    assert_eq!(setup.primary_servers.len(), 2);
+
+    let servers = client.dns().primary_servers(&setup.zone.id);
+    assert_eq!(servers.list().await.unwrap().len(), 2);
+    let first = &setup.primary_servers[0];
+    assert_eq!(
+        client
+            .dns()
+            .primary_server(&first.id)
+            .get()
+            .await
+            .unwrap()
+            .port,
+        53
+    );
+    client
+        .dns()
+        .primary_server(&first.id)
+        .delete()
+        .await
+        .unwrap();
+    assert!(
+        client
+            .dns()
+            .primary_server(&first.id)
+            .get_opt()
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    let err = client
+        .dns()
+        .setup_secondary_zone(
+            "other.example",
+            &[("not-an-ip", 53)],
+            Duration::from_secs(1),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err.api_error().unwrap().status.as_u16(), 422);
+}
+
+#[tokio::test]
+async fn test_fake_dns_api_watch_zone() {
+    use futures_util::StreamExt;
+    use hetzner::ChangeKind;
+
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let doomed = fake.add_record(&zone.id, "old", "A", "192.0.2.1");
+    let edited = fake.add_record(&zone.id, "www", "A", "192.0.2.2");
+    let client = fake.client();
+    let watcher = client.clone();
+
+    let changes = async {
+        // Out-of-band edits, made after the watcher's initial snapshot.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let records = client.dns().records(&zone.id);
+        records
+            .create("new", "A", "192.0.2.3", Ttl::FIVE_MINUTES)
+            .await
+            .unwrap();
+        client.dns().record(&doomed.id).delete().await.unwrap();
+        client
+            .dns()
+            .record(&edited.id)
+            .update(UpdateRecordInput {
+                zone_id: zone.id.clone(),
+                record_type: "A".into(),
+                name: "www".into(),
+                value: "192.0.2.20".into(),
+                ttl: None,
+            })
+            .await
+            .unwrap();
+    };
+    let watch = async {
+        let events = watcher
+            .dns()
+            // By name: events still carry the zone's id.
+            .watch_zone("example.com", Duration::from_millis(30))
+            .take(3)
+            .map(Result::unwrap)
+            .collect::<Vec<_>>();
+        tokio::time::timeout(Duration::from_secs(5), events)
+            .await
+            .unwrap()
+    };
+    let ((), events) = tokio::join!(changes, watch);
+
+    assert!(events.iter().all(|e| e.zone_id == zone.id));
+    assert!(events.iter().any(|e| e.kind == ChangeKind::Created
+        && e.before.is_none()
+        && e.record().is_some_and(|r| r.name == "new")));
+    assert!(events.iter().any(|e| e.kind == ChangeKind::Deleted
+        && e.after.is_none()
+        && e.before.as_ref() == Some(&doomed)
+        && e.record() == Some(&doomed)));
+    assert!(events.iter().any(|e| e.kind == ChangeKind::Updated
+        && e.before.as_ref() == Some(&edited)
+        && e.record().is_some_and(|r| r.value == "192.0.2.20")));
+}
+
+#[tokio::test]
+async fn test_fake_dns_api_cancellation() {
+    use futures_util::StreamExt;
+    use hetzner::CancellationToken;
+
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.set_zone_status(&zone.id, "pending");
+    let token = CancellationToken::new();
+    let client = fake.client().with_cancellation(token.clone());
+
+    let cancel = async {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        token.cancel();
+    };
+    let wait = async {
+        let zone_api = client.dns().zone(&zone.id);
+        let verified = zone_api.wait_until_verified(Duration::from_secs(60));
+        let events = client
+            .dns()
+            .watch_zone(&zone.id, Duration::from_millis(20))
+            .collect::<Vec<_>>();
+        tokio::time::timeout(Duration::from_secs(5), async {
+            tokio::join!(verified, events)
+        })
+        .await
+        .unwrap()
+    };
+    let ((), (verified, events)) = tokio::join!(cancel, wait);
+
+    assert!(matches!(verified.unwrap_err(), HetznerError::Cancelled));
+    assert!(events.is_empty());
+    let err = client
+        .dns()
+        .setup_secondary_zone("example.org", &[("192.0.2.1", 53)], Duration::from_secs(1))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HetznerError::Cancelled));
+    assert_eq!(client.dns().list_zones().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_fake_dns_api_lists_more_than_a_page_of_records() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    for i in 0..150 {
+        fake.add_record(&zone.id, &format!("host{i}"), "A", "192.0.2.1");
+    }
+    let client = fake.client();
+    let records = client.dns().records(&zone.id);
+
+    assert_eq!(records.list().await.unwrap().len(), 150);
+    let second = records.page(2, 100).await.unwrap();
+    assert_eq!(second.items.len(), 50);
+    assert!(!second.has_next());
+}
+
+#[tokio::test]
+async fn test_fake_dns_api_batch_commit() {
+    use hetzner::{AppliedChange, BatchOperation, MutationOperation};
+    use std::sync::{Arc, Mutex};
+
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let cname = fake.add_record(&zone.id, "www", "CNAME", "example.net.");
+    let mail = fake.add_record(&zone.id, "mail", "A", "192.0.2.1");
+    let operations = Arc::new(Mutex::new(Vec::new()));
+    let sink = operations.clone();
+    let client = fake
+        .client()
+        .with_on_mutation(move |event| sink.lock().unwrap().push(event.operation));
+
+    let result = client
+        .dns()
+        .batch(&zone.id)
+        .create("www", "A", "192.0.2.10", Ttl::FIVE_MINUTES)
+        .create("bad", "BOGUS", "x", None)
+        .update(&mail.id, "mail", "A", "192.0.2.2", Ttl::new(600).unwrap())
+        .delete(&cname.id)
+        .commit()
+        .await
+        .unwrap();
+
+    assert!(!result.is_success());
+    assert_eq!(result.applied.len(), 3);
+    assert!(matches!(
+        &result.applied[0],
+        AppliedChange::Deleted { before: Some(before), .. } if before.record_type == "CNAME"
+    ));
+    assert!(matches!(
+        &result.applied[1],
+        AppliedChange::Updated { before: Some(before), after }
+            if before.value == "192.0.2.1" && after.value == "192.0.2.2"
+    ));
+    assert!(matches!(&result.applied[2], AppliedChange::Created(r) if r.name == "www"));
+    assert_eq!(result.failed.len(), 1);
+    assert!(matches!(
+        &result.failed[0].operation,
+        BatchOperation::Create(input) if input.name == "bad"
+    ));
+    assert_eq!(
+        *operations.lock().unwrap(),
+        [
+            MutationOperation::DeleteRecord,
+            MutationOperation::UpdateRecord,
+            MutationOperation::CreateRecord,
+            MutationOperation::CreateRecord,
+        ]
+    );
+
+    let result = client
+        .dns()
+        .batch(&zone.id)
+        .delete("missing")
+        .delete(&mail.id)
+        .create("api", "A", "192.0.2.3", None)
+        .commit()
+        .await
+        .unwrap();
+    assert!(result.applied.is_empty());
+    assert_eq!(result.failed.len(), 1);
+    // The failed delete stops the commit before the next one.
+    assert!(matches!(
+        &result.skipped[..],
+        [BatchOperation::Delete { record_id }, BatchOperation::Create(_)] if *record_id == mail.id
+    ));
+    assert_eq!(
+        client.dns().records(&zone.id).list().await.unwrap().len(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn test_fake_dns_api_batch_rollback_on_failure() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let cname = fake.add_record(&zone.id, "www", "CNAME", "example.net.");
+    let mail = fake.add_record(&zone.id, "mail", "A", "192.0.2.1");
+    let client = fake.client();
+
+    let result = client
+        .dns()
+        .batch(&zone.id)
+        .delete(&cname.id)
+        .update(&mail.id, "mail", "A", "192.0.2.2", Ttl::new(600).unwrap())
+        .create("www", "A", "192.0.2.10", Ttl::FIVE_MINUTES)
+        .create("bad", "BOGUS", "x", None)
+        .rollback_on_failure()
+        .commit()
+        .await
+        .unwrap();
+
+    let rollback = result.rollback.as_ref().unwrap();
+    assert!(rollback.is_complete());
+    assert_eq!(rollback.reverted.len(), 3);
+
+    let mut records = client.dns().records(&zone.id).list().await.unwrap();
+    records.sort_by(|a, b| a.name.cmp(&b.name));
+    let summary: Vec<_> = records
+        .iter()
+        .map(|r| (r.name.as_str(), r.record_type.as_str(), r.value.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        [("mail", "A", "192.0.2.1"), ("www", "CNAME", "example.net.")]
+    );
+    assert_eq!(records[0].ttl, mail.ttl);
+}
+
+#[tokio::test]
+async fn test_fake_dns_api_swap_record_sets() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let blue_1 = fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    fake.add_record(&zone.id, "www", "A", "192.0.2.2");
+    let green = fake.add_record(&zone.id, "green.www", "A", "198.51.100.1");
+    let aaaa = fake.add_record(&zone.id, "www", "AAAA", "2001:db8::1");
+    let client = fake.client();
+
+    let swapped = client
+        .dns()
+        .swap_record_sets("example.com", "WWW.", "green.www", "a")
+        .await
+        .unwrap();
+    assert_eq!(swapped.records.len(), 3);
+    assert!(swapped.failed_records.is_empty());
+
+    let names = |fake: &FakeDnsApi| {
+        let mut names: Vec<(String, String)> = fake
+            .records(&zone.id)
+            .into_iter()
+            .map(|record| (record.name, record.value))
+            .collect();
+        names.sort();
+        names
+    };
+    assert_eq!(
+        names(&fake),
+        [
+            ("green.www".to_string(), "192.0.2.1".to_string()),
+            ("green.www".to_string(), "192.0.2.2".to_string()),
+            ("www".to_string(), "198.51.100.1".to_string()),
+            ("www".to_string(), "2001:db8::1".to_string()),
+        ]
+    );
+    let records = fake.records(&zone.id);
+    let by_id = |id: &str| records.iter().find(|record| record.id == id).unwrap();
+    assert_eq!(by_id(&blue_1.id).name, "green.www");
+    assert_eq!(by_id(&green.id).name, "www");
+    assert_eq!(by_id(&aaaa.id).name, "www");
+
+    let err = client
+        .dns()
+        .records(&zone.id)
+        .swap_record_sets("www", "green.www", "MX")
+        .await
+        .unwrap_err();
+    assert!(err.is_not_found(), "{err}");
+}
+
+#[tokio::test]
+async fn test_batch_commit_reports_progress() {
+    use std::sync::{Arc, Mutex};
+
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let old = fake.add_record(&zone.id, "old", "A", "192.0.2.1");
+    let client = fake.client();
+
+    let progress: Arc<Mutex<Vec<(usize, usize)>>> = Arc::default();
+    let seen = progress.clone();
+    let result = client
+        .dns()
+        .batch("example.com")
+        .delete(old.id)
+        .create("a", "A", "192.0.2.2", Ttl::FIVE_MINUTES)
+        .create("b", "A", "192.0.2.3", Ttl::FIVE_MINUTES)
+        .on_progress(move |p| {
+            assert_eq!(p.zone.as_deref(), Some("example.com"));
+            seen.lock().unwrap().push((p.done, p.total));
+        })
+        .commit()
+        .await
+        .unwrap();
+    assert!(result.is_success());
+    assert_eq!(*progress.lock().unwrap(), [(1, 3), (3, 3)]);
+}
+
+#[tokio::test]
+async fn test_create_record_idempotent_returns_the_existing_record() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let existing = fake.add_record(&zone.id, "mail", "MX", "10 mx.example.com.");
+    let client = fake.client();
+
+    let first = client
+        .dns()
+        .create_record_idempotent("example.com", "www", "A", "192.0.2.1", Ttl::FIVE_MINUTES)
+        .await
+        .unwrap();
+    let retried = client
+        .dns()
+        .records(&zone.id)
+        .create_idempotent("WWW", "a", "192.0.2.1", Ttl::new(600).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(retried.record.id, first.record.id);
+    assert_eq!(retried.record.ttl, Some(Ttl::FIVE_MINUTES));
+
+    let same_value = client
+        .dns()
+        .create_record_idempotent("example.com", "mail", "MX", "10 MX.example.com", None)
+        .await
+        .unwrap();
+    assert_eq!(same_value.record.id, existing.id);
+
+    let other_value = client
+        .dns()
+        .create_record_idempotent("example.com", "www", "A", "192.0.2.2", Ttl::FIVE_MINUTES)
+        .await
+        .unwrap();
+    assert_ne!(other_value.record.id, first.record.id);
+    assert_eq!(fake.records(&zone.id).len(), 3);
+}
+
+#[tokio::test]
+async fn test_find_records_by_value_across_zones() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let example = fake.add_zone("example.com");
+    let other = fake.add_zone("other.net");
+    let www = fake.add_record(&example.id, "www", "A", "192.0.2.1");
+    let api = fake.add_record(&other.id, "api", "A", "192.0.2.1");
+    let v6 = fake.add_record(&other.id, "api", "AAAA", "2001:db8::1");
+    let alias = fake.add_record(&example.id, "old", "CNAME", "www");
+    let external = fake.add_record(&other.id, "shop", "CNAME", "WWW.example.com.");
+    fake.add_record(&example.id, "@", "TXT", "192.0.2.1");
+    fake.add_record(&example.id, "mail", "A", "192.0.2.10");
+    let client = fake.client();
+
+    let matches = client
+        .dns()
+        .find_records_by_value("192.0.2.1", SearchScope::Account)
+        .await
+        .unwrap();
+    let found: Vec<_> = matches
+        .iter()
+        .map(|m| (m.zone_name.as_str(), m.record.id.as_str()))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            ("example.com", www.id.as_str()),
+            ("other.net", api.id.as_str())
+        ]
+    );
+
+    let matches = client
+        .dns()
+        .find_records_by_value("2001:DB8:0::1", SearchScope::zone("other.net"))
+        .await
+        .unwrap();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].record.id, v6.id);
+
+    let matches = client
+        .dns()
+        .find_records_by_value("www.example.com.", SearchScope::Account)
+        .await
+        .unwrap();
+    let ids: Vec<_> = matches.iter().map(|m| m.record.id.as_str()).collect();
+    assert_eq!(ids, [alias.id.as_str(), external.id.as_str()]);
+
+    let matches = client
+        .dns()
+        .find_records_by_value("192.0.2.1", SearchScope::zone(other.id.as_str()))
+        .await
+        .unwrap();
+    assert_eq!(matches.len(), 1);
+}
+
+#[tokio::test]
+async fn test_account_searches_cover_every_page_of_zones() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    for i in 0..120 {
+        fake.add_zone(&format!("filler{i:03}.com"));
+    }
+    let late = fake.add_zone("late.org");
+    let www = fake.add_record(&late.id, "www", "A", "192.0.2.1");
+    let alias = fake.add_record(&late.id, "app", "CNAME", "www.late.org.");
+    let client = fake.client();
+
+    let matches = client
+        .dns()
+        .find_records_by_value("192.0.2.1", SearchScope::Account)
+        .await
+        .unwrap();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].record.id, www.id);
+
+    let references = client.dns().find_references("www.late.org").await.unwrap();
+    assert_eq!(references.len(), 1);
+    assert_eq!(references[0].record.id, alias.id);
+}
+
+#[tokio::test]
+async fn test_find_references_across_zones() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let example = fake.add_zone("example.com");
+    let other = fake.add_zone("other.net");
+    let alias = fake.add_record(&example.id, "www", "CNAME", "host");
+    let mail = fake.add_record(&example.id, "@", "MX", "10 host.example.com.");
+    let ns = fake.add_record(&other.id, "sub", "NS", "HOST.example.com");
+    let srv = fake.add_record(&other.id, "_sip._tcp", "SRV", "10 5 5060 host.example.com.");
+    fake.add_record(&example.id, "host", "A", "192.0.2.1");
+    fake.add_record(&example.id, "@", "TXT", "host.example.com");
+    fake.add_record(&other.id, "www", "CNAME", "host");
+    fake.add_record(&other.id, "@", "MX", "10 otherhost.example.com.");
+    let client = fake.client();
+
+    let references = client
+        .dns()
+        .find_references("host.example.com")
+        .await
+        .unwrap();
+    let found: Vec<_> = references
+        .iter()
+        .map(|m| (m.zone_name.as_str(), m.record.id.as_str()))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            ("example.com", mail.id.as_str()),
+            ("example.com", alias.id.as_str()),
+            ("other.net", srv.id.as_str()),
+            ("other.net", ns.id.as_str()),
+        ]
+    );
+
+    assert!(
+        client
+            .dns()
+            .find_references("gone.example.com.")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+}
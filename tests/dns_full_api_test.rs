@@ -1,5 +1,5 @@
-use hetzner::HetznerClient;
-use hetzner::api::dns::records::UpdateRecordInput;
+use hetzner::api::dns::records::{Ttl, UpdateRecordInput};
+use hetzner::{HetznerClient, HetznerDnsApi, HetznerError};
 use httpmock::prelude::*;
 use serde_json::json;
 
@@ -92,7 +92,7 @@ async fn test_dns_api_full_surface() {
     let created = client
         .dns()
         .records("zone-1")
-        .create("www", "A", "1.2.3.4", 3600)
+        .create("www", "A", "1.2.3.4", Ttl::HOUR)
         .await
         .unwrap();
     assert_eq!(created.record.id, "record-1");
@@ -108,7 +108,7 @@ async fn test_dns_api_full_surface() {
     });
 
     let got = client.dns().record("record-1").get().await.unwrap();
-    assert_eq!(got.record.name, "www");
+    assert_eq!(got.name, "www");
     get_record_mock.assert();
 
     let update_record_mock = server.mock(|when, then| {
@@ -128,7 +128,7 @@ async fn test_dns_api_full_surface() {
             record_type: "A".to_string(),
             name: "www".to_string(),
             value: "1.2.3.4".to_string(),
-            ttl: 3600,
+            ttl: Some(Ttl::HOUR),
         })
         .await
         .unwrap();
@@ -147,3 +147,93 @@ async fn test_dns_api_full_surface() {
     client.dns().record("record-1").delete().await.unwrap();
     delete_record_mock.assert();
 }
+
+#[tokio::test]
+async fn test_get_record_typed_and_raw() {
+    let server = MockServer::start();
+    let client = HetznerClient::new("dns-token").with_dns_base_url(server.base_url());
+    let mut raw = record_json();
+    raw["dnssec"] = json!({"signed": true});
+    server.mock(|when, then| {
+        when.method(GET).path("/records/record-1");
+        then.status(200).json_body(json!({"record": raw}));
+    });
+
+    let record = HetznerDnsApi::get_record(&client, "record-1").await;
+    if cfg!(feature = "strict") {
+        assert!(matches!(record, Err(HetznerError::Serialization(_))));
+    } else {
+        let record = record.unwrap();
+        assert_eq!(record.name, "www");
+        assert_eq!(record.ttl, Some(Ttl::HOUR));
+        assert_eq!(record.modified.as_deref(), Some("2024-01-01T00:00:00Z"));
+    }
+
+    let value = client.dns().record("record-1").get_raw().await.unwrap();
+    assert_eq!(value, raw);
+}
+
+#[tokio::test]
+async fn test_get_record_raw_without_record_object_is_an_error() {
+    let server = MockServer::start();
+    let client = HetznerClient::new("dns-token").with_dns_base_url(server.base_url());
+    server.mock(|when, then| {
+        when.method(GET).path("/records/record-1");
+        then.status(200).json_body(json!({}));
+    });
+
+    let value = client.dns().record("record-1").get_raw().await;
+    assert!(matches!(value, Err(HetznerError::UnexpectedResponse(_))));
+}
+
+#[tokio::test]
+async fn test_stream_stops_when_next_page_repeats_the_current_one() {
+    use futures_util::TryStreamExt;
+
+    let server = MockServer::start();
+    let client = HetznerClient::new("dns-token").with_dns_base_url(server.base_url());
+    // Hetzner reports the last page as its own next page.
+    let page = server.mock(|when, then| {
+        when.method(GET).path("/records").query_param("page", "1");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(include_str!("fixtures/dns/records_list.json"));
+    });
+
+    let records: Vec<_> = client
+        .dns()
+        .records("5ReCcDLuzMTc7hxMZ8sGJr")
+        .stream()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(records.len(), 2);
+    page.assert_hits(1);
+}
+
+#[tokio::test]
+async fn test_page_skips_unknown_fields_and_tolerates_missing_pagination() {
+    let server = MockServer::start();
+    let client = HetznerClient::new("dns-token").with_dns_base_url(server.base_url());
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/records")
+            .query_param("zone_id", "zone-1");
+        then.status(200).json_body(json!({
+            "notice": {"nested": [1, 2, {"deep": null}]},
+            "records": [record_json()],
+            "meta": {"pagination": null}
+        }));
+    });
+
+    let page = client.dns().records("zone-1").page(1, 100).await;
+    if cfg!(feature = "strict") {
+        assert!(matches!(page, Err(HetznerError::Serialization(_))));
+        return;
+    }
+    let page = page.unwrap();
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].value, "1.2.3.4");
+    assert!(page.pagination.is_none());
+    assert!(!page.has_next());
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "health")]
+
+use hetzner::Severity;
+use hetzner::testing::FakeDnsApi;
+use hickory_proto::op::{Message, OpCode, ResponseCode};
+use hickory_proto::rr::rdata::{A, SOA};
+use hickory_proto::rr::{Name, RData, Record, RecordType};
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::net::UdpSocket;
+
+/// Answers SOA queries for `example.com.` with `serial` and A queries for
+/// `www.example.com.`; everything else is NXDOMAIN.
+async fn start_nameserver(serial: u32) -> SocketAddr {
+    let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let addr = socket.local_addr().unwrap();
+    let origin = Name::from_str("example.com.").unwrap();
+    let www = Name::from_str("www.example.com.").unwrap();
+    tokio::spawn(async move {
+        let mut buf = vec![0; 4096];
+        loop {
+            let (len, peer) = socket.recv_from(&mut buf).await.unwrap();
+            let request = Message::from_vec(&buf[..len]).unwrap();
+            let mut response = Message::response(request.metadata.id, OpCode::Query);
+            for query in request.queries {
+                let name = query.name().clone();
+                match query.query_type() {
+                    RecordType::SOA if name == origin => {
+                        response.add_answer(Record::from_rdata(
+                            name.clone(),
+                            3600,
+                            RData::SOA(SOA::new(
+                                Name::from_str("ns1.example.com.").unwrap(),
+                                Name::from_str("hostmaster.example.com.").unwrap(),
+                                serial,
+                                3600,
+                                600,
+                                86400,
+                                300,
+                            )),
+                        ));
+                    }
+                    RecordType::A if name == www => {
+                        response.add_answer(Record::from_rdata(
+                            name.clone(),
+                            300,
+                            RData::A(A::new(192, 0, 2, 1)),
+                        ));
+                    }
+                    _ => response.metadata.response_code = ResponseCode::NXDomain,
+                }
+                response.add_query(query);
+            }
+            let bytes = response.to_vec().unwrap();
+            socket.send_to(&bytes, peer).await.unwrap();
+        }
+    });
+    addr
+}
+
+#[tokio::test]
+async fn test_health_report() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    let client = fake.client();
+    let ns1 = start_nameserver(7).await;
+
+    let report = client
+        .dns()
+        .zone(&zone.id)
+        .health_check()
+        .nameservers([ns1])
+        .timeout(Duration::from_secs(2))
+        .run()
+        .await
+        .unwrap();
+    assert_eq!(report.zone_name, "example.com");
+    assert_eq!(report.nameservers[0].soa_serial, Some(7));
+    assert!(report.nameservers_reachable() && report.soa_serials_consistent());
+    assert!(report.unresolvable.is_empty());
+    assert!(report.is_healthy(), "{report:#?}");
+
+    let ghost = fake.add_record(&zone.id, "ghost", "TXT", "\"hello\"");
+    let ns2 = start_nameserver(8).await;
+    let report = client
+        .dns()
+        .zone(&zone.id)
+        .health_check()
+        .nameservers([ns1, ns2])
+        .timeout(Duration::from_secs(2))
+        .run()
+        .await
+        .unwrap();
+    assert!(report.nameservers_reachable());
+    assert!(!report.soa_serials_consistent());
+    assert_eq!(report.unresolvable.len(), 1);
+    assert_eq!(report.unresolvable[0].name, "ghost");
+    assert_eq!(report.unresolvable[0].record_ids, [ghost.id]);
+    assert_eq!(report.worst_severity(), Some(Severity::Error));
+    assert!(!report.is_healthy());
+}
+
+#[tokio::test]
+async fn test_health_report_unreachable_nameserver() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let silent = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+
+    let report = fake
+        .client()
+        .dns()
+        .zone(&zone.id)
+        .health_check()
+        .nameservers([silent.local_addr().unwrap()])
+        .timeout(Duration::from_millis(200))
+        .run()
+        .await
+        .unwrap();
+    assert!(!report.nameservers_reachable());
+    assert!(report.nameservers[0].error.is_some());
+    assert!(!report.is_healthy());
+}
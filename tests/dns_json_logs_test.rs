@@ -0,0 +1,72 @@
+#![cfg(feature = "json-logs")]
+
+use hetzner::Ttl;
+use hetzner::testing::FakeDnsApi;
+use serde_json::Value;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Default)]
+struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Buffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_mutations_are_logged_as_json() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let client = fake.client();
+
+    let buffer = Buffer::default();
+    let writer = buffer.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .json()
+        .with_max_level(tracing::Level::INFO)
+        .with_writer(move || writer.clone())
+        .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    client
+        .dns()
+        .records(&zone.id)
+        .create("www", "A", "192.0.2.1", Ttl::FIVE_MINUTES)
+        .await
+        .unwrap();
+    client
+        .dns()
+        .records(&zone.id)
+        .create("bad", "BOGUS", "x", Ttl::FIVE_MINUTES)
+        .await
+        .unwrap_err();
+
+    let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+    let events: Vec<Value> = output
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).unwrap())
+        .filter(|line| line["target"] == "hetzner::mutation")
+        .collect();
+    assert_eq!(events.len(), 2, "{output}");
+
+    let fields = &events[0]["fields"];
+    assert_eq!(fields["operation"], "create_record");
+    assert_eq!(fields["zone_id"], zone.id.as_str());
+    assert_eq!(fields["record_name"], "www");
+    assert_eq!(fields["record_type"], "A");
+    assert_eq!(fields["record_value"], "192.0.2.1");
+    assert_eq!(fields["result"], "succeeded");
+    assert!(fields["latency_ms"].is_u64());
+    assert!(fields["correlation_id"].is_string());
+
+    assert_eq!(events[1]["level"], "WARN");
+    assert_eq!(events[1]["fields"]["result"], "failed");
+    assert!(events[1]["fields"]["error"].is_string());
+}
@@ -0,0 +1,97 @@
+use hetzner::Ttl;
+use hetzner::api::dns::lease::LEASE_REGISTRY_NAME;
+use hetzner::testing::FakeDnsApi;
+use std::time::{Duration, SystemTime};
+
+#[tokio::test]
+async fn test_cleanup_expired_deletes_only_expired_leases() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let other_zone = fake.add_zone("example.net");
+    let permanent = fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    let client = fake.client();
+
+    let expired = client
+        .dns()
+        .records("example.com")
+        .create_leased("pr-41", "A", "192.0.2.41", Ttl::MINUTE, Duration::ZERO)
+        .await
+        .unwrap();
+    let active = client
+        .dns()
+        .records(&zone.id)
+        .create_leased("pr-42", "A", "192.0.2.42", None, Duration::from_secs(3600))
+        .await
+        .unwrap();
+    let elsewhere = client
+        .dns()
+        .records(&other_zone.id)
+        .create_leased("ci", "CNAME", "example.com.", None, Duration::ZERO)
+        .await
+        .unwrap();
+    assert!(expired.is_expired(SystemTime::now()));
+    assert!(!active.is_expired(SystemTime::now()));
+    assert_eq!(fake.records(&zone.id).len(), 5);
+
+    let mut leases = client.dns().records(&zone.id).leases().await.unwrap();
+    leases.sort_by(|a, b| a.record.name.cmp(&b.record.name));
+    assert_eq!(leases, [expired.clone(), active.clone()]);
+
+    let removed = client.dns().cleanup_expired().await.unwrap();
+    let mut removed: Vec<_> = removed.into_iter().map(|lease| lease.record.id).collect();
+    removed.sort();
+    let mut wanted = vec![expired.record.id.clone(), elsewhere.record.id.clone()];
+    wanted.sort();
+    assert_eq!(removed, wanted);
+
+    let left = fake.records(&zone.id);
+    assert_eq!(left.len(), 3);
+    assert!(left.contains(&permanent));
+    assert!(left.iter().any(|record| record.id == active.record.id));
+    assert!(fake.records(&other_zone.id).is_empty());
+
+    // A leased record deleted by hand leaves its registry entry behind
+    // until the next sweep.
+    client
+        .dns()
+        .record(&active.record.id)
+        .delete()
+        .await
+        .unwrap();
+    assert!(
+        client
+            .dns()
+            .records(&zone.id)
+            .cleanup_expired()
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(fake.records(&zone.id), [permanent]);
+    assert!(
+        !fake
+            .records(&zone.id)
+            .iter()
+            .any(|record| record.name == LEASE_REGISTRY_NAME)
+    );
+}
+
+#[tokio::test]
+async fn test_cleanup_expired_sweeps_every_page_of_zones() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    for i in 0..120 {
+        fake.add_zone(&format!("filler{i:03}.com"));
+    }
+    let zone = fake.add_zone("late.org");
+    let client = fake.client();
+    let expired = client
+        .dns()
+        .records(&zone.id)
+        .create_leased("pr-1", "A", "192.0.2.1", None, Duration::ZERO)
+        .await
+        .unwrap();
+
+    let removed = client.dns().cleanup_expired().await.unwrap();
+    assert_eq!(removed, [expired]);
+    assert!(fake.records(&zone.id).is_empty());
+}
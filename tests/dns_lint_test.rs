@@ -0,0 +1,82 @@
+use hetzner::api::dns::lint::lint_zone;
+use hetzner::testing::FakeDnsApi;
+use hetzner::{LintRule, Severity, Ttl};
+
+#[tokio::test]
+async fn test_zone_lint() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let client = fake.client();
+    let records = client.dns().records(&zone.id);
+    for (name, record_type, value, ttl) in [
+        ("@", "CNAME", "lb.example.net.", 300),
+        ("www", "CNAME", "gone.example.com.", 300),
+        ("api", "CNAME", "app", 300),
+        ("app", "A", "192.0.2.1", 60),
+        ("app", "A", "192.0.2.1", 300),
+        ("@", "TXT", "\"v=spf1 include:_spf.example.net ~all\"", 300),
+        (
+            "news",
+            "TXT",
+            "\"v=spf1 a mx include:a.example include:b.example include:c.example \" \"include:d.example include:e.example include:f.example include:g.example exists:%{i}.x.example redirect=h.example\"",
+            300,
+        ),
+        ("news", "MX", "10 mx.example.net.", 300),
+        ("parked", "TXT", "\"v=spf1 -all\"", 300),
+    ] {
+        let ttl = Ttl::new(ttl).unwrap();
+        records.create(name, record_type, value, ttl).await.unwrap();
+    }
+
+    let findings = client.dns().zone(&zone.id).lint().await.unwrap();
+    let found = |rule: LintRule, name: &str| {
+        findings
+            .iter()
+            .find(|f| f.rule == rule && f.name == name)
+            .unwrap_or_else(|| panic!("no {rule:?} finding for {name}: {findings:#?}"))
+    };
+
+    assert_eq!(found(LintRule::CnameAtApex, "@").severity, Severity::Error);
+    assert!(found(LintRule::DanglingCname, "www").is_error());
+    assert_eq!(found(LintRule::DuplicateRecord, "app").record_ids.len(), 2);
+    assert!(
+        found(LintRule::SpfLookupLimit, "news")
+            .message
+            .contains("11")
+    );
+    found(LintRule::SpfWithoutMx, "@");
+    // `api` points at the existing `app`, `parked` sends no mail.
+    assert!(
+        findings
+            .iter()
+            .all(|f| f.name != "api" && f.name != "parked")
+    );
+    assert!(
+        !findings
+            .iter()
+            .any(|f| f.rule == LintRule::CnameWithOtherData)
+    );
+    assert!(findings[0].is_error());
+    assert!(findings.windows(2).all(|w| w[0].severity >= w[1].severity));
+}
+
+#[tokio::test]
+async fn test_zone_lint_low_ttl() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let mut records = vec![
+        fake.add_record(&zone.id, "app", "A", "192.0.2.1"),
+        fake.add_record(&zone.id, "www", "A", "192.0.2.2"),
+    ];
+    records[0].ttl = Some(Ttl::MINUTE);
+    records[1].ttl = Some(Ttl::FIVE_MINUTES);
+
+    let findings = lint_zone(&zone, &records);
+    let low: Vec<_> = findings
+        .iter()
+        .filter(|f| f.rule == LintRule::LowTtl)
+        .collect();
+    assert_eq!(low.len(), 1);
+    assert_eq!(low[0].name, "app");
+    assert_eq!(low[0].severity, Severity::Info);
+}
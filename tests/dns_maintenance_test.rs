@@ -0,0 +1,82 @@
+use hetzner::testing::FakeDnsApi;
+use hetzner::{MaintenanceSnapshot, Ttl};
+use std::net::IpAddr;
+
+fn addresses(fake: &FakeDnsApi, zone_id: &str) -> Vec<(String, String, String, Option<Ttl>)> {
+    let mut records: Vec<_> = fake
+        .records(zone_id)
+        .into_iter()
+        .map(|record| (record.name, record.record_type, record.value, record.ttl))
+        .collect();
+    records.sort();
+    records
+}
+
+#[tokio::test]
+async fn test_maintenance_points_names_at_ip_and_restores_them() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.add_record(&zone.id, "@", "A", "192.0.2.1");
+    fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    fake.add_record(&zone.id, "www", "A", "192.0.2.2");
+    fake.add_record(&zone.id, "www", "AAAA", "2001:db8::1");
+    fake.add_record(&zone.id, "mail", "A", "192.0.2.10");
+    fake.add_record(&zone.id, "www", "TXT", "\"kept\"");
+    let before = addresses(&fake, &zone.id);
+    let client = fake.client();
+
+    let maintenance_ip: IpAddr = "203.0.113.99".parse().unwrap();
+    let snapshot = client
+        .dns()
+        .enter_maintenance("example.com", &["@", "www"], maintenance_ip)
+        .await
+        .unwrap();
+    assert_eq!(snapshot.records.len(), 4);
+
+    let during = addresses(&fake, &zone.id);
+    let maintenance = |name: &str| {
+        (
+            name.to_string(),
+            "A".to_string(),
+            "203.0.113.99".to_string(),
+            Some(Ttl::MINUTE),
+        )
+    };
+    assert!(during.contains(&maintenance("@")));
+    assert!(during.contains(&maintenance("www")));
+    assert!(!during.iter().any(|record| record.1 == "AAAA"));
+    assert!(during.iter().any(|record| record.2 == "192.0.2.10"));
+    assert!(during.iter().any(|record| record.1 == "TXT"));
+    assert_eq!(during.len(), 4);
+
+    let stored: MaintenanceSnapshot =
+        serde_json::from_str(&serde_json::to_string(&snapshot).unwrap()).unwrap();
+    client.dns().exit_maintenance(&stored).await.unwrap();
+    assert_eq!(addresses(&fake, &zone.id), before);
+    client.dns().exit_maintenance(&stored).await.unwrap();
+    assert_eq!(addresses(&fake, &zone.id), before);
+}
+
+#[tokio::test]
+async fn test_maintenance_over_ipv6_replaces_a_records() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.add_record(&zone.id, "shop", "A", "192.0.2.1");
+    let client = fake.client();
+
+    let snapshot = client
+        .dns()
+        .enter_maintenance(&zone.id, &["shop"], "2001:db8::99".parse().unwrap())
+        .await
+        .unwrap();
+    let during = fake.records(&zone.id);
+    assert_eq!(during.len(), 1);
+    assert_eq!(during[0].record_type, "AAAA");
+    assert_eq!(during[0].value, "2001:db8::99");
+
+    client.dns().exit_maintenance(&snapshot).await.unwrap();
+    let after = fake.records(&zone.id);
+    assert_eq!(after.len(), 1);
+    assert_eq!(after[0].record_type, "A");
+    assert_eq!(after[0].value, "192.0.2.1");
+}
@@ -0,0 +1,65 @@
+use hetzner::testing::{FakeDnsApi, MockHetznerClient};
+use hetzner::{HetznerDnsApi, HetznerError, Ttl};
+
+async fn point_www_at(api: &impl HetznerDnsApi, zone_id: &str, ip: &str) -> hetzner::Result<()> {
+    let records = api.list_records(zone_id).await?;
+    for record in records
+        .iter()
+        .filter(|r| r.name == "www" && r.record_type == "A")
+    {
+        api.delete_record(&record.id).await?;
+    }
+    api.create_record(zone_id, "www", "A", ip, Ttl::FIVE_MINUTES)
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_client_runs_application_code() {
+    let mock = MockHetznerClient::new();
+    let zone = mock.add_zone("example.com");
+    let old = mock.add_record(&zone.id, "www", "A", "192.0.2.1");
+
+    point_www_at(&mock, &zone.id, "192.0.2.2").await.unwrap();
+
+    let records = mock.list_records(&zone.id).await.unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].value, "192.0.2.2");
+    assert_eq!(
+        mock.calls()[..3],
+        [
+            "GET records".to_string(),
+            format!("DELETE records/{}", old.id),
+            "POST records".to_string(),
+        ]
+    );
+
+    let err = mock.get_record("missing").await.unwrap_err();
+    assert!(matches!(err, HetznerError::Api(api) if api.status.as_u16() == 404));
+}
+
+#[tokio::test]
+async fn test_mock_client_lists_every_page() {
+    let mock = MockHetznerClient::new();
+    let zone = mock.add_zone("example.com");
+    for i in 0..150 {
+        mock.add_record(&zone.id, &format!("host{i}"), "A", "192.0.2.1");
+    }
+    for i in 0..120 {
+        mock.add_zone(&format!("example{i}.net"));
+    }
+
+    assert_eq!(mock.list_records(&zone.id).await.unwrap().len(), 150);
+    assert_eq!(mock.list_zones().await.unwrap().len(), 121);
+    assert_eq!(mock.calls(), ["GET records", "GET zones", "GET zones"]);
+}
+
+#[tokio::test]
+async fn test_client_implements_trait() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let client = fake.client();
+
+    point_www_at(&client, &zone.id, "192.0.2.3").await.unwrap();
+    assert_eq!(fake.records(&zone.id)[0].value, "192.0.2.3");
+}
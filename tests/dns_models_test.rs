@@ -0,0 +1,505 @@
+use hetzner::{
+    BillingPeriod, RecordsEnvelope, Ttl, ZoneEnvelope, ZonePrice, ZoneType, ZonesEnvelope,
+};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeSet;
+
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> serde_json::Value {
+    let first = serde_json::to_value(value).unwrap();
+    let reparsed: T = serde_json::from_value(first.clone()).unwrap();
+    let second = serde_json::to_value(&reparsed).unwrap();
+    assert_eq!(first, second);
+    second
+}
+
+/// Every object key path in `value`, e.g. `zone.txt_verification.token`,
+/// with `[]` standing for array elements.
+fn key_paths(value: &serde_json::Value) -> BTreeSet<String> {
+    fn walk(value: &serde_json::Value, prefix: &str, paths: &mut BTreeSet<String>) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, value) in map {
+                    let path = format!("{prefix}{key}");
+                    walk(value, &format!("{path}."), paths);
+                    paths.insert(path);
+                }
+            }
+            serde_json::Value::Array(items) => {
+                for item in items {
+                    walk(item, &format!("{prefix}[]."), paths);
+                }
+            }
+            _ => {}
+        }
+    }
+    let mut paths = BTreeSet::new();
+    walk(value, "", &mut paths);
+    paths
+}
+
+#[test]
+fn test_models_serialize_with_api_keys() {
+    let captured: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/dns/zone_get.json")).unwrap();
+    let zone: ZoneEnvelope = serde_json::from_value(captured.clone()).unwrap();
+    assert_eq!(
+        key_paths(&serde_json::to_value(&zone).unwrap()),
+        key_paths(&captured)
+    );
+
+    // Records leave out fields they don't have; what is written must still
+    // be a key the API uses.
+    let captured: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/dns/records_list.json")).unwrap();
+    let records: RecordsEnvelope = serde_json::from_value(captured.clone()).unwrap();
+    let written = key_paths(&serde_json::to_value(&records).unwrap());
+    let known = key_paths(&captured);
+    assert!(
+        written.is_subset(&known),
+        "{:?}",
+        written.difference(&known)
+    );
+
+    let mut captured: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/dns/zones_list_sparse.json")).unwrap();
+    // Unknown fields are covered by `test_sparse_zones_fixture`.
+    captured["zones"][0]
+        .as_object_mut()
+        .unwrap()
+        .remove("some_future_field");
+    let zones: ZonesEnvelope = serde_json::from_value(captured).unwrap();
+    let json = serde_json::to_value(&zones).unwrap();
+    assert_eq!(
+        json["zones"][1]["txt_verification"],
+        serde_json::json!({"name": "_hetzner-verification", "token": "a1b2c3"})
+    );
+    assert!(json["zones"][0]["zone_type"].is_null());
+    assert!(
+        key_paths(&json)
+            .iter()
+            .all(|path| !path.contains(char::is_uppercase)),
+        "{json}"
+    );
+}
+
+#[test]
+fn test_full_zone_fixture() {
+    let envelope: ZoneEnvelope =
+        serde_json::from_str(include_str!("fixtures/dns/zone_get.json")).unwrap();
+    let zone = &envelope.zone;
+
+    assert_eq!(zone.name, "example.com");
+    assert_eq!(zone.ttl, Some(Ttl::DAY));
+    assert_eq!(zone.ns.len(), 3);
+    assert_eq!(zone.records_count, 2);
+    assert_eq!(zone.status.as_deref(), Some("verified"));
+    round_trip(&envelope);
+}
+
+#[test]
+fn test_zone_builder() {
+    use hetzner::Zone;
+
+    let captured = serde_json::from_str::<ZoneEnvelope>(include_str!("fixtures/dns/zone_get.json"))
+        .unwrap()
+        .zone;
+    let built = Zone::builder("5ReCcDLuzMTc7hxMZ8sGJr", "example.com")
+        .ttl(Ttl::DAY)
+        .registrar("")
+        .legacy_dns_host("")
+        .ns([
+            "hydrogen.ns.hetzner.com",
+            "oxygen.ns.hetzner.com",
+            "helium.ns.hetzner.de",
+        ])
+        .created("2021-02-23 12:16:44.434 +0000 UTC")
+        .verified("")
+        .modified("2021-02-23 12:16:44.434 +0000 UTC")
+        .project("")
+        .owner("")
+        .permission("")
+        .zone_type(ZoneType::default())
+        .status("verified")
+        .txt_verification("", "")
+        .records_count(2)
+        .build();
+    assert_eq!(built, captured);
+
+    let bare = Zone::builder("z1", "example.org").build();
+    assert_eq!(bare.name, "example.org");
+    assert_eq!(bare.default_ttl(), Ttl::DAY);
+    assert!(bare.status.is_none() && bare.ns.is_empty() && !bare.paused);
+    assert_eq!(
+        Zone::builder("", "").build(),
+        Zone::default(),
+        "unset fields match Default"
+    );
+}
+
+#[test]
+fn test_sparse_zones_fixture() {
+    let envelope =
+        serde_json::from_str::<ZonesEnvelope>(include_str!("fixtures/dns/zones_list_sparse.json"));
+    if cfg!(feature = "strict") {
+        let err = envelope.unwrap_err();
+        assert!(err.to_string().contains("some_future_field"), "{err}");
+        return;
+    }
+    let envelope = envelope.unwrap();
+
+    let sparse = &envelope.zones[0];
+    assert_eq!(sparse.name, "sparse.example");
+    assert!(sparse.ns.is_empty());
+    assert!(!sparse.paused);
+    assert!(sparse.ttl.is_none());
+    assert!(sparse.txt_verification.is_none());
+    assert!(sparse.zone_type.is_none());
+
+    let pending = &envelope.zones[1];
+    let verification = pending.txt_verification.as_ref().unwrap();
+    assert_eq!(verification.name, "_hetzner-verification");
+    assert_eq!(verification.token, "a1b2c3");
+
+    let total = envelope
+        .meta
+        .as_ref()
+        .and_then(|meta| meta.pagination.total_entries);
+    assert_eq!(total, Some(2));
+    round_trip(&envelope);
+}
+
+#[test]
+fn test_records_fixture() {
+    let envelope: RecordsEnvelope =
+        serde_json::from_str(include_str!("fixtures/dns/records_list.json")).unwrap();
+
+    assert_eq!(envelope.records[0].ttl, Some(Ttl::FIVE_MINUTES));
+    let mx = &envelope.records[1];
+    assert_eq!(mx.record_type, "MX");
+    assert!(mx.ttl.is_none());
+    assert!(mx.created.is_none());
+    round_trip(&envelope);
+}
+
+#[test]
+fn test_zone_type_prices() {
+    let nested: ZoneType = serde_json::from_value(serde_json::json!({
+        "id": "zt1",
+        "name": "primary",
+        "description": "",
+        "prices": {
+            "currency": "EUR",
+            "vat_rate": "19.000000",
+            "price_monthly": {"net": "1.0000", "gross": "1.1900"},
+            "price_yearly": {"net": 12, "gross": 14.28}
+        }
+    }))
+    .unwrap();
+    assert_eq!(
+        nested.prices,
+        [
+            ZonePrice {
+                currency: "EUR".into(),
+                vat_rate: Some("19.000000".into()),
+                net: "1.0000".into(),
+                gross: "1.1900".into(),
+                billing_period: BillingPeriod::Monthly,
+            },
+            ZonePrice {
+                currency: "EUR".into(),
+                vat_rate: Some("19.000000".into()),
+                net: "12".into(),
+                gross: "14.28".into(),
+                billing_period: BillingPeriod::Yearly,
+            },
+        ]
+    );
+    let json = round_trip(&nested);
+    assert_eq!(json["prices"][1]["billing_period"], "yearly");
+
+    let flat: ZoneType = serde_json::from_value(serde_json::json!({
+        "prices": [{"currency": "USD", "net": "0.5", "gross": "0.5", "billing_period": "hourly"}]
+    }))
+    .unwrap();
+    assert_eq!(flat.prices.len(), 1);
+    assert_eq!(flat.prices[0].billing_period, BillingPeriod::Hourly);
+    assert_eq!(flat.prices[0].vat_rate, None);
+
+    let none: ZoneType = serde_json::from_value(serde_json::json!({"prices": null})).unwrap();
+    assert!(none.prices.is_empty());
+}
+
+#[test]
+fn test_display() {
+    let records: RecordsEnvelope =
+        serde_json::from_str(include_str!("fixtures/dns/records_list.json")).unwrap();
+    assert_eq!(records.records[0].to_string(), "www\t300\tIN\tA\t192.0.2.1");
+    assert_eq!(
+        records.records[1].to_string(),
+        "@\tIN\tMX\t10 mail.example.com."
+    );
+
+    let zone: ZoneEnvelope =
+        serde_json::from_str(include_str!("fixtures/dns/zone_get.json")).unwrap();
+    assert_eq!(
+        zone.zone.to_string(),
+        format!(
+            "example.com (2 records, ttl 86400, verified) [{}]",
+            zone.zone.id
+        )
+    );
+
+    let plan = hetzner::api::dns::presets::google_workspace_mx().plan(&records.records);
+    assert_eq!(
+        plan.to_string(),
+        "- @\tIN\tMX\t10 mail.example.com.\n+ @\tIN\tMX\t1 smtp.google.com.\n"
+    );
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn test_table_render() {
+    let records: RecordsEnvelope =
+        serde_json::from_str(include_str!("fixtures/dns/records_list.json")).unwrap();
+    let table = hetzner::table::render(&records.records[..2]);
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("ID                                NAME  TYPE  TTL  VALUE"));
+    assert!(lines[1].ends_with("www   A     300  192.0.2.1"));
+    assert!(lines[2].ends_with("@     MX    -    10 mail.example.com."));
+}
+
+#[cfg(feature = "table")]
+#[test]
+fn test_render_table_options() {
+    use hetzner::table::{TableOptions, TableStyle, render_table};
+
+    let records: RecordsEnvelope =
+        serde_json::from_str(include_str!("fixtures/dns/records_list.json")).unwrap();
+    let records = &records.records[..2];
+
+    let table = render_table(
+        records,
+        &TableOptions::new()
+            .columns(["value", "NAME", "nope"])
+            .style(TableStyle::Plain),
+    );
+    assert_eq!(
+        table,
+        "VALUE                 NAME\n192.0.2.1             www\n10 mail.example.com.  @\n"
+    );
+
+    let table = render_table(records, &TableOptions::new().columns(["name", "type"]));
+    assert!(table.starts_with('┌'), "{table}");
+    assert!(table.contains("│ www  ┆ A    │"), "{table}");
+
+    let table = render_table(
+        records,
+        &TableOptions::new().max_width(50).style(TableStyle::Ascii),
+    );
+    assert!(
+        table.lines().all(|line| line.chars().count() <= 50),
+        "{table}"
+    );
+    assert!(table.contains("| ID "), "{table}");
+
+    let zone: hetzner::ZoneEnvelope =
+        serde_json::from_str(include_str!("fixtures/dns/zone_get.json")).unwrap();
+    let table = render_table(
+        std::slice::from_ref(&zone.zone),
+        &TableOptions::new()
+            .columns(["name", "ttl"])
+            .style(TableStyle::Markdown),
+    );
+    assert_eq!(
+        table,
+        "| NAME        | TTL   |\n|-------------|-------|\n| example.com | 86400 |\n"
+    );
+}
+
+#[test]
+fn test_record_keys_and_ordering() {
+    use hetzner::{DnsRecord, RecordKey};
+    use std::collections::{BTreeSet, HashSet};
+
+    assert_eq!(
+        RecordKey::new("WWW.", "cname", "Web.Example.NET."),
+        RecordKey::new("www", "CNAME", "web.example.net")
+    );
+    assert_eq!(
+        RecordKey::new("@", "AAAA", "2001:DB8:0:0::1"),
+        RecordKey::new("", "AAAA", "2001:db8::1")
+    );
+    assert_eq!(
+        RecordKey::new("@", "TXT", "\"v=spf1 \" \"-all\""),
+        RecordKey::new("@", "TXT", "v=spf1 -all")
+    );
+    assert_ne!(
+        RecordKey::new("@", "TXT", "Token"),
+        RecordKey::new("@", "TXT", "token")
+    );
+
+    let records: RecordsEnvelope =
+        serde_json::from_str(include_str!("fixtures/dns/records_list.json")).unwrap();
+    let mut sorted = records.records.clone();
+    sorted.sort();
+    assert_eq!(sorted[0].name, "@");
+    let set: HashSet<_> = records.records.iter().cloned().collect();
+    assert_eq!(set.len(), records.records.len());
+
+    let desired: BTreeSet<RecordKey> = [DnsRecord::new("www", "A", "192.0.2.1", None).key()].into();
+    let live: BTreeSet<RecordKey> = records.records.iter().map(|r| r.key()).collect();
+    assert!(desired.is_subset(&live));
+}
+
+#[test]
+fn test_normalize_value() {
+    use hetzner::normalize_value;
+
+    for (record_type, a, b) in [
+        ("A", " 192.0.2.1 ", "192.0.2.1"),
+        ("AAAA", "2001:DB8:0:0:0:0:0:1", "2001:db8::1"),
+        ("CNAME", "Web.Example.NET.", "web.example.net"),
+        ("MX", "10   Mail.Example.com.", "10 mail.example.com"),
+        (
+            "SRV",
+            "0 5 5060 SIP.example.com.",
+            "0 5 5060 sip.example.com",
+        ),
+        ("NS", "NS1.example.net.", "ns1.example.net"),
+        ("TXT", "\"v=spf1 \" \"-all\"", "v=spf1 -all"),
+        (
+            "CAA",
+            "0 ISSUE \"letsencrypt.org\"",
+            "0 issue letsencrypt.org",
+        ),
+        ("TLSA", "3 1 1 ABCDEF", "3 1 1 abcdef"),
+    ] {
+        assert_eq!(normalize_value(record_type, a), b, "{record_type} {a}");
+        assert_eq!(normalize_value(record_type, b), b, "{record_type} {b}");
+    }
+    // Escaped quotes and backslashes inside TXT strings are part of the data.
+    assert_eq!(
+        normalize_value("TXT", r#""say \"hi\"" " and \\ bye""#),
+        r#"say "hi" and \ bye"#
+    );
+    assert_eq!(
+        normalize_value("TXT", r#""a\"b""#),
+        normalize_value("TXT", r#""a\"" "b""#)
+    );
+    // TXT data and unknown types are compared as given.
+    assert_eq!(normalize_value("TXT", "Token"), "Token");
+    assert_eq!(
+        normalize_value("HINFO", "\"PC\" \"Linux\""),
+        "\"PC\" \"Linux\""
+    );
+}
+
+#[test]
+fn test_derived_models_round_trip() {
+    use hetzner::api::dns::lint::lint_zone;
+    use hetzner::{
+        AppliedChange, BatchFailure, BatchOperation, BatchResult, ChangeEvent, ChangeKind,
+        PresetPlan, RecordKey, SortKey, ZoneStats,
+    };
+
+    assert_eq!(round_trip(&Ttl::FIVE_MINUTES), serde_json::json!(300));
+    assert_eq!(round_trip(&None::<Ttl>), serde_json::Value::Null);
+    assert_eq!(round_trip(&SortKey::Modified), "modified");
+
+    let zone = serde_json::from_str::<ZoneEnvelope>(include_str!("fixtures/dns/zone_get.json"))
+        .unwrap()
+        .zone;
+    let records: RecordsEnvelope =
+        serde_json::from_str(include_str!("fixtures/dns/records_list.json")).unwrap();
+    let record = records.records[0].clone();
+
+    let stats = ZoneStats::compute(&zone, &records.records);
+    let reparsed: ZoneStats = serde_json::from_value(round_trip(&stats)).unwrap();
+    assert_eq!(reparsed, stats);
+    round_trip(&lint_zone(&zone, &records.records));
+    let _: RecordKey = serde_json::from_value(round_trip(&record.key())).unwrap();
+
+    let result = BatchResult {
+        applied: vec![AppliedChange::Updated {
+            before: None,
+            after: record.clone(),
+        }],
+        failed: vec![BatchFailure {
+            operation: BatchOperation::Delete {
+                record_id: "r9".to_string(),
+            },
+            error: "not found".to_string(),
+        }],
+        ..Default::default()
+    };
+    let json = round_trip(&result);
+    assert_eq!(json["failed"][0]["operation"]["delete"]["record_id"], "r9");
+    assert_eq!(serde_json::from_value::<BatchResult>(json).unwrap(), result);
+
+    let plan = PresetPlan {
+        unchanged: records.records.clone(),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::from_value::<PresetPlan>(round_trip(&plan)).unwrap(),
+        plan
+    );
+    let event = ChangeEvent {
+        zone_id: record.zone_id.clone(),
+        kind: ChangeKind::Deleted,
+        before: Some(record),
+        after: None,
+        observed_at: std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000),
+    };
+    let json = round_trip(&event);
+    assert_eq!(json["kind"], "deleted");
+    assert_eq!(serde_json::from_value::<ChangeEvent>(json).unwrap(), event);
+}
+
+#[test]
+fn test_ttl_parsing_and_limits() {
+    use hetzner::api::dns::records::CreateRecordInput;
+    use hetzner::{HetznerError, Zone};
+
+    assert_eq!("300".parse::<Ttl>().unwrap(), Ttl::FIVE_MINUTES);
+    assert_eq!("5m".parse::<Ttl>().unwrap(), Ttl::FIVE_MINUTES);
+    assert_eq!("1h 30m".parse::<Ttl>().unwrap(), Ttl::new(5400).unwrap());
+    assert_eq!("1d".parse::<Ttl>().unwrap(), Ttl::DAY);
+    assert!("default".parse::<Ttl>().is_err());
+    assert!(matches!(
+        "10s".parse::<Ttl>(),
+        Err(HetznerError::InvalidTtl(_))
+    ));
+    assert!("soon".parse::<Ttl>().is_err());
+    assert!(Ttl::new(10).is_err());
+    assert!(Ttl::new(Ttl::MAX_SECONDS + 1).is_err());
+    assert!(Ttl::try_from(u64::from(u32::MAX) + 60).is_err());
+    assert_eq!(Ttl::new(60).unwrap(), Ttl::MINUTE);
+    assert_eq!(
+        Ttl::try_from(std::time::Duration::from_secs(3600)).unwrap(),
+        Ttl::HOUR
+    );
+
+    assert_eq!(Ttl::HOUR.to_string(), "1h");
+    assert_eq!(Ttl::new(5400).unwrap().to_string(), "1h 30m");
+
+    // TTLs read from JSON go through the same checks, but a zone with a TTL
+    // out of range still parses, with the TTL unset.
+    assert!(serde_json::from_str::<Ttl>("10").is_err());
+    let zone: Zone =
+        serde_json::from_str(r#"{"id": "z1", "name": "example.com", "ttl": 5}"#).unwrap();
+    assert_eq!(zone.ttl, None);
+    let zones: ZonesEnvelope = serde_json::from_str(
+        r#"{"zones": [{"id": "z1", "name": "a.example", "ttl": 5}, {"id": "z2", "name": "b.example", "ttl": 600}]}"#,
+    )
+    .unwrap();
+    assert_eq!(zones.zones[0].ttl, None);
+    assert_eq!(zones.zones[1].ttl, Some(Ttl::new(600).unwrap()));
+    let input: CreateRecordInput = serde_json::from_str(
+        r#"{"name": "www", "type": "A", "value": "192.0.2.1", "zone_id": "z1", "ttl": 600}"#,
+    )
+    .unwrap();
+    assert_eq!(input.ttl, Some(Ttl::new(600).unwrap()));
+}
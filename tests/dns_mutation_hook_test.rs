@@ -0,0 +1,101 @@
+use hetzner::api::dns::records::{Ttl, UpdateRecordInput};
+use hetzner::testing::FakeDnsApi;
+use hetzner::{MutationEvent, MutationOperation, MutationOutcome};
+use std::sync::{Arc, Mutex};
+
+#[tokio::test]
+async fn test_on_mutation_reports_every_write() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let events: Arc<Mutex<Vec<MutationEvent>>> = Arc::default();
+    let sink = events.clone();
+    let client = fake
+        .client()
+        .with_on_mutation(move |event| sink.lock().unwrap().push(event.clone()));
+
+    let created = client
+        .dns()
+        .records(&zone.id)
+        .create("www", "A", "192.0.2.1", Ttl::FIVE_MINUTES)
+        .await
+        .unwrap()
+        .record;
+    client
+        .dns()
+        .record(&created.id)
+        .update(UpdateRecordInput {
+            zone_id: zone.id.clone(),
+            record_type: "A".into(),
+            name: "www".into(),
+            value: "192.0.2.2".into(),
+            ttl: Some(Ttl::FIVE_MINUTES),
+        })
+        .await
+        .unwrap();
+    client.dns().record(&created.id).delete().await.unwrap();
+    client
+        .dns()
+        .records(&zone.id)
+        .create("bad", "BOGUS", "x", Ttl::FIVE_MINUTES)
+        .await
+        .unwrap_err();
+    // Reads are not reported.
+    client.dns().records(&zone.id).list().await.unwrap();
+
+    let events = events.lock().unwrap();
+    let operations: Vec<_> = events.iter().map(|e| e.operation).collect();
+    assert_eq!(
+        operations,
+        [
+            MutationOperation::CreateRecord,
+            MutationOperation::UpdateRecord,
+            MutationOperation::DeleteRecord,
+            MutationOperation::CreateRecord,
+        ]
+    );
+
+    assert_eq!(events[0].after.as_ref().unwrap().id, created.id);
+    assert_eq!(events[1].before.as_ref().unwrap().value, "192.0.2.1");
+    assert_eq!(events[1].after.as_ref().unwrap().value, "192.0.2.2");
+    assert_eq!(events[2].before.as_ref().unwrap().value, "192.0.2.2");
+    assert_eq!(events[2].zone_id.as_deref(), Some(zone.id.as_str()));
+    assert!(
+        events[..3]
+            .iter()
+            .all(|e| e.outcome == MutationOutcome::Succeeded)
+    );
+    assert!(matches!(events[3].outcome, MutationOutcome::Failed(_)));
+    assert!(events.iter().all(|e| !e.elapsed.is_zero()));
+}
+
+#[tokio::test]
+async fn test_on_mutation_carries_correlation_ids() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let record = fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    let events: Arc<Mutex<Vec<MutationEvent>>> = Arc::default();
+    let sink = events.clone();
+    let client = fake
+        .client()
+        .with_on_mutation(move |event| sink.lock().unwrap().push(event.clone()));
+
+    client.dns().record(&record.id).delete().await.unwrap();
+    let operation = client.operation();
+    for name in ["a", "b"] {
+        operation
+            .dns()
+            .records(&zone.id)
+            .create(name, "A", "192.0.2.1", Ttl::FIVE_MINUTES)
+            .await
+            .unwrap();
+    }
+
+    let events = events.lock().unwrap();
+    let ids: Vec<_> = events
+        .iter()
+        .map(|e| e.correlation_id.as_deref().unwrap())
+        .collect();
+    assert_ne!(ids[0], ids[1]);
+    assert_eq!(ids[1], ids[2]);
+    assert_eq!(Some(ids[1]), operation.correlation_id());
+}
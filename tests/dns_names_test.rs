@@ -0,0 +1,101 @@
+use hetzner::testing::FakeDnsApi;
+use hetzner::{HetznerError, Record, RecordName, RecordRef, ZoneEnvelope, ZoneName};
+
+#[test]
+fn test_zone_and_record_names_are_validated() {
+    let zone = ZoneName::new("Example.COM.").unwrap();
+    assert_eq!(zone.as_str(), "example.com");
+    assert_eq!(zone.fqdn(), "example.com.");
+    assert!(zone.contains("WWW.example.com."));
+    assert!(!zone.contains("notexample.com"));
+
+    for bad in ["com", "exa mple.com", "-bad.example.com", "a..b.com", ""] {
+        assert!(
+            matches!(ZoneName::new(bad), Err(HetznerError::InvalidName(_))),
+            "{bad:?}"
+        );
+    }
+    assert!(ZoneName::new(format!("{}.com", "a".repeat(64))).is_err());
+
+    let apex = RecordName::new("").unwrap();
+    assert!(apex.is_apex());
+    assert_eq!(apex, RecordName::new("@").unwrap());
+    assert_eq!(apex.fqdn(&zone), "example.com.");
+
+    let dmarc: RecordName = "_DMARC".parse().unwrap();
+    assert_eq!(dmarc.fqdn(&zone), "_dmarc.example.com.");
+    assert!(RecordName::new("*.dev").unwrap().is_wildcard());
+    assert!(RecordName::new("dev.*").is_err());
+    assert!(RecordName::new("white space").is_err());
+
+    assert_eq!(
+        RecordName::relative_to("www.example.com.", &zone).unwrap(),
+        RecordName::new("www").unwrap()
+    );
+    assert!(
+        RecordName::relative_to("example.com", &zone)
+            .unwrap()
+            .is_apex()
+    );
+    assert!(RecordName::relative_to("www.example.org.", &zone).is_err());
+
+    let parsed: ZoneName = serde_json::from_str("\"Example.com\"").unwrap();
+    assert_eq!(parsed, zone);
+    assert!(serde_json::from_str::<ZoneName>("\"no spaces.com\"").is_err());
+    assert_eq!(serde_json::to_string(&dmarc).unwrap(), "\"_dmarc\"");
+}
+
+#[test]
+fn test_record_fqdn_and_relative_name() {
+    let zone: ZoneEnvelope =
+        serde_json::from_str(include_str!("fixtures/dns/zone_get.json")).unwrap();
+    let zone = zone.zone;
+    assert_eq!(zone.name, "example.com");
+    let record = |name: &str| -> Record {
+        serde_json::from_value(serde_json::json!({
+            "id": "r1",
+            "name": name,
+            "type": "A",
+            "value": "192.0.2.1",
+            "zone_id": zone.id,
+        }))
+        .unwrap()
+    };
+
+    for (name, relative, fqdn) in [
+        ("@", "@", "example.com."),
+        ("", "@", "example.com."),
+        ("example.com.", "@", "example.com."),
+        ("WWW", "www", "www.example.com."),
+        ("_dmarc", "_dmarc", "_dmarc.example.com."),
+        ("*.dev", "*.dev", "*.dev.example.com."),
+        ("api.example.com.", "api", "api.example.com."),
+        ("api.example.com", "api", "api.example.com."),
+        ("www.example.org.", "www.example.org.", "www.example.org."),
+        (
+            "notexample.com",
+            "notexample.com",
+            "notexample.com.example.com.",
+        ),
+    ] {
+        let record = record(name);
+        assert_eq!(record.relative_name(&zone), relative, "{name:?}");
+        assert_eq!(record.fqdn(&zone), fqdn, "{name:?}");
+    }
+}
+
+#[tokio::test]
+async fn test_names_work_as_api_arguments() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let www = fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    let client = fake.client();
+
+    let zone_name = ZoneName::new("example.com").unwrap();
+    let records = client.dns().records(&zone_name).list().await.unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0], www);
+
+    let www_ref = RecordRef::new(zone_name, RecordName::new("WWW").unwrap(), "A");
+    assert_eq!(client.dns().record_ref(&www_ref).get().await.unwrap(), www);
+}
@@ -0,0 +1,38 @@
+use hetzner::testing::FakeDnsApi;
+use serde_json::Value;
+
+#[tokio::test]
+async fn test_export_records_ndjson_writes_one_record_per_line() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let first = fake.add_zone("example.com");
+    let second = fake.add_zone("example.org");
+    for index in 0..150 {
+        fake.add_record(&first.id, &format!("host{index}"), "A", "192.0.2.1");
+    }
+    fake.add_record(&second.id, "www", "CNAME", "example.com.");
+    let client = fake.client();
+
+    let mut output = Vec::new();
+    let written = client
+        .dns()
+        .export_records_ndjson(&mut output)
+        .await
+        .unwrap();
+    assert_eq!(written, 151);
+
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.ends_with('\n'));
+    let lines: Vec<Value> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 151);
+    assert!(
+        lines[..150]
+            .iter()
+            .all(|line| line["zone_name"] == "example.com" && line["zone_id"] == first.id.as_str())
+    );
+    assert_eq!(lines[150]["zone_name"], "example.org");
+    assert_eq!(lines[150]["type"], "CNAME");
+    assert_eq!(lines[150]["value"], "example.com.");
+}
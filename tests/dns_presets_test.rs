@@ -0,0 +1,81 @@
+use hetzner::api::dns::presets;
+use hetzner::testing::FakeDnsApi;
+
+#[tokio::test]
+async fn test_fastmail_preset_is_idempotent() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let old_mx = fake.add_record(&zone.id, "@", "MX", "10 mx.old-provider.net.");
+    let old_spf = fake.add_record(
+        &zone.id,
+        "@",
+        "TXT",
+        "\"v=spf1 include:old-provider.net -all\"",
+    );
+    let verification = fake.add_record(&zone.id, "@", "TXT", "\"google-site-verification=abc\"");
+    let www = fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    let client = fake.client();
+    let presets_api = client.dns().presets(&zone.id);
+
+    let preset = presets::fastmail("example.com");
+    let plan = presets_api.plan(&preset).await.unwrap();
+    assert_eq!(plan.create.len(), 6);
+    let deleted: Vec<_> = plan.delete.iter().map(|r| r.id.as_str()).collect();
+    assert_eq!(deleted.len(), 2);
+    assert!(deleted.contains(&old_mx.id.as_str()) && deleted.contains(&old_spf.id.as_str()));
+    assert!(plan.unchanged.is_empty());
+
+    let result = presets_api.apply_fastmail().await.unwrap();
+    assert!(result.is_success());
+    assert_eq!(result.applied.len(), 8);
+    let records = fake.records(&zone.id);
+    assert_eq!(records.len(), 8);
+    assert!(records.iter().any(|r| r.id == verification.id));
+    assert!(records.iter().any(|r| r.id == www.id));
+    assert!(
+        records
+            .iter()
+            .any(|r| r.name == "fm2._domainkey" && r.value == "fm2.example.com.dkim.fmhosted.com.")
+    );
+
+    let plan = presets_api.plan(&preset).await.unwrap();
+    assert!(plan.is_empty());
+    assert_eq!(plan.unchanged.len(), 6);
+    let again = presets_api.apply_fastmail().await.unwrap();
+    assert!(again.applied.is_empty());
+}
+
+#[tokio::test]
+async fn test_github_pages_replaces_conflicting_records() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    fake.add_record(&zone.id, "@", "A", "185.199.108.153");
+    fake.add_record(&zone.id, "@", "A", "192.0.2.2");
+    let client = fake.client();
+
+    let plan = client
+        .dns()
+        .presets(&zone.id)
+        .plan(&presets::github_pages("octocat"))
+        .await
+        .unwrap();
+    assert_eq!(plan.unchanged.len(), 1);
+    assert_eq!(plan.delete.len(), 2);
+    assert_eq!(plan.create.len(), 8);
+
+    client
+        .dns()
+        .presets(&zone.id)
+        .apply_github_pages("octocat")
+        .await
+        .unwrap();
+    let records = fake.records(&zone.id);
+    let www: Vec<_> = records.iter().filter(|r| r.name == "www").collect();
+    assert_eq!(www.len(), 1);
+    assert_eq!(
+        (www[0].record_type.as_str(), www[0].value.as_str()),
+        ("CNAME", "octocat.github.io.")
+    );
+    assert!(!records.iter().any(|r| r.value == "192.0.2.2"));
+}
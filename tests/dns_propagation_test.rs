@@ -0,0 +1,85 @@
+#![cfg(feature = "propagation")]
+
+use hetzner::HetznerError;
+use hetzner::testing::FakeDnsApi;
+use hickory_proto::op::{Message, OpCode, ResponseCode};
+use hickory_proto::rr::rdata::A;
+use hickory_proto::rr::{Name, RData, Record, RecordType};
+use std::net::{Ipv4Addr, SocketAddr};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::net::UdpSocket;
+
+/// Answers A queries for `www.example.com.` with `stale` for the first
+/// `stale_queries` queries and with 192.0.2.1 afterwards.
+async fn start_resolver(stale: Ipv4Addr, stale_queries: usize) -> SocketAddr {
+    let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let addr = socket.local_addr().unwrap();
+    let www = Name::from_str("www.example.com.").unwrap();
+    let queries = Arc::new(AtomicUsize::new(0));
+    tokio::spawn(async move {
+        let mut buf = vec![0; 4096];
+        loop {
+            let (len, peer) = socket.recv_from(&mut buf).await.unwrap();
+            let request = Message::from_vec(&buf[..len]).unwrap();
+            let mut response = Message::response(request.metadata.id, OpCode::Query);
+            for query in request.queries {
+                let name = query.name().clone();
+                if query.query_type() == RecordType::A && name == www {
+                    let ip = if queries.fetch_add(1, Ordering::SeqCst) < stale_queries {
+                        stale
+                    } else {
+                        Ipv4Addr::new(192, 0, 2, 1)
+                    };
+                    response.add_answer(Record::from_rdata(name, 300, RData::A(A::from(ip))));
+                } else {
+                    response.metadata.response_code = ResponseCode::NXDomain;
+                }
+                response.add_query(query);
+            }
+            let bytes = response.to_vec().unwrap();
+            socket.send_to(&bytes, peer).await.unwrap();
+        }
+    });
+    addr
+}
+
+#[tokio::test]
+async fn test_await_propagation_quorum() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let record = fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    let client = fake.client();
+
+    let stale = Ipv4Addr::new(198, 51, 100, 1);
+    let current = start_resolver(stale, 0).await;
+    let lagging = start_resolver(stale, 2).await;
+    let never = start_resolver(stale, usize::MAX).await;
+
+    let status = client
+        .dns()
+        .await_propagation(&record, &[current, never], 1, Duration::from_secs(2))
+        .await
+        .unwrap();
+    assert!(status.is_propagated());
+    assert_eq!(status.agreeing(), 1);
+    assert_eq!(status.answers[1].values, ["198.51.100.1"]);
+    assert!(!status.answers[1].serves_record);
+
+    let status = client
+        .dns()
+        .await_propagation(&record, &[current, lagging], 2, Duration::from_secs(2))
+        .await
+        .unwrap();
+    assert_eq!(status.agreeing(), 2);
+
+    let err = client
+        .dns()
+        .await_propagation(&record, &[current, never], 2, Duration::from_millis(300))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HetznerError::Timeout(_)), "{err}");
+    assert!(err.to_string().contains("1 of 2 resolvers agree"), "{err}");
+}
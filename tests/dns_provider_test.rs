@@ -0,0 +1,115 @@
+use hetzner::testing::{FakeDnsApi, MockHetznerClient};
+use hetzner::{DnsProvider, DnsRecord, HetznerDnsApi, HetznerError, Ttl};
+
+/// Provider-agnostic code, as a multi-provider tool would write it.
+async fn point_www_at<P: DnsProvider>(provider: &P, zone: &str, ip: &str) -> Result<(), P::Error> {
+    provider
+        .upsert(
+            zone,
+            &DnsRecord::new("www", "A", ip, Some(Ttl::FIVE_MINUTES)),
+        )
+        .await
+}
+
+async fn check_provider(provider: &impl DnsProvider<Error = HetznerError>) {
+    assert_eq!(provider.list_zones().await.unwrap(), ["example.com"]);
+
+    point_www_at(provider, "example.com", "192.0.2.2")
+        .await
+        .unwrap();
+    provider
+        .upsert(
+            "example.com.",
+            &DnsRecord::new("@", "TXT", "v=spf1 -all", None),
+        )
+        .await
+        .unwrap();
+    let mut records = provider.list_records("example.com").await.unwrap();
+    records.sort_by(|a, b| a.name.cmp(&b.name));
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[0].value, "v=spf1 -all");
+    assert_eq!(
+        records[2],
+        DnsRecord::new("www", "A", "192.0.2.2", Some(Ttl::FIVE_MINUTES))
+    );
+
+    let removed = provider
+        .delete(
+            "example.com",
+            &DnsRecord::new("mail", "A", "192.0.2.9", None),
+        )
+        .await
+        .unwrap();
+    assert_eq!(removed, 1);
+    assert_eq!(provider.list_records("example.com").await.unwrap().len(), 2);
+
+    let err = provider.list_records("example.org").await.unwrap_err();
+    assert!(matches!(err, HetznerError::ZoneNotFound(_)));
+}
+
+#[tokio::test]
+async fn test_dns_provider_on_mock_client() {
+    let mock = MockHetznerClient::new();
+    let zone = mock.add_zone("example.com");
+    mock.add_record(&zone.id, "www", "A", "192.0.2.1");
+    mock.add_record(&zone.id, "mail", "A", "192.0.2.9");
+
+    check_provider(&mock).await;
+    let calls = mock.calls();
+    assert!(calls.iter().any(|call| call.starts_with("PUT records/")));
+}
+
+#[tokio::test]
+async fn test_dns_provider_on_hetzner_client() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    fake.add_record(&zone.id, "mail", "A", "192.0.2.9");
+
+    check_provider(&fake.client()).await;
+}
+
+#[tokio::test]
+async fn test_dns_provider_finds_zones_past_the_first_page() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    for i in 0..120 {
+        fake.add_zone(&format!("filler{i:03}.com"));
+    }
+    let late = fake.add_zone("late.org");
+    fake.add_record(&late.id, "www", "A", "192.0.2.1");
+    let client = fake.client();
+
+    assert_eq!(DnsProvider::list_zones(&client).await.unwrap().len(), 121);
+    let records = DnsProvider::list_records(&client, "late.org")
+        .await
+        .unwrap();
+    assert_eq!(records, [DnsRecord::new("www", "A", "192.0.2.1", None)]);
+}
+
+#[tokio::test]
+async fn test_dns_provider_keeps_zone_default_ttl_and_matches_qualified_names() {
+    let mock = MockHetznerClient::new();
+    let zone = mock.add_zone("example.com");
+    let www = mock.add_record(&zone.id, "www", "A", "192.0.2.1");
+
+    DnsProvider::upsert(
+        &mock,
+        "example.com",
+        &DnsRecord::new("www.example.com.", "A", "192.0.2.2", None),
+    )
+    .await
+    .unwrap();
+    DnsProvider::upsert(
+        &mock,
+        "example.com",
+        &DnsRecord::new("@", "TXT", "v=spf1 -all", None),
+    )
+    .await
+    .unwrap();
+
+    let records = HetznerDnsApi::list_records(&mock, &zone.id).await.unwrap();
+    assert_eq!(records.len(), 2);
+    let updated = records.iter().find(|record| record.id == www.id).unwrap();
+    assert_eq!(updated.value, "192.0.2.2");
+    assert!(records.iter().all(|record| record.ttl.is_none()));
+}
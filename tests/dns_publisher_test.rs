@@ -0,0 +1,207 @@
+#![cfg(any(feature = "mqtt", feature = "nats"))]
+
+use futures_util::StreamExt;
+use hetzner::testing::FakeDnsApi;
+use hetzner::{ChangeEvent, ChangeKind, EventPublisher, MutationEvent, MutationOperation};
+use std::time::{Duration, SystemTime};
+use tokio::sync::mpsc;
+
+type Published = mpsc::UnboundedReceiver<(String, Vec<u8>)>;
+
+async fn next(published: &mut Published) -> (String, Vec<u8>) {
+    tokio::time::timeout(Duration::from_secs(5), published.recv())
+        .await
+        .expect("no message published")
+        .unwrap()
+}
+
+/// Whether `topic` matches the subscription `pattern`, with the wildcards
+/// of NATS (`*`, `>`) or MQTT (`+`, `#`).
+fn subscribed(pattern: &str, topic: &str, separator: char) -> bool {
+    let mut topic = topic.split(separator);
+    for token in pattern.split(separator) {
+        match (token, topic.next()) {
+            (">" | "#", Some(_)) => return true,
+            ("*" | "+", Some(_)) => {}
+            (token, Some(part)) if token == part => {}
+            _ => return false,
+        }
+    }
+    topic.next().is_none()
+}
+
+/// Publishes an event of a watch given the zone's name and a write made
+/// through the client, and returns what the bus received for each.
+async fn publish_both(publisher: EventPublisher, published: &mut Published) -> [String; 2] {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let watcher = fake.client();
+    let changes = watcher
+        .dns()
+        .watch_zone("example.com", Duration::from_millis(20));
+    let mut changes = std::pin::pin!(changes);
+    let edit = async {
+        // After the watch's first poll, which only takes a snapshot.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    };
+    let ((), change) = tokio::join!(edit, changes.next());
+    let change = change.unwrap().unwrap();
+    assert_eq!(change.kind, ChangeKind::Created);
+    publisher.publish_change(&change).await.unwrap();
+    let (change_topic, payload) = next(published).await;
+    let received: ChangeEvent = serde_json::from_slice(&payload).unwrap();
+    assert_eq!(received, change);
+
+    let client = fake.client().with_on_mutation(publisher.mutation_hook());
+    client
+        .dns()
+        .records(&zone.id)
+        .create("api", "A", "192.0.2.2", None)
+        .await
+        .unwrap();
+    let (mutation_topic, payload) = next(published).await;
+    let received: MutationEvent = serde_json::from_slice(&payload).unwrap();
+    assert_eq!(received.operation, MutationOperation::CreateRecord);
+    assert_eq!(received.after.unwrap().name, "api");
+
+    [change_topic, mutation_topic]
+}
+
+#[cfg(feature = "nats")]
+#[tokio::test]
+async fn test_event_publisher_publishes_to_nats_subjects() {
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+
+    // Just enough of a NATS server to accept one client's publishes.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let (sender, mut published) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let (reader, mut writer) = stream.into_split();
+        let info = format!(
+            "INFO {{\"server_id\":\"fake\",\"server_name\":\"fake\",\"version\":\"2.10.0\",\
+             \"host\":\"127.0.0.1\",\"port\":{port},\"max_payload\":1048576,\"proto\":1}}\r\n"
+        );
+        writer.write_all(info.as_bytes()).await.unwrap();
+        let mut reader = BufReader::new(reader);
+        let mut line = String::new();
+        while reader.read_line(&mut line).await.unwrap() > 0 {
+            let words: Vec<&str> = line.split_whitespace().collect();
+            match words.as_slice() {
+                ["PING"] => writer.write_all(b"PONG\r\n").await.unwrap(),
+                ["PUB", subject, length] => {
+                    let mut payload = vec![0; length.parse::<usize>().unwrap() + 2];
+                    reader.read_exact(&mut payload).await.unwrap();
+                    payload.truncate(payload.len() - 2);
+                    sender.send((subject.to_string(), payload)).unwrap();
+                }
+                _ => {}
+            }
+            line.clear();
+        }
+    });
+
+    let client = async_nats::connect(format!("nats://127.0.0.1:{port}"))
+        .await
+        .unwrap();
+    let publisher = EventPublisher::nats(client);
+    let [change, mutation] = publish_both(publisher.clone(), &mut published).await;
+    assert!(
+        subscribed("hetzner.dns.*.change.>", &change, '.'),
+        "{change}"
+    );
+    assert!(change.ends_with(".change.created"), "{change}");
+    assert!(
+        subscribed("hetzner.dns.*.mutation.>", &mutation, '.'),
+        "{mutation}"
+    );
+    assert!(mutation.ends_with(".mutation.create_record"), "{mutation}");
+    assert_eq!(
+        change.split('.').nth(2),
+        mutation.split('.').nth(2),
+        "both carry the zone id"
+    );
+
+    // A hand-made event naming the zone still stays one token.
+    let named = ChangeEvent {
+        zone_id: "example.com".to_string(),
+        kind: ChangeKind::Deleted,
+        before: None,
+        after: None,
+        observed_at: SystemTime::now(),
+    };
+    assert_eq!(
+        publisher.change_topic(&named),
+        "hetzner.dns.example_com.change.deleted"
+    );
+}
+
+#[cfg(feature = "mqtt")]
+#[tokio::test]
+async fn test_event_publisher_publishes_to_mqtt_topics() {
+    use rumqttc::{AsyncClient, MqttOptions, QoS};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    // Just enough of an MQTT 3.1.1 broker to accept QoS 0 publishes.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let (sender, mut published) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        while let Ok(header) = stream.read_u8().await {
+            let (mut length, mut shift) = (0usize, 0);
+            loop {
+                let byte = stream.read_u8().await.unwrap();
+                length |= usize::from(byte & 0x7f) << shift;
+                shift += 7;
+                if byte & 0x80 == 0 {
+                    break;
+                }
+            }
+            let mut body = vec![0; length];
+            stream.read_exact(&mut body).await.unwrap();
+            match header >> 4 {
+                // CONNECT -> CONNACK
+                1 => stream.write_all(&[0x20, 0x02, 0x00, 0x00]).await.unwrap(),
+                // PUBLISH
+                3 => {
+                    let topic_length = usize::from(u16::from_be_bytes([body[0], body[1]]));
+                    let topic = String::from_utf8(body[2..2 + topic_length].to_vec()).unwrap();
+                    sender
+                        .send((topic, body[2 + topic_length..].to_vec()))
+                        .unwrap();
+                }
+                // PINGREQ -> PINGRESP
+                12 => stream.write_all(&[0xd0, 0x00]).await.unwrap(),
+                _ => {}
+            }
+        }
+    });
+
+    let (client, mut event_loop) =
+        AsyncClient::new(MqttOptions::new("hetzner-test", "127.0.0.1", port), 10);
+    tokio::spawn(async move { while event_loop.poll().await.is_ok() {} });
+    let publisher = EventPublisher::mqtt(client, QoS::AtMostOnce);
+    let [change, mutation] = publish_both(publisher.clone(), &mut published).await;
+    assert!(
+        subscribed("hetzner/dns/+/change/#", &change, '/'),
+        "{change}"
+    );
+    assert!(change.ends_with("/change/created"), "{change}");
+    assert!(mutation.ends_with("/mutation/create_record"), "{mutation}");
+    assert_eq!(change.split('/').nth(2), mutation.split('/').nth(2));
+
+    let named = ChangeEvent {
+        zone_id: "a/b+#".to_string(),
+        kind: ChangeKind::Deleted,
+        before: None,
+        after: None,
+        observed_at: SystemTime::now(),
+    };
+    assert_eq!(
+        publisher.prefix("dns").change_topic(&named),
+        "dns/a_b__/change/deleted"
+    );
+}
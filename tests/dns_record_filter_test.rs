@@ -0,0 +1,174 @@
+use hetzner::testing::MockHetznerClient;
+use hetzner::{HetznerDnsApi, HetznerError, RecordFilter, RecordType, Sort, SortKey, Ttl};
+
+#[tokio::test]
+async fn test_record_filter() {
+    let mock = MockHetznerClient::new();
+    let zone = mock.add_zone("example.com");
+    mock.add_record(&zone.id, "www", "CNAME", "old-host.example.net.");
+    mock.add_record(&zone.id, "api", "CNAME", "new-host.example.net.");
+    mock.add_record(&zone.id, "www.staging", "A", "192.0.2.1");
+    mock.add_record(&zone.id, "mail", "MX", "10 old-host.example.net.");
+    let records = mock.list_records(&zone.id).await.unwrap();
+
+    let old_cnames = RecordFilter::new()
+        .record_type(RecordType::Cname)
+        .value_contains("old-host");
+    let matched = old_cnames.apply(records.clone());
+    assert_eq!(matched.len(), 1);
+    assert_eq!(matched[0].name, "www");
+
+    let staging = RecordFilter::new()
+        .name_glob("*.staging")
+        .apply(records.clone());
+    assert_eq!(staging.len(), 1);
+    assert_eq!(staging[0].record_type, "A");
+
+    let regex = RecordFilter::new().name_regex("^(www|api)$").unwrap();
+    assert_eq!(regex.apply(records.clone()).len(), 2);
+    let err = RecordFilter::new().name_regex("(www").unwrap_err();
+    assert!(matches!(err, HetznerError::InvalidConfig(_)), "{err}");
+
+    let several = RecordFilter::new()
+        .record_type(RecordType::Mx)
+        .record_type(RecordType::A);
+    assert_eq!(several.apply(records.clone()).len(), 2);
+
+    let mut with_ttl = records[0].clone();
+    with_ttl.ttl = Some(Ttl::FIVE_MINUTES);
+    let ttl = RecordFilter::new().ttl_range(Ttl::MINUTE..=Ttl::new(600).unwrap());
+    assert!(ttl.matches(&with_ttl));
+    assert!(
+        !RecordFilter::new()
+            .ttl_range(..Ttl::FIVE_MINUTES)
+            .matches(&with_ttl)
+    );
+    // Records without an explicit TTL never satisfy a TTL bound.
+    assert!(!ttl.matches(&records[1]));
+
+    assert_eq!("cname".parse::<RecordType>(), Ok(RecordType::Cname));
+    assert!("BOGUS".parse::<RecordType>().is_err());
+}
+
+#[tokio::test]
+async fn test_record_sort() {
+    let mock = MockHetznerClient::new();
+    let zone = mock.add_zone("example.com");
+    mock.add_record(&zone.id, "www", "CNAME", "web.example.net.");
+    mock.add_record(&zone.id, "api", "A", "192.0.2.2");
+    mock.add_record(&zone.id, "Mail", "MX", "10 mx.example.net.");
+    mock.add_record(&zone.id, "api", "AAAA", "2001:db8::1");
+    let mut records = mock.list_records(&zone.id).await.unwrap();
+    records[0].ttl = Some(Ttl::MINUTE);
+    records[2].ttl = Some(Ttl::HOUR);
+
+    let names = |records: &[hetzner::types::Record]| {
+        records
+            .iter()
+            .map(|r| format!("{} {}", r.name, r.record_type))
+            .collect::<Vec<_>>()
+    };
+
+    Sort::by(SortKey::Name).records(&mut records);
+    assert_eq!(
+        names(&records),
+        ["api A", "api AAAA", "Mail MX", "www CNAME"]
+    );
+
+    Sort::by(SortKey::Type).descending().records(&mut records);
+    assert_eq!(
+        names(&records),
+        ["Mail MX", "www CNAME", "api AAAA", "api A"]
+    );
+
+    Sort::by(SortKey::Ttl).records(&mut records);
+    assert_eq!(
+        names(&records),
+        ["api A", "api AAAA", "www CNAME", "Mail MX"]
+    );
+
+    assert_eq!("modified".parse::<SortKey>(), Ok(SortKey::Modified));
+    assert!("size".parse::<SortKey>().is_err());
+}
+
+#[tokio::test]
+async fn test_unknown_record_types_pass_through() {
+    use hetzner::HetznerClient;
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    assert_eq!(RecordType::from("https"), RecordType::Other("HTTPS".into()));
+    assert_eq!(RecordType::from("mx"), RecordType::Mx);
+    assert!(!RecordType::from("SVCB").is_known());
+    assert_eq!(
+        serde_json::from_value::<Vec<RecordType>>(json!(["A", "HTTPS"])).unwrap(),
+        [RecordType::A, RecordType::Other("HTTPS".into())]
+    );
+    assert_eq!(
+        serde_json::to_value(RecordType::Other("HTTPS".into())).unwrap(),
+        "HTTPS"
+    );
+
+    let server = MockServer::start();
+    let client = HetznerClient::new("token").with_dns_base_url(server.base_url());
+    let record = json!({
+        "id": "r1",
+        "zone_id": "z1",
+        "name": "@",
+        "type": "HTTPS",
+        "value": "1 . alpn=h2"
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/records")
+            .json_body_partial(r#"{"type": "HTTPS"}"#);
+        then.status(200).json_body(json!({ "record": record }));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/records");
+        then.status(200).json_body(json!({ "records": [record] }));
+    });
+
+    let created = client
+        .dns()
+        .records("z1")
+        .create(
+            "@",
+            RecordType::Other("HTTPS".into()),
+            "1 . alpn=h2",
+            Ttl::FIVE_MINUTES,
+        )
+        .await
+        .unwrap()
+        .record;
+    assert_eq!(created.kind(), RecordType::Other("HTTPS".into()));
+
+    let records = client.dns().records("z1").list().await.unwrap();
+    let https = RecordFilter::new().record_type(RecordType::from("https"));
+    assert_eq!(https.apply(records).len(), 1);
+}
+
+#[test]
+fn test_record_type_semantics() {
+    assert!(RecordType::A.is_address() && RecordType::Aaaa.is_address());
+    assert!(!RecordType::Cname.is_address());
+    assert!(!RecordType::Cname.allows_multiple_values());
+    assert!(RecordType::Mx.allows_multiple_values());
+    assert!(RecordType::Other("HTTPS".into()).allows_multiple_values());
+    let hosts: Vec<_> = RecordType::ALL
+        .into_iter()
+        .filter(RecordType::requires_fqdn_value)
+        .collect();
+    assert_eq!(
+        hosts,
+        [
+            RecordType::Cname,
+            RecordType::Mx,
+            RecordType::Ns,
+            RecordType::Ptr,
+            RecordType::Srv
+        ]
+    );
+    assert_eq!(RecordType::Ns.default_ttl_hint(), Ttl::DAY);
+    assert_eq!(RecordType::A.default_ttl_hint(), Ttl::HOUR);
+}
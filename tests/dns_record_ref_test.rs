@@ -0,0 +1,123 @@
+use hetzner::testing::FakeDnsApi;
+use hetzner::{HetznerError, RecordRef, RecordType, Ttl};
+
+#[tokio::test]
+async fn test_record_ref_updates_and_deletes_by_name() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let www = fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    fake.add_record(&zone.id, "www", "AAAA", "2001:db8::1");
+    let client = fake.client();
+
+    let www_ref = RecordRef::new("example.com", "WWW.", "a");
+    assert_eq!(client.dns().record_ref(&www_ref).get().await.unwrap(), www);
+
+    let updated = client
+        .dns()
+        .record_ref(&www_ref)
+        .update("192.0.2.9", Ttl::FIVE_MINUTES)
+        .await
+        .unwrap()
+        .record;
+    assert_eq!(updated.id, www.id);
+    assert_eq!(updated.value, "192.0.2.9");
+    assert_eq!(updated.ttl, Some(Ttl::FIVE_MINUTES));
+
+    let updated = client
+        .dns()
+        .record_ref(&www_ref)
+        .update_ttl(Ttl::MINUTE)
+        .await
+        .unwrap()
+        .record;
+    assert_eq!(updated.value, "192.0.2.9");
+
+    client.dns().record_ref(&www_ref).delete().await.unwrap();
+    let left = fake.records(&zone.id);
+    assert_eq!(left.len(), 1);
+    assert_eq!(left[0].record_type, "AAAA");
+    assert!(
+        client
+            .dns()
+            .record_ref(&www_ref)
+            .get_opt()
+            .await
+            .unwrap()
+            .is_none()
+    );
+    let err = client
+        .dns()
+        .record_ref(&www_ref)
+        .delete()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HetznerError::RecordNotFound(_)), "{err}");
+    assert!(err.is_not_found());
+}
+
+#[tokio::test]
+async fn test_record_ref_value_disambiguates() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.add_record(&zone.id, "@", "MX", "10 mx1.example.com.");
+    let second = fake.add_record(&zone.id, "@", "MX", "20 mx2.example.com.");
+    let client = fake.client();
+
+    let any_mx = RecordRef::new(zone.id.as_str(), "@", "MX");
+    let err = client.dns().record_ref(&any_mx).get().await.unwrap_err();
+    assert!(
+        matches!(err, HetznerError::AmbiguousRecord { matches: 2, .. }),
+        "{err}"
+    );
+
+    let mx2 = any_mx.value("20 MX2.example.com");
+    assert_eq!(client.dns().record_ref(&mx2).get().await.unwrap(), second);
+
+    let config: RecordRef = serde_json::from_value(serde_json::json!({
+        "zone": { "name": "example.com" },
+        "name": "@",
+        "type": "MX",
+        "value": "20 mx2.example.com."
+    }))
+    .unwrap();
+    client
+        .dns()
+        .record_ref(&config)
+        .update_value("30 mx2.example.com.")
+        .await
+        .unwrap();
+    assert!(
+        fake.records(&zone.id)
+            .iter()
+            .any(|record| record.value == "30 mx2.example.com.")
+    );
+}
+
+#[tokio::test]
+async fn test_get_record_by_name() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let apex = fake.add_record(&zone.id, "@", "A", "192.0.2.1");
+    let www = fake.add_record(&zone.id, "www", "CNAME", "example.com.");
+    let client = fake.client();
+
+    let found = client
+        .dns()
+        .get_record_by_name("example.com", "WWW.", RecordType::Cname)
+        .await
+        .unwrap();
+    assert_eq!(found, Some(www));
+    let found = client
+        .dns()
+        .records(&zone.id)
+        .get_by_name("", "a")
+        .await
+        .unwrap();
+    assert_eq!(found, Some(apex));
+    let missing = client
+        .dns()
+        .get_record_by_name(&zone.id, "www", "A")
+        .await
+        .unwrap();
+    assert_eq!(missing, None);
+}
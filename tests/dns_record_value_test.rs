@@ -0,0 +1,154 @@
+use hetzner::testing::FakeDnsApi;
+use hetzner::{HetznerError, RecordType, RecordValue};
+
+fn parse(record_type: RecordType, value: &str) -> RecordValue {
+    RecordValue::parse(&record_type, value).unwrap()
+}
+
+#[test]
+fn test_record_value_parsing() {
+    assert_eq!(
+        parse(RecordType::A, " 192.0.2.1 "),
+        RecordValue::A {
+            address: "192.0.2.1".parse().unwrap()
+        }
+    );
+    assert_eq!(
+        parse(RecordType::Aaaa, "2001:DB8::1"),
+        RecordValue::Aaaa {
+            address: "2001:db8::1".parse().unwrap()
+        }
+    );
+    assert_eq!(
+        parse(RecordType::Mx, "10 mail.example.com."),
+        RecordValue::Mx {
+            priority: 10,
+            exchange: "mail.example.com.".to_string()
+        }
+    );
+    assert_eq!(
+        parse(RecordType::Srv, "0 5 5060 sip.example.com."),
+        RecordValue::Srv {
+            priority: 0,
+            weight: 5,
+            port: 5060,
+            target: "sip.example.com.".to_string()
+        }
+    );
+    assert_eq!(
+        parse(RecordType::Txt, r#""v=spf1 " "-all" "say \"hi\"""#),
+        RecordValue::Txt {
+            strings: vec!["v=spf1 ".into(), "-all".into(), "say \"hi\"".into()]
+        }
+    );
+    assert_eq!(
+        parse(RecordType::Txt, "unquoted text"),
+        RecordValue::Txt {
+            strings: vec!["unquoted text".into()]
+        }
+    );
+    assert_eq!(
+        parse(
+            RecordType::Caa,
+            r#"128 issue "letsencrypt.org; validationmethods=dns-01""#
+        ),
+        RecordValue::Caa {
+            flags: 128,
+            tag: "issue".to_string(),
+            value: "letsencrypt.org; validationmethods=dns-01".to_string()
+        }
+    );
+    assert_eq!(
+        parse(RecordType::Hinfo, r#""INTEL" "Linux 6""#),
+        RecordValue::Hinfo {
+            cpu: "INTEL".to_string(),
+            os: "Linux 6".to_string()
+        }
+    );
+    assert!(matches!(
+        parse(
+            RecordType::Soa,
+            "hydrogen.ns.hetzner.com. dns.hetzner.com. 2024010101 86400 10800 3600000 3600"
+        ),
+        RecordValue::Soa {
+            serial: 2024010101,
+            minimum: 3600,
+            ..
+        }
+    ));
+    assert!(matches!(
+        parse(RecordType::Ds, "2371 13 2 1F987CC6583E92DF0890718C42"),
+        RecordValue::Ds {
+            key_tag: 2371,
+            algorithm: 13,
+            digest_type: 2,
+            ..
+        }
+    ));
+    assert_eq!(
+        parse(RecordType::from("SVCB"), "1 . alpn=h2"),
+        RecordValue::Other {
+            record_type: "SVCB".to_string(),
+            value: "1 . alpn=h2".to_string()
+        }
+    );
+
+    let json = serde_json::to_value(parse(RecordType::Mx, "10 mx.example.com.")).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({"type": "MX", "priority": 10, "exchange": "mx.example.com."})
+    );
+    assert_eq!(
+        serde_json::from_value::<RecordValue>(json).unwrap(),
+        parse(RecordType::Mx, "10 mx.example.com.")
+    );
+
+    for (record_type, value) in [
+        (RecordType::A, "2001:db8::1"),
+        (RecordType::Mx, "ten mail.example.com."),
+        (RecordType::Mx, "10"),
+        (RecordType::Srv, "0 5 70000 sip.example.com."),
+        (RecordType::Tlsa, "3 1 1 not-hex"),
+        (RecordType::Caa, "0 issue"),
+    ] {
+        let err = RecordValue::parse(&record_type, value).unwrap_err();
+        assert!(
+            matches!(&err, HetznerError::InvalidRecordValue(message) if message.contains(value)),
+            "{record_type} {value:?}: {err}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_get_all_records_typed() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    fake.add_record(&zone.id, "@", "MX", "10 mail.example.com.");
+    fake.add_record(&zone.id, "_sip._tcp", "SRV", "10 60 5060 sip.example.com.");
+    fake.add_record(&zone.id, "broken", "MX", "mail.example.com.");
+    let client = fake.client();
+
+    let typed = client
+        .dns()
+        .get_all_records_typed("example.com")
+        .await
+        .unwrap();
+    let ports: Vec<u16> = typed
+        .records
+        .iter()
+        .filter_map(|record| match record.value {
+            RecordValue::Srv { port, .. } => Some(port),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(ports, [5060]);
+    assert!(typed.records.iter().any(|record| matches!(
+        &record.value,
+        RecordValue::Mx { priority: 10, exchange } if exchange == "mail.example.com."
+    )));
+    assert_eq!(typed.records.len(), 3);
+    assert_eq!(typed.issues.len(), 1);
+    assert_eq!(typed.issues[0].record.name, "broken");
+    assert_eq!(typed.issues[0].message, "expected 2 fields, got 1");
+}
@@ -0,0 +1,179 @@
+#![cfg(feature = "scheduler")]
+
+use hetzner::testing::FakeDnsApi;
+use hetzner::{HealthProbe, ScheduleStatus, ScheduledChange, Scheduler, Ttl};
+use httpmock::prelude::*;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+fn schedule_path() -> std::path::PathBuf {
+    let suffix = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("hetzner-schedule-{suffix}.json"))
+}
+
+fn values(fake: &FakeDnsApi, zone_id: &str) -> Vec<String> {
+    let mut values: Vec<String> = fake
+        .records(zone_id)
+        .into_iter()
+        .filter(|record| record.name == "www" && record.record_type == "A")
+        .map(|record| record.value)
+        .collect();
+    values.sort();
+    values
+}
+
+#[tokio::test]
+async fn test_scheduler_applies_due_changes_and_persists_status() {
+    let path = schedule_path();
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    fake.add_record(&zone.id, "www", "A", "192.0.2.2");
+    fake.add_record(&zone.id, "mail", "A", "192.0.2.10");
+
+    let scheduler = Scheduler::open(fake.client(), &path).unwrap();
+    let now = SystemTime::now();
+    scheduler
+        .schedule(
+            ScheduledChange::new(
+                "move-www",
+                now - Duration::from_secs(1),
+                "example.com",
+                "www",
+                "A",
+                ["192.0.2.2", "198.51.100.7"],
+            )
+            .ttl(Ttl::MINUTE),
+        )
+        .unwrap();
+    scheduler
+        .schedule(ScheduledChange::new(
+            "later",
+            now + Duration::from_secs(3600),
+            "example.com",
+            "www",
+            "A",
+            ["203.0.113.1"],
+        ))
+        .unwrap();
+
+    let executed = scheduler.run_due().await.unwrap();
+    assert_eq!(executed.len(), 1);
+    assert_eq!(executed[0].id, "move-www");
+    assert_eq!(executed[0].status, ScheduleStatus::Applied);
+    assert_eq!(executed[0].previous.len(), 2);
+    assert_eq!(values(&fake, &zone.id), ["192.0.2.2", "198.51.100.7"]);
+    assert_eq!(fake.records(&zone.id).len(), 3);
+
+    assert!(scheduler.run_due().await.unwrap().is_empty());
+    let reopened = Scheduler::open(fake.client(), &path).unwrap();
+    let statuses: Vec<_> = reopened
+        .changes()
+        .into_iter()
+        .map(|change| (change.id, change.status))
+        .collect();
+    assert_eq!(
+        statuses,
+        [
+            ("move-www".to_string(), ScheduleStatus::Applied),
+            ("later".to_string(), ScheduleStatus::Pending),
+        ]
+    );
+    assert!(reopened.cancel("later").unwrap());
+    assert!(!reopened.cancel("move-www").unwrap());
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn test_scheduler_reverts_when_health_check_fails() {
+    let path = schedule_path();
+    let health = MockServer::start_async().await;
+    let probe = health
+        .mock_async(|when, then| {
+            when.method(GET).path("/healthz");
+            then.status(503);
+        })
+        .await;
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+
+    let scheduler = Scheduler::open(fake.client(), &path).unwrap();
+    scheduler
+        .schedule(
+            ScheduledChange::new(
+                "move-www",
+                SystemTime::now(),
+                zone.id.as_str(),
+                "www",
+                "A",
+                ["198.51.100.7"],
+            )
+            .health_check(HealthProbe::new(health.url("/healthz")).attempts(2)),
+        )
+        .unwrap();
+
+    let executed = scheduler.run_due().await.unwrap();
+    probe.assert_hits_async(2).await;
+    match &executed[0].status {
+        ScheduleStatus::Reverted { reason } => assert!(reason.contains("503"), "{reason}"),
+        other => panic!("expected a revert, got {other:?}"),
+    }
+    assert_eq!(values(&fake, &zone.id), ["192.0.2.1"]);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn test_scheduler_picks_up_changes_from_other_processes() {
+    let path = schedule_path();
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+
+    let daemon = Scheduler::open(fake.client(), &path).unwrap();
+    let now = SystemTime::now();
+    daemon
+        .schedule(ScheduledChange::new(
+            "later",
+            now + Duration::from_secs(3600),
+            "example.com",
+            "www",
+            "A",
+            ["203.0.113.1"],
+        ))
+        .unwrap();
+
+    let other = Scheduler::open(fake.client(), &path).unwrap();
+    other
+        .schedule(ScheduledChange::new(
+            "move-www",
+            now - Duration::from_secs(1),
+            "example.com",
+            "www",
+            "A",
+            ["198.51.100.7"],
+        ))
+        .unwrap();
+
+    let executed = daemon.run_due().await.unwrap();
+    assert_eq!(executed.len(), 1);
+    assert_eq!(executed[0].id, "move-www");
+    assert_eq!(values(&fake, &zone.id), ["198.51.100.7"]);
+
+    let reopened = Scheduler::open(fake.client(), &path).unwrap();
+    let statuses: Vec<_> = reopened
+        .changes()
+        .into_iter()
+        .map(|change| (change.id, change.status))
+        .collect();
+    assert_eq!(
+        statuses,
+        [
+            ("later".to_string(), ScheduleStatus::Pending),
+            ("move-www".to_string(), ScheduleStatus::Applied),
+        ]
+    );
+    let _ = std::fs::remove_file(&path);
+}
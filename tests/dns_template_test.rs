@@ -0,0 +1,105 @@
+use hetzner::testing::FakeDnsApi;
+use hetzner::{HetznerError, RecordTemplate, Ttl};
+use std::collections::BTreeMap;
+
+fn params(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+fn stack() -> RecordTemplate {
+    RecordTemplate::new("agency stack")
+        .record("@", "A", "{ip}", None)
+        .record("www", "CNAME", "{host}.", Some(Ttl::FIVE_MINUTES))
+        .record("@", "TXT", "\"v=spf1 a:mail.{zone} -all\"", None)
+}
+
+#[test]
+fn test_template_render() {
+    let template = stack();
+    assert_eq!(template.placeholders(), ["host", "ip", "zone"]);
+
+    let preset = template
+        .render(&params(&[
+            ("ip", "192.0.2.10"),
+            ("host", "lb.agency.net"),
+            ("zone", "client.com"),
+        ]))
+        .unwrap();
+    assert_eq!(preset.records[0].value, "192.0.2.10");
+    assert_eq!(preset.records[1].value, "lb.agency.net.");
+    assert_eq!(preset.records[2].value, "\"v=spf1 a:mail.client.com -all\"");
+
+    let err = template
+        .render(&params(&[("ip", "192.0.2.10")]))
+        .unwrap_err();
+    assert!(matches!(&err, HetznerError::Template(message) if message.contains("{host}")));
+
+    let escaped = RecordTemplate::new("spf macro")
+        .record("@", "TXT", "\"v=spf1 exists:%{{i}}.{domain} -all\"", None)
+        .render(&params(&[("domain", "spf.example")]))
+        .unwrap();
+    assert_eq!(
+        escaped.records[0].value,
+        "\"v=spf1 exists:%{i}.spf.example -all\""
+    );
+    assert!(
+        RecordTemplate::new("broken")
+            .record("@", "A", "{ip", None)
+            .render(&params(&[("ip", "x")]))
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_template_apply_all() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let first = fake.add_zone("first.com");
+    let second = fake.add_zone("second.com");
+    let client = fake.client();
+    let template = stack();
+
+    let outcomes = template
+        .apply_all(
+            &client,
+            [
+                (
+                    first.id.clone(),
+                    params(&[("ip", "192.0.2.1"), ("host", "a.net")]),
+                ),
+                (
+                    "missing".to_string(),
+                    params(&[("ip", "192.0.2.9"), ("host", "x.net")]),
+                ),
+                (
+                    second.id.clone(),
+                    params(&[("ip", "192.0.2.2"), ("host", "b.net")]),
+                ),
+            ],
+        )
+        .await;
+    assert_eq!(outcomes.len(), 3);
+    assert!(outcomes[0].1.as_ref().unwrap().is_success());
+    assert!(outcomes[1].1.as_ref().unwrap_err().is_not_found());
+    assert!(outcomes[2].1.is_ok());
+
+    let records = fake.records(&second.id);
+    assert_eq!(records.len(), 3);
+    assert!(
+        records
+            .iter()
+            .any(|r| r.value == "\"v=spf1 a:mail.second.com -all\"")
+    );
+
+    let again = template
+        .apply(
+            &client,
+            &first.id,
+            &params(&[("ip", "192.0.2.1"), ("host", "a.net")]),
+        )
+        .await
+        .unwrap();
+    assert!(again.applied.is_empty());
+}
@@ -0,0 +1,71 @@
+use hetzner::api::dns::records::{Ttl, UpdateRecordInput};
+use hetzner::testing::FakeDnsApi;
+use hetzner::{MutationOperation, UndoJournal};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn journal_path() -> std::path::PathBuf {
+    let suffix = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("hetzner-journal-{suffix}.json"))
+}
+
+#[tokio::test]
+async fn test_undo_journal_reverts_in_reverse_order() {
+    let path = journal_path();
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let kept = fake.add_record(&zone.id, "mail", "A", "192.0.2.10");
+    let client = fake
+        .client()
+        .with_undo_journal(UndoJournal::open(&path).unwrap());
+
+    let created = client
+        .dns()
+        .records(&zone.id)
+        .create("www", "A", "192.0.2.1", Ttl::FIVE_MINUTES)
+        .await
+        .unwrap()
+        .record;
+    client
+        .dns()
+        .record(&kept.id)
+        .update(UpdateRecordInput {
+            zone_id: zone.id.clone(),
+            record_type: "A".into(),
+            name: "mail".into(),
+            value: "192.0.2.11".into(),
+            ttl: Some(Ttl::MINUTE),
+        })
+        .await
+        .unwrap();
+    client.dns().record(&kept.id).delete().await.unwrap();
+    assert_eq!(fake.records(&zone.id).len(), 1);
+
+    // Undo the delete: the record comes back with its updated value.
+    let entry = client.undo_last().await.unwrap().unwrap();
+    assert_eq!(entry.operation, MutationOperation::DeleteRecord);
+    let restored = fake
+        .records(&zone.id)
+        .into_iter()
+        .find(|r| r.name == "mail")
+        .unwrap();
+    assert_eq!(restored.value, "192.0.2.11");
+
+    // The update targeted the old id, which no longer exists after re-creation.
+    assert!(client.undo_last().await.is_err());
+
+    let reopened = UndoJournal::open(&path).unwrap();
+    let entries = reopened.entries();
+    assert_eq!(entries.len(), 3);
+    assert!(entries[2].reverted);
+    assert!(!entries[0].reverted);
+
+    // Reverting the create removes the record it made; reverts are not journaled.
+    reopened.revert(&client, &entries[0]).await.unwrap();
+    assert!(fake.records(&zone.id).iter().all(|r| r.id != created.id));
+    assert_eq!(reopened.entries().len(), 3);
+
+    std::fs::remove_file(path).ok();
+}
@@ -0,0 +1,141 @@
+#![cfg(feature = "webhook-emitter")]
+
+use hetzner::api::dns::webhook_emitter::{signature, verify_signature};
+use hetzner::testing::FakeDnsApi;
+use hetzner::{
+    ChangeEvent, ChangeKind, ConstantBackoff, HetznerError, WebhookEmitter, WebhookEvent,
+};
+use httpmock::prelude::*;
+use std::time::{Duration, SystemTime};
+
+const SECRET: &[u8] = b"webhook-secret";
+
+fn header<'a>(request: &'a HttpMockRequest, name: &str) -> Option<&'a str> {
+    request
+        .headers
+        .as_ref()?
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+fn signed(request: &HttpMockRequest) -> bool {
+    let (Some(timestamp), Some(header_value)) = (
+        header(request, "X-Hetzner-Timestamp").and_then(|ts| ts.parse().ok()),
+        header(request, "X-Hetzner-Signature"),
+    ) else {
+        return false;
+    };
+    let body = request.body.as_deref().unwrap_or_default();
+    verify_signature(SECRET, timestamp, body, header_value)
+}
+
+#[tokio::test]
+async fn test_webhook_emitter_signs_and_retries_deliveries() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let record = fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    let server = MockServer::start_async().await;
+    let accepted = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/hook")
+                .header("X-Hetzner-Event", "change")
+                .body_contains("\"type\":\"change\"")
+                .matches(signed);
+            then.status(204);
+        })
+        .await;
+    let emitter = WebhookEmitter::new(&server.url("/hook"), SECRET).unwrap();
+    let event = ChangeEvent {
+        zone_id: zone.id.clone(),
+        kind: ChangeKind::Created,
+        before: None,
+        after: Some(record),
+        observed_at: SystemTime::now(),
+    };
+    emitter.send_change(&event).await.unwrap();
+    accepted.assert_async().await;
+
+    let failing = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/down");
+            then.status(503);
+        })
+        .await;
+    let emitter = WebhookEmitter::new(&server.url("/down"), SECRET)
+        .unwrap()
+        .backoff(ConstantBackoff {
+            delay: Duration::from_millis(1),
+            max_retries: 2,
+        });
+    let err = emitter
+        .send(&WebhookEvent::Change(event))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HetznerError::Webhook(_)), "{err:?}");
+    failing.assert_hits_async(3).await;
+}
+
+#[tokio::test]
+async fn test_webhook_emitter_delivers_client_mutations() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    fake.add_zone("example.com");
+    let server = MockServer::start_async().await;
+    let hook = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/hook")
+                .header("X-Hetzner-Event", "mutation")
+                .body_contains("\"operation\":\"create_record\"")
+                .matches(signed);
+            then.status(200);
+        })
+        .await;
+    let emitter = WebhookEmitter::new(&server.url("/hook"), SECRET).unwrap();
+    let client = fake.client().with_on_mutation(emitter.mutation_hook());
+
+    client
+        .dns()
+        .records("example.com")
+        .create("www", "A", "192.0.2.1", None)
+        .await
+        .unwrap();
+    for _ in 0..100 {
+        if hook.hits_async().await == 1 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    hook.assert_async().await;
+}
+
+#[test]
+fn test_webhook_emitter_requires_https_and_checks_signatures() {
+    for url in [
+        "https://hooks.example.com/dns",
+        "http://127.0.0.1:8080/",
+        "http://[::1]/",
+        "http://localhost/",
+    ] {
+        WebhookEmitter::new(url, SECRET).unwrap();
+    }
+    for url in [
+        "http://hooks.example.com/dns",
+        "ftp://127.0.0.1/",
+        "not a url",
+    ] {
+        let err = WebhookEmitter::new(url, SECRET).unwrap_err();
+        assert!(
+            matches!(err, HetznerError::InvalidConfig(_)),
+            "{url}: {err:?}"
+        );
+    }
+
+    let header = signature(SECRET, 1_700_000_000, b"{}");
+    assert!(header.starts_with("sha256="));
+    assert!(verify_signature(SECRET, 1_700_000_000, b"{}", &header));
+    assert!(!verify_signature(SECRET, 1_700_000_001, b"{}", &header));
+    assert!(!verify_signature(b"other", 1_700_000_000, b"{}", &header));
+    assert!(!verify_signature(SECRET, 1_700_000_000, b"{}", "sha256=zz"));
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "webhook-server")]
+
+use hetzner::testing::FakeDnsApi;
+use hetzner::{HetznerError, Ttl, WebhookServer};
+use serde_json::{Value, json};
+
+async fn start(server: WebhookServer) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/update", listener.local_addr().unwrap());
+    tokio::spawn(server.serve(listener));
+    url
+}
+
+async fn post(url: &str, token: &str, body: Value) -> (u16, Value) {
+    let response = reqwest::Client::new()
+        .post(url)
+        .bearer_auth(token)
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+    let status = response.status().as_u16();
+    (status, response.json().await.unwrap())
+}
+
+#[tokio::test]
+async fn test_webhook_server_applies_allowed_updates() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.add_record(&zone.id, "home", "A", "198.51.100.1");
+    let url = start(
+        WebhookServer::new(fake.client(), "s3cret")
+            .unwrap()
+            .allow("example.com", "home")
+            .allow("Example.com.", "v6")
+            .ttl(Ttl::MINUTE),
+    )
+    .await;
+
+    let update = json!({ "zone": "example.com", "name": "home", "value": "203.0.113.7" });
+    let (status, body) = post(&url, "s3cret", update.clone()).await;
+    assert_eq!(status, 200, "{body}");
+    assert_eq!(body["updated"]["after"]["value"], "203.0.113.7");
+    let (status, body) = post(&url, "s3cret", update).await;
+    assert_eq!(status, 200);
+    assert_eq!(body["unchanged"]["value"], "203.0.113.7");
+
+    let (status, body) = post(
+        &url,
+        "s3cret",
+        json!({ "zone": "example.com", "name": "v6", "value": "2001:db8::7" }),
+    )
+    .await;
+    assert_eq!(status, 200, "{body}");
+    assert_eq!(body["created"]["type"], "AAAA");
+    assert_eq!(body["created"]["ttl"], 60);
+    assert_eq!(fake.records(&zone.id).len(), 2);
+}
+
+#[tokio::test]
+async fn test_webhook_server_rejects_unauthorized_and_unlisted_requests() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let url = start(
+        WebhookServer::new(fake.client(), "s3cret")
+            .unwrap()
+            .allow("example.com", "home"),
+    )
+    .await;
+
+    let update = json!({ "zone": "example.com", "name": "home", "value": "203.0.113.7" });
+    let (status, _) = post(&url, "wrong", update).await;
+    assert_eq!(status, 401);
+
+    let (status, body) = post(
+        &url,
+        "s3cret",
+        json!({ "zone": "example.com", "name": "www", "value": "203.0.113.7" }),
+    )
+    .await;
+    assert_eq!(status, 403);
+    assert_eq!(body["error"], "record is not on the allow-list");
+
+    let (status, _) = post(
+        &url,
+        "s3cret",
+        json!({ "zone": "example.com", "name": "home", "value": "not-an-ip" }),
+    )
+    .await;
+    assert_eq!(status, 400);
+    assert!(fake.records(&zone.id).is_empty());
+
+    // Schema errors are only reported to authenticated callers.
+    let (status, _) = post(&url, "wrong", json!({ "unexpected": true })).await;
+    assert_eq!(status, 401);
+    let (status, body) = post(&url, "s3cret", json!({ "unexpected": true })).await;
+    assert_eq!(status, 400);
+    assert_eq!(body["error"], "body is not a valid update request");
+}
+
+#[tokio::test]
+async fn test_webhook_server_does_not_leak_upstream_errors() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let url = start(
+        WebhookServer::new(fake.client(), "s3cret")
+            .unwrap()
+            .allow("missing.example", "home"),
+    )
+    .await;
+
+    let (status, body) = post(
+        &url,
+        "s3cret",
+        json!({ "zone": "missing.example", "name": "home", "value": "203.0.113.7" }),
+    )
+    .await;
+    assert_eq!(status, 404);
+    assert_eq!(body, json!({ "error": "zone or record not found" }));
+}
+
+#[tokio::test]
+async fn test_webhook_server_refuses_an_empty_token() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    for token in ["", "  "] {
+        let err = WebhookServer::new(fake.client(), token).unwrap_err();
+        assert!(matches!(err, HetznerError::InvalidConfig(_)), "{err}");
+    }
+}
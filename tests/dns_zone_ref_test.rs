@@ -0,0 +1,117 @@
+use hetzner::testing::FakeDnsApi;
+use hetzner::{HetznerClient, HetznerError, Ttl, ZoneRef};
+use httpmock::prelude::*;
+use serde_json::json;
+
+#[test]
+fn test_zone_ref_from_strings() {
+    assert_eq!(
+        ZoneRef::<&str>::from("example.com"),
+        ZoneRef::Name("example.com")
+    );
+    assert_eq!(
+        ZoneRef::<&str>::from("rMu2waTJPbHr4hQ7S3jXk"),
+        ZoneRef::Id("rMu2waTJPbHr4hQ7S3jXk")
+    );
+    assert_eq!(ZoneRef::from("example.com."), ZoneRef::name("example.com."));
+    assert_eq!(ZoneRef::name("example.com.").to_string(), "example.com");
+
+    let config: ZoneRef = serde_json::from_value(json!({ "name": "example.com" })).unwrap();
+    assert_eq!(config, ZoneRef::name("example.com"));
+}
+
+#[tokio::test]
+async fn test_record_and_zone_methods_accept_zone_names() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    let client = fake.client();
+
+    let records = client.dns().records("example.com").list().await.unwrap();
+    assert_eq!(records.len(), 1);
+    let config = ZoneRef::name("Example.COM.");
+    client
+        .dns()
+        .records(&config)
+        .create("mail", "A", "192.0.2.2", Ttl::FIVE_MINUTES)
+        .await
+        .unwrap();
+    assert_eq!(fake.records(&zone.id).len(), 2);
+    assert_eq!(
+        client.dns().zone(&config).get().await.unwrap().zone.id,
+        zone.id
+    );
+    assert_eq!(
+        client.dns().resolve_zone("example.com").await.unwrap(),
+        zone.id
+    );
+
+    client
+        .dns()
+        .batch("example.com")
+        .create("ftp", "CNAME", "www", None)
+        .commit()
+        .await
+        .unwrap();
+    assert!(
+        fake.records(&zone.id)
+            .iter()
+            .any(|record| record.name == "ftp" && record.zone_id == zone.id)
+    );
+
+    let err = client
+        .dns()
+        .records("missing.org")
+        .list()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HetznerError::ZoneNotFound(name) if name == "missing.org"));
+}
+
+#[tokio::test]
+async fn test_zone_names_are_resolved_once_per_client() {
+    let server = MockServer::start();
+    let client = HetznerClient::new("dns-token").with_dns_base_url(server.base_url());
+    let zones_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/zones")
+            .query_param("name", "example.com");
+        then.status(200).json_body(json!({
+            "zones": [{ "id": "zone-1", "name": "example.com" }]
+        }));
+    });
+    let records_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/records")
+            .query_param("zone_id", "zone-1");
+        then.status(200).json_body(json!({ "records": [] }));
+    });
+
+    let clone = client.clone();
+    client.dns().records("example.com").list().await.unwrap();
+    clone.dns().records("example.com").list().await.unwrap();
+    zones_mock.assert_hits(1);
+    records_mock.assert_hits(2);
+
+    client.dns().clear_zone_cache();
+    clone.dns().records("example.com").list().await.unwrap();
+    zones_mock.assert_hits(2);
+}
+
+#[tokio::test]
+async fn test_zone_names_beyond_the_first_page_are_resolved() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    for i in 0..150 {
+        fake.add_zone(&format!("example{i:03}.com"));
+    }
+    let last = fake.add_zone("example.org");
+    let client = fake.client();
+
+    let first_page = client.dns().list_zones().await.unwrap();
+    assert_eq!(first_page.len(), 100);
+    assert!(first_page.iter().all(|zone| zone.id != last.id));
+    assert_eq!(
+        client.dns().resolve_zone("example.org.").await.unwrap(),
+        last.id
+    );
+}
@@ -0,0 +1,50 @@
+use hetzner::Ttl;
+use hetzner::testing::FakeDnsApi;
+
+#[tokio::test]
+async fn test_zone_stats() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let client = fake.client();
+    let records = client.dns().records(&zone.id);
+    records
+        .create("www", "A", "192.0.2.1", Ttl::FIVE_MINUTES)
+        .await
+        .unwrap();
+    records
+        .create("*", "A", "192.0.2.1", Ttl::FIVE_MINUTES)
+        .await
+        .unwrap();
+    records
+        .create("*.dev", "CNAME", "www.example.com.", Ttl::MINUTE)
+        .await
+        .unwrap();
+    records
+        .create("@", "TXT", "\"v=spf1 -all\"", None)
+        .await
+        .unwrap();
+    records
+        .create(
+            "_dmarc",
+            "TXT",
+            "\"v=DMARC1; p=reject; rua=mailto:d@example.com\"",
+            None,
+        )
+        .await
+        .unwrap();
+
+    let stats = client.dns().zone(&zone.id).stats().await.unwrap();
+    assert_eq!(stats.zone_name, "example.com");
+    assert_eq!(stats.total_records, 5);
+    assert_eq!((stats.count_of("a"), stats.count_of("TXT")), (2, 2));
+    assert_eq!(stats.count_of("MX"), 0);
+    assert_eq!(stats.default_ttl_records, 2);
+    assert_eq!(stats.ttl_distribution[&Ttl::FIVE_MINUTES], 2);
+    assert_eq!(stats.ttl_distribution[&Ttl::MINUTE], 1);
+    assert_eq!(stats.ttl_distribution[&zone.default_ttl()], 2);
+    assert!(stats.uses_wildcards());
+    assert_eq!(stats.wildcard_names, ["*", "*.dev"]);
+    let largest: Vec<_> = stats.largest_txt.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(largest, ["_dmarc", "@"]);
+    assert_eq!(stats.largest_txt[1].bytes, "\"v=spf1 -all\"".len());
+}
@@ -0,0 +1,220 @@
+use hetzner::{ApiErrorCode, ConstantBackoff, ErrorContext, HetznerClient, HetznerError, Ttl};
+use httpmock::prelude::*;
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+fn api_error(err: HetznerError) -> hetzner::ApiError {
+    match err {
+        HetznerError::Api(api) => *api,
+        other => panic!("expected api error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_cloud_error_fields() {
+    let server = MockServer::start();
+    let client = HetznerClient::new("token").with_cloud_base_url(server.base_url());
+    server.mock(|when, then| {
+        when.method(GET).path("/servers/1");
+        then.status(422)
+            .header("X-Request-Id", "req-123")
+            .json_body(json!({
+                "error": {
+                    "code": "invalid_input",
+                    "message": "invalid input in field 'name'",
+                    "details": {"fields": [{"name": "name", "messages": ["is too long"]}]}
+                }
+            }));
+    });
+
+    let err = api_error(client.cloud().servers().get(1).await.unwrap_err());
+    assert_eq!(err.status.as_u16(), 422);
+    assert_eq!(err.code, "invalid_input");
+    assert_eq!(err.message, "invalid input in field 'name'");
+    assert_eq!(err.request_id.as_deref(), Some("req-123"));
+    assert!(err.details.is_some());
+    assert!(err.body.contains("is too long"));
+    assert_eq!(err.error_code(), ApiErrorCode::InvalidInput);
+}
+
+#[tokio::test]
+async fn test_dns_numeric_code_and_plain_bodies() {
+    let server = MockServer::start();
+    let client = HetznerClient::new("token").with_dns_base_url(server.base_url());
+    server.mock(|when, then| {
+        when.method(GET).path("/records/missing");
+        then.status(404).json_body(
+            json!({"record": {}, "error": {"message": "record not found", "code": 404}}),
+        );
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/zones");
+        then.status(401)
+            .json_body(json!({"message": "Invalid authentication credentials"}));
+    });
+    server.mock(|when, then| {
+        when.method(DELETE).path("/records/huge");
+        then.status(502).body("x".repeat(5000));
+    });
+
+    let err = api_error(client.dns().record("missing").get().await.unwrap_err());
+    assert_eq!(err.code, "not_found");
+    assert_eq!(err.message, "record not found");
+
+    let err = api_error(client.dns().list_zones().await.unwrap_err());
+    assert_eq!(err.code, "unauthorized");
+    assert_eq!(err.message, "Invalid authentication credentials");
+
+    let err = client.dns().record("huge").delete().await.unwrap_err();
+    assert!(err.is_retryable());
+    let err = api_error(err);
+    assert_eq!(err.status.as_u16(), 502);
+    assert!(err.body.len() < 1100);
+    assert!(err.body.ends_with("(truncated)"));
+}
+
+#[tokio::test]
+async fn test_error_classification() {
+    let server = MockServer::start();
+    let client = HetznerClient::new("token").with_dns_base_url(server.base_url());
+    for (id, status) in [
+        ("limited", 429),
+        ("gone", 404),
+        ("denied", 401),
+        ("bad", 422),
+        ("taken", 409),
+    ] {
+        server.mock(|when, then| {
+            when.method(GET).path(format!("/records/{id}"));
+            then.status(status)
+                .json_body(json!({"error": {"message": "nope", "code": status}}));
+        });
+    }
+
+    let limited = client.dns().record("limited").get().await.unwrap_err();
+    assert!(limited.is_rate_limited() && limited.is_retryable());
+
+    let gone = client.dns().record("gone").get().await.unwrap_err();
+    assert!(gone.is_not_found() && !gone.is_retryable());
+
+    let denied = client.dns().record("denied").get().await.unwrap_err();
+    assert!(denied.is_auth_error() && !denied.is_not_found());
+
+    let bad = client.dns().record("bad").get().await.unwrap_err();
+    assert!(!bad.is_retryable() && !bad.is_rate_limited() && !bad.is_auth_error());
+
+    let taken = client.dns().record("taken").get().await.unwrap_err();
+    assert!(!taken.is_retryable(), "{taken}");
+
+    assert!(HetznerError::ZoneNotFound("example.com".into()).is_not_found());
+}
+
+#[tokio::test]
+async fn test_dns_error_codes() {
+    let server = MockServer::start();
+    let client = HetznerClient::new("token").with_dns_base_url(server.base_url());
+    server.mock(|when, then| {
+        when.method(POST).path("/records");
+        then.status(422).json_body(
+            json!({"error": {"message": "422 Unprocessable Entity: taken name", "code": 422}}),
+        );
+    });
+    server.mock(|when, then| {
+        when.method(PUT).path("/records/r1");
+        then.status(422)
+            .json_body(json!({"error": {"message": "invalid TTL", "code": 422}}));
+    });
+
+    let err = api_error(
+        client
+            .dns()
+            .records("z1")
+            .create("www", "A", "192.0.2.1", Ttl::FIVE_MINUTES)
+            .await
+            .unwrap_err(),
+    );
+    assert_eq!(err.error_code(), ApiErrorCode::TakenName);
+
+    let input = hetzner::api::dns::records::UpdateRecordInput {
+        zone_id: "z1".into(),
+        record_type: "A".into(),
+        name: "www".into(),
+        value: "192.0.2.1".into(),
+        ttl: Some(hetzner::Ttl::MINUTE),
+    };
+    let err = api_error(client.dns().record("r1").update(input).await.unwrap_err());
+    assert_eq!(err.error_code(), ApiErrorCode::InvalidTtl);
+
+    assert_eq!(
+        ApiErrorCode::parse("some_new_code", "whatever"),
+        ApiErrorCode::Unknown("some_new_code".into())
+    );
+    assert_eq!(
+        ApiErrorCode::parse("uniqueness_error", "server with name already exists"),
+        ApiErrorCode::UniquenessError
+    );
+    assert_eq!(
+        ApiErrorCode::parse("invalid_input", "invalid ttl for this record"),
+        ApiErrorCode::InvalidInput
+    );
+}
+
+#[tokio::test]
+async fn test_on_error_receives_failed_requests_with_context() {
+    let server = MockServer::start();
+    let reports: Arc<Mutex<Vec<(String, ErrorContext)>>> = Arc::default();
+    let sink = reports.clone();
+    let client = HetznerClient::new("token")
+        .with_dns_base_url(server.base_url())
+        .with_backoff(ConstantBackoff {
+            delay: Duration::ZERO,
+            max_retries: 2,
+        })
+        .with_on_error(move |err, context| {
+            sink.lock()
+                .unwrap()
+                .push((err.to_string(), context.clone()));
+        });
+    server.mock(|when, then| {
+        when.method(GET).path("/records/flaky");
+        then.status(503)
+            .json_body(json!({"error": {"message": "unavailable", "code": 503}}));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/records/gone");
+        then.status(404)
+            .json_body(json!({"error": {"message": "record not found", "code": 404}}));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/zones");
+        then.status(200).json_body(json!({"zones": []}));
+    });
+
+    client.dns().list_zones().await.unwrap();
+    client.dns().record("flaky").get().await.unwrap_err();
+    let operation = client.operation();
+    operation.dns().record("gone").get().await.unwrap_err();
+    // Resolved without a request, so not reported.
+    operation
+        .dns()
+        .records("missing.org")
+        .list()
+        .await
+        .unwrap_err();
+
+    let reports = reports.lock().unwrap();
+    assert_eq!(reports.len(), 2, "{reports:?}");
+    let (message, context) = &reports[0];
+    assert!(message.contains("unavailable"));
+    assert_eq!(context.method, reqwest::Method::GET);
+    assert_eq!(context.url, format!("{}/records/flaky", server.base_url()));
+    assert_eq!(context.attempts, 3);
+    assert!(context.correlation_id.is_some());
+
+    assert_eq!(reports[1].1.attempts, 1);
+    assert_eq!(
+        reports[1].1.correlation_id.as_deref(),
+        operation.correlation_id()
+    );
+}
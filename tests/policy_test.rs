@@ -0,0 +1,211 @@
+use hetzner::policy::{
+    Decision, MinTtl, NoDelete, PolicySet, ProposedChange, WarnOnly, ZoneRequiresFlag,
+};
+use hetzner::testing::FakeDnsApi;
+use hetzner::{HetznerClient, HetznerError, MutationOperation, RecordType, Ttl};
+use httpmock::prelude::*;
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+#[tokio::test]
+async fn test_policies_deny_changes_before_sending() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let mx = fake.add_record(&zone.id, "@", "MX", "10 mx.example.com.");
+    let www = fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    let client = fake.client().with_policy(
+        PolicySet::new()
+            .with(MinTtl(Ttl::FIVE_MINUTES))
+            .with(NoDelete::of([RecordType::Mx])),
+    );
+
+    let err = client
+        .dns()
+        .records(&zone.id)
+        .create("api", "A", "192.0.2.2", Ttl::new(120).unwrap())
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(&err, HetznerError::PolicyDenied(reason) if reason.contains("TTL 120s")),
+        "{err}"
+    );
+    let err = client.dns().record(&mx.id).delete().await.unwrap_err();
+    assert!(
+        matches!(&err, HetznerError::PolicyDenied(reason) if reason.contains("MX")),
+        "{err}"
+    );
+    assert_eq!(fake.records(&zone.id).len(), 2);
+
+    client
+        .dns()
+        .records(&zone.id)
+        .create("api", "A", "192.0.2.2", None)
+        .await
+        .unwrap();
+    client.dns().record(&www.id).delete().await.unwrap();
+    assert_eq!(fake.records(&zone.id).len(), 2);
+
+    // A batch is checked as a whole: nothing is applied.
+    let err = client
+        .dns()
+        .batch(&zone.id)
+        .create("ok", "A", "192.0.2.3", Ttl::FIVE_MINUTES)
+        .delete(&mx.id)
+        .commit()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HetznerError::PolicyDenied(_)), "{err}");
+    assert_eq!(fake.records(&zone.id).len(), 2);
+}
+
+#[tokio::test]
+async fn test_zone_requires_flag_and_custom_policies() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let prod = fake.add_zone("example.com");
+    let staging = fake.add_zone("example.net");
+    let evaluated = Arc::new(Mutex::new(Vec::new()));
+    let seen = evaluated.clone();
+    let client = fake.client().with_policy(
+        PolicySet::new()
+            .with(ZoneRequiresFlag::new("example.com.", "prod-approved"))
+            .with(WarnOnly(MinTtl(Ttl::FIVE_MINUTES)))
+            .with(move |change: &ProposedChange| {
+                seen.lock()
+                    .unwrap()
+                    .push((change.operation, change.zone_name.clone()));
+                Decision::Allow
+            }),
+    );
+
+    let err = client
+        .dns()
+        .records(&prod.id)
+        .create("www", "A", "192.0.2.1", Ttl::MINUTE)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(&err, HetznerError::PolicyDenied(reason) if reason.contains("prod-approved")),
+        "{err}"
+    );
+    client
+        .dns()
+        .records("example.net")
+        .create("www", "A", "192.0.2.1", Ttl::MINUTE)
+        .await
+        .unwrap();
+    client
+        .clone()
+        .with_flag("prod-approved")
+        .dns()
+        .records(&prod.id)
+        .create("www", "A", "192.0.2.1", Ttl::MINUTE)
+        .await
+        .unwrap();
+
+    assert_eq!(fake.records(&prod.id).len(), 1);
+    assert_eq!(fake.records(&staging.id).len(), 1);
+    assert_eq!(
+        *evaluated.lock().unwrap(),
+        [
+            (
+                MutationOperation::CreateRecord,
+                Some("example.com".to_string())
+            ),
+            (
+                MutationOperation::CreateRecord,
+                Some("example.net".to_string())
+            ),
+            (
+                MutationOperation::CreateRecord,
+                Some("example.com".to_string())
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_policies_deny_changes_they_cannot_judge() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/records/flaky");
+        then.status(503)
+            .json_body(json!({"error": {"message": "unavailable", "code": 503}}));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/records/gone");
+        then.status(404)
+            .json_body(json!({"error": {"message": "not found", "code": 404}}));
+    });
+    let delete = server.mock(|when, then| {
+        when.method(DELETE);
+        then.status(200);
+    });
+    let client = HetznerClient::new("token")
+        .with_dns_base_url(server.base_url())
+        .with_policy(NoDelete::of([RecordType::Mx]));
+
+    // A failed read of the record fails the delete instead of skipping the
+    // check.
+    let err = client.dns().record("flaky").delete().await.unwrap_err();
+    assert!(err.is_retryable(), "{err}");
+    let err = client.dns().record("gone").delete().await.unwrap_err();
+    assert!(
+        matches!(&err, HetznerError::PolicyDenied(reason) if reason.contains("could not be determined")),
+        "{err}"
+    );
+    delete.assert_hits(0);
+}
+
+#[tokio::test]
+async fn test_policies_cover_zones_and_primary_servers() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    let server = fake
+        .client()
+        .dns()
+        .primary_servers(&zone.id)
+        .create("192.0.2.1", 53)
+        .await
+        .unwrap();
+    let client = fake.client().with_policy(
+        PolicySet::new()
+            .with(ZoneRequiresFlag::new("example.com", "prod-approved"))
+            .with(ZoneRequiresFlag::new("example.org", "prod-approved")),
+    );
+
+    let err = client
+        .dns()
+        .create_zone("example.org", None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HetznerError::PolicyDenied(_)), "{err}");
+    let err = client
+        .dns()
+        .primary_servers(&zone.id)
+        .create("192.0.2.2", 53)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HetznerError::PolicyDenied(_)), "{err}");
+    let err = client
+        .dns()
+        .primary_server(&server.id)
+        .delete()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HetznerError::PolicyDenied(_)), "{err}");
+    assert_eq!(fake.zones().len(), 1);
+
+    let approved = client.with_flag("prod-approved");
+    approved
+        .dns()
+        .primary_server(&server.id)
+        .delete()
+        .await
+        .unwrap();
+    approved
+        .dns()
+        .create_zone("example.org", None)
+        .await
+        .unwrap();
+    assert_eq!(fake.zones().len(), 2);
+}
@@ -0,0 +1,69 @@
+use hetzner::testing::FakeDnsApi;
+use hetzner::{ReadCache, ReadCacheStats};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_read_cache_serves_stale_listings_while_refreshing() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    let client = fake
+        .client()
+        .with_read_cache(ReadCache::new(Duration::from_millis(200)));
+    let records = || client.dns().records(&zone.id).list_cached();
+
+    assert_eq!(records().await.unwrap().len(), 1);
+    fake.add_record(&zone.id, "api", "A", "192.0.2.2");
+    // Fresh: the external change is not seen yet.
+    assert_eq!(records().await.unwrap().len(), 1);
+
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    // Stale: served at once, refreshed in the background.
+    assert_eq!(records().await.unwrap().len(), 1);
+    for _ in 0..100 {
+        if client.read_cache_stats().refreshes == 1 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(records().await.unwrap().len(), 2);
+    assert_eq!(
+        client.read_cache_stats(),
+        ReadCacheStats {
+            hits: 2,
+            stale_hits: 1,
+            misses: 1,
+            refreshes: 1,
+            refresh_failures: 0,
+        }
+    );
+
+    // Writes through the client drop the zone's listing.
+    client
+        .dns()
+        .records("example.com")
+        .create("mail", "A", "192.0.2.3", None)
+        .await
+        .unwrap();
+    assert_eq!(records().await.unwrap().len(), 3);
+    assert_eq!(client.read_cache_stats().misses, 2);
+
+    let zones = client.dns().list_zones_cached().await.unwrap();
+    assert_eq!(zones, client.dns().list_zones_cached().await.unwrap());
+    assert_eq!(client.read_cache_stats().misses, 3);
+    assert_eq!(client.read_cache_stats().hits, 3);
+
+    // Without a cache every read goes to the API.
+    let uncached = fake.client();
+    assert_eq!(
+        uncached
+            .dns()
+            .records(&zone.id)
+            .list_cached()
+            .await
+            .unwrap()
+            .len(),
+        3
+    );
+    assert_eq!(uncached.read_cache_stats(), ReadCacheStats::default());
+}
@@ -0,0 +1,185 @@
+use hetzner::{
+    BackoffPolicy, CircuitBreaker, ConstantBackoff, ExponentialBackoff, FibonacciBackoff,
+    HetznerClient, HetznerError, Ttl,
+};
+use httpmock::prelude::*;
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Retries immediately up to `max_retries` times and records each attempt.
+#[derive(Clone, Default)]
+struct Recording {
+    max_retries: u32,
+    attempts: Arc<Mutex<Vec<u32>>>,
+}
+
+impl BackoffPolicy for Recording {
+    fn next_delay(&self, attempt: u32, _error: &HetznerError) -> Option<Duration> {
+        self.attempts.lock().unwrap().push(attempt);
+        (attempt <= self.max_retries).then_some(Duration::ZERO)
+    }
+}
+
+#[tokio::test]
+async fn test_backoff_policy_retries_retryable_errors() {
+    let server = MockServer::start();
+    let unavailable = server.mock(|when, then| {
+        when.method(GET).path("/zones");
+        then.status(503);
+    });
+    let bad_request = server.mock(|when, then| {
+        when.method(GET).path("/records/bad");
+        then.status(422)
+            .json_body(json!({"error": {"message": "invalid", "code": 422}}));
+    });
+    let policy = Recording {
+        max_retries: 2,
+        ..Default::default()
+    };
+    let client = HetznerClient::new("token")
+        .with_dns_base_url(server.base_url())
+        .with_backoff(policy.clone());
+
+    let err = client.dns().list_zones().await.unwrap_err();
+    assert_eq!(err.api_error().unwrap().status.as_u16(), 503);
+    unavailable.assert_hits(3);
+    assert_eq!(*policy.attempts.lock().unwrap(), [1, 2, 3]);
+
+    client.dns().record("bad").get().await.unwrap_err();
+    bad_request.assert_hits(1);
+    assert_eq!(policy.attempts.lock().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_backoff_policy_only_retries_rate_limited_posts() {
+    let server = MockServer::start();
+    let failing = server.mock(|when, then| {
+        when.method(POST)
+            .path("/records")
+            .body_contains("\"failing\"");
+        then.status(500);
+    });
+    let limited = server.mock(|when, then| {
+        when.method(POST)
+            .path("/records")
+            .body_contains("\"limited\"");
+        then.status(429);
+    });
+    let client = HetznerClient::new("token")
+        .with_dns_base_url(server.base_url())
+        .with_backoff(ConstantBackoff {
+            delay: Duration::from_millis(1),
+            max_retries: 2,
+        });
+
+    let records = client.dns().records("z1");
+    records
+        .create("failing", "A", "192.0.2.1", Ttl::FIVE_MINUTES)
+        .await
+        .unwrap_err();
+    failing.assert_hits(1);
+    let err = records
+        .create("limited", "A", "192.0.2.1", Ttl::FIVE_MINUTES)
+        .await
+        .unwrap_err();
+    assert!(err.is_rate_limited());
+    limited.assert_hits(3);
+}
+
+#[test]
+fn test_builtin_backoff_delays() {
+    let err = HetznerError::Timeout("test".into());
+    let delays = |policy: &dyn BackoffPolicy| {
+        (1..=7)
+            .map(|attempt| policy.next_delay(attempt, &err).map(|d| d.as_millis()))
+            .collect::<Vec<_>>()
+    };
+
+    let exponential = ExponentialBackoff {
+        initial: Duration::from_millis(100),
+        max_delay: Duration::from_millis(500),
+        max_retries: 5,
+    };
+    assert_eq!(
+        delays(&exponential),
+        [
+            Some(100),
+            Some(200),
+            Some(400),
+            Some(500),
+            Some(500),
+            None,
+            None
+        ]
+    );
+
+    let fibonacci = FibonacciBackoff {
+        unit: Duration::from_millis(100),
+        max_delay: Duration::from_secs(1),
+        max_retries: 6,
+    };
+    assert_eq!(
+        delays(&fibonacci),
+        [
+            Some(100),
+            Some(100),
+            Some(200),
+            Some(300),
+            Some(500),
+            Some(800),
+            None
+        ]
+    );
+
+    let constant = ConstantBackoff {
+        delay: Duration::from_millis(50),
+        max_retries: 1,
+    };
+    assert_eq!(delays(&constant)[..2], [Some(50), None]);
+}
+
+#[tokio::test]
+async fn test_circuit_breaker_opens_after_consecutive_failures() {
+    let server = MockServer::start();
+    let mut unavailable = server.mock(|when, then| {
+        when.method(GET).path("/zones");
+        then.status(503);
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/records/missing");
+        then.status(404)
+            .json_body(json!({"error": {"message": "record not found", "code": 404}}));
+    });
+    let client = HetznerClient::new("token")
+        .with_dns_base_url(server.base_url())
+        .with_circuit_breaker(CircuitBreaker {
+            failure_threshold: 2,
+            cool_down: Duration::from_millis(200),
+        });
+
+    client.dns().list_zones().await.unwrap_err();
+    // Client errors reset the count.
+    client.dns().record("missing").get().await.unwrap_err();
+    client.dns().list_zones().await.unwrap_err();
+    client.dns().list_zones().await.unwrap_err();
+    unavailable.assert_hits(3);
+
+    let clone = client.clone();
+    let err = clone.dns().list_zones().await.unwrap_err();
+    assert!(
+        matches!(err, HetznerError::CircuitOpen { retry_in } if retry_in <= Duration::from_millis(200)),
+        "{err}"
+    );
+    unavailable.assert_hits(3);
+
+    unavailable.delete();
+    let healthy = server.mock(|when, then| {
+        when.method(GET).path("/zones");
+        then.status(200).json_body(json!({"zones": []}));
+    });
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    client.dns().list_zones().await.unwrap();
+    client.dns().list_zones().await.unwrap();
+    healthy.assert_hits(2);
+}
@@ -0,0 +1,58 @@
+use hetzner::testing::MockHetznerClient;
+use hetzner::{DnsProvider, DnsRecord, StateFile};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn state_path() -> std::path::PathBuf {
+    let suffix = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("hetzner-state-{suffix}.json"))
+}
+
+#[tokio::test]
+async fn test_drift_report_separates_external_and_pending_changes() {
+    let path = state_path();
+    let mock = MockHetznerClient::new();
+    let zone = mock.add_zone("example.com");
+    mock.add_record(&zone.id, "www", "A", "192.0.2.1");
+    mock.add_record(&zone.id, "mail", "A", "192.0.2.2");
+
+    let state = StateFile::open(&path).unwrap();
+    assert!(state.zone("example.com").is_none());
+    state.snapshot(&mock, "example.com").await.unwrap();
+    assert_eq!(state.zone("Example.com.").unwrap().len(), 2);
+
+    let applied = state.zone("example.com").unwrap();
+    let report = state
+        .drift_report(&mock, "example.com", &applied)
+        .await
+        .unwrap();
+    assert!(report.is_clean());
+
+    // Someone edits the zone by hand...
+    let mail = DnsRecord::new("mail", "A", "192.0.2.2", None);
+    let ftp = DnsRecord::new("ftp", "A", "192.0.2.3", None);
+    mock.delete("example.com", &mail).await.unwrap();
+    mock.upsert("example.com", &ftp).await.unwrap();
+    // ...while the spec gains a record that hasn't been applied.
+    let api = DnsRecord::new("api", "A", "192.0.2.4", None);
+    let mut spec = applied.clone();
+    spec.push(api.clone());
+
+    let report = state
+        .drift_report(&mock, "example.com", &spec)
+        .await
+        .unwrap();
+    assert_eq!(report.zone, "example.com");
+    assert_eq!(report.external_removals, [mail]);
+    assert_eq!(report.external_additions.len(), 1);
+    assert_eq!(report.external_additions[0].name, "ftp");
+    assert_eq!(report.pending_additions, [api]);
+    assert!(report.pending_removals.is_empty());
+    assert!(report.has_external_changes() && report.has_pending_changes());
+
+    let reopened = StateFile::open(&path).unwrap();
+    assert_eq!(reopened.zone("example.com"), Some(applied));
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "strict")]
+
+use hetzner::testing::FakeDnsApi;
+use hetzner::{HetznerClient, HetznerError, Record, Zone};
+use httpmock::prelude::*;
+use serde_json::json;
+
+#[test]
+fn test_strict_models_reject_unknown_fields() {
+    let record = json!({
+        "id": "rec-1", "name": "www", "type": "A", "value": "192.0.2.1",
+        "zone_id": "zone-1", "weight": 10
+    });
+    let err = serde_json::from_value::<Record>(record).unwrap_err();
+    assert!(err.to_string().contains("unknown field `weight`"), "{err}");
+
+    let zone = json!({"id": "zone-1", "name": "example.com", "dnssec": true});
+    assert!(serde_json::from_value::<Zone>(zone).is_err());
+}
+
+#[tokio::test]
+async fn test_strict_listings_reject_unknown_fields() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/records");
+        then.status(200)
+            .json_body(json!({"records": [], "notice": "new field"}));
+    });
+    let client = HetznerClient::new("token").with_dns_base_url(server.base_url());
+
+    let err = client
+        .dns()
+        .records("zone-1")
+        .page(1, 100)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HetznerError::Serialization(_)), "{err}");
+}
+
+#[tokio::test]
+async fn test_strict_mode_accepts_the_fake_api() {
+    let fake = FakeDnsApi::start().await.unwrap();
+    let zone = fake.add_zone("example.com");
+    fake.add_record(&zone.id, "www", "A", "192.0.2.1");
+    let client = fake.client();
+
+    assert_eq!(client.dns().list_zones().await.unwrap().len(), 1);
+    assert_eq!(
+        client.dns().records(&zone.id).list().await.unwrap().len(),
+        1
+    );
+}
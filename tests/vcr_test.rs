@@ -0,0 +1,71 @@
+use hetzner::{Cassette, HetznerClient, HetznerError, VcrMode};
+use httpmock::prelude::*;
+use serde_json::json;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn cassette_path() -> std::path::PathBuf {
+    let suffix = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("hetzner-vcr-{suffix}.json"))
+}
+
+#[tokio::test]
+async fn test_record_then_replay() {
+    let path = cassette_path();
+    let server = MockServer::start();
+    let servers_mock = server.mock(|when, then| {
+        when.method(GET).path("/servers").query_param("page", "2");
+        then.status(200)
+            .json_body(json!({"servers": [], "meta": null}));
+    });
+    let missing_mock = server.mock(|when, then| {
+        when.method(DELETE).path("/records/missing");
+        then.status(404)
+            .json_body(json!({"error": {"code": "not_found", "message": "record not found"}}));
+    });
+
+    let recorder = HetznerClient::new("token")
+        .with_cloud_base_url(server.base_url())
+        .with_dns_base_url(server.base_url())
+        .with_cassette(Cassette::open(&path, VcrMode::Record).unwrap());
+    let query = vec![("page".to_string(), "2".to_string())];
+    recorder
+        .cloud()
+        .list_servers(Some(&query), None)
+        .await
+        .unwrap();
+    recorder.dns().record("missing").delete().await.unwrap_err();
+    servers_mock.assert();
+    missing_mock.assert();
+
+    // Replay never reaches the network, so an unroutable base URL is fine.
+    let replayer = HetznerClient::new("token")
+        .with_cloud_base_url("http://127.0.0.1:9")
+        .with_dns_base_url("http://127.0.0.1:9")
+        .with_cassette(Cassette::open(&path, VcrMode::Replay).unwrap());
+    let servers = replayer
+        .cloud()
+        .list_servers(Some(&query), None)
+        .await
+        .unwrap();
+    assert_eq!(servers, json!({"servers": [], "meta": null}));
+
+    let err = replayer.dns().record("missing").delete().await.unwrap_err();
+    assert!(matches!(err, HetznerError::Api(api) if api.code == "not_found"));
+
+    let err = replayer
+        .cloud()
+        .list_servers(Some(&query), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HetznerError::Cassette(_)));
+
+    std::fs::remove_file(&path).ok();
+    let err = Cassette::open(&path, VcrMode::Replay).unwrap_err();
+    assert!(
+        matches!(&err, HetznerError::Cassette(reason) if reason.contains("failed to read")),
+        "{err}"
+    );
+}